@@ -0,0 +1,127 @@
+// File: benches/reader.rs
+// Criterion benchmarks for the reader hot paths against a synthetic fixture
+//
+// Run with `cargo bench --bench reader` (or `-- block_detail` for one group). Both fixtures are
+// built once per run into temp directories: `indexed` has every table, `unindexed` lacks
+// `block_hashes` so hash lookups fall back to the full-chain scan.
+
+use std::path::Path;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use tari_lmdb_inspector::lmdb_reader::{
+    iter_headers_desc, read_block, read_block_with_transactions, read_lmdb_headers_with_filter, search_block_by_hash, BlockFilter,
+    BlockInclude, StreamReader, TransactionKernelRowData, TransactionOutputRowData,
+};
+use tari_lmdb_inspector::testutil::{build_chain, drop_table, ChainSpec, FixtureBlock};
+
+/// Blocks in the benchmark fixtures
+const FIXTURE_BLOCKS: u64 = 1000;
+
+fn spec() -> ChainSpec {
+    ChainSpec { blocks: FIXTURE_BLOCKS, kernels_per_block: 4, outputs_per_block: 6, spends_per_block: 2, ..ChainSpec::default() }
+}
+
+struct Fixture {
+    indexed: tempfile::TempDir,
+    unindexed: tempfile::TempDir,
+    blocks: Vec<FixtureBlock>,
+}
+
+fn fixture() -> Fixture {
+    let indexed = tempfile::tempdir().expect("temp dir");
+    let blocks = build_chain(indexed.path(), &spec()).expect("fixture builds");
+    let unindexed = tempfile::tempdir().expect("temp dir");
+    build_chain(unindexed.path(), &spec()).expect("fixture builds");
+    drop_table(unindexed.path(), "block_hashes").expect("index dropped");
+    let fixture = Fixture { indexed, unindexed, blocks };
+    check_fixture(&fixture);
+    fixture
+}
+
+/// Fail fast if the readers don't see the chain the fixture wrote, so no group times a wrong answer
+fn check_fixture(fixture: &Fixture) {
+    let path = fixture.indexed.path();
+    let tip = FIXTURE_BLOCKS - 1;
+    assert_eq!(fixture.blocks.len() as u64, FIXTURE_BLOCKS);
+    assert_eq!(StreamReader::open(path).unwrap().snapshot().unwrap().tip_height().unwrap(), Some(tip));
+
+    let range = read_lmdb_headers_with_filter(path, "headers", BlockFilter::Range(0, tip)).unwrap();
+    assert_eq!(range.len() as u64, FIXTURE_BLOCKS);
+    let last: Vec<u64> = read_lmdb_headers_with_filter(path, "headers", BlockFilter::LastN(100)).unwrap().iter().map(|b| b.height).collect();
+    assert_eq!(last, (tip - 99..=tip).collect::<Vec<_>>());
+    let desc = iter_headers_desc(path, None, 50).unwrap();
+    assert_eq!((desc.len(), desc[0].height), (50, tip));
+
+    let newest = &fixture.blocks[tip as usize].hash;
+    for dir in [&fixture.indexed, &fixture.unindexed] {
+        let found = search_block_by_hash(dir.path(), newest).unwrap().expect("tip block found");
+        assert_eq!(found.height, tip);
+    }
+}
+
+fn header_scans(c: &mut Criterion, path: &Path) {
+    let mut group = c.benchmark_group("header_scan");
+    for count in [100u64, 1000] {
+        let start = FIXTURE_BLOCKS - count;
+        group.bench_with_input(BenchmarkId::new("range", count), &count, |b, _| {
+            b.iter(|| read_lmdb_headers_with_filter(path, "headers", BlockFilter::Range(start, FIXTURE_BLOCKS - 1)).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("last_n", count), &count, |b, &count| {
+            b.iter(|| read_lmdb_headers_with_filter(path, "headers", BlockFilter::LastN(count as usize)).unwrap())
+        });
+    }
+    group.bench_function("desc_from_tip_50", |b| b.iter(|| iter_headers_desc(path, None, 50).unwrap()));
+    group.finish();
+}
+
+fn block_detail(c: &mut Criterion, path: &Path) {
+    let height = FIXTURE_BLOCKS / 2;
+    let mut group = c.benchmark_group("block_detail");
+    group.bench_function("header_only", |b| b.iter(|| read_block(path, black_box(height), BlockInclude::HEADER).unwrap()));
+    group.bench_function("with_transactions", |b| b.iter(|| read_block_with_transactions(path, black_box(height)).unwrap()));
+    group.finish();
+}
+
+fn prefix_scans(c: &mut Criterion, path: &Path) {
+    let reader = StreamReader::open(path).unwrap();
+    let mut group = c.benchmark_group("prefix_scan");
+    group.bench_function("block_kernels_and_outputs", |b| {
+        let snapshot = reader.snapshot().unwrap();
+        let (height, header) = snapshot.headers(FIXTURE_BLOCKS / 2, FIXTURE_BLOCKS / 2).unwrap().next().unwrap().unwrap();
+        let block_hash = snapshot.block_hash(height, &header);
+        b.iter(|| {
+            let kernels = snapshot.kernels::<TransactionKernelRowData>(&block_hash).unwrap().count();
+            let outputs = snapshot.outputs::<TransactionOutputRowData>(&block_hash).unwrap().count();
+            black_box(kernels + outputs)
+        })
+    });
+    group.bench_function("all_kernels", |b| {
+        let snapshot = reader.snapshot().unwrap();
+        b.iter(|| snapshot.kernels::<TransactionKernelRowData>(&[]).unwrap().count())
+    });
+    group.finish();
+}
+
+fn hash_resolution(c: &mut Criterion, fixture: &Fixture) {
+    let newest = &fixture.blocks[fixture.blocks.len() - 1].hash;
+    let oldest = &fixture.blocks[1].hash;
+    let mut group = c.benchmark_group("hash_resolution");
+    group.bench_function("indexed", |b| b.iter(|| search_block_by_hash(fixture.indexed.path(), black_box(newest)).unwrap()));
+    // The fallback scans height chunks in parallel; a hit in the first and in the last chunk
+    group.sample_size(10);
+    group.bench_function("scan_tip", |b| b.iter(|| search_block_by_hash(fixture.unindexed.path(), black_box(newest)).unwrap()));
+    group.bench_function("scan_genesis", |b| b.iter(|| search_block_by_hash(fixture.unindexed.path(), black_box(oldest)).unwrap()));
+    group.finish();
+}
+
+fn reader_benches(c: &mut Criterion) {
+    let fixture = fixture();
+    let path = fixture.indexed.path();
+    header_scans(c, path);
+    block_detail(c, path);
+    prefix_scans(c, path);
+    hash_resolution(c, &fixture);
+}
+
+criterion_group!(benches, reader_benches);
+criterion_main!(benches);
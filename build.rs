@@ -0,0 +1,38 @@
+// File: build.rs
+// Build metadata for `/api/version`: the inspector's git commit and the Tari revision its
+// header and transaction types come from. Both are optional; a source tarball without `.git`
+// or `Cargo.lock` still builds and reports them as unknown.
+
+use std::process::Command;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    if let Ok(head) = std::fs::read_to_string(".git/HEAD") {
+        if let Some(reference) = head.trim().strip_prefix("ref: ") {
+            println!("cargo:rerun-if-changed=.git/{}", reference);
+        }
+    }
+
+    let git_hash = Command::new("git")
+        .args(["rev-parse", "--short=12", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok());
+    if let Some(hash) = git_hash {
+        println!("cargo:rustc-env=INSPECTOR_GIT_HASH={}", hash.trim());
+    }
+
+    // The `tari_core` entry of the lock file names the version and commit the decoders target
+    let lock = std::fs::read_to_string("Cargo.lock").unwrap_or_default();
+    let Some(entry) = lock.split("[[package]]").find(|entry| entry.contains("name = \"tari_core\"")) else { return };
+    for line in entry.lines() {
+        if let Some(version) = line.strip_prefix("version = ") {
+            println!("cargo:rustc-env=INSPECTOR_TARI_VERSION={}", version.trim_matches('"'));
+        }
+        if let Some((_, revision)) = line.strip_prefix("source = ").and_then(|source| source.trim_matches('"').split_once('#')) {
+            println!("cargo:rustc-env=INSPECTOR_TARI_REVISION={}", revision);
+        }
+    }
+}
@@ -0,0 +1,204 @@
+// File: src/analytics/burns.rs
+// Version: 1.0.0 - Burned outputs, burn kernels and cumulative burned value
+// Tree: tari-lmdb-inspector/src/analytics/burns.rs
+//
+// A burn is an output with `OutputType::Burn` plus a kernel flagged `BURN_KERNEL` whose
+// `burn_commitment` names the burned output. Burn outputs use revealed-value range proofs,
+// so `minimum_value_promise` is the burned amount. Burn outputs never enter the UTXO set.
+
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use tari_utilities::byte_array::ByteArray;
+
+use tari_node_components::blocks::BlockHeader;
+use crate::lmdb_reader::{
+    OutputKind, ReaderError, ReaderResult, Snapshot, StreamReader, TransactionKernelRowData, TransactionOutputRowData,
+};
+use crate::lmdb_reader::parallel::{par_map_chunks, DEFAULT_CHUNK_SIZE};
+
+/// Default number of blocks scanned
+pub const DEFAULT_WINDOW: u64 = 10_000;
+
+/// Largest window accepted from API callers (covers mainnet from genesis)
+pub const MAX_WINDOW: u64 = 1_000_000;
+
+/// A burn output
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BurnedOutput {
+    pub height: u64,
+    pub commitment: String,
+    /// Burned value in µT
+    pub value: u64,
+    /// Whether a burn kernel in the same block commits to this output
+    pub has_burn_kernel: bool,
+}
+
+/// A kernel flagged as a burn
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BurnKernel {
+    pub height: u64,
+    pub excess: String,
+    /// Commitment of the burned output, if the kernel carries one
+    pub burn_commitment: Option<String>,
+}
+
+/// Burned value in one block and the running total over the window
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockBurns {
+    pub height: u64,
+    pub timestamp: u64,
+    pub burn_outputs: usize,
+    /// µT burned in this block
+    pub burned: u64,
+    /// µT burned from the start of the window up to and including this block
+    pub cumulative_burned: u64,
+}
+
+/// Burn analytics for a window of blocks; only blocks containing burns are listed
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BurnAnalytics {
+    pub window: u64,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub blocks: Vec<BlockBurns>,
+    pub outputs: Vec<BurnedOutput>,
+    pub kernels: Vec<BurnKernel>,
+    /// µT burned across the window
+    pub total_burned: u64,
+    /// Headers that could not be decoded (C29-mined blocks)
+    pub blocks_skipped: usize,
+}
+
+/// Burns found in one block
+struct ScannedBlock {
+    height: u64,
+    timestamp: u64,
+    outputs: Vec<BurnedOutput>,
+    kernels: Vec<BurnKernel>,
+}
+
+/// Collect burn outputs and burn kernels over the last `window` blocks
+/// Large windows are split across a rayon pool, one read transaction per chunk
+pub fn burn_analytics(path: &Path, window: u64) -> ReaderResult<BurnAnalytics> {
+    let window = window.clamp(1, MAX_WINDOW);
+    let reader = StreamReader::open(path)?;
+    let end_height = reader.snapshot()?.tip_height()?.unwrap_or(0);
+    let start_height = end_height.saturating_sub(window - 1);
+
+    // `None` marks a header that could not be decoded
+    let per_block = par_map_chunks(&reader, start_height, end_height, DEFAULT_CHUNK_SIZE, |snapshot, start, end| {
+        let mut rows = Vec::new();
+        for entry in snapshot.headers(start, end)? {
+            let (height, header) = match entry {
+                Ok(entry) => entry,
+                // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                Err(ReaderError::UnsupportedHeader { .. }) => {
+                    rows.push(None);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            rows.push(Some(block_burns(snapshot, height, &header)?));
+        }
+        Ok(rows)
+    })?;
+
+    let mut blocks = Vec::new();
+    let mut outputs = Vec::new();
+    let mut kernels = Vec::new();
+    let mut cumulative_burned = 0;
+    let mut blocks_skipped = 0;
+    for scanned in per_block {
+        let Some(scanned) = scanned else {
+            blocks_skipped += 1;
+            continue;
+        };
+        if scanned.outputs.is_empty() && scanned.kernels.is_empty() {
+            continue;
+        }
+        let burned: u64 = scanned.outputs.iter().map(|o| o.value).sum();
+        cumulative_burned += burned;
+        blocks.push(BlockBurns {
+            height: scanned.height,
+            timestamp: scanned.timestamp,
+            burn_outputs: scanned.outputs.len(),
+            burned,
+            cumulative_burned,
+        });
+        outputs.extend(scanned.outputs);
+        kernels.extend(scanned.kernels);
+    }
+
+    Ok(BurnAnalytics {
+        window,
+        start_height,
+        end_height,
+        blocks,
+        outputs,
+        kernels,
+        total_burned: cumulative_burned,
+        blocks_skipped,
+    })
+}
+
+/// Burn outputs and burn kernels of one block, with outputs matched to kernel commitments
+fn block_burns(snapshot: &Snapshot<'_>, height: u64, header: &BlockHeader) -> ReaderResult<ScannedBlock> {
+    let block_hash = snapshot.block_hash(height, header);
+
+    let mut kernels = Vec::new();
+    for row in snapshot.kernels::<TransactionKernelRowData>(&block_hash)? {
+        let row = row?;
+        if row.kernel.features.is_burned() {
+            kernels.push(BurnKernel {
+                height,
+                excess: hex::encode(row.kernel.excess.as_bytes()),
+                burn_commitment: row.kernel.burn_commitment.as_ref().map(|c| hex::encode(c.as_bytes())),
+            });
+        }
+    }
+
+    let mut outputs = Vec::new();
+    for row in snapshot.outputs::<TransactionOutputRowData>(&block_hash)? {
+        let row = row?;
+        if !OutputKind::of(&row.output.features.output_type).is_burn() {
+            continue;
+        }
+        let commitment = hex::encode(row.output.commitment.as_bytes());
+        outputs.push(BurnedOutput {
+            height,
+            has_burn_kernel: kernels.iter().any(|k| k.burn_commitment.as_deref() == Some(commitment.as_str())),
+            commitment,
+            value: row.output.minimum_value_promise.as_u64(),
+        });
+    }
+
+    Ok(ScannedBlock {
+        height,
+        timestamp: header.timestamp.as_u64(),
+        outputs,
+        kernels,
+    })
+}
+
+/// Print the burn report for the CLI
+pub fn print_burns(report: &BurnAnalytics) {
+    println!("🔥 Burns in blocks {}..={} ({} blocks scanned)", report.start_height, report.end_height, report.window);
+    println!("┌─────────┬──────────────────────┬─────────────────┬────────┐");
+    println!("│ Height  │ Commitment           │ Burned (T)      │ Kernel │");
+    println!("├─────────┼──────────────────────┼─────────────────┼────────┤");
+    for output in &report.outputs {
+        println!("│ {:>7} │ {:<20} │ {:>15.6} │ {:<6} │",
+            output.height,
+            &output.commitment[..20.min(output.commitment.len())],
+            output.value as f64 / 1_000_000.0,
+            if output.has_burn_kernel { "yes" } else { "no" });
+    }
+    println!("└─────────┴──────────────────────┴─────────────────┴────────┘");
+    println!("Burn outputs: {} in {} blocks", report.outputs.len(), report.blocks.len());
+    println!("Burn kernels: {}", report.kernels.len());
+    println!("Total burned: {:.6} T", report.total_burned as f64 / 1_000_000.0);
+    if report.blocks_skipped > 0 {
+        println!("Note: Skipped {} blocks mined with C29 algorithm.", report.blocks_skipped);
+    }
+}
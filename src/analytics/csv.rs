@@ -0,0 +1,197 @@
+// File: src/analytics/csv.rs
+// Version: 1.1.0 - Timestamp anomalies table
+// Tree: tari-lmdb-inspector/src/analytics/csv.rs
+//
+// Each analytics response renders the series its chart draws (one row per block, bucket or
+// sample); window totals and summaries stay JSON-only. Values are written as they appear in the
+// JSON body, amounts in µT, and fields are quoted per RFC 4180 only when they need it.
+
+use super::burns::BurnAnalytics;
+use super::coinbase::LockedCoinbaseReport;
+use super::fees::FeeAnalytics;
+use super::intervals::IntervalHistogram;
+use super::pow_share::PowShare;
+use super::top_blocks::TopBlocks;
+use super::tx_volume::TxVolume;
+use super::utxo_growth::UtxoGrowth;
+use crate::audit::timestamps::TimestampAnomalies;
+
+/// A response that can be downloaded as a CSV table
+pub trait CsvTable {
+    /// Column names
+    fn columns(&self) -> Vec<String>;
+
+    /// One row per chart point, with as many fields as `columns`
+    fn rows(&self) -> Vec<Vec<String>>;
+}
+
+/// Render `table` as CSV with a header line and CRLF line endings
+pub fn to_csv(table: &dyn CsvTable) -> String {
+    let mut csv = String::new();
+    for row in std::iter::once(table.columns()).chain(table.rows()) {
+        let fields: Vec<String> = row.iter().map(|field| escape(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Quote a field containing a comma, quote or line break, doubling inner quotes
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn columns(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+impl CsvTable for FeeAnalytics {
+    fn columns(&self) -> Vec<String> {
+        columns(&["height", "timestamp", "kernel_count", "total_fees", "min_fee", "median_fee", "p90_fee", "max_fee"])
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.blocks.iter().map(|b| vec![
+            b.height.to_string(),
+            b.timestamp.to_string(),
+            b.kernel_count.to_string(),
+            b.total_fees.to_string(),
+            b.min_fee.to_string(),
+            b.median_fee.to_string(),
+            b.p90_fee.to_string(),
+            b.max_fee.to_string(),
+        ]).collect()
+    }
+}
+
+impl CsvTable for IntervalHistogram {
+    fn columns(&self) -> Vec<String> {
+        columns(&["label", "min_seconds", "max_seconds", "count"])
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.buckets.iter().map(|b| vec![
+            b.label.clone(),
+            b.min_seconds.to_string(),
+            optional(b.max_seconds),
+            b.count.to_string(),
+        ]).collect()
+    }
+}
+
+impl CsvTable for UtxoGrowth {
+    fn columns(&self) -> Vec<String> {
+        columns(&["height", "timestamp", "utxo_set_size"])
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.samples.iter().map(|s| vec![
+            s.height.to_string(),
+            s.timestamp.to_string(),
+            s.utxo_set_size.to_string(),
+        ]).collect()
+    }
+}
+
+impl CsvTable for LockedCoinbaseReport {
+    fn columns(&self) -> Vec<String> {
+        columns(&["height", "commitment", "value", "maturity", "blocks_remaining"])
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.outputs.iter().map(|o| vec![
+            o.height.to_string(),
+            o.commitment.clone(),
+            o.value.to_string(),
+            o.maturity.to_string(),
+            o.blocks_remaining.to_string(),
+        ]).collect()
+    }
+}
+
+impl CsvTable for BurnAnalytics {
+    fn columns(&self) -> Vec<String> {
+        columns(&["height", "timestamp", "burn_outputs", "burned", "cumulative_burned"])
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.blocks.iter().map(|b| vec![
+            b.height.to_string(),
+            b.timestamp.to_string(),
+            b.burn_outputs.to_string(),
+            b.burned.to_string(),
+            b.cumulative_burned.to_string(),
+        ]).collect()
+    }
+}
+
+impl CsvTable for PowShare {
+    /// `height` and one percentage column per algorithm
+    fn columns(&self) -> Vec<String> {
+        std::iter::once("height".to_string()).chain(self.algorithms.iter().cloned()).collect()
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.points.iter().map(|p| {
+            std::iter::once(p.height.to_string())
+                .chain(self.algorithms.iter().map(|algo| p.shares.get(algo).copied().unwrap_or(0.0).to_string()))
+                .collect()
+        }).collect()
+    }
+}
+
+impl CsvTable for TopBlocks {
+    fn columns(&self) -> Vec<String> {
+        columns(&["height", "hash", "timestamp", "kernels", "outputs", "inputs", "total_fees"])
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.blocks.iter().map(|b| vec![
+            b.height.to_string(),
+            b.hash.clone(),
+            b.timestamp.to_string(),
+            b.kernels.to_string(),
+            b.outputs.to_string(),
+            b.inputs.to_string(),
+            b.total_fees.to_string(),
+        ]).collect()
+    }
+}
+
+impl CsvTable for TxVolume {
+    fn columns(&self) -> Vec<String> {
+        columns(&["height", "kernels", "blocks"])
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.points.iter().map(|p| vec![
+            p.height.to_string(),
+            p.kernels.to_string(),
+            p.blocks.to_string(),
+        ]).collect()
+    }
+}
+
+impl CsvTable for TimestampAnomalies {
+    fn columns(&self) -> Vec<String> {
+        columns(&["height", "timestamp", "kind", "reference", "seconds"])
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        self.anomalies.iter().map(|a| vec![
+            a.height.to_string(),
+            a.timestamp.to_string(),
+            a.kind.as_str().to_string(),
+            a.reference.to_string(),
+            a.seconds.to_string(),
+        ]).collect()
+    }
+}
@@ -0,0 +1,154 @@
+// File: src/analytics/fees.rs
+// Version: 1.1.0 - Per-block fee reads split across a rayon pool
+// Tree: tari-lmdb-inspector/src/analytics/fees.rs
+//
+// Aggregates kernel fees over the last N blocks. Coinbase kernels carry no fee and are
+// excluded so they do not drag every percentile to zero.
+
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use tari_node_components::blocks::BlockHeader;
+use crate::analytics::percentile;
+use crate::lmdb_reader::{ReaderError, ReaderResult, Snapshot, StreamReader, TransactionKernelRowData};
+use crate::lmdb_reader::parallel::{par_map_chunks, DEFAULT_CHUNK_SIZE};
+
+/// Default number of blocks aggregated
+pub const DEFAULT_WINDOW: u64 = 1000;
+
+/// Largest window accepted from API callers
+pub const MAX_WINDOW: u64 = 10_000;
+
+/// Fee statistics for a single block (fees in µT)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockFeeStats {
+    pub height: u64,
+    pub timestamp: u64,
+    pub kernel_count: usize,
+    pub total_fees: u64,
+    pub min_fee: u64,
+    pub median_fee: u64,
+    pub p90_fee: u64,
+    pub max_fee: u64,
+}
+
+/// Fee distribution across the whole window (fees in µT)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct FeeSummary {
+    pub kernel_count: usize,
+    pub total_fees: u64,
+    pub median_fee: u64,
+    pub p90_fee: u64,
+    pub p99_fee: u64,
+    pub max_fee: u64,
+    /// Histogram as (upper bound in µT, kernel count); the last bucket is open-ended
+    #[schema(value_type = Vec<Vec<u64>>)]
+    pub histogram: Vec<(u64, usize)>,
+}
+
+/// Fee analytics for a window of blocks
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FeeAnalytics {
+    pub window: u64,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub blocks: Vec<BlockFeeStats>,
+    pub summary: FeeSummary,
+    /// Headers that could not be decoded (C29-mined blocks)
+    pub blocks_skipped: usize,
+}
+
+/// Upper bounds (µT) of the histogram buckets
+const HISTOGRAM_BOUNDS: [u64; 8] = [100, 500, 1_000, 5_000, 10_000, 50_000, 100_000, u64::MAX];
+
+/// Aggregate kernel fees over the last `window` blocks
+/// Large windows are split across a rayon pool, one read transaction per chunk
+pub fn fee_analytics(path: &Path, window: u64) -> ReaderResult<FeeAnalytics> {
+    let window = window.clamp(1, MAX_WINDOW);
+    let reader = StreamReader::open(path)?;
+    let end_height = reader.snapshot()?.tip_height()?.unwrap_or(0);
+    let start_height = end_height.saturating_sub(window - 1);
+
+    // `None` marks a header that could not be decoded
+    let per_block = par_map_chunks(&reader, start_height, end_height, DEFAULT_CHUNK_SIZE, |snapshot, start, end| {
+        let mut rows = Vec::new();
+        for entry in snapshot.headers(start, end)? {
+            let (height, header) = match entry {
+                Ok(entry) => entry,
+                // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                Err(ReaderError::UnsupportedHeader { .. }) => {
+                    rows.push(None);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            rows.push(Some(block_fees(snapshot, height, &header)?));
+        }
+        Ok(rows)
+    })?;
+
+    let mut blocks = Vec::new();
+    let mut all_fees = Vec::new();
+    let mut blocks_skipped = 0;
+    for row in per_block {
+        match row {
+            Some((stats, fees)) => {
+                blocks.push(stats);
+                all_fees.extend(fees);
+            }
+            None => blocks_skipped += 1,
+        }
+    }
+
+    all_fees.sort_unstable();
+    let histogram = HISTOGRAM_BOUNDS.iter().enumerate().map(|(i, upper)| {
+        let lower = if i == 0 { 0 } else { HISTOGRAM_BOUNDS[i - 1] };
+        let count = all_fees.iter().filter(|&&fee| fee >= lower && (fee < *upper || *upper == u64::MAX)).count();
+        (*upper, count)
+    }).collect();
+
+    let summary = FeeSummary {
+        kernel_count: all_fees.len(),
+        total_fees: all_fees.iter().sum(),
+        median_fee: percentile(&all_fees, 0.5),
+        p90_fee: percentile(&all_fees, 0.9),
+        p99_fee: percentile(&all_fees, 0.99),
+        max_fee: all_fees.last().copied().unwrap_or(0),
+        histogram,
+    };
+
+    Ok(FeeAnalytics {
+        window,
+        start_height,
+        end_height,
+        blocks,
+        summary,
+        blocks_skipped,
+    })
+}
+
+/// Fee stats for one block plus its sorted non-coinbase fees
+fn block_fees(snapshot: &Snapshot<'_>, height: u64, header: &BlockHeader) -> ReaderResult<(BlockFeeStats, Vec<u64>)> {
+    let block_hash = snapshot.block_hash(height, header);
+    let mut fees = Vec::new();
+    for row in snapshot.kernels::<TransactionKernelRowData>(&block_hash)? {
+        let row = row?;
+        if !row.kernel.features.is_coinbase() {
+            fees.push(row.kernel.fee.0);
+        }
+    }
+
+    fees.sort_unstable();
+    let stats = BlockFeeStats {
+        height,
+        timestamp: header.timestamp.as_u64(),
+        kernel_count: fees.len(),
+        total_fees: fees.iter().sum(),
+        min_fee: fees.first().copied().unwrap_or(0),
+        median_fee: percentile(&fees, 0.5),
+        p90_fee: percentile(&fees, 0.9),
+        max_fee: fees.last().copied().unwrap_or(0),
+    };
+    Ok((stats, fees))
+}
@@ -0,0 +1,118 @@
+// File: src/analytics/intervals.rs
+// Version: 1.1.0 - Stream headers instead of per-height lookups
+// Tree: tari-lmdb-inspector/src/analytics/intervals.rs
+
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::lmdb_reader::{ReaderResult, StreamReader};
+
+/// Default number of blocks analysed
+pub const DEFAULT_WINDOW: u64 = 1000;
+
+/// Largest window accepted from API callers
+pub const MAX_WINDOW: u64 = 100_000;
+
+/// Bucket definitions: (label, lower bound inclusive, upper bound exclusive) in seconds
+const BUCKETS: [(&str, u64, u64); 5] = [
+    ("<30s", 0, 30),
+    ("30-60s", 30, 60),
+    ("1-2m", 60, 120),
+    ("2-5m", 120, 300),
+    (">5m", 300, u64::MAX),
+];
+
+/// A single histogram bucket
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IntervalBucket {
+    pub label: String,
+    pub min_seconds: u64,
+    /// Exclusive upper bound, `None` for the open-ended last bucket
+    pub max_seconds: Option<u64>,
+    pub count: usize,
+}
+
+/// Distribution of block intervals over a window
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IntervalHistogram {
+    pub window: u64,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub buckets: Vec<IntervalBucket>,
+    /// Intervals counted in the buckets
+    pub sample_count: usize,
+    pub average_seconds: f64,
+    pub median_seconds: u64,
+    /// Blocks whose timestamp was not after their parent's (excluded from buckets)
+    pub non_positive_intervals: usize,
+}
+
+/// Build the interval histogram for the last `window` blocks
+pub fn interval_histogram(path: &Path, window: u64) -> ReaderResult<IntervalHistogram> {
+    let window = window.clamp(1, MAX_WINDOW);
+    let reader = StreamReader::open(path)?;
+    let snapshot = reader.snapshot()?;
+
+    let end_height = snapshot.tip_height()?.unwrap_or(0);
+    // One extra header so the first block in the window has an interval
+    let start_height = end_height.saturating_sub(window);
+
+    // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+    let timestamps: Vec<(u64, u64)> = snapshot.headers(start_height, end_height)?
+        .filter_map(Result::ok)
+        .map(|(height, header)| (height, header.timestamp.as_u64()))
+        .collect();
+
+    let mut histogram = histogram_from_timestamps(&timestamps);
+    histogram.window = window;
+    histogram.start_height = start_height + 1;
+    histogram.end_height = end_height;
+    Ok(histogram)
+}
+
+/// Bucket intervals between consecutive (height, timestamp) pairs in ascending height order
+pub fn histogram_from_timestamps(timestamps: &[(u64, u64)]) -> IntervalHistogram {
+    let mut counts = [0usize; BUCKETS.len()];
+    let mut intervals = Vec::new();
+    let mut non_positive_intervals = 0;
+
+    for pair in timestamps.windows(2) {
+        let ((prev_height, prev_ts), (height, ts)) = (pair[0], pair[1]);
+        if height != prev_height + 1 {
+            continue;
+        }
+        if ts <= prev_ts {
+            non_positive_intervals += 1;
+            continue;
+        }
+        let interval = ts - prev_ts;
+        if let Some(index) = BUCKETS.iter().position(|(_, lo, hi)| interval >= *lo && interval < *hi) {
+            counts[index] += 1;
+        }
+        intervals.push(interval);
+    }
+
+    intervals.sort_unstable();
+    let average_seconds = if intervals.is_empty() {
+        0.0
+    } else {
+        intervals.iter().sum::<u64>() as f64 / intervals.len() as f64
+    };
+
+    IntervalHistogram {
+        window: timestamps.len() as u64,
+        start_height: timestamps.first().map(|(h, _)| *h).unwrap_or(0),
+        end_height: timestamps.last().map(|(h, _)| *h).unwrap_or(0),
+        buckets: BUCKETS.iter().zip(counts.iter()).map(|((label, lo, hi), count)| IntervalBucket {
+            label: label.to_string(),
+            min_seconds: *lo,
+            max_seconds: if *hi == u64::MAX { None } else { Some(*hi) },
+            count: *count,
+        }).collect(),
+        sample_count: intervals.len(),
+        average_seconds,
+        median_seconds: super::percentile(&intervals, 0.5),
+        non_positive_intervals,
+    }
+}
@@ -0,0 +1,42 @@
+// File: src/analytics/mod.rs
+// Version: 1.6.0 - CSV downloads of the analytics series
+// Tree: tari-lmdb-inspector/src/analytics/mod.rs
+
+// Kernel fee percentiles per block and over a window
+pub mod fees;
+
+// Block interval distribution
+pub mod intervals;
+
+// UTXO set size over time from header output_smt_size
+pub mod utxo_growth;
+
+// Coinbase outputs still inside their maturity window
+pub mod coinbase;
+
+// Burn outputs, burn kernels and cumulative burned value
+pub mod burns;
+
+// Kernel count, UTXO count and emission as of a height
+pub mod state_at;
+
+// Rolling share of blocks per PoW algorithm
+pub mod pow_share;
+
+// Largest / busiest blocks from an index of per-block aggregates
+pub mod top_blocks;
+
+// Per-height kernel counts kept in a sidecar cache
+pub mod tx_volume;
+
+// CSV rendering of the series above (`?format=csv`, `Accept: text/csv`, `*.csv` paths)
+pub mod csv;
+
+/// Value at percentile `p` (0.0..=1.0) of an ascending slice, nearest-rank method
+pub fn percentile(sorted: &[u64], p: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (p.clamp(0.0, 1.0) * sorted.len() as f64).ceil() as usize;
+    sorted[rank.saturating_sub(1).min(sorted.len() - 1)]
+}
@@ -0,0 +1,134 @@
+// File: src/analytics/pow_share.rs
+// Version: 1.0.0 - Rolling proof-of-work algorithm share
+// Tree: tari-lmdb-inspector/src/analytics/pow_share.rs
+//
+// Tari blocks are mined with several PoW algorithms (RandomX variants, SHA3x, Cuckaroo 29).
+// Each point is the share of every algorithm over the `window` blocks ending at that height.
+// C29 headers cannot be decoded by this build, so undecodable headers are counted as C29.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::lmdb_reader::{ReaderError, ReaderResult, StreamReader};
+
+/// Default rolling window (about a day of blocks)
+pub const DEFAULT_WINDOW: u64 = 720;
+
+/// Largest rolling window accepted from API callers
+pub const MAX_WINDOW: u64 = 10_000;
+
+/// Default number of heights charted
+pub const DEFAULT_SPAN: u64 = 2_880;
+
+/// Largest span accepted from API callers
+pub const MAX_SPAN: u64 = 100_000;
+
+/// Points returned at most; larger spans are sampled
+const MAX_POINTS: u64 = 500;
+
+/// Label for headers that failed to decode
+const UNDECODED_ALGO: &str = "Cuckaroo29";
+
+/// Algorithm shares over the window ending at `height`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PowSharePoint {
+    pub height: u64,
+    /// Algorithm → percentage of the window's blocks (0-100)
+    pub shares: BTreeMap<String, f64>,
+}
+
+/// Rolling PoW share series
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PowShare {
+    pub window: u64,
+    pub start_height: u64,
+    pub end_height: u64,
+    /// Heights between points
+    pub step: u64,
+    /// Every algorithm seen, in display order
+    pub algorithms: Vec<String>,
+    pub points: Vec<PowSharePoint>,
+    /// Blocks per algorithm over the whole span
+    pub totals: BTreeMap<String, u64>,
+}
+
+/// Rolling share of each PoW algorithm over `span` heights ending at the tip
+pub fn pow_share(path: &Path, window: u64, span: u64) -> ReaderResult<PowShare> {
+    let window = window.clamp(1, MAX_WINDOW);
+    let span = span.clamp(1, MAX_SPAN);
+    let reader = StreamReader::open(path)?;
+    let snapshot = reader.snapshot()?;
+
+    let end_height = snapshot.tip_height()?.unwrap_or(0);
+    let start_height = end_height.saturating_sub(span - 1);
+    // The first point needs a full window behind it
+    let scan_start = start_height.saturating_sub(window - 1);
+
+    let mut algos = Vec::new();
+    for entry in snapshot.headers(scan_start, end_height)? {
+        match entry {
+            Ok((height, header)) => algos.push((height, format!("{:?}", header.pow.pow_algo))),
+            // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+            Err(ReaderError::UnsupportedHeader { height, .. }) => algos.push((height, UNDECODED_ALGO.to_string())),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(share_from_algorithms(&algos, window, start_height, end_height))
+}
+
+/// Build the series from (height, algorithm) pairs in ascending height order
+pub fn share_from_algorithms(algos: &[(u64, String)], window: u64, start_height: u64, end_height: u64) -> PowShare {
+    let span = end_height.saturating_sub(start_height) + 1;
+    let step = span.div_ceil(MAX_POINTS).max(1);
+
+    let mut algorithms: Vec<String> = Vec::new();
+    for (_, algo) in algos {
+        if !algorithms.contains(algo) {
+            algorithms.push(algo.clone());
+        }
+    }
+    algorithms.sort();
+
+    let mut totals = BTreeMap::new();
+    let mut in_window: BTreeMap<&str, u64> = BTreeMap::new();
+    let mut points = Vec::new();
+    let mut tail = 0;
+    for (i, (height, algo)) in algos.iter().enumerate() {
+        *in_window.entry(algo.as_str()).or_default() += 1;
+        while algos[tail].0 + window <= *height {
+            if let Some(count) = in_window.get_mut(algos[tail].1.as_str()) {
+                *count -= 1;
+            }
+            tail += 1;
+        }
+        if *height < start_height {
+            continue;
+        }
+        *totals.entry(algo.clone()).or_default() += 1;
+
+        let is_last = i + 1 == algos.len();
+        if (height - start_height) % step == 0 || is_last {
+            let blocks = (i + 1 - tail) as f64;
+            points.push(PowSharePoint {
+                height: *height,
+                shares: in_window.iter()
+                    .filter(|(_, count)| **count > 0)
+                    .map(|(algo, count)| (algo.to_string(), *count as f64 * 100.0 / blocks))
+                    .collect(),
+            });
+        }
+    }
+
+    PowShare {
+        window,
+        start_height,
+        end_height,
+        step,
+        algorithms,
+        points,
+        totals,
+    }
+}
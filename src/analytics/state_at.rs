@@ -0,0 +1,78 @@
+// File: src/analytics/state_at.rs
+// Version: 1.0.0 - Chain state as of a given height
+// Tree: tari-lmdb-inspector/src/analytics/state_at.rs
+//
+// Headers record the kernel MMR size and output SMT size after their block, so the kernel count
+// and UTXO set size at any height come from a single header read. Emission is not stored in
+// LMDB; it is the consensus emission schedule's supply at that height for the configured
+// network.
+
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use tari_common::configuration::Network;
+use tari_core::consensus::ConsensusManager;
+use crate::lmdb_reader::{ReaderError, ReaderResult, StreamReader};
+
+/// Chain state after the block at `height`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChainStateAt {
+    pub height: u64,
+    pub hash: String,
+    pub timestamp: u64,
+    /// Kernels in the chain up to and including this block (`kernel_mmr_size`)
+    pub kernel_count: u64,
+    /// Unspent outputs after this block (`output_smt_size`)
+    pub utxo_count: u64,
+    /// Scheduled emission (block rewards, excluding fees) up to this height in µT
+    pub emission: Option<u64>,
+    pub network: String,
+}
+
+/// Read the chain state as of `height`
+pub fn state_at_height(path: &Path, height: u64, network: Network) -> ReaderResult<ChainStateAt> {
+    let reader = StreamReader::open(path)?;
+    let snapshot = reader.snapshot()?;
+    let (_, header) = snapshot.headers(height, height)?
+        .next()
+        .ok_or(ReaderError::BlockNotFound(height))??;
+    let hash = hex::encode(snapshot.block_hash(height, &header));
+
+    Ok(ChainStateAt {
+        height,
+        hash,
+        timestamp: header.timestamp.as_u64(),
+        kernel_count: header.kernel_mmr_size,
+        utxo_count: header.output_smt_size,
+        emission: scheduled_supply(network, height),
+        network: network.to_string(),
+    })
+}
+
+/// Total block reward emitted by `height` under the network's consensus rules
+pub fn scheduled_supply(network: Network, height: u64) -> Option<u64> {
+    let consensus = ConsensusManager::builder(network).build().ok()?;
+    Some(consensus.emission_schedule().supply_at_block(height).as_u64())
+}
+
+/// Print the state at a height for the CLI, with the change since `base` when given
+pub fn print_state(state: &ChainStateAt, base: Option<&ChainStateAt>) {
+    let delta = |now: u64, then: Option<u64>| match then {
+        Some(then) => format!(" ({:+})", now as i128 - then as i128),
+        None => String::new(),
+    };
+    let tari = |micro: u64| micro as f64 / 1_000_000.0;
+    println!("🕰️  Chain state at height {} ({})", state.height, state.network);
+    println!("Hash: {}", state.hash);
+    println!("Time: {}", chrono::DateTime::from_timestamp(state.timestamp as i64, 0)
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| state.timestamp.to_string()));
+    println!("Kernels: {}{}", state.kernel_count, delta(state.kernel_count, base.map(|b| b.kernel_count)));
+    println!("UTXOs: {}{}", state.utxo_count, delta(state.utxo_count, base.map(|b| b.utxo_count)));
+    match (state.emission, base.and_then(|b| b.emission)) {
+        (Some(emission), Some(then)) => println!("Emission: {:.6} T ({:+.6} T)", tari(emission), tari(emission) - tari(then)),
+        (Some(emission), None) => println!("Emission: {:.6} T", tari(emission)),
+        (None, _) => println!("Emission: unavailable"),
+    }
+}
@@ -0,0 +1,229 @@
+// File: src/analytics/top_blocks.rs
+// Version: 1.1.0 - Index persisted in the cache directory
+// Tree: tari-lmdb-inspector/src/analytics/top_blocks.rs
+//
+// Ranking blocks needs every block's kernel, output and fee totals, which means reading the
+// transaction tables. Web mode keeps an `AggregateIndex` of the last `window` blocks, filled in
+// the background at startup and topped up when the database changes; the CLI builds one on
+// demand. The newest `REORG_DEPTH` blocks are re-read on every refresh so reorgs are picked up,
+// and the index is stored in the cache directory so a restart only reads the new blocks.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use tari_node_components::blocks::BlockHeader;
+use crate::cache::{CacheDir, TipKey};
+use crate::lmdb_reader::{
+    ReaderError, ReaderResult, Snapshot, StreamReader, TransactionInputRowData, TransactionKernelRowData,
+    TransactionOutputRowData,
+};
+use crate::lmdb_reader::parallel::{par_map_chunks, DEFAULT_CHUNK_SIZE};
+
+/// Blocks indexed by default
+pub const DEFAULT_INDEX_WINDOW: u64 = 10_000;
+
+/// Default leaderboard length
+pub const DEFAULT_LIMIT: usize = 20;
+
+/// Longest leaderboard accepted from API callers
+pub const MAX_LIMIT: usize = 500;
+
+/// Newest blocks re-read on every refresh
+const REORG_DEPTH: u64 = 10;
+
+/// Index file inside the cache directory
+pub const CACHE_FILE: &str = "top_blocks.bin";
+
+/// Ranking criterion
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum TopBy {
+    #[default]
+    Kernels,
+    Outputs,
+    Fees,
+}
+
+impl TopBy {
+    fn key(&self, block: &BlockAggregate) -> u64 {
+        match self {
+            TopBy::Kernels => block.kernels,
+            TopBy::Outputs => block.outputs,
+            TopBy::Fees => block.total_fees,
+        }
+    }
+}
+
+/// Transaction totals for one block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BlockAggregate {
+    pub height: u64,
+    pub hash: String,
+    pub timestamp: u64,
+    pub kernels: u64,
+    pub outputs: u64,
+    pub inputs: u64,
+    /// Sum of kernel fees in µT
+    pub total_fees: u64,
+}
+
+/// Leaderboard response
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TopBlocks {
+    pub by: TopBy,
+    /// Lowest and highest indexed heights; `None` while the index is still empty
+    pub indexed_from: Option<u64>,
+    pub indexed_to: Option<u64>,
+    pub blocks: Vec<BlockAggregate>,
+}
+
+/// Per-block aggregates for the newest `window` blocks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateIndex {
+    window: u64,
+    blocks: BTreeMap<u64, BlockAggregate>,
+}
+
+impl AggregateIndex {
+    pub fn new(window: u64) -> Self {
+        Self { window: window.max(1), blocks: BTreeMap::new() }
+    }
+
+    /// The index stored in `cache` if it is still on the chain at `db_path`, else an empty one
+    pub fn load_cached(cache: &CacheDir, db_path: &Path, window: u64) -> Self {
+        let mut index = Self::new(window);
+        if let Some((_, cached)) = cache.load::<AggregateIndex>(CACHE_FILE, db_path) {
+            index.blocks = cached.blocks;
+        }
+        index
+    }
+
+    /// Store the index in `cache`, keyed by its newest block
+    pub fn store(&self, cache: &CacheDir) -> anyhow::Result<()> {
+        match self.blocks.values().next_back() {
+            Some(last) => cache.store(CACHE_FILE, &TipKey { height: last.height, hash: last.hash.clone() }, self),
+            None => Ok(()),
+        }
+    }
+
+    /// Heights to read for `tip`: anything missing from the window plus the newest
+    /// `REORG_DEPTH` blocks
+    pub fn pending(&self, tip: u64) -> (u64, u64) {
+        let floor = tip.saturating_sub(self.window - 1);
+        let start = match self.blocks.keys().next_back() {
+            Some(last) => (last + 1).min(tip.saturating_sub(REORG_DEPTH - 1)).max(floor),
+            None => floor,
+        };
+        (start, tip)
+    }
+
+    /// Store freshly read aggregates and drop blocks that left the window
+    pub fn apply(&mut self, tip: u64, rows: Vec<BlockAggregate>) {
+        // Heights above the tip belong to a chain that was reorged away
+        self.blocks.retain(|height, _| *height <= tip);
+        for row in rows {
+            self.blocks.insert(row.height, row);
+        }
+        let floor = tip.saturating_sub(self.window - 1);
+        self.blocks = self.blocks.split_off(&floor);
+    }
+
+    /// Read what `pending` asks for and apply it
+    pub fn refresh(&mut self, path: &Path) -> ReaderResult<()> {
+        let tip = StreamReader::open(path)?.snapshot()?.tip_height()?.unwrap_or(0);
+        let (start, end) = self.pending(tip);
+        let rows = scan_aggregates(path, start, end)?;
+        self.apply(tip, rows);
+        Ok(())
+    }
+
+    /// The `limit` highest blocks by `by`, ties broken by the newer block
+    pub fn top(&self, by: TopBy, limit: usize) -> TopBlocks {
+        let mut blocks: Vec<&BlockAggregate> = self.blocks.values().collect();
+        blocks.sort_by(|a, b| by.key(b).cmp(&by.key(a)).then(b.height.cmp(&a.height)));
+        TopBlocks {
+            by,
+            indexed_from: self.blocks.keys().next().copied(),
+            indexed_to: self.blocks.keys().next_back().copied(),
+            blocks: blocks.into_iter().take(limit).cloned().collect(),
+        }
+    }
+}
+
+/// Read per-block aggregates for `start..=end`; undecodable (C29) headers are skipped
+/// Large ranges are split across a rayon pool, one read transaction per chunk
+pub fn scan_aggregates(path: &Path, start: u64, end: u64) -> ReaderResult<Vec<BlockAggregate>> {
+    let reader = StreamReader::open(path)?;
+    par_map_chunks(&reader, start, end, DEFAULT_CHUNK_SIZE, |snapshot, start, end| {
+        let mut rows = Vec::new();
+        for entry in snapshot.headers(start, end)? {
+            let (height, header) = match entry {
+                Ok(entry) => entry,
+                // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                Err(ReaderError::UnsupportedHeader { .. }) => continue,
+                Err(e) => return Err(e),
+            };
+            rows.push(block_aggregate(snapshot, height, &header)?);
+        }
+        Ok(rows)
+    })
+}
+
+fn block_aggregate(snapshot: &Snapshot<'_>, height: u64, header: &BlockHeader) -> ReaderResult<BlockAggregate> {
+    let block_hash = snapshot.block_hash(height, header);
+    let (mut kernels, mut total_fees) = (0, 0);
+    for row in snapshot.kernels::<TransactionKernelRowData>(&block_hash)? {
+        kernels += 1;
+        total_fees += row?.kernel.fee.0;
+    }
+    let mut outputs = 0;
+    for row in snapshot.outputs::<TransactionOutputRowData>(&block_hash)? {
+        row?;
+        outputs += 1;
+    }
+    let mut inputs = 0;
+    for row in snapshot.inputs::<TransactionInputRowData>(&block_hash)? {
+        row?;
+        inputs += 1;
+    }
+
+    Ok(BlockAggregate {
+        height,
+        hash: hex::encode(&block_hash),
+        timestamp: header.timestamp.as_u64(),
+        kernels,
+        outputs,
+        inputs,
+        total_fees,
+    })
+}
+
+/// Bring the cached index over the newest `window` blocks up to date and rank it (CLI)
+pub fn top_blocks(path: &Path, cache: &CacheDir, by: TopBy, limit: usize, window: u64) -> anyhow::Result<TopBlocks> {
+    let mut index = AggregateIndex::load_cached(cache, path, window);
+    index.refresh(path)?;
+    index.store(cache)?;
+    Ok(index.top(by, limit))
+}
+
+/// Print the leaderboard for the CLI
+pub fn print_top_blocks(top: &TopBlocks) {
+    match (top.indexed_from, top.indexed_to) {
+        (Some(from), Some(to)) => println!("🏆 Top blocks by {:?} in heights {}..={}", top.by, from, to),
+        _ => println!("🏆 Top blocks by {:?} (no blocks indexed)", top.by),
+    }
+    println!("┌──────┬─────────┬─────────────────────┬─────────┬─────────┬────────┬──────────────┐");
+    println!("│ Rank │ Height  │ Time (UTC)          │ Kernels │ Outputs │ Inputs │ Fees (µT)    │");
+    println!("├──────┼─────────┼─────────────────────┼─────────┼─────────┼────────┼──────────────┤");
+    for (rank, block) in top.blocks.iter().enumerate() {
+        let time = chrono::DateTime::from_timestamp(block.timestamp as i64, 0)
+            .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| block.timestamp.to_string());
+        println!("│ {:>4} │ {:>7} │ {:<19} │ {:>7} │ {:>7} │ {:>6} │ {:>12} │",
+            rank + 1, block.height, time, block.kernels, block.outputs, block.inputs, block.total_fees);
+    }
+    println!("└──────┴─────────┴─────────────────────┴─────────┴─────────┴────────┴──────────────┘");
+}
@@ -0,0 +1,208 @@
+// File: src/analytics/tx_volume.rs
+// Version: 1.1.0 - Series file kept in the cache directory
+// Tree: tari-lmdb-inspector/src/analytics/tx_volume.rs
+//
+// Each header records the kernel MMR size after its block, so a block's kernel count is the
+// difference from its parent. The series is kept in a small bincode file in the cache
+// directory and extended by the web stats worker on each database change, so requests never
+// rescan the chain. Hashes of the newest blocks are stored with it; if one no longer matches
+// the database the series is re-read from that height rather than discarded.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::lmdb_reader::{ReaderError, ReaderResult, StreamReader};
+
+/// Most heights read per refresh; a new cache catches up over several refreshes
+pub const MAX_BLOCKS_PER_REFRESH: u64 = 100_000;
+
+/// Newest block hashes remembered for reorg detection
+const ANCHOR_DEPTH: usize = 20;
+
+/// Points returned at most; larger ranges are bucketed
+pub const MAX_POINTS: u64 = 1_000;
+
+/// Kernels added by a range of blocks
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TxVolumePoint {
+    /// First height of the bucket
+    pub height: u64,
+    pub kernels: u64,
+    /// Blocks in the bucket with a known count (C29 headers and their children are unknown)
+    pub blocks: u64,
+}
+
+/// Kernel count series response
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TxVolume {
+    pub start_height: u64,
+    pub end_height: u64,
+    /// Heights per point
+    pub bucket: u64,
+    /// Highest cached height; `None` until the first refresh finishes
+    pub covered_to: Option<u64>,
+    pub total_kernels: u64,
+    pub points: Vec<TxVolumePoint>,
+}
+
+/// Persisted kernel counts by height plus the anchors used for reorg detection
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TxVolumeCache {
+    /// Kernels added by each block, indexed by height; `None` where it could not be derived
+    counts: Vec<Option<u32>>,
+    /// Height → hash of the newest cached blocks
+    anchors: BTreeMap<u64, String>,
+}
+
+impl TxVolumeCache {
+    /// Load the cache from disk; a missing or unreadable file gives an empty cache
+    pub fn load(path: &Path) -> Self {
+        std::fs::read(path)
+            .ok()
+            .and_then(|data| bincode::deserialize(&data).ok())
+            .unwrap_or_default()
+    }
+
+    /// Persist the cache to disk
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, bincode::serialize(self)?)?;
+        Ok(())
+    }
+
+    /// Highest cached height
+    pub fn covered_to(&self) -> Option<u64> {
+        (self.counts.len() as u64).checked_sub(1)
+    }
+
+    /// Kernel count of the block at `height`, if cached and known
+    pub fn count(&self, height: u64) -> Option<u32> {
+        self.counts.get(height as usize).copied().flatten()
+    }
+
+    /// Re-read from the first anchor that no longer matches, then extend towards the tip by at
+    /// most `MAX_BLOCKS_PER_REFRESH` heights. Returns the number of heights read.
+    pub fn refresh(&mut self, db_path: &Path) -> ReaderResult<u64> {
+        let reader = StreamReader::open(db_path)?;
+        let snapshot = reader.snapshot()?;
+        let Some(tip) = snapshot.tip_height()? else {
+            *self = Self::default();
+            return Ok(0);
+        };
+
+        let mut next = self.counts.len() as u64;
+        for (index, (height, hash)) in self.anchors.iter().enumerate() {
+            let current = match snapshot.headers(*height, *height)?.next() {
+                Some(Ok((_, header))) => Some(hex::encode(snapshot.block_hash(*height, &header))),
+                _ => None,
+            };
+            if current.as_ref() != Some(hash) {
+                // A fork below the oldest anchor cannot be located, so start again
+                next = if index == 0 { 0 } else { *height };
+                break;
+            }
+        }
+        self.counts.truncate(next as usize);
+        self.anchors.retain(|height, _| *height < next);
+
+        if next > tip {
+            return Ok(0);
+        }
+        let end = tip.min(next + MAX_BLOCKS_PER_REFRESH - 1);
+
+        // The parent's MMR size is needed for the first count
+        let mut parent: Option<(u64, u64)> = None;
+        for entry in snapshot.headers(next.saturating_sub(1), end)? {
+            match entry {
+                Ok((height, header)) => {
+                    if height >= next {
+                        let count = match parent {
+                            _ if height == 0 => Some(header.kernel_mmr_size),
+                            Some((parent_height, parent_size)) if parent_height + 1 == height => {
+                                Some(header.kernel_mmr_size.saturating_sub(parent_size))
+                            }
+                            _ => None,
+                        };
+                        self.set(height, count.map(|c| c.min(u32::MAX as u64) as u32));
+                        if height + ANCHOR_DEPTH as u64 > end {
+                            self.anchors.insert(height, hex::encode(snapshot.block_hash(height, &header)));
+                        }
+                    }
+                    parent = Some((height, header.kernel_mmr_size));
+                }
+                // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                Err(ReaderError::UnsupportedHeader { height, .. }) => {
+                    if height >= next {
+                        self.set(height, None);
+                    }
+                    parent = None;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        while self.anchors.len() > ANCHOR_DEPTH {
+            self.anchors.pop_first();
+        }
+        Ok(end - next + 1)
+    }
+
+    fn set(&mut self, height: u64, count: Option<u32>) {
+        if self.counts.len() <= height as usize {
+            self.counts.resize(height as usize + 1, None);
+        }
+        self.counts[height as usize] = count;
+    }
+
+    /// Series over `from..=to` (default: everything cached), `bucket` heights per point,
+    /// widened so at most `MAX_POINTS` points are returned
+    pub fn series(&self, from: Option<u64>, to: Option<u64>, bucket: Option<u64>) -> TxVolume {
+        let covered_to = self.covered_to();
+        let end_height = to.unwrap_or(covered_to.unwrap_or(0)).min(covered_to.unwrap_or(0));
+        let start_height = from.unwrap_or(0).min(end_height);
+        let span = end_height - start_height + 1;
+        let bucket = bucket.unwrap_or(1).max(span.div_ceil(MAX_POINTS)).max(1);
+
+        let mut points = Vec::new();
+        if covered_to.is_some() {
+            let mut height = start_height;
+            while height <= end_height {
+                let last = (height + bucket - 1).min(end_height);
+                let counts: Vec<u32> = (height..=last).filter_map(|h| self.count(h)).collect();
+                points.push(TxVolumePoint {
+                    height,
+                    kernels: counts.iter().map(|c| *c as u64).sum(),
+                    blocks: counts.len() as u64,
+                });
+                height = last + 1;
+            }
+        }
+
+        TxVolume {
+            start_height,
+            end_height,
+            bucket,
+            covered_to,
+            total_kernels: points.iter().map(|p| p.kernels).sum(),
+            points,
+        }
+    }
+}
+
+/// Series file inside the cache directory
+pub const CACHE_FILE: &str = "tx_volume.bin";
+
+/// Load the cache at `cache_path`, extend it from the database and save it back
+pub fn refresh_tx_volume(db_path: &Path, cache_path: &Path) -> Result<TxVolumeCache> {
+    let mut cache = TxVolumeCache::load(cache_path);
+    let before = cache.covered_to();
+    // A reorg onto a shorter chain only truncates, so compare coverage as well
+    if cache.refresh(db_path)? > 0 || cache.covered_to() != before {
+        cache.save(cache_path)?;
+    }
+    Ok(cache)
+}
@@ -0,0 +1,284 @@
+// File: src/audit/follow.rs
+// Version: 1.0.0 - Continuous audit of new blocks (audit --follow)
+// Tree: tari-lmdb-inspector/src/audit/follow.rs
+//
+// Validates each block as it lands, using only the header chain and the block's own rows so a
+// check stays cheap however long the chain is:
+// - hash chain: `prev_hash` is the hash of the block below
+// - kernel MMR: `kernel_mmr_size` grew by the number of kernel rows stored for the block
+// - output SMT: `output_smt_size` grew by the unburned outputs minus the inputs of the block
+// - timestamps: the median-time-past and FTL rules of `audit --timestamps`
+//
+// The first observation only checks the tip; after that every new height is checked, and after
+// a rewind the new tip and everything above it. Results accumulate in an `AuditStatus` that the
+// daemon serves at `/api/audit/status`, and each failing block is sent to the webhook.
+
+use std::path::Path;
+use std::sync::Arc;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::sync::RwLock;
+use utoipa::ToSchema;
+
+use tari_node_components::blocks::BlockHeader;
+use tari_transaction_components::transaction_components::OutputType;
+use crate::data_models::AppConfig;
+use crate::file_watcher::{LmdbWatcher, TipCoalescer, TipMark};
+use crate::lmdb_reader::{ReaderError, ReaderResult, StreamReader, TransactionInputRowData, TransactionKernelRowData, TransactionOutputRowData};
+use crate::lmdb_reader::metadata::read_chain_metadata;
+use crate::lmdb_reader::r#async;
+use crate::notify::{Notifier, NotifyEvent};
+use super::timestamps::{anomalies_from_timestamps, AnomalyKind, MEDIAN_TIMESTAMP_COUNT};
+
+/// Findings kept in the status, oldest dropped first
+pub const MAX_RECENT_FINDINGS: usize = 100;
+
+/// Audit status shared between the follow loop and the status endpoint
+pub type SharedAuditStatus = Arc<RwLock<AuditStatus>>;
+
+/// Consistency rule a block is checked against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditRule {
+    HashChain,
+    KernelMmrSize,
+    OutputSmtSize,
+    Timestamp,
+}
+
+impl AuditRule {
+    /// Name as it appears in JSON
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::HashChain => "hash_chain",
+            Self::KernelMmrSize => "kernel_mmr_size",
+            Self::OutputSmtSize => "output_smt_size",
+            Self::Timestamp => "timestamp",
+        }
+    }
+}
+
+/// A rule a block broke
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct AuditFinding {
+    pub height: u64,
+    pub hash: String,
+    pub rule: AuditRule,
+    pub message: String,
+}
+
+/// Result of checking one block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BlockAudit {
+    pub height: u64,
+    pub hash: String,
+    pub findings: Vec<AuditFinding>,
+}
+
+impl BlockAudit {
+    pub fn is_ok(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    fn fail(&mut self, rule: AuditRule, message: String) {
+        self.findings.push(AuditFinding { height: self.height, hash: self.hash.clone(), rule, message });
+    }
+}
+
+/// Running totals of the follow loop, served at `/api/audit/status`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct AuditStatus {
+    pub started_at: u64,
+    /// Last block checked
+    pub last_height: Option<u64>,
+    pub last_hash: Option<String>,
+    pub last_checked_at: Option<u64>,
+    /// Whether the last block checked passed every rule
+    pub last_block_ok: bool,
+    pub blocks_checked: u64,
+    pub blocks_failed: u64,
+    /// Most recent findings, oldest first (up to 100)
+    pub findings: Vec<AuditFinding>,
+}
+
+impl AuditStatus {
+    pub fn new(started_at: u64) -> Self {
+        Self { started_at, ..Self::default() }
+    }
+
+    /// Add checked blocks (ascending height) to the totals
+    pub fn record(&mut self, audits: &[BlockAudit], now: u64) {
+        for audit in audits {
+            self.blocks_checked += 1;
+            if !audit.is_ok() {
+                self.blocks_failed += 1;
+            }
+            self.findings.extend(audit.findings.iter().cloned());
+            self.last_height = Some(audit.height);
+            self.last_hash = Some(audit.hash.clone());
+            self.last_block_ok = audit.is_ok();
+        }
+        let excess = self.findings.len().saturating_sub(MAX_RECENT_FINDINGS);
+        self.findings.drain(..excess);
+        self.last_checked_at = Some(now);
+    }
+}
+
+/// Check blocks `start..=end` against the audit rules, with `now` as the clock for the FTL
+pub fn audit_blocks(path: &Path, start: u64, end: u64, now: u64) -> ReaderResult<Vec<BlockAudit>> {
+    let reader = StreamReader::open(path)?;
+    let snapshot = reader.snapshot()?;
+
+    // Parents and median context for the first blocks checked
+    let context_start = start.saturating_sub(MEDIAN_TIMESTAMP_COUNT as u64);
+    let headers: Vec<(u64, BlockHeader)> = snapshot.headers(context_start, end)?
+        .filter_map(Result::ok)
+        .collect();
+    let timestamps: Vec<(u64, u64)> = headers.iter().map(|(height, header)| (*height, header.timestamp.as_u64())).collect();
+    let anomalies = anomalies_from_timestamps(&timestamps, start, now).anomalies;
+
+    let mut audits = Vec::new();
+    for (i, (height, header)) in headers.iter().enumerate() {
+        if *height < start {
+            continue;
+        }
+        let block_hash = snapshot.block_hash(*height, header);
+        let mut audit = BlockAudit { height: *height, hash: hex::encode(&block_hash), findings: Vec::new() };
+
+        let parent = i.checked_sub(1).map(|p| &headers[p]).filter(|(parent_height, _)| parent_height + 1 == *height);
+        match parent {
+            Some((parent_height, parent_header)) => {
+                let parent_hash = snapshot.block_hash(*parent_height, parent_header);
+                if header.prev_hash.as_slice() != parent_hash.as_slice() {
+                    audit.fail(AuditRule::HashChain, format!(
+                        "prev_hash {} is not the hash of block {} ({})",
+                        hex::encode(header.prev_hash.as_slice()), parent_height, hex::encode(&parent_hash)
+                    ));
+                }
+
+                let kernels = row_count(snapshot.kernels::<TransactionKernelRowData>(&block_hash), |_| true)?;
+                let grown = header.kernel_mmr_size as i128 - parent_header.kernel_mmr_size as i128;
+                if let Some(kernels) = kernels.filter(|kernels| *kernels as i128 != grown) {
+                    audit.fail(AuditRule::KernelMmrSize, format!(
+                        "kernel_mmr_size grew by {} but the block has {} kernels", grown, kernels
+                    ));
+                }
+
+                let outputs = row_count(
+                    snapshot.outputs::<TransactionOutputRowData>(&block_hash),
+                    |row| row.output.features.output_type != OutputType::Burn,
+                )?;
+                let inputs = row_count(snapshot.inputs::<TransactionInputRowData>(&block_hash), |_| true)?;
+                let grown = header.output_smt_size as i128 - parent_header.output_smt_size as i128;
+                if let (Some(outputs), Some(inputs)) = (outputs, inputs) {
+                    if outputs as i128 - inputs as i128 != grown {
+                        audit.fail(AuditRule::OutputSmtSize, format!(
+                            "output_smt_size grew by {} but the block adds {} unburned outputs and spends {}",
+                            grown, outputs, inputs
+                        ));
+                    }
+                }
+            }
+            None if *height > 0 => audit.fail(AuditRule::HashChain, format!("Header {} is missing or undecodable", height - 1)),
+            None => {}
+        }
+
+        for anomaly in anomalies.iter().filter(|anomaly| anomaly.height == *height) {
+            let message = match anomaly.kind {
+                AnomalyKind::BeforeMedian => format!("Timestamp is {}s before the median of the previous {} blocks", anomaly.seconds, MEDIAN_TIMESTAMP_COUNT),
+                AnomalyKind::FutureDrift => format!("Timestamp is {}s ahead of the clock", anomaly.seconds),
+            };
+            audit.fail(AuditRule::Timestamp, message);
+        }
+        audits.push(audit);
+    }
+    Ok(audits)
+}
+
+/// Rows of a block matching `keep`; `None` when the table does not exist (the rule is skipped)
+fn row_count<T>(
+    rows: ReaderResult<impl Iterator<Item = ReaderResult<T>>>,
+    keep: impl Fn(&T) -> bool,
+) -> ReaderResult<Option<u64>> {
+    match rows {
+        Ok(rows) => {
+            let mut count = 0;
+            for row in rows {
+                if keep(&row?) {
+                    count += 1;
+                }
+            }
+            Ok(Some(count))
+        }
+        Err(ReaderError::TableNotFound(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Check every new block until the watcher stops, printing each result, updating `status` and
+/// sending failing blocks to the webhook
+pub async fn follow_chain(config: &AppConfig, status: SharedAuditStatus) -> Result<()> {
+    if config.demo {
+        anyhow::bail!("audit --follow watches the database files and is not available with --demo");
+    }
+    let mut watcher = LmdbWatcher::from_config(config)?;
+    let mut tips = TipCoalescer::default();
+    let notifier = config.notify.clone().map(Notifier::new).transpose()?;
+
+    println!("👀 Auditing new blocks (Ctrl+C to stop)...");
+    if watcher.is_polling() {
+        println!("⏱️  Change detection: {}", watcher.describe());
+    }
+
+    // Check the current tip straight away, then every change
+    let mut changed = true;
+    while changed {
+        if let Ok(Some(metadata)) = read_chain_metadata(&config.database_path) {
+            if let Some(change) = tips.observe(TipMark::from(&metadata)) {
+                let heights = change.new_heights().unwrap_or(change.after.height..=change.after.height);
+                if change.is_rewind() {
+                    println!("⚠️  Tip moved back to {}, re-checking from there", change.after.height);
+                }
+                let now = chrono::Utc::now().timestamp() as u64;
+                let path = config.database_path.clone();
+                let (start, end) = (*heights.start(), *heights.end());
+                let audits = match r#async::run_blocking(config.read_timeout, "audit", move || audit_blocks(&path, start, end, now)).await {
+                    Ok(audits) => audits,
+                    Err(e) => {
+                        eprintln!("❌ Audit of blocks {}-{} failed: {}", start, end, e);
+                        changed = watcher.next_change().await.is_some();
+                        continue;
+                    }
+                };
+
+                for audit in &audits {
+                    print_block_audit(audit);
+                }
+                status.write().await.record(&audits, now);
+
+                if let Some(notifier) = &notifier {
+                    let events: Vec<NotifyEvent> = audits.iter()
+                        .filter(|audit| !audit.is_ok())
+                        .map(|audit| NotifyEvent::AuditFailure { height: audit.height, hash: audit.hash.clone(), findings: audit.findings.clone() })
+                        .collect();
+                    notifier.send_all(&events).await;
+                }
+            }
+        }
+        changed = watcher.next_change().await.is_some();
+    }
+    Ok(())
+}
+
+/// One line per checked block, plus one per finding
+pub fn print_block_audit(audit: &BlockAudit) {
+    let short = &audit.hash[..audit.hash.len().min(16)];
+    if audit.is_ok() {
+        println!("✅ {} {}", audit.height, short);
+        return;
+    }
+    println!("❌ {} {}", audit.height, short);
+    for finding in &audit.findings {
+        println!("     {}: {}", finding.rule.as_str(), finding.message);
+    }
+}
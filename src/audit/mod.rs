@@ -0,0 +1,15 @@
+// File: src/audit/mod.rs
+// Version: 1.3.0 - Continuous audit of new blocks
+// Tree: tari-lmdb-inspector/src/audit/mod.rs
+
+// Kernel MR and block output MR recomputed from the stored rows
+pub mod roots;
+
+// Timestamps earlier than the median of the previous 11 blocks or beyond the FTL
+pub mod timestamps;
+
+// Outputs spent by more than one input
+pub mod double_spends;
+
+// Hash chain, MMR size and timestamp checks of each new block (audit --follow)
+pub mod follow;
@@ -0,0 +1,248 @@
+// File: src/audit/roots.rs
+// Version: 1.0.0 - Merkle root verification for one block (audit --verify-roots)
+// Tree: tari-lmdb-inspector/src/audit/roots.rs
+//
+// A header commits to the chain's transaction data through Merkle roots; recomputing them from
+// the stored rows shows whether those rows are intact. The kernel MR is the root of the kernel
+// MMR over every kernel up to and including the block (`kernel_mmr_size` leaves, ordered by
+// `mmr_position`), so checking it reads the whole kernels table. The block output MR only
+// covers the block's own outputs: coinbase output hashes in canonical order, then the root of
+// the other outputs. The output MR (the SMT of the whole UTXO set) is not recomputed.
+//
+// Leaves are rehashed from the decoded kernel / output rather than taken from the row's stored
+// hash, and rows whose stored hash disagrees are reported.
+
+use std::path::Path;
+use serde::Serialize;
+use tokio_util::sync::CancellationToken;
+
+use tari_core::{KernelMmr, PrunedOutputMmr};
+use tari_mmr::pruned_hashset::PrunedHashSet;
+use tari_node_components::blocks::BlockHeader;
+use crate::lmdb_reader::{open_backend, KvRead, ReaderError, ReaderResult, TransactionKernelRowData, TransactionOutputRowData};
+use crate::progress::Progress;
+
+/// Stored-hash mismatches listed individually before the rest are only counted
+const MAX_LISTED_MISMATCHES: usize = 10;
+
+/// Header root compared against the recomputed value
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RootCheck {
+    /// Header field (`kernel_mr`, `block_output_mr`)
+    pub root: String,
+    pub header: String,
+    /// `None` when the root could not be computed
+    pub computed: Option<String>,
+    /// Leaves the root was computed over
+    pub leaves: u64,
+    pub matches: bool,
+}
+
+/// Merkle root verification of one block
+#[derive(Debug, Clone, Serialize)]
+pub struct RootReport {
+    pub height: u64,
+    pub hash: String,
+    pub checks: Vec<RootCheck>,
+    /// What makes a mismatch likely: missing leaves, rows whose stored hash is wrong
+    pub problems: Vec<String>,
+}
+
+impl RootReport {
+    /// Every root matched and nothing looked wrong
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty() && self.checks.iter().all(|check| check.matches)
+    }
+}
+
+/// Recompute the kernel MR of the block at `height` (and its block output MR when `outputs`) and
+/// compare them with the header; stops with `ReaderError::Cancelled` once `cancel` fires
+pub fn verify_roots(
+    path: &Path,
+    height: u64,
+    outputs: bool,
+    progress: &dyn Progress,
+    cancel: &CancellationToken,
+) -> ReaderResult<RootReport> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+
+    let data = read.get("headers", &height.to_le_bytes())?.ok_or(ReaderError::BlockNotFound(height))?;
+    let header: BlockHeader = bincode::deserialize(&data)
+        .map_err(|source| ReaderError::UnsupportedHeader { height, source })?;
+    let block_hash = header.hash();
+
+    let mut report = RootReport { height, hash: hex::encode(block_hash.as_slice()), checks: Vec::new(), problems: Vec::new() };
+    report.checks.push(check_kernel_mr(&*read, &header, &mut report.problems, progress, cancel)?);
+    if outputs {
+        report.checks.push(check_block_output_mr(&*read, &header, block_hash.as_slice(), &mut report.problems)?);
+    }
+    Ok(report)
+}
+
+/// Rebuild the kernel MMR from every kernel with a position below the header's `kernel_mmr_size`
+fn check_kernel_mr(
+    read: &dyn KvRead,
+    header: &BlockHeader,
+    problems: &mut Vec<String>,
+    progress: &dyn Progress,
+    cancel: &CancellationToken,
+) -> ReaderResult<RootCheck> {
+    let size = header.kernel_mmr_size;
+    progress.begin("Reading kernels", Some(read.entries("kernels")?));
+
+    let mut leaves: Vec<(u64, Vec<u8>)> = Vec::new();
+    let mut mismatched = Vec::new();
+    let mut scanned = 0;
+    let mut failure = None;
+    read.scan("kernels", &[], &mut |_, value| {
+        if cancel.is_cancelled() {
+            failure = Some(ReaderError::Cancelled);
+            return false;
+        }
+        let row: TransactionKernelRowData = match bincode::deserialize(value) {
+            Ok(row) => row,
+            Err(source) => {
+                failure = Some(ReaderError::Decode { table: "kernels", source });
+                return false;
+            }
+        };
+        if row.mmr_position < size {
+            let hash = row.kernel.hash();
+            if hash != row.hash {
+                mismatched.push(row.mmr_position);
+            }
+            leaves.push((row.mmr_position, hash.to_vec()));
+        }
+        scanned += 1;
+        if scanned % 10_000 == 0 {
+            progress.update(scanned);
+        }
+        true
+    })?;
+    if let Some(e) = failure {
+        if matches!(e, ReaderError::Cancelled) {
+            progress.finish("Cancelled");
+        }
+        return Err(e);
+    }
+    progress.finish(&format!("Read {} kernels", scanned));
+
+    leaves.sort_unstable_by_key(|(position, _)| *position);
+    let positions_complete = leaves.iter().enumerate().all(|(i, (position, _))| i as u64 == *position);
+    if !positions_complete {
+        problems.push(format!(
+            "Kernel MMR positions 0..{} are not all present exactly once ({} kernels found)",
+            size, leaves.len()
+        ));
+    }
+    report_mismatches("kernels at MMR positions", &mismatched, problems);
+
+    let mut mmr = KernelMmr::new(Vec::new());
+    let computed = leaves.into_iter()
+        .try_for_each(|(_, hash)| mmr.push(hash).map(|_| ()))
+        .and_then(|_| mmr.get_merkle_root())
+        .map(hex::encode);
+    Ok(root_check("kernel_mr", header.kernel_mr.as_slice(), computed, size, problems))
+}
+
+/// Rebuild the block output MR: coinbase output hashes, then the root of the other outputs
+fn check_block_output_mr(
+    read: &dyn KvRead,
+    header: &BlockHeader,
+    block_hash: &[u8],
+    problems: &mut Vec<String>,
+) -> ReaderResult<RootCheck> {
+    let mut rows: Vec<TransactionOutputRowData> = Vec::new();
+    let mut failure = None;
+    read.scan("utxos", block_hash, &mut |key, value| {
+        if !key.starts_with(block_hash) {
+            return false;
+        }
+        match bincode::deserialize(value) {
+            Ok(row) => rows.push(row),
+            Err(source) => {
+                failure = Some(ReaderError::Decode { table: "utxos", source });
+                return false;
+            }
+        }
+        true
+    })?;
+    if let Some(e) = failure {
+        return Err(e);
+    }
+    if rows.is_empty() {
+        problems.push("No outputs stored for the block (pruned node?)".to_string());
+    }
+
+    // Bodies keep outputs in canonical (sorted) order
+    rows.sort_by(|a, b| a.output.cmp(&b.output));
+    let mut mismatched = Vec::new();
+    let mut coinbase_mmr = PrunedOutputMmr::new(PrunedHashSet::default());
+    let mut normal_mmr = PrunedOutputMmr::new(PrunedHashSet::default());
+    let pushed = rows.iter().enumerate().try_for_each(|(i, row)| {
+        let hash = row.output.hash();
+        if hash != row.hash {
+            mismatched.push(i as u64);
+        }
+        let mmr = if row.output.features.is_coinbase() { &mut coinbase_mmr } else { &mut normal_mmr };
+        mmr.push(hash.to_vec()).map(|_| ())
+    });
+    report_mismatches("outputs at block indexes", &mismatched, problems);
+
+    let computed = pushed
+        .and_then(|_| normal_mmr.get_merkle_root())
+        .and_then(|normal_root| coinbase_mmr.push(normal_root))
+        .and_then(|_| coinbase_mmr.get_merkle_root())
+        .map(hex::encode);
+    Ok(root_check("block_output_mr", header.block_output_mr.as_slice(), computed, rows.len() as u64, problems))
+}
+
+fn root_check<E: std::fmt::Display>(
+    root: &str,
+    header: &[u8],
+    computed: Result<String, E>,
+    leaves: u64,
+    problems: &mut Vec<String>,
+) -> RootCheck {
+    let header = hex::encode(header);
+    let computed = match computed {
+        Ok(computed) => Some(computed),
+        Err(e) => {
+            problems.push(format!("Cannot compute {}: {}", root, e));
+            None
+        }
+    };
+    RootCheck { root: root.to_string(), matches: computed.as_deref() == Some(header.as_str()), header, computed, leaves }
+}
+
+/// Record rows whose stored hash differs from the rehashed content
+fn report_mismatches(rows: &str, positions: &[u64], problems: &mut Vec<String>) {
+    if positions.is_empty() {
+        return;
+    }
+    let listed: Vec<String> = positions.iter().take(MAX_LISTED_MISMATCHES).map(|p| p.to_string()).collect();
+    let more = positions.len().saturating_sub(MAX_LISTED_MISMATCHES);
+    problems.push(format!(
+        "Stored hash does not match the content of {} {}{}",
+        rows, listed.join(", "), if more > 0 { format!(" and {} more", more) } else { String::new() }
+    ));
+}
+
+/// Print each root with its header and recomputed values
+pub fn print_roots(report: &RootReport) {
+    println!("Block {} ({})", report.height, report.hash);
+    for check in &report.checks {
+        let mark = if check.matches { "✅" } else { "❌" };
+        println!("  {} {} over {} leaves", mark, check.root, check.leaves);
+        println!("     header:   {}", check.header);
+        println!("     computed: {}", check.computed.as_deref().unwrap_or("-"));
+    }
+    println!();
+    for problem in &report.problems {
+        println!("⚠️  {}", problem);
+    }
+    if report.is_ok() {
+        println!("✅ Roots match the stored transaction data");
+    }
+}
@@ -0,0 +1,173 @@
+// File: src/audit/timestamps.rs
+// Version: 1.0.0 - Timestamp anomaly detection (audit --timestamps, /analytics/timestamp-anomalies)
+// Tree: tari-lmdb-inspector/src/audit/timestamps.rs
+//
+// Negative intervals only show that a block is older than its parent, which consensus allows.
+// The rules that matter are the ones a base node enforces: a timestamp may not be earlier than
+// the median of the previous 11 blocks, and may not be more than the future time limit (FTL)
+// ahead of the node's clock. The future check can only use the clock at audit time, so it
+// catches blocks that are still in the future, not ones that were when they were mined.
+
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::lmdb_reader::{ReaderResult, StreamReader};
+use crate::timezone::DisplayZone;
+
+/// Default number of blocks checked
+pub const DEFAULT_WINDOW: u64 = 1000;
+
+/// Largest window accepted from API callers
+pub const MAX_WINDOW: u64 = 100_000;
+
+/// Previous blocks whose median timestamp a block must not be earlier than
+pub const MEDIAN_TIMESTAMP_COUNT: usize = 11;
+
+/// Seconds a timestamp may be ahead of the clock (consensus FTL)
+pub const FUTURE_TIME_LIMIT: u64 = 540;
+
+/// Rule a block's timestamp breaks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyKind {
+    /// Earlier than the median of the previous 11 blocks
+    BeforeMedian,
+    /// More than the FTL ahead of the clock
+    FutureDrift,
+}
+
+impl AnomalyKind {
+    /// Name as it appears in JSON
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::BeforeMedian => "before_median",
+            Self::FutureDrift => "future_drift",
+        }
+    }
+}
+
+/// A block whose timestamp breaks a rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct TimestampAnomaly {
+    pub height: u64,
+    pub timestamp: u64,
+    pub kind: AnomalyKind,
+    /// Median of the previous blocks (`before_median`) or the clock (`future_drift`)
+    pub reference: u64,
+    /// Seconds between the timestamp and the reference
+    pub seconds: u64,
+}
+
+/// Timestamp anomalies over a window
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimestampAnomalies {
+    pub window: u64,
+    pub start_height: u64,
+    pub end_height: u64,
+    /// Clock the future check used (Unix seconds)
+    pub checked_at: u64,
+    /// Blocks whose timestamp was not after their parent's (allowed, shown as "⚠ -time")
+    pub non_positive_intervals: usize,
+    pub anomalies: Vec<TimestampAnomaly>,
+}
+
+/// Check the last `window` blocks against the timestamp rules, with `now` as the clock
+pub fn timestamp_anomalies(path: &Path, window: u64, now: u64) -> ReaderResult<TimestampAnomalies> {
+    let window = window.clamp(1, MAX_WINDOW);
+    let reader = StreamReader::open(path)?;
+    let snapshot = reader.snapshot()?;
+
+    let end_height = snapshot.tip_height()?.unwrap_or(0);
+    let start_height = end_height.saturating_sub(window - 1);
+    // The first blocks checked need their own previous blocks for the median
+    let context_start = start_height.saturating_sub(MEDIAN_TIMESTAMP_COUNT as u64);
+
+    let timestamps: Vec<(u64, u64)> = snapshot.headers(context_start, end_height)?
+        .filter_map(Result::ok)
+        .map(|(height, header)| (height, header.timestamp.as_u64()))
+        .collect();
+
+    let mut report = anomalies_from_timestamps(&timestamps, start_height, now);
+    report.window = window;
+    Ok(report)
+}
+
+/// Check (height, timestamp) pairs in ascending height order from `start_height` on; earlier
+/// pairs only serve as median context
+pub fn anomalies_from_timestamps(timestamps: &[(u64, u64)], start_height: u64, now: u64) -> TimestampAnomalies {
+    let mut anomalies = Vec::new();
+    let mut non_positive_intervals = 0;
+    let mut checked = 0u64;
+
+    for (i, &(height, timestamp)) in timestamps.iter().enumerate() {
+        if height < start_height {
+            continue;
+        }
+        checked += 1;
+        let previous = &timestamps[i.saturating_sub(MEDIAN_TIMESTAMP_COUNT)..i];
+        if let Some(&(parent_height, parent_ts)) = previous.last() {
+            if parent_height + 1 == height && timestamp <= parent_ts {
+                non_positive_intervals += 1;
+            }
+        }
+
+        let previous: Vec<u64> = previous.iter().map(|(_, ts)| *ts).collect();
+        if let Some(median) = median_timestamp(&previous) {
+            if timestamp < median {
+                anomalies.push(TimestampAnomaly { height, timestamp, kind: AnomalyKind::BeforeMedian, reference: median, seconds: median - timestamp });
+            }
+        }
+        if timestamp > now + FUTURE_TIME_LIMIT {
+            anomalies.push(TimestampAnomaly { height, timestamp, kind: AnomalyKind::FutureDrift, reference: now, seconds: timestamp - now });
+        }
+    }
+
+    TimestampAnomalies {
+        window: checked,
+        start_height: timestamps.iter().map(|(h, _)| *h).find(|h| *h >= start_height).unwrap_or(start_height),
+        end_height: timestamps.last().map(|(h, _)| *h).unwrap_or(0),
+        checked_at: now,
+        non_positive_intervals,
+        anomalies,
+    }
+}
+
+/// Median of up to 11 previous timestamps as a base node computes it (mean of the middle two
+/// for an even count); `None` for the genesis block
+pub fn median_timestamp(previous: &[u64]) -> Option<u64> {
+    if previous.is_empty() {
+        return None;
+    }
+    let mut sorted = previous.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    Some(if sorted.len() % 2 == 0 { (sorted[mid - 1] + sorted[mid]) / 2 } else { sorted[mid] })
+}
+
+/// Print the anomalies as a table
+pub fn print_timestamp_anomalies(report: &TimestampAnomalies, zone: &DisplayZone) {
+    println!("Blocks {}-{} ({} checked, clock {})",
+        report.start_height, report.end_height, report.window, zone.datetime(report.checked_at));
+    println!("Non-positive intervals: {} (allowed by consensus)", report.non_positive_intervals);
+    println!();
+
+    if report.anomalies.is_empty() {
+        println!("✅ No timestamp is earlier than the median of the previous {} blocks and within {}s of the clock",
+            MEDIAN_TIMESTAMP_COUNT, FUTURE_TIME_LIMIT);
+        return;
+    }
+    println!("{:<10} {:<25} {:<14} {:<25} {:>10}", "Height", "Timestamp", "Rule", "Reference", "Seconds");
+    println!("{}", "-".repeat(88));
+    for anomaly in &report.anomalies {
+        println!("{:<10} {:<25} {:<14} {:<25} {:>10}",
+            anomaly.height,
+            zone.datetime(anomaly.timestamp),
+            anomaly.kind.as_str(),
+            zone.datetime(anomaly.reference),
+            anomaly.seconds,
+        );
+    }
+    println!();
+    println!("⚠️  {} timestamp anomalies", report.anomalies.len());
+}
@@ -0,0 +1,150 @@
+// File: src/auth.rs
+// Version: 1.2.0 - Percent-decoded ?token=, cookie-safe --auth-token checked at startup
+// Tree: tari-lmdb-inspector/src/auth.rs
+//
+// With `--auth-token`, every route except the embedded static assets requires the token.
+// API clients send `Authorization: Bearer <token>`. Browsers cannot set headers on page
+// loads or WebSocket upgrades, so `?token=<token>` is also accepted; it answers with an
+// HttpOnly cookie that carries the token for the dashboard's later fetches and `/ws`. The
+// token goes into that cookie verbatim, so `--auth-token` is limited to cookie-safe characters.
+
+use std::sync::Arc;
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::verbosity;
+
+/// Cookie set after a successful `?token=` login
+const COOKIE_NAME: &str = "inspector_token";
+
+/// How a request presented the token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Credential {
+    Header,
+    Cookie,
+    Query,
+}
+
+/// Configured token; `None` leaves the server open
+#[derive(Clone, Default)]
+pub struct AuthToken {
+    token: Option<Arc<str>>,
+    /// Mark the login cookie `Secure` (served over HTTPS)
+    secure_cookie: bool,
+}
+
+/// `--auth-token` value parser: printable ASCII without space, `"`, `,`, `;` or `\`, the
+/// characters a cookie value may hold unquoted (RFC 6265)
+pub fn parse_token(value: &str) -> Result<String, String> {
+    match value.chars().find(|c| !matches!(c, '!' | '#'..='+' | '-'..=':' | '<'..='[' | ']'..='~')) {
+        Some(c) => Err(format!("{:?} is not allowed in a token; use printable ASCII without space, '\"', ',', ';' or '\\'", c)),
+        None => Ok(value.to_string()),
+    }
+}
+
+impl AuthToken {
+    pub fn new(token: Option<String>) -> Self {
+        Self {
+            token: token.filter(|t| !t.is_empty()).map(Arc::from),
+            secure_cookie: false,
+        }
+    }
+
+    /// Only send the login cookie over HTTPS
+    pub fn with_secure_cookie(mut self, secure: bool) -> Self {
+        self.secure_cookie = secure;
+        self
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.token.is_some()
+    }
+
+    /// Which credential on the request matches the token, if any
+    fn check(&self, token: &str, headers: &HeaderMap, query: Option<&str>) -> Option<Credential> {
+        let matches = |candidate: &str| constant_time_eq(candidate.as_bytes(), token.as_bytes());
+
+        let bearer = headers.get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "));
+        if bearer.is_some_and(|t| matches(t.trim())) {
+            return Some(Credential::Header);
+        }
+
+        let cookie = headers.get_all(header::COOKIE).iter()
+            .filter_map(|v| v.to_str().ok())
+            .flat_map(|v| v.split(';'))
+            .filter_map(|pair| pair.trim().split_once('='))
+            .find(|(name, _)| *name == COOKIE_NAME)
+            .map(|(_, value)| value);
+        if cookie.is_some_and(matches) {
+            return Some(Credential::Cookie);
+        }
+
+        let from_query = query.into_iter()
+            .flat_map(|q| q.split('&'))
+            .filter_map(|pair| pair.split_once('='))
+            .find(|(name, _)| *name == "token")
+            .and_then(|(_, value)| percent_decode(value));
+        if from_query.is_some_and(|t| matches(&t)) {
+            return Some(Credential::Query);
+        }
+
+        None
+    }
+}
+
+/// Reject requests without the configured token (401 with `WWW-Authenticate: Bearer`)
+pub async fn require_token(State(auth): State<AuthToken>, request: Request, next: Next) -> Response {
+    let Some(token) = auth.token.clone() else {
+        return next.run(request).await;
+    };
+
+    match auth.check(&token, request.headers(), request.uri().query()) {
+        Some(Credential::Query) => {
+            let mut response = next.run(request).await;
+            let secure = if auth.secure_cookie { "; Secure" } else { "" };
+            let cookie = format!("{}={}; Path=/; HttpOnly; SameSite=Strict{}", COOKIE_NAME, token, secure);
+            if let Ok(value) = HeaderValue::from_str(&cookie) {
+                response.headers_mut().append(header::SET_COOKIE, value);
+            }
+            response
+        }
+        Some(_) => next.run(request).await,
+        None => {
+            if verbosity::normal() {
+                println!("🔒 Unauthorized request for {}", request.uri().path());
+            }
+            let mut response = (StatusCode::UNAUTHORIZED, "Missing or invalid token").into_response();
+            response.headers_mut().insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+            response
+        }
+    }
+}
+
+/// Decode `%XX` escapes in a query value; `None` if an escape is malformed or the result is not
+/// UTF-8. `+` stays literal: tokens never contain spaces, so it cannot stand for one
+fn percent_decode(value: &str) -> Option<String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            decoded.push(hex::decode(value.get(i + 1..i + 3)?).ok()?[0]);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+/// Compare without short-circuiting on the first differing byte
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
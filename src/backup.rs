@@ -0,0 +1,91 @@
+// File: src/backup.rs
+// Version: 1.0.0 - Online compacting snapshot of the LMDB environment
+// Tree: tari-lmdb-inspector/src/backup.rs
+//
+// Uses mdb_env_copy2 with MDB_CP_COMPACT through lmdb-zero's `Environment::copy`. The copy runs
+// inside a read transaction, so it is safe while the base node keeps writing, and the compacted
+// output omits free pages. LMDB offers no progress callback, so progress is estimated by
+// watching the output file grow against the live page count.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use anyhow::{bail, Context, Result};
+use lmdb_zero::EnvBuilder;
+
+use crate::db_check;
+use crate::lmdb_reader::env_path;
+
+/// How often the output file size is sampled for progress
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Outcome of a finished backup
+#[derive(Debug, Clone)]
+pub struct BackupSummary {
+    pub output_file: PathBuf,
+    pub source_bytes: u64,
+    pub backup_bytes: u64,
+    pub elapsed: Duration,
+}
+
+/// Copy the environment at `path` into `out_dir`, compacting as it goes
+pub fn backup_database(path: &Path, out_dir: &Path) -> Result<BackupSummary> {
+    let path_str = env_path(path)?;
+
+    std::fs::create_dir_all(out_dir)
+        .with_context(|| format!("Cannot create backup directory {}", out_dir.display()))?;
+    let output_file = out_dir.join("data.mdb");
+    if output_file.exists() {
+        bail!("{} already exists; refusing to overwrite an existing snapshot", output_file.display());
+    }
+    let out_str = env_path(out_dir)?.into_owned();
+
+    // Live pages (used minus free) approximate the size of the compacted copy
+    let report = db_check::check_database(path)?;
+    let expected_bytes = report.used_bytes().saturating_sub(report.free_pages * report.page_size as u64).max(1);
+    let source_bytes = report.used_bytes();
+
+    let mut builder = EnvBuilder::new()?;
+    builder.set_maxdbs(64)?;
+    let env = unsafe {
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+    };
+
+    println!("📦 Copying {} of live data to {}", format_mib(expected_bytes), out_dir.display());
+    let started = Instant::now();
+    let copy = std::thread::spawn(move || env.copy(&out_str, lmdb_zero::copy::COMPACT));
+
+    while !copy.is_finished() {
+        std::thread::sleep(PROGRESS_INTERVAL);
+        let written = std::fs::metadata(&output_file).map(|m| m.len()).unwrap_or(0);
+        let percent = (written as f64 / expected_bytes as f64 * 100.0).min(99.9);
+        print!("\r   {:>5.1}%  {} / {}", percent, format_mib(written), format_mib(expected_bytes));
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+    }
+    println!();
+
+    match copy.join() {
+        Ok(result) => result.context("LMDB compacting copy failed")?,
+        Err(_) => bail!("Backup thread panicked"),
+    }
+
+    let backup_bytes = std::fs::metadata(&output_file).map(|m| m.len()).unwrap_or(0);
+    Ok(BackupSummary {
+        output_file,
+        source_bytes,
+        backup_bytes,
+        elapsed: started.elapsed(),
+    })
+}
+
+/// Print the result of a backup
+pub fn print_summary(summary: &BackupSummary) {
+    println!("✅ Snapshot written to {}", summary.output_file.display());
+    println!("   Source used: {}", format_mib(summary.source_bytes));
+    println!("   Snapshot:    {}", format_mib(summary.backup_bytes));
+    println!("   Reclaimed:   {}", format_mib(summary.source_bytes.saturating_sub(summary.backup_bytes)));
+    println!("   Took:        {:.1}s", summary.elapsed.as_secs_f64());
+}
+
+fn format_mib(bytes: u64) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
@@ -0,0 +1,116 @@
+// File: src/block_cache.rs
+// Version: 1.2.0 - Lookups check the block hash
+// Tree: tari-lmdb-inspector/src/block_cache.rs
+//
+// Confirmed blocks never change, so repeated `/api/v1/block/:height` requests for them can be
+// served without decoding their transactions. Entries remember the block hash they were built
+// from and a lookup must name the hash now at that height; the unconfirmed tip is never cached
+// and any entry whose hash no longer matches the chain is dropped.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use crate::data_models::{BlockDetailResponse, BlockInfo};
+
+/// Default number of block detail responses kept in memory
+pub const DEFAULT_CAPACITY: usize = 1024;
+
+/// A cached response together with the block hash it describes
+struct CacheEntry {
+    hash: String,
+    response: Arc<BlockDetailResponse>,
+}
+
+/// Least-recently-used cache of block detail responses keyed by height + hash
+pub struct BlockCache {
+    capacity: usize,
+    entries: HashMap<u64, CacheEntry>,
+    /// Heights ordered from least to most recently used
+    order: VecDeque<u64>,
+    hits: u64,
+    misses: u64,
+}
+
+impl BlockCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Look up a cached response for `height` built from the block with `hash`; an entry for
+    /// another block at that height (replaced by a reorg) is dropped and counts as a miss
+    pub fn get(&mut self, height: u64, hash: &str) -> Option<Arc<BlockDetailResponse>> {
+        match self.entries.get(&height) {
+            Some(entry) if entry.hash == hash => {
+                let response = entry.response.clone();
+                self.touch(height);
+                self.hits += 1;
+                Some(response)
+            }
+            Some(_) => {
+                self.entries.remove(&height);
+                self.order.retain(|h| *h != height);
+                self.misses += 1;
+                None
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Cache a response for a confirmed block
+    pub fn insert(&mut self, height: u64, hash: String, response: BlockDetailResponse) {
+        if self.entries.insert(height, CacheEntry { hash, response: Arc::new(response) }).is_some() {
+            self.touch(height);
+            return;
+        }
+
+        self.order.push_back(height);
+        while self.order.len() > self.capacity {
+            if let Some(evicted) = self.order.pop_front() {
+                self.entries.remove(&evicted);
+            }
+        }
+    }
+
+    /// Drop entries at or above `tip_height` and any whose hash disagrees with the current chain
+    /// `recent_blocks` is the freshly read list of recent blocks (any order)
+    pub fn reconcile(&mut self, tip_height: u64, recent_blocks: &[BlockInfo]) {
+        let current: HashMap<u64, &str> = recent_blocks.iter()
+            .map(|b| (b.height, b.hash.as_str()))
+            .collect();
+
+        let stale: Vec<u64> = self.entries.iter()
+            .filter(|(height, entry)| {
+                **height >= tip_height
+                    || current.get(height).is_some_and(|hash| *hash != entry.hash)
+            })
+            .map(|(height, _)| *height)
+            .collect();
+
+        for height in stale {
+            self.entries.remove(&height);
+            self.order.retain(|h| *h != height);
+        }
+    }
+
+    /// (entries, hits, misses) for logging
+    pub fn stats(&self) -> (usize, u64, u64) {
+        (self.entries.len(), self.hits, self.misses)
+    }
+
+    /// Mark `height` as most recently used
+    fn touch(&mut self, height: u64) {
+        if let Some(pos) = self.order.iter().position(|h| *h == height) {
+            self.order.remove(pos);
+        }
+        self.order.push_back(height);
+    }
+}
@@ -0,0 +1,133 @@
+// File: src/block_stream.rs
+// Version: 1.1.0 - Chunked block range streaming over WebSocket with ack-based backpressure
+// Tree: tari-lmdb-inspector/src/block_stream.rs
+//
+// `GET /api/v1/blocks/range` answers with one body, which for a long range means the browser
+// waits for (and holds) everything before drawing a row. A `StreamBlocks { from, to }` WebSocket
+// request instead gets a `StreamStarted` followed by `BlockChunk`s of `STREAM_CHUNK` blocks, in
+// the same `RangeBlock` format. The server stays at most `STREAM_WINDOW` chunks ahead of the
+// client's last `StreamAck`, so a slow renderer pauses the LMDB reads instead of filling the
+// socket buffer, and a client that stops acking for `ACK_TIMEOUT` has its stream ended.
+//
+// A connection has at most one stream: a new `StreamBlocks` replaces the running one, and
+// `StopStream` or a disconnect ends it.
+
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+use crate::data_models::{RangeBlock, WebSocketMessage};
+use crate::data_provider::DataProvider;
+use crate::lmdb_reader::BlockFilter;
+use crate::timezone::DisplayZone;
+
+/// Blocks per `BlockChunk`
+pub const STREAM_CHUNK: u64 = 100;
+
+/// Chunks sent ahead of the last acknowledged one
+pub const STREAM_WINDOW: u64 = 2;
+
+/// How long a stream waits for an ack before giving up on the client
+pub const ACK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// A running block stream; dropping it stops the stream
+pub struct BlockStream {
+    id: u64,
+    /// Chunks the client has acknowledged
+    acked: watch::Sender<u64>,
+    task: JoinHandle<()>,
+}
+
+impl BlockStream {
+    /// Stream `from..=to` to `tx` as stream `id`, with `timestamp_local` in `zone`
+    pub fn start(
+        id: u64,
+        from: u64,
+        to: u64,
+        zone: DisplayZone,
+        provider: Arc<dyn DataProvider>,
+        tx: mpsc::Sender<WebSocketMessage>,
+    ) -> Self {
+        let (acked, acks) = watch::channel(0);
+        let task = tokio::spawn(stream_blocks(id, from, to, zone, provider, acks, tx));
+        Self { id, acked, task }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Record that the client has rendered chunk `seq` (and every chunk before it)
+    pub fn ack(&self, seq: u64) {
+        self.acked.send_if_modified(|acked| {
+            let modified = seq + 1 > *acked;
+            *acked = (*acked).max(seq + 1);
+            modified
+        });
+    }
+}
+
+impl Drop for BlockStream {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Number of chunks covering `from..=to`
+pub fn chunk_count(from: u64, to: u64) -> u64 {
+    if from > to { 0 } else { (to - from) / STREAM_CHUNK + 1 }
+}
+
+async fn stream_blocks(
+    stream: u64,
+    from: u64,
+    to: u64,
+    zone: DisplayZone,
+    provider: Arc<dyn DataProvider>,
+    mut acks: watch::Receiver<u64>,
+    tx: mpsc::Sender<WebSocketMessage>,
+) {
+    let chunks = chunk_count(from, to);
+    if chunks == 0 {
+        let _ = tx.send(WebSocketMessage::Error {
+            message: format!("Stream range {}..={} is empty", from, to),
+        }).await;
+        return;
+    }
+    if tx.send(WebSocketMessage::StreamStarted { stream, from, to, chunks }).await.is_err() {
+        return;
+    }
+
+    for seq in 0..chunks {
+        // Wait until this chunk is inside the window
+        match tokio::time::timeout(ACK_TIMEOUT, acks.wait_for(|acked| seq < acked + STREAM_WINDOW)).await {
+            Ok(Ok(_)) => {}
+            Ok(Err(_)) => return,
+            Err(_) => {
+                let _ = tx.send(WebSocketMessage::Error {
+                    message: format!("Stream {} stopped: no ack for {}s", stream, ACK_TIMEOUT.as_secs()),
+                }).await;
+                return;
+            }
+        }
+
+        let start = from + seq * STREAM_CHUNK;
+        let end = (start + STREAM_CHUNK - 1).min(to);
+        let message = match provider.headers(BlockFilter::Range(start, end)).await {
+            Ok(blocks) => WebSocketMessage::BlockChunk {
+                stream,
+                seq,
+                last: seq + 1 == chunks,
+                blocks: blocks.into_iter().map(|block| RangeBlock::from(block).localized(&zone)).collect(),
+            },
+            Err(e) => WebSocketMessage::Error {
+                message: format!("Stream {} failed at blocks {}..={}: {}", stream, start, end, e),
+            },
+        };
+        let failed = matches!(message, WebSocketMessage::Error { .. });
+        if tx.send(message).await.is_err() || failed {
+            return;
+        }
+    }
+}
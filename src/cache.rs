@@ -0,0 +1,199 @@
+// File: src/cache.rs
+// Version: 1.1.0 - Warm-start dashboard snapshot
+// Tree: tari-lmdb-inspector/src/cache.rs
+//
+// Derived artifacts (height → hash map, per-block aggregates, analytics series) take a full
+// scan to build, so they are kept in a cache directory (`--cache-dir`, default
+// `~/.tari-lmdb-inspector`) and only extended after a restart. Each artifact is stored with
+// the height and hash of the last block it covers; on load that block must still be in the
+// chain, otherwise a reorg replaced it and the artifact is discarded.
+
+use std::path::{Path, PathBuf};
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::data_models::DashboardData;
+use crate::lmdb_reader::{paths, ReaderError, ReaderResult, StreamReader};
+
+/// Height → hash artifact file
+pub const HEIGHT_HASHES_FILE: &str = "height_hashes.bin";
+
+/// Last dashboard state, saved on shutdown for a warm start
+pub const DASHBOARD_FILE: &str = "dashboard.json";
+
+/// Most heights added to the height → hash map per refresh
+pub const MAX_HASHES_PER_REFRESH: u64 = 100_000;
+
+/// Default cache directory
+pub fn default_dir() -> PathBuf {
+    let base = paths::home_dir().unwrap_or_else(|| PathBuf::from("."));
+    base.join(".tari-lmdb-inspector")
+}
+
+/// The last block an artifact covers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TipKey {
+    pub height: u64,
+    pub hash: String,
+}
+
+impl TipKey {
+    /// Whether the block at `height` in the database still has `hash`
+    pub fn is_current(&self, db_path: &Path) -> ReaderResult<bool> {
+        let reader = StreamReader::open(db_path)?;
+        let snapshot = reader.snapshot()?;
+        Ok(match snapshot.headers(self.height, self.height)?.next() {
+            Some(Ok((height, header))) => hex::encode(snapshot.block_hash(height, &header)) == self.hash,
+            _ => false,
+        })
+    }
+}
+
+/// Stored artifact with the block it was built up to
+#[derive(Serialize, Deserialize)]
+struct Entry<T> {
+    tip: TipKey,
+    value: T,
+}
+
+/// Directory holding derived artifacts
+#[derive(Debug, Clone)]
+pub struct CacheDir {
+    dir: PathBuf,
+}
+
+impl Default for CacheDir {
+    fn default() -> Self {
+        Self::new(default_dir())
+    }
+}
+
+impl CacheDir {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of an artifact file inside the directory
+    pub fn file(&self, name: &str) -> PathBuf {
+        self.dir.join(name)
+    }
+
+    /// Load an artifact if its tip block is still in the chain at `db_path`; stale or corrupt
+    /// files are removed
+    pub fn load<T: DeserializeOwned>(&self, name: &str, db_path: &Path) -> Option<(TipKey, T)> {
+        let path = self.file(name);
+        let data = std::fs::read(&path).ok()?;
+        let current = bincode::deserialize::<Entry<T>>(&data)
+            .ok()
+            .map(|entry| (entry.tip.is_current(db_path), entry));
+        match current {
+            Some((Ok(true), entry)) => Some((entry.tip, entry.value)),
+            // An unreadable database says nothing about the artifact, so keep it
+            Some((Err(_), _)) => None,
+            _ => {
+                let _ = std::fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    /// Dashboard saved by the previous run, marked stale; it is shown as-is until the first
+    /// read replaces it, so it is not checked against the chain
+    pub fn load_dashboard(&self) -> Option<DashboardData> {
+        let data = std::fs::read_to_string(self.file(DASHBOARD_FILE)).ok()?;
+        let mut dashboard: DashboardData = serde_json::from_str(&data).ok()?;
+        dashboard.stale = true;
+        Some(dashboard)
+    }
+
+    /// Save the dashboard for the next start; empty or already stale data is skipped
+    pub fn store_dashboard(&self, dashboard: &DashboardData) -> Result<()> {
+        if dashboard.stale || dashboard.last_updated == 0 {
+            return Ok(());
+        }
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(self.file(DASHBOARD_FILE), serde_json::to_string(dashboard)?)?;
+        Ok(())
+    }
+
+    /// Store an artifact built up to `tip`
+    pub fn store<T: Serialize>(&self, name: &str, tip: &TipKey, value: &T) -> Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let entry = Entry { tip: tip.clone(), value };
+        // Write then rename so a crash never leaves a truncated artifact
+        let path = self.file(name);
+        let partial = path.with_extension("partial");
+        std::fs::write(&partial, bincode::serialize(&entry)?)?;
+        std::fs::rename(&partial, &path)?;
+        Ok(())
+    }
+}
+
+/// Block hashes by height; `None` where the header could not be decoded
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HeightHashes {
+    hashes: Vec<Option<Vec<u8>>>,
+}
+
+impl HeightHashes {
+    /// Highest height covered
+    pub fn covered_to(&self) -> Option<u64> {
+        (self.hashes.len() as u64).checked_sub(1)
+    }
+
+    pub fn hash_at(&self, height: u64) -> Option<&[u8]> {
+        self.hashes.get(height as usize)?.as_deref()
+    }
+
+    /// Height of the block with this hash, if covered
+    pub fn height_of(&self, hash: &[u8]) -> Option<u64> {
+        self.hashes.iter().position(|h| h.as_deref() == Some(hash)).map(|h| h as u64)
+    }
+
+    /// Extend towards the tip by at most `MAX_HASHES_PER_REFRESH` heights; returns the key of
+    /// the last block covered
+    fn extend(&mut self, db_path: &Path) -> ReaderResult<Option<TipKey>> {
+        let reader = StreamReader::open(db_path)?;
+        let snapshot = reader.snapshot()?;
+        let Some(tip) = snapshot.tip_height()? else {
+            return Ok(None);
+        };
+        let start = self.hashes.len() as u64;
+        let end = tip.min(start + MAX_HASHES_PER_REFRESH - 1);
+        if start <= end {
+            for entry in snapshot.headers(start, end)? {
+                let (height, hash) = match entry {
+                    Ok((height, header)) => (height, Some(snapshot.block_hash(height, &header))),
+                    // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                    Err(ReaderError::UnsupportedHeader { height, .. }) => (height, None),
+                    Err(e) => return Err(e),
+                };
+                self.hashes.resize(height as usize, None);
+                self.hashes.push(hash);
+            }
+        }
+
+        // The key must be a decodable block, so walk back past any C29 tail
+        Ok(self.hashes.iter().enumerate().rev()
+            .find_map(|(height, hash)| Some(TipKey { height: height as u64, hash: hex::encode(hash.as_ref()?) })))
+    }
+}
+
+/// Load the height → hash map from `cache`, extend it from the database and store it back
+pub fn refresh_height_hashes(db_path: &Path, cache: &CacheDir) -> Result<HeightHashes> {
+    let mut hashes = cache.load::<HeightHashes>(HEIGHT_HASHES_FILE, db_path)
+        .map(|(_, hashes)| hashes)
+        .unwrap_or_default();
+    let before = hashes.covered_to();
+    if let Some(tip) = hashes.extend(db_path)? {
+        if hashes.covered_to() != before {
+            cache.store(HEIGHT_HASHES_FILE, &tip, &hashes)?;
+        }
+    }
+    Ok(hashes)
+}
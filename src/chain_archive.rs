@@ -0,0 +1,278 @@
+// File: src/chain_archive.rs
+// Version: 1.3.0 - Export reads through KvBackend
+// Tree: tari-lmdb-inspector/src/chain_archive.rs
+//
+// `export-chain` copies the raw LMDB rows for a height range into a `.tar.zst` archive and
+// `import-chain` loads them into a fresh LMDB environment that every other mode can open.
+// Rows are stored byte-for-byte, so a header or transaction that fails to decode on one
+// machine fails the same way on another without shipping a multi-GB database.
+//
+// Archive layout:
+//   manifest.json          - ArchiveManifest
+//   tables/<name>.rows     - repeated [u32 LE key len][key][u32 LE value len][value]
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use anyhow::{bail, Context, Result};
+use lmdb_zero::{EnvBuilder, Database, WriteTransaction};
+use lmdb_zero::DatabaseOptions;
+use serde::{Deserialize, Serialize};
+
+use tari_node_components::blocks::BlockHeader;
+use crate::lmdb_reader::block_hash::BlockHashResolver;
+use crate::lmdb_reader::{env_path, open_backend, KvRead, ReaderError};
+use crate::progress::Progress;
+use tokio_util::sync::CancellationToken;
+
+/// Archive format version written to the manifest
+const FORMAT_VERSION: u32 = 1;
+
+/// Tables keyed by little-endian height
+const HEIGHT_TABLES: [&str; 2] = ["headers", "header_accumulated_data"];
+
+/// Tables whose keys start with the block hash
+const BLOCK_PREFIX_TABLES: [&str; 3] = ["kernels", "utxos", "inputs"];
+
+/// Hash → height index, needed for hash search
+const HASH_INDEX_TABLE: &str = "block_hashes";
+
+/// zstd compression level for exported archives
+const ZSTD_LEVEL: i32 = 9;
+
+/// Description of an exported archive
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveManifest {
+    pub format_version: u32,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub created_at: u64,
+    pub tool_version: String,
+    /// Rows exported per table
+    pub tables: BTreeMap<String, u64>,
+}
+
+/// Export raw rows for heights `start..=end` into a `.tar.zst` archive
+/// Each table is reported to `progress` as one operation over the range's heights; the export
+/// stops with `ReaderError::Cancelled` (and writes nothing) once `cancel` fires
+pub fn export_chain(
+    path: &Path,
+    start: u64,
+    end: u64,
+    out: &Path,
+    progress: &dyn Progress,
+    cancel: &CancellationToken,
+) -> Result<ArchiveManifest> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    read.entries("headers").context("headers table not found")?;
+    let resolver = BlockHashResolver::new(&*read)?;
+
+    let mut tables: BTreeMap<String, Vec<u8>> = BTreeMap::new();
+    let mut counts: BTreeMap<String, u64> = BTreeMap::new();
+
+    // Height-keyed tables
+    for name in HEIGHT_TABLES {
+        if !read.has_table(name)? {
+            continue;
+        }
+        let buffer = tables.entry(name.to_string()).or_default();
+        let count = counts.entry(name.to_string()).or_default();
+        progress.begin(&format!("Exporting {}", name), Some(end - start + 1));
+        for height in start..=end {
+            if let Some(value) = read.get(name, &height.to_le_bytes())? {
+                write_row(buffer, &height.to_le_bytes(), &value);
+                *count += 1;
+            }
+            progress.update(height - start + 1);
+            if cancel.is_cancelled() {
+                progress.finish("Export cancelled");
+                return Err(ReaderError::Cancelled.into());
+            }
+        }
+        progress.finish(&format!("{}: {} rows", name, count));
+    }
+
+    // Block hashes drive the prefix scans of the transaction tables
+    let mut block_hashes = Vec::new();
+    for height in start..=end {
+        match block_hash_at(&resolver, &*read, height) {
+            Some(hash) => block_hashes.push((height, hash)),
+            None => println!("⚠️  No usable hash for height {}; its transactions are not exported", height),
+        }
+    }
+
+    if read.has_table(HASH_INDEX_TABLE)? {
+        let buffer = tables.entry(HASH_INDEX_TABLE.to_string()).or_default();
+        let count = counts.entry(HASH_INDEX_TABLE.to_string()).or_default();
+        for (_, hash) in &block_hashes {
+            if let Some(value) = read.get(HASH_INDEX_TABLE, hash.as_slice())? {
+                write_row(buffer, hash, &value);
+                *count += 1;
+            }
+        }
+    }
+
+    for name in BLOCK_PREFIX_TABLES {
+        if !read.has_table(name)? {
+            continue;
+        }
+        let buffer = tables.entry(name.to_string()).or_default();
+        let count = counts.entry(name.to_string()).or_default();
+        let mut cursor = read.cursor(name)?;
+        progress.begin(&format!("Exporting {}", name), Some(block_hashes.len() as u64));
+        for (done, (_, hash)) in block_hashes.iter().enumerate() {
+            progress.update(done as u64 + 1);
+            if cancel.is_cancelled() {
+                progress.finish("Export cancelled");
+                return Err(ReaderError::Cancelled.into());
+            }
+            let mut entry = cursor.seek(hash.as_slice())?;
+            while let Some((key, value)) = entry {
+                if !key.starts_with(hash) {
+                    break;
+                }
+                write_row(buffer, key, value);
+                *count += 1;
+                entry = cursor.next()?;
+            }
+        }
+        progress.finish(&format!("{}: {} rows", name, count));
+    }
+
+    let manifest = ArchiveManifest {
+        format_version: FORMAT_VERSION,
+        start_height: start,
+        end_height: end,
+        created_at: chrono::Utc::now().timestamp() as u64,
+        tool_version: env!("CARGO_PKG_VERSION").to_string(),
+        tables: counts,
+    };
+
+    let file = File::create(out).with_context(|| format!("Cannot create {}", out.display()))?;
+    let encoder = zstd::stream::write::Encoder::new(file, ZSTD_LEVEL)?.auto_finish();
+    let mut archive = tar::Builder::new(encoder);
+    append_file(&mut archive, "manifest.json", &serde_json::to_vec_pretty(&manifest)?)?;
+    for (name, rows) in &tables {
+        append_file(&mut archive, &format!("tables/{}.rows", name), rows)?;
+    }
+    archive.into_inner()?;
+
+    Ok(manifest)
+}
+
+/// Load an exported archive into a new LMDB environment at `target`
+pub fn import_chain(archive_path: &Path, target: &Path) -> Result<ArchiveManifest> {
+    let file = File::open(archive_path).with_context(|| format!("Cannot open {}", archive_path.display()))?;
+    let decoder = zstd::stream::read::Decoder::new(file)?;
+    let mut archive = tar::Archive::new(decoder);
+
+    let mut manifest: Option<ArchiveManifest> = None;
+    let mut tables: BTreeMap<String, Vec<u8>> = BTreeMap::new();
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_path = entry.path()?.to_string_lossy().into_owned();
+        let mut data = Vec::new();
+        entry.read_to_end(&mut data)?;
+
+        if entry_path == "manifest.json" {
+            manifest = Some(serde_json::from_slice(&data).context("Invalid manifest.json")?);
+        } else if let Some(name) = entry_path.strip_prefix("tables/").and_then(|p| p.strip_suffix(".rows")) {
+            tables.insert(name.to_string(), data);
+        }
+    }
+
+    let manifest = manifest.context("Archive has no manifest.json")?;
+    if manifest.format_version != FORMAT_VERSION {
+        bail!("Unsupported archive format version {}", manifest.format_version);
+    }
+
+    if target.join("data.mdb").exists() {
+        bail!("{} already contains an LMDB environment; import into an empty directory", target.display());
+    }
+    std::fs::create_dir_all(target)?;
+    let target_str = env_path(target)?;
+
+    let total_bytes: usize = tables.values().map(Vec::len).sum();
+    let mut builder = EnvBuilder::new()?;
+    builder.set_maxdbs(40)?;
+    builder.set_mapsize(total_bytes * 4 + 64 * 1024 * 1024)?;
+    let env = unsafe {
+        builder.open(&target_str, lmdb_zero::open::Flags::empty(), 0o600)?
+    };
+
+    // Create every table before the write transaction; creation opens its own transaction
+    let mut dbs = Vec::new();
+    for (name, rows) in &tables {
+        dbs.push((name, rows, Database::open(&env, Some(name), &DatabaseOptions::new(lmdb_zero::db::CREATE))?));
+    }
+
+    let txn = WriteTransaction::new(&env)?;
+    for (name, rows, db) in &dbs {
+        let mut access = txn.access();
+        for (key, value) in read_rows(rows).with_context(|| format!("Corrupt rows for table {}", name))? {
+            access.put(db, key, value, lmdb_zero::put::Flags::empty())?;
+        }
+    }
+    txn.commit()?;
+
+    Ok(manifest)
+}
+
+/// Print an archive manifest
+pub fn print_manifest(manifest: &ArchiveManifest) {
+    println!("  Heights: {} - {}", manifest.start_height, manifest.end_height);
+    for (table, rows) in &manifest.tables {
+        println!("  {:<28} {:>8} rows", table, rows);
+    }
+}
+
+/// Hash of the block at `height`, or `None` when it is unconfirmed and its header cannot be decoded
+fn block_hash_at(resolver: &BlockHashResolver, read: &dyn KvRead, height: u64) -> Option<Vec<u8>> {
+    if let Some(resolved) = resolver.resolve_confirmed(read, height) {
+        return Some(resolved.bytes);
+    }
+    let data = read.get("headers", &height.to_le_bytes()).ok()??;
+    // C29-mined headers cannot be decoded, so their hash cannot be resolved either
+    let header = bincode::deserialize::<BlockHeader>(&data).ok()?;
+    Some(header.hash().to_vec())
+}
+
+fn write_row(buffer: &mut Vec<u8>, key: &[u8], value: &[u8]) {
+    buffer.extend_from_slice(&(key.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(key);
+    buffer.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    buffer.extend_from_slice(value);
+}
+
+fn read_rows(mut data: &[u8]) -> Result<Vec<(&[u8], &[u8])>> {
+    let mut rows = Vec::new();
+    while !data.is_empty() {
+        let (key, rest) = take_field(data)?;
+        let (value, rest) = take_field(rest)?;
+        rows.push((key, value));
+        data = rest;
+    }
+    Ok(rows)
+}
+
+fn take_field(data: &[u8]) -> Result<(&[u8], &[u8])> {
+    if data.len() < 4 {
+        bail!("Truncated length prefix");
+    }
+    let len = u32::from_le_bytes(data[..4].try_into()?) as usize;
+    if data.len() < 4 + len {
+        bail!("Truncated field");
+    }
+    Ok((&data[4..4 + len], &data[4 + len..]))
+}
+
+fn append_file<W: std::io::Write>(archive: &mut tar::Builder<W>, name: &str, data: &[u8]) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    archive.append_data(&mut header, name, data)?;
+    Ok(())
+}
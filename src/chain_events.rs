@@ -0,0 +1,317 @@
+// File: src/chain_events.rs
+// Version: 1.2.1 - Timeline times in the configured display zone
+// Tree: tari-lmdb-inspector/src/chain_events.rs
+//
+// Scans the most recent headers and kernels for notable events and merges them into a
+// locally persisted history, so operators can see what happened while they were away.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use hex;
+
+use tari_node_components::blocks::{BlockHeader, BlockHeaderAccumulatedData};
+use crate::lmdb_reader::{open_backend, TransactionKernelRowData};
+use crate::lmdb_reader::stream::decode_height;
+use crate::lmdb_reader::block_hash::BlockHashResolver;
+use crate::timezone::DisplayZone;
+
+/// Number of recent blocks scanned for events on each refresh
+pub const DEFAULT_SCAN_WINDOW: u64 = 500;
+
+/// Interval (seconds) above which the gap before a block counts as a stall
+const STALL_THRESHOLD_SECS: u64 = 20 * 60;
+
+/// Target difficulty ratio vs. the per-algorithm moving average that counts as a spike
+const DIFFICULTY_SPIKE_RATIO: f64 = 2.0;
+
+/// Kernel count ratio vs. the window average that counts as a large block
+const LARGE_BLOCK_RATIO: f64 = 5.0;
+
+/// Minimum kernel count for a block to be flagged as large
+const LARGE_BLOCK_MIN_KERNELS: u64 = 50;
+
+/// Number of tip hashes remembered between refreshes for reorg detection
+const TIP_WINDOW: usize = 100;
+
+/// Maximum number of events kept in the persisted history
+const MAX_HISTORY: usize = 5_000;
+
+/// Kind of chain event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChainEventKind {
+    Reorg,
+    Stall,
+    DifficultySpike,
+    LargeBlock,
+    Burn,
+}
+
+impl ChainEventKind {
+    /// Short label for tables and timelines
+    pub fn label(&self) -> &'static str {
+        match self {
+            ChainEventKind::Reorg => "REORG",
+            ChainEventKind::Stall => "STALL",
+            ChainEventKind::DifficultySpike => "DIFF SPIKE",
+            ChainEventKind::LargeBlock => "LARGE BLOCK",
+            ChainEventKind::Burn => "BURN",
+        }
+    }
+}
+
+/// A single event in the chain timeline
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChainEvent {
+    pub kind: ChainEventKind,
+    pub height: u64,
+    pub timestamp: u64,
+    pub description: String,
+}
+
+/// Locally persisted event history plus the tip window used for reorg detection
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct EventStore {
+    pub events: Vec<ChainEvent>,
+    /// Last known height → hash for the most recent blocks
+    pub tip_hashes: BTreeMap<u64, String>,
+    pub last_scanned_height: u64,
+}
+
+impl EventStore {
+    /// Load the store from disk, returning an empty store if it does not exist yet
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Persist the store to disk
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Most recent events first, limited to `limit`
+    pub fn recent(&self, limit: usize) -> Vec<ChainEvent> {
+        self.events.iter().rev().take(limit).cloned().collect()
+    }
+
+    /// Merge a fresh scan into the history, detecting reorgs against the remembered tip window
+    fn merge(&mut self, scan: ChainScan) {
+        // Any remembered height whose hash changed means the chain was reorganised there
+        let mut reorg_from: Option<(u64, u64)> = None;
+        for (height, old_hash) in &self.tip_hashes {
+            if let Some((new_hash, timestamp)) = scan.hashes.get(height) {
+                if new_hash != old_hash {
+                    reorg_from = Some((*height, *timestamp));
+                    break;
+                }
+            }
+        }
+        if let Some((fork_height, timestamp)) = reorg_from {
+            let old_tip = self.tip_hashes.keys().next_back().copied().unwrap_or(fork_height);
+            let depth = old_tip.saturating_sub(fork_height) + 1;
+            self.push(ChainEvent {
+                kind: ChainEventKind::Reorg,
+                height: fork_height,
+                timestamp,
+                description: format!("Reorg of depth {} (fork at height {}, previous tip {})", depth, fork_height, old_tip),
+            });
+        }
+
+        for event in scan.events {
+            self.push(event);
+        }
+
+        self.tip_hashes = scan.hashes.iter()
+            .rev()
+            .take(TIP_WINDOW)
+            .map(|(height, (hash, _))| (*height, hash.clone()))
+            .collect();
+        self.last_scanned_height = scan.hashes.keys().next_back().copied().unwrap_or(self.last_scanned_height);
+
+        self.events.sort_by(|a, b| a.timestamp.cmp(&b.timestamp).then(a.height.cmp(&b.height)));
+        if self.events.len() > MAX_HISTORY {
+            let excess = self.events.len() - MAX_HISTORY;
+            self.events.drain(0..excess);
+        }
+    }
+
+    /// Add an event unless it is already recorded (reorgs may legitimately repeat at a height)
+    fn push(&mut self, event: ChainEvent) {
+        let duplicate = self.events.iter().any(|e| {
+            e.kind == event.kind
+                && e.height == event.height
+                && (event.kind != ChainEventKind::Reorg || e.description == event.description)
+        });
+        if !duplicate {
+            self.events.push(event);
+        }
+    }
+}
+
+/// Events and tip hashes gathered from one scan of the database
+struct ChainScan {
+    events: Vec<ChainEvent>,
+    hashes: BTreeMap<u64, (String, u64)>,
+}
+
+/// Event history file inside the cache directory
+pub const STORE_FILE: &str = "chain_events.json";
+
+/// Scan the database, merge new events into the store at `store_path` and return the updated store
+pub fn refresh_events(db_path: &Path, store_path: &Path, window: u64) -> Result<EventStore> {
+    let mut store = EventStore::load(store_path)?;
+    let scan = scan_chain(db_path, window)?;
+    store.merge(scan);
+    store.save(store_path)?;
+    Ok(store)
+}
+
+/// Scan the last `window` blocks for stalls, difficulty spikes, large blocks and burns
+fn scan_chain(path: &Path, window: u64) -> Result<ChainScan> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    let resolver = BlockHashResolver::new(&*read)?;
+
+    let Some((tip_key, _)) = read.last("headers")? else {
+        return Ok(ChainScan { events: Vec::new(), hashes: BTreeMap::new() });
+    };
+    let tip_height = decode_height(&tip_key);
+    let start_height = tip_height.saturating_sub(window.saturating_sub(1));
+
+    let mut headers = Vec::new();
+    for height in start_height..=tip_height {
+        let Some(data) = read.get("headers", &height.to_le_bytes())? else { continue };
+        // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+        if let Ok(header) = bincode::deserialize::<BlockHeader>(&data) {
+            headers.push(header);
+        }
+    }
+
+    let mut events = Vec::new();
+    let mut hashes = BTreeMap::new();
+
+    let block_hashes: Vec<Vec<u8>> = headers.iter()
+        .map(|header| resolver.resolve_block_hash(&*read, header.height, header).bytes)
+        .collect();
+    for (header, hash) in headers.iter().zip(&block_hashes) {
+        hashes.insert(header.height, (hex::encode(hash), header.timestamp.as_u64()));
+    }
+
+    // Stalls: long gaps between consecutive blocks
+    for pair in headers.windows(2) {
+        let (prev, curr) = (&pair[0], &pair[1]);
+        let gap = curr.timestamp.as_u64().saturating_sub(prev.timestamp.as_u64());
+        if curr.height == prev.height + 1 && gap >= STALL_THRESHOLD_SECS {
+            events.push(ChainEvent {
+                kind: ChainEventKind::Stall,
+                height: curr.height,
+                timestamp: curr.timestamp.as_u64(),
+                description: format!("No block for {}m {}s before height {}", gap / 60, gap % 60, curr.height),
+            });
+        }
+    }
+
+    // Large blocks: kernel count from the kernel MMR size delta
+    let kernel_counts: Vec<(u64, u64, u64)> = headers.windows(2)
+        .filter(|pair| pair[1].height == pair[0].height + 1)
+        .map(|pair| (pair[1].height, pair[1].timestamp.as_u64(), pair[1].kernel_mmr_size.saturating_sub(pair[0].kernel_mmr_size)))
+        .collect();
+    if !kernel_counts.is_empty() {
+        let average = kernel_counts.iter().map(|(_, _, c)| *c).sum::<u64>() as f64 / kernel_counts.len() as f64;
+        for (height, timestamp, count) in &kernel_counts {
+            if *count >= LARGE_BLOCK_MIN_KERNELS && *count as f64 >= average * LARGE_BLOCK_RATIO {
+                events.push(ChainEvent {
+                    kind: ChainEventKind::LargeBlock,
+                    height: *height,
+                    timestamp: *timestamp,
+                    description: format!("{} kernels ({:.1}x the window average of {:.1})", count, *count as f64 / average.max(1.0), average),
+                });
+            }
+        }
+    }
+
+    // Difficulty spikes: target difficulty vs. moving average of the same PoW algorithm
+    if read.has_table("header_accumulated_data")? {
+        let mut averages: HashMap<String, (f64, u64)> = HashMap::new();
+        for header in &headers {
+            let Some(data) = read.get("header_accumulated_data", &header.height.to_le_bytes())? else { continue };
+            let Ok(accumulated) = bincode::deserialize::<BlockHeaderAccumulatedData>(&data) else { continue };
+            let difficulty = accumulated.target_difficulty.as_u64() as f64;
+            let algo = format!("{:?}", header.pow.pow_algo);
+            let entry = averages.entry(algo.clone()).or_insert((difficulty, 0));
+            if entry.1 >= 10 && difficulty >= entry.0 * DIFFICULTY_SPIKE_RATIO {
+                events.push(ChainEvent {
+                    kind: ChainEventKind::DifficultySpike,
+                    height: header.height,
+                    timestamp: header.timestamp.as_u64(),
+                    description: format!("{} target difficulty {:.0} is {:.1}x the recent average", algo, difficulty, difficulty / entry.0),
+                });
+            }
+            // Exponential moving average keeps the baseline responsive
+            entry.0 = entry.0 * 0.9 + difficulty * 0.1;
+            entry.1 += 1;
+        }
+    }
+
+    // Burns: kernels flagged as burn kernels
+    if read.has_table("kernels")? {
+        for (header, block_hash) in headers.iter().zip(&block_hashes) {
+            let mut kernel_cursor = read.cursor("kernels")?;
+            let mut burns = 0;
+            let mut entry = kernel_cursor.seek(block_hash.as_slice())?;
+            while let Some((key, value)) = entry {
+                if !key.starts_with(block_hash.as_slice()) {
+                    break;
+                }
+                if let Ok(row) = bincode::deserialize::<TransactionKernelRowData>(value) {
+                    if row.kernel.features.is_burned() {
+                        burns += 1;
+                    }
+                }
+                entry = kernel_cursor.next()?;
+            }
+            if burns > 0 {
+                events.push(ChainEvent {
+                    kind: ChainEventKind::Burn,
+                    height: header.height,
+                    timestamp: header.timestamp.as_u64(),
+                    description: format!("{} burn kernel(s) in block {}", burns, header.height),
+                });
+            }
+        }
+    }
+
+    Ok(ChainScan { events, hashes })
+}
+
+/// Print the timeline for the CLI, with times in `zone`
+pub fn print_timeline(events: &[ChainEvent], zone: &DisplayZone) {
+    if events.is_empty() {
+        println!("No chain events recorded yet.");
+        return;
+    }
+
+    // Wide enough for a numeric offset such as `+05:30` in place of the zone name
+    println!();
+    println!("╭─{:─<26}─┬─{:─<11}─┬─{:─<8}─┬─{:─<60}─╮", "", "", "", "");
+    println!("│ {:^26} │ {:^11} │ {:^8} │ {:^60} │", "Time", "Event", "Height", "Details");
+    println!("├─{:─<26}─┼─{:─<11}─┼─{:─<8}─┼─{:─<60}─┤", "", "", "", "");
+    for event in events {
+        let time = zone.datetime(event.timestamp);
+        let mut description = event.description.clone();
+        description.truncate(60);
+        println!("│ {:<26} │ {:<11} │ {:>8} │ {:<60} │", time, event.kind.label(), event.height, description);
+    }
+    println!("╰─{:─<26}─┴─{:─<11}─┴─{:─<8}─┴─{:─<60}─╯", "", "", "", "");
+}
@@ -1,10 +1,20 @@
 // File: src/cli_interface.rs
 // Rewritten CLI interface with improved organization
 
+use std::sync::Arc;
 use anyhow::Result;
-use chrono::{Utc, TimeZone};
 use crate::data_models::AppConfig;
-use crate::lmdb_reader::{read_lmdb_headers_with_filter, read_block_with_transactions, BlockFilter};
+use crate::data_provider::{self, to_block_infos, DataProvider};
+use crate::file_watcher::{LmdbWatcher, TipCoalescer, TipMark};
+use crate::notify::Notifier;
+use crate::shutdown;
+use crate::progress::TerminalProgress;
+use crate::theme::Theme;
+use crate::timezone::DisplayZone;
+use crate::denomination::Denomination;
+use tokio_util::sync::CancellationToken;
+use crate::lmdb_reader::{read_lmdb_headers_with_filter, BlockFilter, BlockSummary, DataCompleteness};
+use crate::lmdb_reader::metadata::read_chain_metadata;
 
 /// Execute CLI mode operations
 pub async fn run_cli_mode(
@@ -13,17 +23,153 @@ pub async fn run_cli_mode(
     detail: Option<u64>,
     range: Option<String>,
     block: Option<u64>,
+    pow: Option<String>,
+    min_kernels: Option<u64>,
 ) -> Result<()> {
     match detail {
         Some(height) => show_block_detail(config, height).await,
-        None => show_block_list(config, count, range, block).await,
+        None => show_block_list(config, count, range, block, pow, min_kernels).await,
     }
 }
 
+/// Print the last `count` blocks, then keep printing new blocks as they land (like tail -f)
+pub async fn run_follow_mode(config: &AppConfig, count: usize) -> Result<()> {
+    if config.demo {
+        anyhow::bail!("--follow watches the database files and is not available with --demo");
+    }
+    let mut watcher = LmdbWatcher::from_config(config)?;
+    let mut tips = TipCoalescer::default();
+    let mut notifier = config.notify.clone().map(Notifier::new).transpose()?;
+
+    // Read one extra block so the first printed row has an interval
+    let initial = read_lmdb_headers_with_filter(&config.database_path, "headers", BlockFilter::LastN(count + 1))?;
+    if let Some(notifier) = notifier.as_mut() {
+        notifier.observe(&to_block_infos(&initial));
+    }
+
+    println!();
+    print_follow_header(&config.theme);
+    let mut previous: Option<(u64, u64)> = None;
+    let skip = initial.len().saturating_sub(count);
+    for (i, summary) in initial.iter().enumerate() {
+        if i >= skip {
+            print_follow_row(&config.theme, &config.timezone, summary, previous.map(|(_, ts)| ts));
+        }
+        previous = Some((summary.height, summary.header.timestamp));
+    }
+
+    println!("👀 Following new blocks (Ctrl+C to stop)...");
+    if watcher.is_polling() {
+        println!("⏱️  Change detection: {}", watcher.describe());
+    }
+
+    let window = count.max(10);
+    if let Ok(Some(metadata)) = read_chain_metadata(&config.database_path) {
+        tips.observe(TipMark::from(&metadata));
+    }
+    while watcher.next_change().await.is_some() {
+        let last_height = previous.map(|(height, _)| height).unwrap_or(0);
+
+        // Skip bursts that leave the tip in place; otherwise read every new height, however many
+        // a coalesced burst added, plus the usual window for the notifier
+        let filter = match read_chain_metadata(&config.database_path) {
+            Ok(Some(metadata)) => {
+                let Some(change) = tips.observe(TipMark::from(&metadata)) else { continue };
+                let tip = change.after.height;
+                let start = (last_height + 1).min(tip.saturating_sub(window as u64 - 1));
+                BlockFilter::Range(start, tip)
+            },
+            _ => BlockFilter::LastN(window),
+        };
+        let blocks = read_lmdb_headers_with_filter(&config.database_path, "headers", filter)?;
+
+        let Some(tip) = blocks.last() else { continue };
+        if tip.height < last_height {
+            println!("{}", config.theme.paint(config.theme.warn, format!("⚠️  Tip moved back from {} to {} (reorg?)", last_height, tip.height)));
+            previous = Some((tip.height, tip.header.timestamp));
+            continue;
+        }
+
+        for summary in blocks.iter().filter(|b| b.height > last_height) {
+            print_follow_row(&config.theme, &config.timezone, summary, previous.map(|(_, ts)| ts));
+            previous = Some((summary.height, summary.header.timestamp));
+        }
+
+        if let Some(notifier) = notifier.as_mut() {
+            notifier.observe_and_send(&to_block_infos(&blocks)).await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Print the follow-mode table header (includes PoW algorithm)
+fn print_follow_header(theme: &Theme) {
+    println!("╭─{:─<8}─┬─{:─<64}─┬─{:─<23}─┬─{:─<10}─┬─{:─<10}─╮", "", "", "", "", "");
+    println!("│ {} │ {} │ {} │ {} │ {} │",
+        theme.paint(theme.heading, format!("{:^8}", "Height")),
+        theme.paint(theme.heading, format!("{:^64}", "Hash")),
+        theme.paint(theme.heading, format!("{:^23}", "Timestamp")),
+        theme.paint(theme.heading, format!("{:^10}", "Interval")),
+        theme.paint(theme.heading, format!("{:^10}", "PoW")),
+    );
+    println!("├─{:─<8}─┼─{:─<64}─┼─{:─<23}─┼─{:─<10}─┼─{:─<10}─┤", "", "", "", "", "");
+}
+
+/// Print a single follow-mode row
+fn print_follow_row(theme: &Theme, zone: &DisplayZone, summary: &BlockSummary, previous_timestamp: Option<u64>) {
+    let interval_str = match previous_timestamp {
+        Some(prev) if summary.header.timestamp > prev => format_duration((summary.header.timestamp - prev) as i64),
+        Some(_) => "⚠ -time".to_string(),
+        None => "─".to_string(),
+    };
+
+    println!("│ {:>8} │ {:<64} │ {:<23} │ {} │ {:<10} │",
+        summary.height,
+        summary.hash,
+        zone.datetime(summary.header.timestamp),
+        interval_cell(theme, &interval_str),
+        summary.header.pow_algorithm
+    );
+}
+
+/// Provider that draws progress bars for table counting and full-chain hash searches,
+/// and stops them on Ctrl-C
+fn provider(config: &AppConfig) -> Arc<dyn DataProvider> {
+    data_provider::from_config_with(config, Arc::new(TerminalProgress::new()), cancel_on_ctrl_c())
+}
+
+/// Token cancelled by the first Ctrl-C or SIGTERM; a second one exits immediately
+pub fn cancel_on_ctrl_c() -> CancellationToken {
+    let cancel = CancellationToken::new();
+    let token = cancel.clone();
+    tokio::spawn(async move {
+        shutdown::signal().await;
+        eprintln!("\n⏹️  Cancelling... (Ctrl-C again to exit now)");
+        token.cancel();
+        shutdown::signal().await;
+        std::process::exit(130);
+    });
+    cancel
+}
+
 /// Display detailed information for a specific block
 async fn show_block_detail(config: &AppConfig, height: u64) -> Result<()> {
-    let block_detail = read_block_with_transactions(&config.database_path, height)?;
-    print_block_detail(&block_detail);
+    let block_detail = provider(config).block(height).await?;
+    print_block_detail(&config.theme, &config.timezone, config.denom, &block_detail);
+    Ok(())
+}
+
+/// Display detailed information for the block with `hash`
+pub async fn show_block_by_hash(config: &AppConfig, hash: &str) -> Result<()> {
+    let hash = hash.trim().to_lowercase();
+    if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+        anyhow::bail!("Block hash must be 64 hex characters");
+    }
+    match provider(config).block_by_hash(&hash).await? {
+        Some(block_detail) => print_block_detail(&config.theme, &config.timezone, config.denom, &block_detail),
+        None => println!("{}", config.theme.paint(config.theme.error, format!("❌ No block with hash {}", hash))),
+    }
     Ok(())
 }
 
@@ -32,17 +178,23 @@ async fn show_block_list(
     config: &AppConfig, 
     count: usize, 
     range: Option<String>, 
-    block: Option<u64>
+    block: Option<u64>,
+    pow: Option<String>,
+    min_kernels: Option<u64>,
 ) -> Result<()> {
-    let filter = create_block_filter(count, range, block)?;
-    let summaries = read_lmdb_headers_with_filter(&config.database_path, "headers", filter)?;
+    let filter = create_block_filter(count, range, block)?.with_options(pow, min_kernels);
+    let provider = data_provider::from_config(config);
+    let summaries = provider.headers(filter).await?;
+    if let Ok(DataCompleteness::Pruned { horizon }) = provider.data_completeness().await {
+        println!("✂️  Pruned node: transaction data available from height {}", horizon);
+    }
 
     if summaries.is_empty() {
         println!("No blocks found matching the criteria.");
         return Ok(());
     }
 
-    print_blocks_table(&summaries);
+    print_blocks_table(&config.theme, &config.timezone, &summaries);
     print_block_statistics(&summaries);
     Ok(())
 }
@@ -59,6 +211,12 @@ fn create_block_filter(count: usize, range: Option<String>, block: Option<u64>)
 
 /// Parse range string into BlockFilter
 fn parse_range_filter(range_str: String) -> Result<BlockFilter> {
+    let (start, end) = parse_height_range(&range_str)?;
+    Ok(BlockFilter::Range(start, end))
+}
+
+/// Parse a `start-end` height range (inclusive)
+pub fn parse_height_range(range_str: &str) -> Result<(u64, u64)> {
     let parts: Vec<&str> = range_str.split('-').collect();
     if parts.len() != 2 {
         anyhow::bail!("Invalid range format. Use: start-end (e.g., 100-110)");
@@ -71,24 +229,24 @@ fn parse_range_filter(range_str: String) -> Result<BlockFilter> {
         anyhow::bail!("Start height must be <= end height");
     }
     
-    Ok(BlockFilter::Range(start, end))
+    Ok((start, end))
 }
 
 /// Print blocks in a formatted table
-fn print_blocks_table(summaries: &[crate::lmdb_reader::BlockSummary]) {
+fn print_blocks_table(theme: &Theme, zone: &DisplayZone, summaries: &[crate::lmdb_reader::BlockSummary]) {
     println!();
-    print_table_header();
+    print_table_header(theme);
     print_table_separator();
     
     for (i, summary) in summaries.iter().enumerate() {
-        let timestamp_str = format_timestamp(summary.header.timestamp);
+        let timestamp_str = zone.datetime(summary.header.timestamp);
         let interval_str = calculate_interval(summaries, i);
         
-        println!("│ {:>8} │ {:<64} │ {:<23} │ {:>10} │", 
+        println!("│ {:>8} │ {:<64} │ {:<23} │ {} │", 
             summary.height,
             summary.hash,
             timestamp_str,
-            interval_str
+            interval_cell(theme, &interval_str)
         );
     }
     
@@ -96,9 +254,24 @@ fn print_blocks_table(summaries: &[crate::lmdb_reader::BlockSummary]) {
 }
 
 /// Print table header
-fn print_table_header() {
+fn print_table_header(theme: &Theme) {
     println!("╭─{:─<8}─┬─{:─<64}─┬─{:─<23}─┬─{:─<10}─╮", "", "", "", "");
-    println!("│ {:^8} │ {:^64} │ {:^23} │ {:^10} │", "Height", "Hash", "Timestamp", "Interval");
+    println!("│ {} │ {} │ {} │ {} │",
+        theme.paint(theme.heading, format!("{:^8}", "Height")),
+        theme.paint(theme.heading, format!("{:^64}", "Hash")),
+        theme.paint(theme.heading, format!("{:^23}", "Timestamp")),
+        theme.paint(theme.heading, format!("{:^10}", "Interval")),
+    );
+}
+
+/// Right-aligned interval column, highlighted when the timestamp went backwards
+fn interval_cell(theme: &Theme, interval: &str) -> String {
+    let cell = format!("{:>10}", interval);
+    if interval.starts_with('⚠') {
+        theme.paint(theme.warn, cell)
+    } else {
+        cell
+    }
 }
 
 /// Print table separator
@@ -159,14 +332,6 @@ fn calculate_valid_intervals(summaries: &[crate::lmdb_reader::BlockSummary]) ->
         .collect()
 }
 
-/// Format Unix timestamp to human-readable string
-fn format_timestamp(timestamp: u64) -> String {
-    Utc.timestamp_opt(timestamp as i64, 0)
-        .single()
-        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string())
-        .unwrap_or_else(|| format!("Invalid: {}", timestamp))
-}
-
 /// Format duration in seconds to human-readable string
 fn format_duration(seconds: i64) -> String {
     match seconds {
@@ -185,25 +350,25 @@ fn format_duration(seconds: i64) -> String {
 }
 
 /// Print detailed block information
-fn print_block_detail(block: &crate::lmdb_reader::BlockDetailSummary) {
+fn print_block_detail(theme: &Theme, zone: &DisplayZone, denom: Denomination, block: &crate::lmdb_reader::BlockDetailSummary) {
     println!();
-    println!("🔍 Block Detail View");
+    println!("{}", theme.paint(theme.title, "🔍 Block Detail View"));
     
-    print_block_header(block);
-    print_transaction_summary(block);
-    print_transaction_details(block);
+    print_block_header(zone, block);
+    print_transaction_summary(theme, block);
+    print_transaction_details(denom, block);
     
     println!("╰─{:─<70}─╯", "");
 }
 
 /// Print block header information
-fn print_block_header(block: &crate::lmdb_reader::BlockDetailSummary) {
+fn print_block_header(zone: &DisplayZone, block: &crate::lmdb_reader::BlockDetailSummary) {
     println!("╭─{:─<70}─╮", "");
     
     let hash_display = truncate_hash(&block.hash, 48);
     println!("│ Height: {:>8}  Hash: {:<48} │", block.height, hash_display);
     
-    let timestamp_str = format_timestamp(block.header.timestamp);
+    let timestamp_str = zone.datetime(block.header.timestamp);
     println!("│ Timestamp: {:<25} Nonce: {:>15} │", timestamp_str, block.header.nonce);
     
     let prev_hash_display = truncate_hash(&block.header.previous_hash, 50);
@@ -213,8 +378,12 @@ fn print_block_header(block: &crate::lmdb_reader::BlockDetailSummary) {
 }
 
 /// Print transaction summary
-fn print_transaction_summary(block: &crate::lmdb_reader::BlockDetailSummary) {
+fn print_transaction_summary(theme: &Theme, block: &crate::lmdb_reader::BlockDetailSummary) {
     println!("│ 📊 Transaction Summary:                                             │");
+    if block.data_completeness != DataCompleteness::Full {
+        let label = format!("{:<34}", block.data_completeness.label());
+        println!("│   ⚠️  Transaction data unavailable: {} │", theme.paint(theme.warn, label));
+    }
     println!("│   Inputs:  {:>3}  Outputs: {:>3}  Kernels: {:>3}                        │",
         block.transactions.inputs.len(),
         block.transactions.outputs.len(), 
@@ -224,10 +393,10 @@ fn print_transaction_summary(block: &crate::lmdb_reader::BlockDetailSummary) {
 }
 
 /// Print detailed transaction information
-fn print_transaction_details(block: &crate::lmdb_reader::BlockDetailSummary) {
+fn print_transaction_details(denom: Denomination, block: &crate::lmdb_reader::BlockDetailSummary) {
     print_inputs_section(&block.transactions.inputs);
     print_outputs_section(&block.transactions.outputs);
-    print_kernels_section(&block.transactions.kernels);
+    print_kernels_section(denom, &block.transactions.kernels);
 }
 
 /// Print transaction inputs section
@@ -239,8 +408,11 @@ fn print_inputs_section(inputs: &[crate::lmdb_reader::InputSummary]) {
     println!("│ 📥 Transaction Inputs:                                              │");
     for (i, input) in inputs.iter().take(3).enumerate() {
         let commitment_display = truncate_hash(&input.commitment, 20);
+        let origin = input.spent_output_mined_height
+            .map(|height| format!("spends output from #{}", height))
+            .unwrap_or_else(|| "origin unknown".to_string());
         println!("│   {}: {} [{}]                     │", 
-            i + 1, commitment_display, input.input_type);
+            i + 1, commitment_display, origin);
     }
     
     if inputs.len() > 3 {
@@ -259,8 +431,8 @@ fn print_outputs_section(outputs: &[crate::lmdb_reader::OutputSummary]) {
     println!("│ 📤 Transaction Outputs:                                             │");
     for (i, output) in outputs.iter().take(3).enumerate() {
         let commitment_display = truncate_hash(&output.commitment, 20);
-        println!("│   {}: {} [{}]                     │", 
-            i + 1, commitment_display, output.features);
+        println!("│   {}: {} [{}] {}                     │", 
+            i + 1, commitment_display, output.features, output.spend_status.label());
     }
     
     if outputs.len() > 3 {
@@ -271,7 +443,7 @@ fn print_outputs_section(outputs: &[crate::lmdb_reader::OutputSummary]) {
 }
 
 /// Print transaction kernels section
-fn print_kernels_section(kernels: &[crate::lmdb_reader::KernelSummary]) {
+fn print_kernels_section(denom: Denomination, kernels: &[crate::lmdb_reader::KernelSummary]) {
     if kernels.is_empty() {
         return;
     }
@@ -279,8 +451,8 @@ fn print_kernels_section(kernels: &[crate::lmdb_reader::KernelSummary]) {
     println!("│ ⚡ Transaction Kernels:                                             │");
     for (i, kernel) in kernels.iter().take(3).enumerate() {
         let excess_display = truncate_hash(&kernel.excess, 20);
-        println!("│   {}: {} Fee: {} Lock: {}                │", 
-            i + 1, excess_display, kernel.fee, kernel.lock_height);
+        println!("│   {}: {} Fee: {} Lock: {} [{}]                │", 
+            i + 1, excess_display, denom.format(kernel.fee), kernel.lock_height, kernel.kind.label());
     }
     
     if kernels.len() > 3 {
@@ -0,0 +1,121 @@
+// File: src/daemon.rs
+// Version: 1.0.0 - web --daemon: detach into the background with a PID file and a log file
+// Tree: tari-lmdb-inspector/src/daemon.rs
+//
+// `detach` forks before the tokio runtime starts (a fork copies only the calling thread, so a
+// running runtime would lose its workers), starts a new session so closing the terminal does not
+// stop the server, points stdin at /dev/null and stdout / stderr at the log file, and writes the
+// PID file. The parent prints the child's PID and exits. The working directory is kept so
+// relative --database and --cache-dir paths still resolve.
+//
+// Everything that can fail for a predictable reason (a server already running on the PID file,
+// an unwritable log file) is checked before forking, so it is reported on the terminal. Errors
+// after the fork end up in the log file. SIGTERM stops the server through the usual graceful
+// shutdown, after which the `PidFile` guard removes the PID file.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+/// PID file name in the cache directory unless --pid-file is given
+pub const DEFAULT_PID_FILE: &str = "web.pid";
+
+/// Log file name in the cache directory unless --log-file is given
+pub const DEFAULT_LOG_FILE: &str = "web.log";
+
+/// Where a detached server keeps its PID and output
+#[derive(Debug, Clone)]
+pub struct DaemonConfig {
+    pub pid_file: PathBuf,
+    pub log_file: PathBuf,
+}
+
+/// PID file of the detached process, removed when dropped
+#[derive(Debug)]
+pub struct PidFile {
+    path: PathBuf,
+}
+
+impl PidFile {
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for PidFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// PID recorded in `path` if that process is still running
+pub fn running_pid(path: &Path) -> Option<i32> {
+    let pid: i32 = std::fs::read_to_string(path).ok()?.trim().parse().ok()?;
+    (pid > 0 && process_alive(pid)).then_some(pid)
+}
+
+#[cfg(unix)]
+fn process_alive(pid: i32) -> bool {
+    // Signal 0 only checks the process exists; EPERM means it does but belongs to another user
+    unsafe { libc::kill(pid, 0) == 0 } || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(unix))]
+fn process_alive(_pid: i32) -> bool {
+    false
+}
+
+/// Fork into the background; returns in the child only, holding its PID file
+/// Must run before the tokio runtime (or any other thread) starts
+#[cfg(unix)]
+pub fn detach(config: &DaemonConfig) -> Result<PidFile> {
+    use std::fs::{File, OpenOptions};
+    use std::os::unix::io::AsRawFd;
+    use anyhow::{bail, Context};
+
+    if let Some(pid) = running_pid(&config.pid_file) {
+        bail!("A server is already running as PID {} ({}); stop it first or pass another --pid-file", pid, config.pid_file.display());
+    }
+    for path in [&config.pid_file, &config.log_file] {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            std::fs::create_dir_all(dir).with_context(|| format!("Cannot create {}", dir.display()))?;
+        }
+    }
+    let log = OpenOptions::new().create(true).append(true).open(&config.log_file)
+        .with_context(|| format!("Cannot open log file {}", config.log_file.display()))?;
+    let null = File::open("/dev/null").context("Cannot open /dev/null")?;
+
+    match unsafe { libc::fork() } {
+        -1 => bail!("fork failed: {}", std::io::Error::last_os_error()),
+        0 => {}
+        child => {
+            println!("🛰️  Web server detached as PID {}", child);
+            println!("📝 Logs: {}", config.log_file.display());
+            println!("📌 PID file: {} (stop with: kill $(cat {}))", config.pid_file.display(), config.pid_file.display());
+            std::process::exit(0);
+        }
+    }
+
+    // Child: own session, no terminal, output to the log file
+    unsafe {
+        if libc::setsid() == -1 {
+            bail!("setsid failed: {}", std::io::Error::last_os_error());
+        }
+        for (from, to) in [(null.as_raw_fd(), libc::STDIN_FILENO), (log.as_raw_fd(), libc::STDOUT_FILENO), (log.as_raw_fd(), libc::STDERR_FILENO)] {
+            if libc::dup2(from, to) == -1 {
+                bail!("Redirecting output to {} failed: {}", config.log_file.display(), std::io::Error::last_os_error());
+            }
+        }
+    }
+
+    std::fs::write(&config.pid_file, format!("{}\n", std::process::id()))
+        .with_context(|| format!("Cannot write PID file {}", config.pid_file.display()))?;
+    println!("🛰️  Started in the background at {} as PID {}", chrono::Utc::now().to_rfc3339(), std::process::id());
+    Ok(PidFile { path: config.pid_file.clone() })
+}
+
+/// Fork into the background (Unix only)
+#[cfg(not(unix))]
+pub fn detach(_config: &DaemonConfig) -> Result<PidFile> {
+    anyhow::bail!("--daemon is only supported on Unix; run the server under a service manager instead")
+}
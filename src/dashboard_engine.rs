@@ -0,0 +1,356 @@
+// File: src/dashboard_engine.rs
+// Version: 1.4.0 - Per-read progress notes only at --verbosity debug
+// Tree: tari-lmdb-inspector/src/dashboard_engine.rs
+//
+// Both dashboards show the same snapshot: recent blocks, table counts, network stats, data
+// completeness and sync status. `DashboardEngine` builds it from a `DataProvider` in one place,
+// and `changes` turns LMDB file writes into a stream of fresh snapshots. The newest
+// `ANALYSIS_WINDOW` headers are kept in a `TipWindow` between reads: a change only reads the
+// blocks above the cached tip (plus the cached tip itself, whose hash must still match), and a
+// reorg or a long gap rebuilds the window. File changes that leave the metadata tip where it
+// was skip the refresh entirely.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use anyhow::Result;
+use futures::Stream;
+use tokio::sync::Mutex;
+
+use crate::data_models::{AppConfig, BlockInfo, DashboardData, DatabaseStats, NetworkStats};
+use crate::data_provider::{self, DataProvider, MockProvider};
+use crate::file_watcher::{LmdbWatcher, TipCoalescer, TipMark};
+use crate::lmdb_reader::{BlockFilter, BlockSummary, DataCompleteness, ReaderResult};
+use crate::sync_status::{SyncStatus, SyncTracker};
+use crate::verbosity::Verbosity;
+
+/// Blocks read per update (the metadata tip and the ones below it)
+pub const ANALYSIS_WINDOW: u64 = 1000;
+
+/// Newest blocks kept in the snapshot by default
+pub const DEFAULT_DISPLAY_BLOCKS: usize = 200;
+
+/// Builds dashboard snapshots from a provider
+pub struct DashboardEngine {
+    config: AppConfig,
+    provider: Arc<dyn DataProvider>,
+    display_blocks: usize,
+    pow_filter: Option<String>,
+    min_kernels: Option<u64>,
+    /// No progress output on stdout (the TUI owns the terminal)
+    quiet: bool,
+    /// When the tip height last changed, for the sync status
+    sync_tracker: Mutex<SyncTracker>,
+    /// Table counts and the tip hash they were taken at; recounted only for a new tip
+    stats: Mutex<Option<(String, DatabaseStats)>>,
+    /// Newest headers, extended on each read instead of re-read
+    window: Mutex<TipWindow>,
+    /// Metadata tip at the last read
+    tips: Mutex<TipCoalescer>,
+}
+
+/// The newest `capacity` block summaries in ascending height order
+#[derive(Debug, Clone)]
+pub struct TipWindow {
+    blocks: VecDeque<BlockSummary>,
+    capacity: usize,
+    /// Times the window was read in full (first read, reorgs, gaps wider than the window)
+    rebuilds: u64,
+}
+
+impl TipWindow {
+    pub fn new(capacity: usize) -> Self {
+        Self { blocks: VecDeque::new(), capacity, rebuilds: 0 }
+    }
+
+    pub fn blocks(&self) -> Vec<BlockSummary> {
+        self.blocks.iter().cloned().collect()
+    }
+
+    pub fn rebuilds(&self) -> u64 {
+        self.rebuilds
+    }
+
+    /// First height to read for a chain with tip `tip`: the cached tip, so its hash can be
+    /// checked. `None` when the window must be read in full.
+    pub fn resume_from(&self, tip: u64) -> Option<u64> {
+        let cached = self.blocks.back()?;
+        (cached.height <= tip && tip - cached.height < self.capacity as u64).then_some(cached.height)
+    }
+
+    /// Append blocks read from `resume_from`; `false` (window unchanged) when the first one is
+    /// not the cached tip, i.e. a reorg replaced it
+    pub fn extend(&mut self, fresh: Vec<BlockSummary>) -> bool {
+        let mut fresh = fresh.into_iter();
+        match (self.blocks.back(), fresh.next()) {
+            (Some(cached), Some(first)) if cached.height == first.height && cached.hash == first.hash => {}
+            _ => return false,
+        }
+        self.blocks.extend(fresh);
+        while self.blocks.len() > self.capacity {
+            self.blocks.pop_front();
+        }
+        true
+    }
+
+    /// Replace the window with a full read
+    pub fn replace(&mut self, blocks: Vec<BlockSummary>) {
+        let skip = blocks.len().saturating_sub(self.capacity);
+        self.blocks = blocks.into_iter().skip(skip).collect();
+        self.rebuilds += 1;
+    }
+}
+
+impl DashboardEngine {
+    pub fn new(config: AppConfig, provider: Arc<dyn DataProvider>) -> Self {
+        Self {
+            config,
+            provider,
+            display_blocks: DEFAULT_DISPLAY_BLOCKS,
+            pow_filter: None,
+            min_kernels: None,
+            quiet: false,
+            sync_tracker: Mutex::new(SyncTracker::default()),
+            stats: Mutex::new(None),
+            window: Mutex::new(TipWindow::new(ANALYSIS_WINDOW as usize)),
+            tips: Mutex::new(TipCoalescer::default()),
+        }
+    }
+
+    /// Keep only the newest `count` blocks in each snapshot
+    pub fn with_display_blocks(mut self, count: usize) -> Self {
+        self.display_blocks = count;
+        self
+    }
+
+    /// Only show blocks mined with `pow_algorithm` and/or with at least `min_kernels` kernels
+    pub fn with_filter(mut self, pow_algorithm: Option<String>, min_kernels: Option<u64>) -> Self {
+        self.pow_filter = pow_algorithm;
+        self.min_kernels = min_kernels;
+        self
+    }
+
+    /// Suppress progress output on stdout and stderr
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+
+    pub fn provider(&self) -> &Arc<dyn DataProvider> {
+        &self.provider
+    }
+
+    /// Status note, printed at `normal` verbosity
+    fn log(&self, message: &str) {
+        if !self.quiet && self.config.verbosity >= Verbosity::Normal {
+            println!("{}", message);
+        }
+    }
+
+    /// Progress note printed on every read, only at `debug` verbosity
+    fn debug(&self, message: &str) {
+        if !self.quiet && self.config.verbosity >= Verbosity::Debug {
+            println!("{}", message);
+        }
+    }
+
+    fn warn(&self, message: &str) {
+        if !self.quiet {
+            eprintln!("{}", message);
+        }
+    }
+
+    /// Read a fresh snapshot
+    pub async fn read(&self) -> Result<DashboardData> {
+        self.debug("🔄 Reading LMDB data...");
+
+        // The metadata table names the tip directly; fall back to scanning headers without it
+        let metadata = match self.provider.chain_metadata().await {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                self.log(&format!("⚠️  Could not read chain metadata ({}), scanning headers for the tip", e));
+                None
+            }
+        };
+        if let Some(metadata) = &metadata {
+            let change = self.tips.lock().await.observe(TipMark::from(metadata));
+            if let Some(change) = change {
+                if change.is_rewind() {
+                    self.log(&format!("🔀 Tip moved back to height {}", change.after.height));
+                } else if let Some(heights) = change.new_heights() {
+                    self.log(&format!("📦 {} new block(s) up to height {}", heights.end() - heights.start() + 1, heights.end()));
+                }
+            }
+        }
+        let tip = metadata.map(|metadata| metadata.best_block_height);
+        let headers = match tip {
+            Some(tip) => {
+                let window = BlockFilter::Range(tip.saturating_sub(ANALYSIS_WINDOW - 1), tip);
+                self.window_headers(tip).await
+                    .map(|blocks| window.with_options(self.pow_filter.clone(), self.min_kernels).apply(blocks))
+            },
+            None => {
+                let filter = BlockFilter::LastN(ANALYSIS_WINDOW).with_options(self.pow_filter.clone(), self.min_kernels);
+                self.provider.headers(filter).await
+            }
+        };
+
+        // Read blocks and statistics from the configured provider
+        let (recent_blocks, database_stats) = match headers {
+            Ok(blocks) => {
+                self.debug(&format!("📊 Loaded {} blocks to cache for network analysis", blocks.len()));
+
+                // Newest first, with intervals between consecutive blocks
+                let mut recent_blocks = data_provider::to_block_infos(&blocks);
+                recent_blocks.truncate(self.display_blocks);
+                self.debug(&format!("🖥️  Displaying {} most recent blocks in dashboard", recent_blocks.len()));
+
+                let database_stats = self.database_stats(recent_blocks.first()).await;
+                (recent_blocks, database_stats)
+            },
+            Err(e) => {
+                self.log(&format!("⚠️  Could not read from {} ({}), using mock data", self.provider.name(), e));
+
+                let mock = MockProvider::default();
+                let blocks = mock.headers(BlockFilter::LastN(self.display_blocks as u64)).await?;
+                (data_provider::to_block_infos(&blocks), mock.database_stats().await)
+            }
+        };
+
+        let network_stats = NetworkStats {
+            sync_status: self.sync_status(recent_blocks.first()).await,
+            ..network_stats(&recent_blocks, &database_stats)
+        };
+        if network_stats.sync_status != SyncStatus::Synced {
+            self.log(&format!("⏳ Sync status: {}", network_stats.sync_status.label()));
+        }
+
+        // Label missing transaction data instead of reporting zeros
+        let data_completeness = match self.provider.data_completeness().await {
+            Ok(completeness) => completeness,
+            Err(e) => {
+                self.warn(&format!("⚠️  Could not check transaction tables: {}", e));
+                DataCompleteness::Full
+            }
+        };
+        if data_completeness != DataCompleteness::Full {
+            self.log(&format!("⚠️  Transaction data: {}", data_completeness.label()));
+        }
+
+        Ok(DashboardData {
+            database_stats,
+            recent_blocks,
+            network_stats,
+            last_updated: chrono::Utc::now().timestamp() as u64,
+            data_completeness,
+            stale: false,
+        })
+    }
+
+    /// The `ANALYSIS_WINDOW` headers up to `tip`, reading only what changed since the last call
+    async fn window_headers(&self, tip: u64) -> ReaderResult<Vec<BlockSummary>> {
+        let mut window = self.window.lock().await;
+        if let Some(start) = window.resume_from(tip) {
+            let fresh = self.provider.headers(BlockFilter::Range(start, tip)).await?;
+            if window.extend(fresh) {
+                return Ok(window.blocks());
+            }
+            self.log("🔀 Cached tip replaced by a reorg - rereading the block window");
+        }
+        let blocks = self.provider.headers(BlockFilter::Range(tip.saturating_sub(ANALYSIS_WINDOW - 1), tip)).await?;
+        window.replace(blocks);
+        Ok(window.blocks())
+    }
+
+    /// Whether the metadata tip differs from the one at the last read; `true` when it cannot
+    /// be read, so the caller refreshes anyway
+    pub async fn tip_moved(&self) -> bool {
+        match self.provider.chain_metadata().await {
+            Ok(Some(metadata)) => self.tips.lock().await.is_new(&TipMark::from(&metadata)),
+            _ => true,
+        }
+    }
+
+    /// Full reads of the block window so far (first read, reorgs and long gaps)
+    pub async fn window_rebuilds(&self) -> u64 {
+        self.window.lock().await.rebuilds()
+    }
+
+    /// Table counts at `tip`, reusing the previous count while the tip is unchanged
+    async fn database_stats(&self, tip: Option<&BlockInfo>) -> DatabaseStats {
+        let mut cached = self.stats.lock().await;
+        if let (Some(tip), Some((hash, stats))) = (tip, cached.as_ref()) {
+            if &tip.hash == hash {
+                return stats.clone();
+            }
+        }
+
+        self.debug("🔍 Scanning LMDB for real statistics...");
+        let stats = self.provider.database_stats().await;
+        self.debug(&format!("📊 Database stats: UTXOs: {}, Inputs: {}, Kernels: {}",
+            stats.utxos_count, stats.inputs_count, stats.kernels_count));
+        if let Some(tip) = tip {
+            *cached = Some((tip.hash.clone(), stats.clone()));
+        }
+        stats
+    }
+
+    /// Sync status of `tip` as of now
+    pub async fn sync_status(&self, tip: Option<&BlockInfo>) -> SyncStatus {
+        match tip {
+            Some(tip) => self.sync_tracker.lock().await.observe(tip.height, tip.timestamp, chrono::Utc::now().timestamp() as u64),
+            None => SyncStatus::Synced,
+        }
+    }
+
+    /// A fresh snapshot (or the read error) each time the LMDB files change, detected as
+    /// configured by `--watch`
+    pub fn changes(self: Arc<Self>) -> Result<impl Stream<Item = Result<DashboardData>>> {
+        let watcher = LmdbWatcher::from_config(&self.config)?;
+        Ok(self.changes_from(watcher))
+    }
+
+    /// A fresh snapshot (or the read error) for each change `watcher` reports
+    pub fn changes_from(self: Arc<Self>, watcher: LmdbWatcher) -> impl Stream<Item = Result<DashboardData>> {
+        futures::stream::unfold((self, watcher), |(engine, mut watcher)| async move {
+            loop {
+                watcher.next_change().await?;
+                if engine.tip_moved().await {
+                    break;
+                }
+                engine.log("💤 LMDB modified but the tip is unchanged - skipping update");
+            }
+            engine.log("📊 LMDB modified - updating dashboard...");
+            let update = engine.read().await;
+            Some((update, (engine, watcher)))
+        })
+    }
+}
+
+/// Average block time and throughput estimate from newest-first blocks
+fn network_stats(recent_blocks: &[BlockInfo], database_stats: &DatabaseStats) -> NetworkStats {
+    // Calculate average block time from intervals
+    let valid_intervals: Vec<i64> = recent_blocks.iter()
+        .filter_map(|b| b.interval_seconds)
+        .filter(|&interval| interval > 0 && interval < 3600)
+        .collect();
+
+    let average_block_time = if !valid_intervals.is_empty() {
+        valid_intervals.iter().sum::<i64>() / valid_intervals.len() as i64
+    } else {
+        120
+    };
+
+    let tps = if average_block_time > 0 {
+        10.0 / average_block_time as f64 // Estimate 10 transactions per block
+    } else {
+        0.083 // ~1 transaction per 12 seconds
+    };
+
+    NetworkStats {
+        latest_block_height: recent_blocks.first().map(|b| b.height).unwrap_or(0),
+        average_block_time,
+        transactions_per_second: tps.max(0.001), // Minimum TPS
+        utxo_set_size: database_stats.utxos_count,
+        sync_status: SyncStatus::Synced,
+    }
+}
@@ -1,26 +1,84 @@
 // File: src/data_models.rs
 // Shared data structures and models for all interfaces
+//
+// Every block, page and analytics body leaves the process as one of these types: REST handlers,
+// WebSocket messages and TUI exports serialize the same struct, and an RPC layer should wrap them
+// rather than define its own. A field renamed here is renamed everywhere at once.
 
 use std::path::PathBuf;
+use std::time::Duration;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use tari_common::configuration::Network;
+
+use crate::cache::CacheDir;
+use crate::chain_events::ChainEvent;
+use crate::lmdb_reader::{
+    BlockCounts, BlockDetailSummary, BlockHeaderLite, BlockSummary, DataCompleteness, InputSummary, KernelSummary, OutputSummary,
+    TransactionPage, TxComponent,
+};
+use crate::notify::NotifyConfig;
+use crate::progress::ProgressEvent;
+use crate::sync_status::SyncStatus;
+use crate::file_watcher::WatchMode;
+use crate::theme::Theme;
+use crate::denomination::Denomination;
+use crate::limits::{LimitExceeded, Limits};
+use crate::timezone::DisplayZone;
+use crate::verbosity::Verbosity;
+use crate::watchlist::WatchHit;
 
 /// Application configuration
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub database_path: PathBuf,
+    pub notify: Option<NotifyConfig>,
+    /// Time allowed for a single async LMDB read (web and TUI)
+    pub read_timeout: Duration,
+    /// Serve synthetic blocks instead of reading LMDB
+    pub demo: bool,
+    /// TOML file of watched commitments and excesses (web mode)
+    pub watchlist_path: Option<PathBuf>,
+    /// Network whose consensus rules (emission schedule) apply to the database
+    pub network: Network,
+    /// Directory for derived indexes and series that survive restarts
+    pub cache: CacheDir,
+    /// Colors for the TUI and CLI output
+    pub theme: Theme,
+    /// Most updates per second triggered by database writes (web, TUI, cli --follow); `None`
+    /// for no limit
+    pub max_update_rate: Option<f64>,
+    /// How database changes are detected (file events or tip polling)
+    pub watch_mode: WatchMode,
+    /// Time between tip reads when polling
+    pub poll_interval: Duration,
+    /// Zone for CLI and TUI timestamps and API `timestamp_local` fields
+    pub timezone: DisplayZone,
+    /// Unit for amounts in CLI tables, the TUI and server-rendered pages
+    pub denom: Denomination,
+    /// How much human-readable logging is printed; `apply` it for the reader functions
+    pub verbosity: Verbosity,
+    /// Caps on web response size (blocks, transaction items, WebSocket messages)
+    pub limits: Limits,
 }
 
 /// Real-time dashboard data
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DashboardData {
     pub database_stats: DatabaseStats,
     pub recent_blocks: Vec<BlockInfo>,
     pub network_stats: NetworkStats,
     pub last_updated: u64, // Unix timestamp
+    /// Whether transaction statistics cover the whole chain
+    #[serde(default)]
+    pub data_completeness: DataCompleteness,
+    /// Saved by the previous run and shown until the first LMDB read completes
+    #[serde(default)]
+    pub stale: bool,
 }
 
 /// Database statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct DatabaseStats {
     pub utxos_count: usize,
     pub inputs_count: usize,
@@ -30,56 +88,157 @@ pub struct DatabaseStats {
 }
 
 /// Block information for dashboard
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct BlockInfo {
     pub height: u64,
     pub hash: String,
     pub timestamp: u64,
+    /// `timestamp` in the `--timezone` zone (RFC 3339); `null` in UTC
+    #[serde(default)]
+    pub timestamp_local: Option<String>,
     pub transaction_count: usize,
+    /// Outputs created in the block, when derivable
+    #[serde(default)]
+    pub outputs_count: Option<u64>,
+    /// Inputs spent in the block, when the inputs table is present
+    #[serde(default)]
+    pub inputs_count: Option<u64>,
     pub interval_seconds: Option<i64>,
     pub pow_algorithm: Option<String>,
 }
 
 /// Network statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct NetworkStats {
     pub latest_block_height: u64,
     pub average_block_time: i64,
     pub transactions_per_second: f64,
     pub utxo_set_size: usize,
+    /// How far the tip is behind the wall clock
+    #[serde(default)]
+    pub sync_status: SyncStatus,
 }
 
-/// Transaction details
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TransactionDetail {
-    pub inputs: Vec<InputInfo>,
-    pub outputs: Vec<OutputInfo>,
-    pub kernels: Vec<KernelInfo>,
+/// Block header as returned by `/api/v1/block/*`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockHeaderResponse {
+    pub version: u16,
+    pub timestamp: u64,
+    /// `timestamp` in the `--timezone` zone (RFC 3339); `null` in UTC
+    #[serde(default)]
+    pub timestamp_local: Option<String>,
+    pub nonce: u64,
+    pub previous_hash: String,
+    pub output_mr: String,
+    pub kernel_mr: String,
+    pub input_mr: String,
+    pub total_kernel_offset: String,
+    pub total_script_offset: String,
+    pub pow_data_hash: String,
+    pub raw_header_length: usize,
+    pub pow_algorithm: String,
 }
 
-/// Input information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct InputInfo {
-    pub commitment: String,
-    pub input_type: String,
-    pub amount: Option<u64>,
+/// Inputs, outputs and kernels of one block
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockTransactionsResponse {
+    pub inputs: Vec<InputSummary>,
+    pub outputs: Vec<OutputSummary>,
+    pub kernels: Vec<KernelSummary>,
 }
 
-/// Output information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OutputInfo {
-    pub commitment: String,
-    pub features: String,
-    pub amount: Option<u64>,
-    pub script_type: String,
+/// `GET /api/v1/block/:height` and `GET /api/v1/block/hash/:hash`, the `BlockDetail` WebSocket
+/// message and TUI block exports
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockDetailResponse {
+    pub height: u64,
+    pub hash: String,
+    pub header: BlockHeaderResponse,
+    /// Decoded rows; `null` unless `include` names `transactions` (the default)
+    pub transactions: Option<BlockTransactionsResponse>,
+    /// Row counts, when `include` names `counts`
+    pub counts: Option<BlockCounts>,
+    /// Whether `transactions` is complete or data is missing for this block
+    pub data_completeness: DataCompleteness,
 }
 
-/// Kernel information
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct KernelInfo {
-    pub excess: String,
-    pub fee: u64,
-    pub lock_height: u64,
+/// `GET /api/v1/block/:height/{inputs,outputs,kernels}?offset=&limit=` and the `BlockTransactions` WebSocket message
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockTransactionsPage {
+    pub height: u64,
+    pub hash: String,
+    pub component: TxComponent,
+    pub offset: usize,
+    pub limit: usize,
+    /// Rows of `component` in the block; the page is the last one once `offset + limit >= total`
+    pub total: u64,
+    /// The page's rows; only the list for `component` is filled
+    pub transactions: BlockTransactionsResponse,
+}
+
+/// `POST /api/v1/blocks/batch` request body
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockBatchRequest {
+    /// Up to 100 block heights; duplicates are returned once
+    pub heights: Vec<u64>,
+}
+
+/// A batch height that could not be returned
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockBatchMiss {
+    pub height: u64,
+    /// `not_found`, `pruned` or `error`
+    pub reason: String,
+    pub message: String,
+}
+
+/// `POST /api/v1/blocks/batch`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockBatchResponse {
+    /// Found blocks, in request order
+    pub blocks: Vec<BlockDetailResponse>,
+    pub missing: Vec<BlockBatchMiss>,
+}
+
+/// One block in a `GET /api/v1/blocks/range` response
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RangeBlock {
+    pub height: u64,
+    pub hash: String,
+    pub timestamp: u64,
+    /// `timestamp` in the `--timezone` zone (RFC 3339); `null` in UTC
+    #[serde(default)]
+    pub timestamp_local: Option<String>,
+    pub previous_hash: String,
+    pub output_mr: String,
+    pub kernel_mr: String,
+    pub input_mr: String,
+    pub total_kernel_offset: String,
+    pub total_script_offset: String,
+    pub pow_data_hash: String,
+    pub raw_header_length: usize,
+    pub pow_algorithm: String,
+    pub kernel_count: Option<u64>,
+    pub input_count: Option<u64>,
+    pub output_count: Option<u64>,
+}
+
+/// `GET /api/v1/blocks/range`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BlockRangeResponse {
+    pub start: u64,
+    pub end: u64,
+    pub total_found: usize,
+    pub blocks: Vec<RangeBlock>,
+}
+
+/// `GET /api/v1/events/history`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EventsHistoryResponse {
+    pub total_recorded: usize,
+    pub last_scanned_height: u64,
+    /// Newest first
+    pub events: Vec<ChainEvent>,
 }
 
 /// WebSocket message types
@@ -94,20 +253,58 @@ pub enum WebSocketMessage {
     
     /// Request block details
     GetBlockDetail { height: u64 },
+
+    /// Request block details by 64 hex character block hash
+    GetBlockByHash { hash: String },
     
-    /// Block detail response
-    BlockDetail { 
+    /// Block detail response, the `GET /api/v1/block/:height` body
+    BlockDetail { block: BlockDetailResponse },
+
+    /// Request one page of a block's inputs, outputs or kernels (`limit` defaults to 50, at most 500)
+    GetBlockTransactions {
         height: u64,
-        block_info: BlockInfo,
-        transactions: TransactionDetail 
+        component: TxComponent,
+        offset: Option<usize>,
+        limit: Option<usize>,
     },
+
+    /// One page of a block's inputs, outputs or kernels
+    BlockTransactions { page: BlockTransactionsPage },
+
+    /// Stream the block summaries of `from..=to` in chunks, replacing this connection's
+    /// running stream; `to` is capped at the tip
+    StreamBlocks { from: u64, to: u64 },
+
+    /// A stream began; `chunks` `BlockChunk`s follow
+    StreamStarted { stream: u64, from: u64, to: u64, chunks: u64 },
+
+    /// Blocks of one chunk, ascending; `last` on the final chunk
+    BlockChunk { stream: u64, seq: u64, last: bool, blocks: Vec<RangeBlock> },
+
+    /// The client has rendered chunk `seq`; the server sends at most two chunks past it
+    StreamAck { stream: u64, seq: u64 },
+
+    /// End a stream early
+    StopStream { stream: u64 },
     
     /// Error response
     Error { message: String },
+
+    /// A response or incoming message was over one of the size caps
+    LimitExceeded { error: LimitExceeded },
+
+    /// Progress of a long server-side scan (table counting, hash search)
+    Progress { progress: ProgressEvent },
+
+    /// A watchlisted commitment or excess appeared in a new block
+    WatchHit { hit: WatchHit },
     
     /// Ping/Pong for connection health
     Ping,
     Pong,
+
+    /// The server is shutting down and closes the socket next; reconnect later
+    Shutdown,
 }
 
 impl Default for DashboardData {
@@ -117,6 +314,8 @@ impl Default for DashboardData {
             recent_blocks: Vec::new(),
             network_stats: NetworkStats::default(),
             last_updated: 0,
+            data_completeness: DataCompleteness::Full,
+            stale: false,
         }
     }
 }
@@ -140,6 +339,111 @@ impl Default for NetworkStats {
             average_block_time: 0,
             transactions_per_second: 0.0,
             utxo_set_size: 0,
+            sync_status: SyncStatus::Synced,
+        }
+    }
+}
+
+impl DashboardData {
+    /// Fill the recent blocks' `timestamp_local` for `zone`
+    pub fn localized(mut self, zone: &DisplayZone) -> Self {
+        for block in &mut self.recent_blocks {
+            block.timestamp_local = zone.local_time(block.timestamp);
+        }
+        self
+    }
+}
+
+impl BlockDetailResponse {
+    /// Fill `header.timestamp_local` for `zone`
+    pub fn localized(mut self, zone: &DisplayZone) -> Self {
+        self.header.timestamp_local = zone.local_time(self.header.timestamp);
+        self
+    }
+}
+
+impl RangeBlock {
+    /// Fill `timestamp_local` for `zone`
+    pub fn localized(mut self, zone: &DisplayZone) -> Self {
+        self.timestamp_local = zone.local_time(self.timestamp);
+        self
+    }
+}
+
+impl From<BlockHeaderLite> for BlockHeaderResponse {
+    fn from(header: BlockHeaderLite) -> Self {
+        Self {
+            version: header.version,
+            timestamp: header.timestamp,
+            timestamp_local: None,
+            nonce: header.nonce,
+            previous_hash: header.previous_hash,
+            output_mr: header.output_mr,
+            kernel_mr: header.kernel_mr,
+            input_mr: header.input_mr,
+            total_kernel_offset: header.total_kernel_offset,
+            total_script_offset: header.total_script_offset,
+            pow_data_hash: header.pow_data_hash,
+            raw_header_length: header.raw_header_length,
+            pow_algorithm: header.pow_algorithm,
+        }
+    }
+}
+
+impl From<BlockDetailSummary> for BlockDetailResponse {
+    fn from(block: BlockDetailSummary) -> Self {
+        Self {
+            height: block.height,
+            hash: block.hash,
+            header: block.header.into(),
+            transactions: Some(BlockTransactionsResponse {
+                inputs: block.transactions.inputs,
+                outputs: block.transactions.outputs,
+                kernels: block.transactions.kernels,
+            }),
+            counts: block.counts,
+            data_completeness: block.data_completeness,
+        }
+    }
+}
+
+impl From<TransactionPage> for BlockTransactionsPage {
+    fn from(page: TransactionPage) -> Self {
+        Self {
+            height: page.height,
+            hash: page.hash,
+            component: page.component,
+            offset: page.page.offset,
+            limit: page.page.limit,
+            total: page.total,
+            transactions: BlockTransactionsResponse {
+                inputs: page.transactions.inputs,
+                outputs: page.transactions.outputs,
+                kernels: page.transactions.kernels,
+            },
         }
     }
-}
\ No newline at end of file
+}
+
+impl From<BlockSummary> for RangeBlock {
+    fn from(block: BlockSummary) -> Self {
+        Self {
+            height: block.height,
+            hash: block.hash,
+            timestamp: block.header.timestamp,
+            timestamp_local: None,
+            previous_hash: block.header.previous_hash,
+            output_mr: block.header.output_mr,
+            kernel_mr: block.header.kernel_mr,
+            input_mr: block.header.input_mr,
+            total_kernel_offset: block.header.total_kernel_offset,
+            total_script_offset: block.header.total_script_offset,
+            pow_data_hash: block.header.pow_data_hash,
+            raw_header_length: block.header.raw_header_length,
+            pow_algorithm: block.header.pow_algorithm,
+            kernel_count: block.kernel_count,
+            input_count: block.input_count,
+            output_count: block.output_count,
+        }
+    }
+}
@@ -0,0 +1,435 @@
+// File: src/data_provider.rs
+// Version: 1.5.1 - Single cursor loop in count_db_entries_fast
+// Tree: tari-lmdb-inspector/src/data_provider.rs
+//
+// Every interface reads blocks through `DataProvider`. `LmdbProvider` wraps the async LMDB
+// reader; `MockProvider` generates a deterministic synthetic chain so all modes can run with
+// `--demo` (no database needed) and tests can inject fixtures.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
+
+use crate::data_models::{AppConfig, BlockInfo, DatabaseStats};
+use crate::lmdb_reader::{
+    open_backend, BlockCounts, BlockDetailSummary, BlockFilter, BlockHeaderLite, BlockInclude, BlockSummary, DataCompleteness, KvRead, Page,
+    ReaderError, ReaderResult, TransactionPage, TransactionSummary, TxComponent,
+};
+use crate::lmdb_reader::r#async::{self, AsyncReader};
+use crate::lmdb_reader::metadata::{ChainMetadata, PruningInfo};
+use crate::progress::{NoProgress, Progress};
+use crate::search::{self, SearchQuery, SearchResult};
+
+/// Source of block headers, block details and table statistics
+#[async_trait]
+pub trait DataProvider: Send + Sync {
+    /// Short name for log output
+    fn name(&self) -> &'static str;
+
+    /// Block headers matching a filter, in ascending height order
+    async fn headers(&self, filter: BlockFilter) -> ReaderResult<Vec<BlockSummary>>;
+
+    /// Full block detail at `height`
+    async fn block(&self, height: u64) -> ReaderResult<BlockDetailSummary> {
+        self.block_with(height, BlockInclude::default()).await
+    }
+
+    /// Block header at `height` plus the parts in `include`
+    async fn block_with(&self, height: u64, include: BlockInclude) -> ReaderResult<BlockDetailSummary>;
+
+    /// One page of the block's inputs, outputs or kernels at `height`
+    async fn transactions(&self, height: u64, component: TxComponent, page: Page) -> ReaderResult<TransactionPage>;
+
+    /// Full block details for several heights from one read transaction, one result per height
+    async fn blocks(&self, heights: Vec<u64>) -> ReaderResult<Vec<ReaderResult<BlockDetailSummary>>>;
+
+    /// Full block detail for a block hash
+    async fn block_by_hash(&self, hash: &str) -> ReaderResult<Option<BlockDetailSummary>>;
+
+    /// Entry counts of the transaction tables
+    async fn database_stats(&self) -> DatabaseStats;
+
+    /// Whether transaction data is complete, missing or pruned
+    async fn data_completeness(&self) -> ReaderResult<DataCompleteness>;
+
+    /// Chain tip and accumulated work from the `metadata` table, when present
+    async fn chain_metadata(&self) -> ReaderResult<Option<ChainMetadata>>;
+
+    /// Resolve a height, block hash, output hash/commitment or kernel excess
+    async fn search(&self, query: SearchQuery) -> ReaderResult<SearchResult>;
+}
+
+/// Pick the provider for the configured mode
+pub fn from_config(config: &AppConfig) -> Arc<dyn DataProvider> {
+    from_config_with(config, Arc::new(NoProgress), CancellationToken::new())
+}
+
+/// `from_config`, reporting long LMDB scans (counting, hash searches) to `progress` and
+/// stopping them all once `cancel` fires
+pub fn from_config_with(config: &AppConfig, progress: Arc<dyn Progress>, cancel: CancellationToken) -> Arc<dyn DataProvider> {
+    if config.demo {
+        Arc::new(MockProvider::default())
+    } else {
+        Arc::new(LmdbProvider::new(config).with_progress(progress).with_cancellation(cancel))
+    }
+}
+
+/// Convert ascending summaries into newest-first `BlockInfo`s with intervals and row counts
+pub fn to_block_infos(summaries: &[BlockSummary]) -> Vec<BlockInfo> {
+    let mut infos: Vec<BlockInfo> = summaries.iter().enumerate().map(|(i, summary)| {
+        BlockInfo {
+            height: summary.height,
+            hash: summary.hash.clone(),
+            timestamp: summary.header.timestamp,
+            timestamp_local: None,
+            transaction_count: summary.kernel_count.unwrap_or(0) as usize,
+            outputs_count: summary.output_count,
+            inputs_count: summary.input_count,
+            interval_seconds: i.checked_sub(1)
+                .map(|prev| summary.header.timestamp as i64 - summaries[prev].header.timestamp as i64)
+                .filter(|&diff| diff > 0),
+            pow_algorithm: Some(summary.header.pow_algorithm.clone()),
+        }
+    }).collect();
+    infos.reverse();
+    infos
+}
+
+/// Reads a Tari base node LMDB directory
+#[derive(Debug, Clone)]
+pub struct LmdbProvider {
+    database_path: PathBuf,
+    reader: AsyncReader,
+    timeout: std::time::Duration,
+    progress: Arc<dyn Progress>,
+    cancel: CancellationToken,
+}
+
+impl LmdbProvider {
+    pub fn new(config: &AppConfig) -> Self {
+        Self {
+            database_path: config.database_path.clone(),
+            reader: AsyncReader::new(config.database_path.clone(), config.read_timeout),
+            timeout: config.read_timeout,
+            progress: Arc::new(NoProgress),
+            cancel: CancellationToken::new(),
+        }
+    }
+
+    /// Report long scans to `progress`
+    pub fn with_progress(mut self, progress: Arc<dyn Progress>) -> Self {
+        self.reader = self.reader.with_progress(progress.clone());
+        self.progress = progress;
+        self
+    }
+
+    /// Stop long scans once `cancel` fires; each read also stops when its caller goes away
+    pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+        self.reader = self.reader.with_cancellation(cancel.clone());
+        self.cancel = cancel;
+        self
+    }
+}
+
+#[async_trait]
+impl DataProvider for LmdbProvider {
+    fn name(&self) -> &'static str {
+        "lmdb"
+    }
+
+    async fn headers(&self, filter: BlockFilter) -> ReaderResult<Vec<BlockSummary>> {
+        self.reader.headers("headers", filter).await
+    }
+
+    async fn block_with(&self, height: u64, include: BlockInclude) -> ReaderResult<BlockDetailSummary> {
+        self.reader.block(height, include).await
+    }
+
+    async fn transactions(&self, height: u64, component: TxComponent, page: Page) -> ReaderResult<TransactionPage> {
+        self.reader.transactions(height, component, page).await
+    }
+
+    async fn blocks(&self, heights: Vec<u64>) -> ReaderResult<Vec<ReaderResult<BlockDetailSummary>>> {
+        self.reader.blocks(heights).await
+    }
+
+    async fn block_by_hash(&self, hash: &str) -> ReaderResult<Option<BlockDetailSummary>> {
+        self.reader.block_by_hash(hash).await
+    }
+
+    async fn database_stats(&self) -> DatabaseStats {
+        // Counting stops if this future is dropped (client gone) or the provider is cancelled
+        let cancel = self.cancel.child_token();
+        let _cancel_on_drop = cancel.clone().drop_guard();
+        calculate_real_database_stats(&self.database_path, self.progress.clone(), cancel).await
+    }
+
+    async fn data_completeness(&self) -> ReaderResult<DataCompleteness> {
+        let path = self.database_path.clone();
+        r#async::run_blocking(self.timeout, "detect data completeness", move || {
+            crate::lmdb_reader::completeness::detect_completeness(&path)
+        }).await
+    }
+
+    async fn chain_metadata(&self) -> ReaderResult<Option<ChainMetadata>> {
+        let path = self.database_path.clone();
+        r#async::run_blocking(self.timeout, "read chain metadata", move || {
+            crate::lmdb_reader::metadata::read_chain_metadata(&path)
+        }).await
+    }
+
+    async fn search(&self, query: SearchQuery) -> ReaderResult<SearchResult> {
+        let path = self.database_path.clone();
+        let progress = self.progress.clone();
+        r#async::run_cancellable(self.timeout, "search", &self.cancel, move |cancel| {
+            search::search_with_progress(&path, &query, progress.as_ref(), &cancel)
+        }).await
+    }
+}
+
+/// Height of the synthetic chain tip
+const MOCK_TIP_HEIGHT: u64 = 100_000;
+
+/// Number of synthetic blocks below (and including) the tip
+const MOCK_HISTORY: u64 = 5_000;
+
+/// Synthetic target block time in seconds
+const MOCK_BLOCK_TIME: u64 = 120;
+
+/// PoW algorithms rotated through the synthetic chain
+const MOCK_POW: [&str; 3] = ["RandomXM", "Sha3x", "RandomXT"];
+
+/// Deterministic synthetic chain for demo mode and tests
+#[derive(Debug, Clone)]
+pub struct MockProvider {
+    tip_height: u64,
+    tip_timestamp: u64,
+}
+
+impl Default for MockProvider {
+    fn default() -> Self {
+        Self::new(MOCK_TIP_HEIGHT, chrono::Utc::now().timestamp() as u64)
+    }
+}
+
+impl MockProvider {
+    pub fn new(tip_height: u64, tip_timestamp: u64) -> Self {
+        Self { tip_height, tip_timestamp }
+    }
+
+    fn first_height(&self) -> u64 {
+        self.tip_height.saturating_sub(MOCK_HISTORY - 1)
+    }
+
+    fn hash_at(height: u64) -> String {
+        format!("{:064x}", height.wrapping_mul(0x9e37_79b9_7f4a_7c15))
+    }
+
+    fn summary_at(&self, height: u64) -> BlockSummary {
+        let timestamp = self.tip_timestamp.saturating_sub((self.tip_height - height) * MOCK_BLOCK_TIME);
+        let kernel_count = 5 + height % 3;
+        let input_count = height % 4;
+        BlockSummary {
+            height,
+            hash: Self::hash_at(height),
+            header: BlockHeaderLite {
+                version: 1,
+                height,
+                previous_hash: Self::hash_at(height.saturating_sub(1)),
+                timestamp,
+                nonce: height,
+                output_mr: format!("{:064x}", height),
+                kernel_mr: format!("{:064x}", height + 1),
+                input_mr: format!("{:064x}", height + 2),
+                total_kernel_offset: format!("{:064x}", 0),
+                total_script_offset: format!("{:064x}", 0),
+                pow_data_hash: "empty".to_string(),
+                raw_header_length: 0,
+                pow_algorithm: MOCK_POW[(height % MOCK_POW.len() as u64) as usize].to_string(),
+                kernel_mmr_size: height * 6 + kernel_count,
+                output_smt_size: height * 8,
+            },
+            kernel_count: Some(kernel_count),
+            input_count: Some(input_count),
+            // output_smt_size grows by 8 per block: outputs created minus inputs spent
+            output_count: Some(8 + input_count),
+        }
+    }
+
+    fn detail_at(&self, height: u64) -> BlockDetailSummary {
+        let summary = self.summary_at(height);
+        BlockDetailSummary {
+            height: summary.height,
+            hash: summary.hash,
+            header: summary.header,
+            transactions: TransactionSummary {
+                inputs: Vec::new(),
+                outputs: Vec::new(),
+                kernels: Vec::new(),
+            },
+            counts: None,
+            data_completeness: DataCompleteness::Full,
+        }
+    }
+}
+
+#[async_trait]
+impl DataProvider for MockProvider {
+    fn name(&self) -> &'static str {
+        "demo"
+    }
+
+    async fn headers(&self, filter: BlockFilter) -> ReaderResult<Vec<BlockSummary>> {
+        let (low, high) = filter.height_bounds();
+        let blocks = (low.max(self.first_height())..=high.min(self.tip_height))
+            .map(|height| self.summary_at(height))
+            .collect();
+        Ok(filter.apply(blocks))
+    }
+
+    async fn block_with(&self, height: u64, include: BlockInclude) -> ReaderResult<BlockDetailSummary> {
+        if height < self.first_height() || height > self.tip_height {
+            return Err(ReaderError::BlockNotFound(height));
+        }
+        let mut detail = self.detail_at(height);
+        detail.counts = include.counts.then(|| BlockCounts::of(&detail.transactions));
+        Ok(detail)
+    }
+
+    async fn transactions(&self, height: u64, component: TxComponent, page: Page) -> ReaderResult<TransactionPage> {
+        // Synthetic blocks carry no transaction rows, so every page is empty
+        let detail = self.block_with(height, BlockInclude::HEADER).await?;
+        Ok(TransactionPage {
+            height,
+            hash: detail.hash,
+            component,
+            page,
+            total: 0,
+            transactions: detail.transactions,
+        })
+    }
+
+    async fn blocks(&self, heights: Vec<u64>) -> ReaderResult<Vec<ReaderResult<BlockDetailSummary>>> {
+        let mut blocks = Vec::with_capacity(heights.len());
+        for height in heights {
+            blocks.push(self.block(height).await);
+        }
+        Ok(blocks)
+    }
+
+    async fn block_by_hash(&self, hash: &str) -> ReaderResult<Option<BlockDetailSummary>> {
+        Ok((self.first_height()..=self.tip_height)
+            .find(|height| Self::hash_at(*height).eq_ignore_ascii_case(hash))
+            .map(|height| self.detail_at(height)))
+    }
+
+    async fn database_stats(&self) -> DatabaseStats {
+        DatabaseStats {
+            utxos_count: 1_234_567,
+            inputs_count: 987_654,
+            kernels_count: 543_210,
+            total_transactions: 543_210,
+            total_io_records: 2_222_221,
+        }
+    }
+
+    async fn data_completeness(&self) -> ReaderResult<DataCompleteness> {
+        Ok(DataCompleteness::Full)
+    }
+
+    async fn chain_metadata(&self) -> ReaderResult<Option<ChainMetadata>> {
+        Ok(Some(ChainMetadata {
+            best_block_height: self.tip_height,
+            best_block_hash: Some(Self::hash_at(self.tip_height)),
+            best_block_timestamp: Some(self.tip_timestamp),
+            accumulated_work: Some(format!("0x{:x}", self.tip_height as u128 * 1_000_000_000)),
+            pruning: PruningInfo { pruning_horizon: 0, pruned_height: 0 },
+            migration_version: None,
+        }))
+    }
+
+    async fn search(&self, query: SearchQuery) -> ReaderResult<SearchResult> {
+        // The synthetic chain has no outputs or kernels, so only blocks can match
+        let height = match query {
+            SearchQuery::Height(height) => Some(height),
+            SearchQuery::Hash(hash) => {
+                let hash = hex::encode(hash);
+                (self.first_height()..=self.tip_height).find(|height| Self::hash_at(*height) == hash)
+            }
+        };
+        Ok(match height {
+            Some(height) if (self.first_height()..=self.tip_height).contains(&height) => {
+                SearchResult::Block { height, hash: Self::hash_at(height) }
+            }
+            _ => SearchResult::NotFound,
+        })
+    }
+}
+
+/// Calculate real database statistics by scanning LMDB
+async fn calculate_real_database_stats(
+    database_path: &std::path::Path,
+    progress: Arc<dyn Progress>,
+    cancel: CancellationToken,
+) -> DatabaseStats {
+    // Try to get real counts (this is expensive, so we do it occasionally)
+    let (utxos_count, inputs_count, kernels_count) = tokio::task::spawn_blocking({
+        let path = database_path.to_path_buf();
+        move || {
+            let mut utxos = 0;
+            let mut inputs = 0;
+            let mut kernels = 0;
+
+            // Try to count actual database entries; a missing table counts as 0
+            if let Ok(backend) = open_backend(&path) {
+                if let Ok(read) = backend.read() {
+                    utxos = count_db_entries_fast(&*read, "utxos", "UTXOs", progress.as_ref(), &cancel);
+                    inputs = count_db_entries_fast(&*read, "inputs", "Inputs", progress.as_ref(), &cancel);
+                    kernels = count_db_entries_fast(&*read, "kernels", "Kernels", progress.as_ref(), &cancel);
+                }
+            }
+
+            (utxos, inputs, kernels)
+        }
+    }).await.unwrap_or((0, 0, 0));
+
+    DatabaseStats {
+        utxos_count,
+        inputs_count,
+        kernels_count,
+        total_transactions: kernels_count, // 1 kernel = 1 transaction
+        total_io_records: utxos_count + inputs_count,
+    }
+}
+
+/// Fast database entry counting without limits
+fn count_db_entries_fast(
+    read: &dyn KvRead,
+    table: &str,
+    label: &str,
+    progress: &dyn Progress,
+    cancel: &CancellationToken,
+) -> usize {
+    let Ok(mut cursor) = read.cursor(table) else { return 0 };
+    progress.begin(&format!("Counting {} entries", label), None);
+
+    let mut count = 0;
+    let mut found = matches!(cursor.first(), Ok(Some(_)));
+    while found {
+        count += 1;
+        if count % 500_000 == 0 {
+            progress.update(count as u64);
+            // Nobody is waiting for the stats any more; the partial count is discarded
+            if cancel.is_cancelled() {
+                progress.finish(&format!("{}: cancelled", label));
+                return count;
+            }
+        }
+        found = matches!(cursor.next(), Ok(Some(_)));
+    }
+
+    progress.update(count as u64);
+    progress.finish(&format!("{}: {} entries", label, count));
+    count
+}
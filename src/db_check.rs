@@ -0,0 +1,187 @@
+// File: src/db_check.rs
+// Version: 1.0.0 - LMDB environment integrity and health check (mdb_stat style)
+// Tree: tari-lmdb-inspector/src/db_check.rs
+//
+// Reports environment info, per-table statistics and free page usage, and flags the two
+// conditions that most often precede base node trouble: a nearly full map and a bloated
+// freelist (pages LMDB holds on to but cannot hand back to the filesystem).
+
+use std::path::Path;
+use lmdb_zero::{EnvBuilder, Database, ReadTransaction};
+use lmdb_zero::DatabaseOptions;
+use serde::{Deserialize, Serialize};
+
+use crate::lmdb_reader::{env_path, ReaderResult};
+
+/// Map usage above this fraction is flagged as nearly full
+const MAP_FULL_WARN_RATIO: f64 = 0.90;
+
+/// Free pages above this fraction of the used file size are flagged as an oversized freelist
+const FREELIST_WARN_RATIO: f64 = 0.25;
+
+/// LMDB reserves two meta pages at the start of the file
+const META_PAGES: u64 = 2;
+
+/// Statistics for one named table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableStat {
+    pub name: String,
+    pub entries: u64,
+    pub depth: u32,
+    pub branch_pages: u64,
+    pub leaf_pages: u64,
+    pub overflow_pages: u64,
+}
+
+impl TableStat {
+    pub fn total_pages(&self) -> u64 {
+        self.branch_pages + self.leaf_pages + self.overflow_pages
+    }
+}
+
+/// Full health report for an LMDB environment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbCheckReport {
+    pub map_size: u64,
+    pub page_size: u32,
+    pub last_page: u64,
+    pub last_txn_id: u64,
+    pub max_readers: u32,
+    pub num_readers: u32,
+    pub tables: Vec<TableStat>,
+    /// Pages not accounted for by any table (freelist plus pending reuse)
+    pub free_pages: u64,
+    pub warnings: Vec<String>,
+}
+
+impl DbCheckReport {
+    /// Bytes of the map currently in use
+    pub fn used_bytes(&self) -> u64 {
+        (self.last_page + 1) * self.page_size as u64
+    }
+
+    /// Fraction of the map in use
+    pub fn map_usage(&self) -> f64 {
+        if self.map_size == 0 { 0.0 } else { self.used_bytes() as f64 / self.map_size as f64 }
+    }
+}
+
+/// Collect environment info and per-table statistics
+pub fn check_database(path: &Path) -> ReaderResult<DbCheckReport> {
+    let path_str = env_path(path)?;
+
+    let mut builder = EnvBuilder::new()?;
+    builder.set_maxdbs(64)?;
+
+    let env = unsafe {
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+    };
+
+    let info = env.info()?;
+    let env_stat = env.stat()?;
+
+    // Named tables are stored as keys of the unnamed main database
+    let main_db = Database::open(&env, None, &DatabaseOptions::defaults())?;
+    let txn = ReadTransaction::new(&env)?;
+    let access = txn.access();
+
+    let mut names = Vec::new();
+    let mut cursor = txn.cursor(&main_db)?;
+    let mut entry = cursor.first::<[u8], [u8]>(&access);
+    while let Ok((key, _)) = entry {
+        names.push(String::from_utf8_lossy(key).into_owned());
+        entry = cursor.next::<[u8], [u8]>(&access);
+    }
+
+    let mut tables = Vec::new();
+    for name in names {
+        let Ok(db) = Database::open(&env, Some(&name), &DatabaseOptions::defaults()) else { continue };
+        let stat = txn.db_stat(&db)?;
+        tables.push(TableStat {
+            name,
+            entries: stat.entries as u64,
+            depth: stat.depth,
+            branch_pages: stat.branch_pages as u64,
+            leaf_pages: stat.leaf_pages as u64,
+            overflow_pages: stat.overflow_pages as u64,
+        });
+    }
+
+    let main_pages = (env_stat.branch_pages + env_stat.leaf_pages + env_stat.overflow_pages) as u64;
+    let table_pages: u64 = tables.iter().map(TableStat::total_pages).sum();
+    let last_page = info.last_pgno as u64;
+    let free_pages = (last_page + 1).saturating_sub(META_PAGES + main_pages + table_pages);
+
+    let mut report = DbCheckReport {
+        map_size: info.mapsize as u64,
+        page_size: env_stat.psize,
+        last_page,
+        last_txn_id: info.last_txnid as u64,
+        max_readers: info.maxreaders,
+        num_readers: info.numreaders,
+        tables,
+        free_pages,
+        warnings: Vec::new(),
+    };
+
+    if report.map_usage() > MAP_FULL_WARN_RATIO {
+        report.warnings.push(format!(
+            "Map is {:.1}% full; the node will fail with MDB_MAP_FULL unless the map size is increased",
+            report.map_usage() * 100.0
+        ));
+    }
+    if free_pages as f64 > (last_page + 1) as f64 * FREELIST_WARN_RATIO {
+        report.warnings.push(format!(
+            "{} free pages ({:.1}% of the file); a compacting backup would reclaim {}",
+            free_pages,
+            free_pages as f64 / (last_page + 1) as f64 * 100.0,
+            format_bytes(free_pages * report.page_size as u64)
+        ));
+    }
+    if report.num_readers >= report.max_readers {
+        report.warnings.push(format!("Reader table is full ({} of {} slots)", report.num_readers, report.max_readers));
+    }
+
+    Ok(report)
+}
+
+/// Print the report in an mdb_stat-like layout
+pub fn print_report(report: &DbCheckReport) {
+    println!("Environment Info");
+    println!("  Map size:        {} ({} bytes)", format_bytes(report.map_size), report.map_size);
+    println!("  Used:            {} ({:.1}%)", format_bytes(report.used_bytes()), report.map_usage() * 100.0);
+    println!("  Page size:       {}", report.page_size);
+    println!("  Last page:       {}", report.last_page);
+    println!("  Last txn id:     {}", report.last_txn_id);
+    println!("  Readers:         {} / {}", report.num_readers, report.max_readers);
+    println!("  Free pages:      {} ({})", report.free_pages, format_bytes(report.free_pages * report.page_size as u64));
+    println!();
+
+    println!("{:<36} {:>12} {:>6} {:>10} {:>10} {:>10}", "Table", "Entries", "Depth", "Branch", "Leaf", "Overflow");
+    println!("{}", "-".repeat(89));
+    for table in &report.tables {
+        println!("{:<36} {:>12} {:>6} {:>10} {:>10} {:>10}",
+            table.name, table.entries, table.depth, table.branch_pages, table.leaf_pages, table.overflow_pages);
+    }
+    println!();
+
+    if report.warnings.is_empty() {
+        println!("✅ No problems detected");
+    } else {
+        for warning in &report.warnings {
+            println!("⚠️  {}", warning);
+        }
+    }
+}
+
+/// Human readable byte size
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
@@ -0,0 +1,53 @@
+// File: src/denomination.rs
+// Version: 1.0.0 - µT / T amount formatting shared by CLI, TUI and web pages (--denom)
+// Tree: tari-lmdb-inspector/src/denomination.rs
+//
+// Amounts are stored and computed in microTari (µT). `--denom tari` shows them in Tari (T,
+// 1 T = 1,000,000 µT) in CLI tables, the TUI and server-rendered pages. The JSON API is not
+// affected by the flag: kernel fees carry both `fee_ut` (integer µT) and `fee_t` (an exact
+// decimal string, so no precision is lost to floating point).
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// µT in one T
+pub const MICRO_TARI_PER_TARI: u64 = 1_000_000;
+
+/// Unit amounts are displayed in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Denomination {
+    /// µT, as stored on chain
+    #[default]
+    Microtari,
+    /// T with six decimal places
+    Tari,
+}
+
+impl Denomination {
+    /// Unit symbol for column headings
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Self::Microtari => "µT",
+            Self::Tari => "T",
+        }
+    }
+
+    /// `micro_tari` in this unit, without the symbol
+    pub fn amount(&self, micro_tari: u64) -> String {
+        match self {
+            Self::Microtari => micro_tari.to_string(),
+            Self::Tari => tari(micro_tari),
+        }
+    }
+
+    /// `micro_tari` in this unit with the symbol, e.g. `2500 µT` or `0.002500 T`
+    pub fn format(&self, micro_tari: u64) -> String {
+        format!("{} {}", self.amount(micro_tari), self.unit())
+    }
+}
+
+/// `micro_tari` as an exact decimal number of T, e.g. `1.250000`
+pub fn tari(micro_tari: u64) -> String {
+    format!("{}.{:06}", micro_tari / MICRO_TARI_PER_TARI, micro_tari % MICRO_TARI_PER_TARI)
+}
@@ -0,0 +1,300 @@
+// File: src/file_watcher.rs
+// Version: 1.3.0 - FSEvents create events count as database writes
+// Tree: tari-lmdb-inspector/src/file_watcher.rs
+//
+// A base node writes its LMDB files many times per block, and continuously while syncing.
+// `LmdbWatcher` coalesces those writes: a change is reported once the files have been quiet for
+// `DEBOUNCE`, or after `MAX_BURST` of uninterrupted writes so a syncing node still updates, and
+// never more often than the configured rate. `TipCoalescer` then compares the chain tip with the
+// one seen at the previous change, so bursts that did not move the tip (peer and mempool
+// bookkeeping) cost one metadata read instead of a refresh.
+//
+// File events need inotify (or the platform equivalent) on the database's filesystem. On NFS and
+// other network mounts the watch starts but never fires, and some containers refuse it outright,
+// so `--watch auto` polls the tip height every `--poll-interval` instead in both cases.
+
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use anyhow::Result;
+use clap::ValueEnum;
+use notify::{Watcher, RecursiveMode, Event, RecommendedWatcher};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+use crate::data_models::AppConfig;
+use crate::lmdb_reader::metadata::{read_chain_metadata, ChainMetadata};
+use crate::lmdb_reader::StreamReader;
+
+/// How long the database must be quiet before a change is reported
+pub const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Longest run of writes coalesced into one change
+pub const MAX_BURST: Duration = Duration::from_secs(5);
+
+/// Default time between tip reads when polling
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Filesystems whose writes by another host never produce local file events
+const NETWORK_FILESYSTEMS: &[&str] = &["nfs", "nfs4", "cifs", "smb3", "smbfs", "9p", "afs", "ceph", "glusterfs", "fuse.sshfs"];
+
+/// How database changes are detected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum WatchMode {
+    /// File events, or polling on network filesystems and when file events cannot start
+    #[default]
+    Auto,
+    /// File events only; fail if they cannot start
+    Notify,
+    /// Poll the tip height
+    Poll,
+}
+
+/// Watches an LMDB directory for writes to `.mdb` files (zero CPU when idle), or polls its tip
+pub struct LmdbWatcher {
+    source: ChangeSource,
+    /// Shortest time between two reported changes
+    min_interval: Duration,
+    last_change: Option<Instant>,
+}
+
+enum ChangeSource {
+    Events {
+        // Dropping the watcher stops the notifications, so keep it alive with the receiver
+        _watcher: RecommendedWatcher,
+        rx: mpsc::Receiver<()>,
+    },
+    Poll {
+        database_path: PathBuf,
+        interval: Duration,
+        last_tip: Option<TipMark>,
+        /// Why polling was chosen
+        reason: String,
+    },
+}
+
+impl LmdbWatcher {
+    /// Start watching the given LMDB directory
+    pub fn new(database_path: &Path) -> Result<Self> {
+        let (tx, rx) = mpsc::channel(100);
+
+        let mut watcher = notify::recommended_watcher(move |res: Result<Event, notify::Error>| {
+            match res {
+                // A full channel already guarantees a pending change, so drop extras
+                Ok(event) if is_database_write(&event) => {
+                    let _ = tx.try_send(());
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("File watch error: {:?}", e),
+            }
+        })?;
+
+        watcher.watch(database_path, RecursiveMode::NonRecursive)?;
+
+        Ok(Self::with_source(ChangeSource::Events { _watcher: watcher, rx }))
+    }
+
+    /// Poll the tip height of the database every `interval`
+    pub fn poll(database_path: &Path, interval: Duration, reason: impl Into<String>) -> Self {
+        Self::with_source(ChangeSource::Poll {
+            database_path: database_path.to_path_buf(),
+            interval,
+            last_tip: read_tip(database_path),
+            reason: reason.into(),
+        })
+    }
+
+    /// Watcher for `--watch`, `--poll-interval` and `--max-update-rate`
+    pub fn from_config(config: &AppConfig) -> Result<Self> {
+        let path = &config.database_path;
+        let watcher = match config.watch_mode {
+            WatchMode::Notify => Self::new(path)?,
+            WatchMode::Poll => Self::poll(path, config.poll_interval, "--watch poll"),
+            WatchMode::Auto => match network_filesystem(path) {
+                Some(fs) => Self::poll(path, config.poll_interval, format!("{} filesystem", fs)),
+                None => Self::new(path).unwrap_or_else(|e| {
+                    Self::poll(path, config.poll_interval, format!("file events unavailable: {}", e))
+                }),
+            },
+        };
+        Ok(watcher.with_max_rate(config.max_update_rate))
+    }
+
+    fn with_source(source: ChangeSource) -> Self {
+        Self { source, min_interval: Duration::ZERO, last_change: None }
+    }
+
+    /// Whether changes come from polling rather than file events
+    pub fn is_polling(&self) -> bool {
+        matches!(self.source, ChangeSource::Poll { .. })
+    }
+
+    /// Active detection mode, for log output
+    pub fn describe(&self) -> String {
+        match &self.source {
+            ChangeSource::Events { .. } => "file events".to_string(),
+            ChangeSource::Poll { interval, reason, .. } => {
+                format!("polling the tip every {}s ({})", interval.as_secs_f64(), reason)
+            }
+        }
+    }
+
+    /// Report at most `rate` changes per second (`None` for no limit)
+    pub fn with_max_rate(mut self, rate: Option<f64>) -> Self {
+        self.min_interval = min_interval(rate);
+        self
+    }
+
+    /// Wait for the next change, returning once writes have been quiet for `DEBOUNCE` (or have
+    /// gone on for `MAX_BURST`) and the rate limit allows another update
+    /// Returns `None` if the watcher has stopped
+    pub async fn next_change(&mut self) -> Option<()> {
+        match &mut self.source {
+            ChangeSource::Events { rx, .. } => {
+                rx.recv().await?;
+                let burst_end = Instant::now() + MAX_BURST;
+
+                // Wait for writes to complete, restarting the timer on every new event
+                loop {
+                    let quiet = DEBOUNCE.min(burst_end.saturating_duration_since(Instant::now()));
+                    match tokio::time::timeout(quiet, rx.recv()).await {
+                        Ok(Some(())) if Instant::now() < burst_end => continue,
+                        Ok(Some(())) => break,
+                        Ok(None) => return None,
+                        Err(_) => break,
+                    }
+                }
+            }
+            ChangeSource::Poll { database_path, interval, last_tip, .. } => loop {
+                tokio::time::sleep(*interval).await;
+                let path = database_path.clone();
+                let tip = tokio::task::spawn_blocking(move || read_tip(&path)).await.ok().flatten();
+                if tip != *last_tip {
+                    *last_tip = tip;
+                    break;
+                }
+            },
+        }
+
+        // Writes during the wait belong to this change
+        if let Some(last) = self.last_change {
+            tokio::time::sleep_until(last + self.min_interval).await;
+            if let ChangeSource::Events { rx, .. } = &mut self.source {
+                while rx.try_recv().is_ok() {}
+            }
+        }
+        self.last_change = Some(Instant::now());
+        Some(())
+    }
+}
+
+/// Tip from the metadata table, or the highest header when the table is missing
+fn read_tip(database_path: &Path) -> Option<TipMark> {
+    if let Ok(Some(metadata)) = read_chain_metadata(database_path) {
+        return Some(TipMark::from(&metadata));
+    }
+    let reader = StreamReader::open(database_path).ok()?;
+    let height = reader.snapshot().ok()?.tip_height().ok()??;
+    Some(TipMark { height, hash: None })
+}
+
+/// Type of the network filesystem holding `path`, if it is on one (Linux; `None` elsewhere)
+pub fn network_filesystem(path: &Path) -> Option<String> {
+    let mounts = std::fs::read_to_string("/proc/self/mounts").ok()?;
+    let path = path.canonicalize().ok()?;
+    network_filesystem_in(&mounts, &path)
+}
+
+/// `network_filesystem` against a mount table in `/proc/self/mounts` format
+pub fn network_filesystem_in(mounts: &str, path: &Path) -> Option<String> {
+    let (_, fs) = mounts.lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let (_, mount_point, fs) = (fields.next()?, fields.next()?, fields.next()?);
+            // Spaces in mount points are written as \040
+            Some((PathBuf::from(mount_point.replace("\\040", " ")), fs))
+        })
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.components().count())?;
+    NETWORK_FILESYSTEMS.contains(&fs).then(|| fs.to_string())
+}
+
+/// Whether a file event is a write to the environment's `.mdb` files
+///
+/// inotify (Linux) and ReadDirectoryChangesW (Windows) report writes as modify events. FSEvents
+/// (macOS) keeps the flags a file has accumulated since the stream started, so a write to
+/// `data.mdb` can arrive as a create event; both kinds count.
+pub fn is_database_write(event: &Event) -> bool {
+    (event.kind.is_modify() || event.kind.is_create())
+        && event.paths.iter().any(|p| p.extension().is_some_and(|ext| ext == "mdb"))
+}
+
+/// Time between updates for at most `rate` per second; no limit for `None` or a non-positive rate
+pub fn min_interval(rate: Option<f64>) -> Duration {
+    match rate {
+        Some(rate) if rate > 0.0 => Duration::from_secs_f64(1.0 / rate),
+        _ => Duration::ZERO,
+    }
+}
+
+/// Chain tip as named by the metadata table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TipMark {
+    pub height: u64,
+    pub hash: Option<String>,
+}
+
+impl From<&ChainMetadata> for TipMark {
+    fn from(metadata: &ChainMetadata) -> Self {
+        Self { height: metadata.best_block_height, hash: metadata.best_block_hash.clone() }
+    }
+}
+
+/// The tip before and after a coalesced burst of writes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TipChange {
+    pub before: Option<TipMark>,
+    pub after: TipMark,
+}
+
+impl TipChange {
+    /// Whether the tip moved back or was replaced at the same height; a reorg onto a longer
+    /// chain only shows in block hashes
+    pub fn is_rewind(&self) -> bool {
+        self.before.as_ref().is_some_and(|before| before.height >= self.after.height)
+    }
+
+    /// Heights that need reading: those above the previous tip, or up from the new tip after a
+    /// rewind. `None` on the first observation, when everything does.
+    pub fn new_heights(&self) -> Option<RangeInclusive<u64>> {
+        let before = self.before.as_ref()?;
+        let start = if self.is_rewind() { self.after.height } else { before.height + 1 };
+        Some(start..=self.after.height)
+    }
+}
+
+/// Remembers the tip across changes so bursts that leave it in place can be skipped
+#[derive(Debug, Clone, Default)]
+pub struct TipCoalescer {
+    last: Option<TipMark>,
+}
+
+impl TipCoalescer {
+    pub fn last(&self) -> Option<&TipMark> {
+        self.last.as_ref()
+    }
+
+    /// Whether `tip` differs from the last observed tip
+    pub fn is_new(&self, tip: &TipMark) -> bool {
+        self.last.as_ref() != Some(tip)
+    }
+
+    /// Record `tip`; `None` when it is the tip already seen
+    pub fn observe(&mut self, tip: TipMark) -> Option<TipChange> {
+        if !self.is_new(&tip) {
+            return None;
+        }
+        let before = self.last.replace(tip.clone());
+        Some(TipChange { before, after: tip })
+    }
+}
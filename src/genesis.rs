@@ -0,0 +1,120 @@
+// File: src/genesis.rs
+// Version: 1.0.0 - Genesis block decoding and network identification (genesis command)
+// Tree: tari-lmdb-inspector/src/genesis.rs
+//
+// Every Tari network has its own hard-coded genesis block, so the hash of block 0 says which
+// network a database belongs to. `genesis` decodes block 0, lists the genesis hash of every
+// network the linked Tari version knows and marks the one that matches. Preflight uses the
+// same lookup to catch a wrong `--network` at startup.
+
+use std::path::Path;
+use serde::Serialize;
+
+use tari_common::configuration::Network;
+use tari_core::blocks::genesis_block::get_genesis_block;
+use crate::data_models::BlockDetailResponse;
+use crate::lmdb_reader::{read_block_with_transactions, ReaderResult};
+use crate::timezone::DisplayZone;
+
+/// Networks whose genesis hash is compared against the database
+pub const KNOWN_NETWORKS: [Network; 6] = [
+    Network::MainNet,
+    Network::StageNet,
+    Network::NextNet,
+    Network::Esmeralda,
+    Network::Igor,
+    Network::LocalNet,
+];
+
+/// A known network's hard-coded genesis hash
+#[derive(Debug, Clone, Serialize)]
+pub struct KnownGenesis {
+    pub network: String,
+    pub hash: String,
+    /// Whether the database's block 0 has this hash
+    pub matches: bool,
+}
+
+/// Decoded genesis block and the network it identifies
+#[derive(Debug, Clone, Serialize)]
+pub struct GenesisReport {
+    pub block: BlockDetailResponse,
+    /// Network whose genesis block matches, if any
+    pub network: Option<String>,
+    pub known: Vec<KnownGenesis>,
+    pub warnings: Vec<String>,
+}
+
+/// Hex genesis hash hard-coded for `network`
+pub fn genesis_hash(network: Network) -> String {
+    hex::encode(get_genesis_block(network).hash())
+}
+
+/// Network whose genesis block has `hash` (hex)
+pub fn network_of(hash: &str) -> Option<Network> {
+    KNOWN_NETWORKS.into_iter().find(|network| genesis_hash(*network).eq_ignore_ascii_case(hash))
+}
+
+/// Decode block 0 of the database at `path` and check it against the known networks;
+/// `expected` is the `--network` value
+pub fn genesis_report(path: &Path, expected: Network) -> ReaderResult<GenesisReport> {
+    let block = BlockDetailResponse::from(read_block_with_transactions(path, 0)?);
+    let mut network = None;
+    let known: Vec<KnownGenesis> = KNOWN_NETWORKS.into_iter().map(|known| {
+        let hash = genesis_hash(known);
+        let matches = hash.eq_ignore_ascii_case(&block.hash);
+        if matches {
+            network = Some(known);
+        }
+        KnownGenesis { network: known.to_string(), hash, matches }
+    }).collect();
+
+    let mut warnings = Vec::new();
+    match network {
+        Some(network) if network != expected => warnings.push(format!(
+            "This is a {} database but --network is {}",
+            network, expected
+        )),
+        Some(_) => {}
+        None => warnings.push(
+            "Genesis block does not match any network known to this build (a custom or newer network?)".to_string()
+        ),
+    }
+
+    Ok(GenesisReport { block, network: network.map(|n| n.to_string()), known, warnings })
+}
+
+/// Print the genesis header, the known genesis hashes and the verdict
+pub fn print_genesis(report: &GenesisReport, zone: &DisplayZone) {
+    let block = &report.block;
+    println!("Genesis Block");
+    println!("  Hash:            {}", block.hash);
+    println!("  Timestamp:       {}", zone.datetime(block.header.timestamp));
+    println!("  Version:         {}", block.header.version);
+    println!("  PoW:             {} (nonce {})", block.header.pow_algorithm, block.header.nonce);
+    println!("  Output MR:       {}", block.header.output_mr);
+    println!("  Kernel MR:       {}", block.header.kernel_mr);
+    println!("  Input MR:        {}", block.header.input_mr);
+    println!("  Kernel offset:   {}", block.header.total_kernel_offset);
+    println!("  Script offset:   {}", block.header.total_script_offset);
+    if let Some(transactions) = &block.transactions {
+        println!("  Rows:            {} outputs, {} kernels, {} inputs",
+            transactions.outputs.len(), transactions.kernels.len(), transactions.inputs.len());
+    }
+    println!();
+
+    println!("{:<12} {:<64}", "Network", "Genesis hash");
+    println!("{}", "-".repeat(80));
+    for known in &report.known {
+        println!("{:<12} {:<64} {}", known.network, known.hash, if known.matches { "✅" } else { "" });
+    }
+    println!();
+
+    match &report.network {
+        Some(network) => println!("✅ This database belongs to {}", network),
+        None => println!("❓ Unknown network"),
+    }
+    for warning in &report.warnings {
+        println!("⚠️  {}", warning);
+    }
+}
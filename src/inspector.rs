@@ -0,0 +1,69 @@
+// File: src/inspector.rs
+// Version: 1.0.1 - MMR lookups report beyond-tip positions
+// Tree: tari-lmdb-inspector/src/inspector.rs
+
+use std::path::{Path, PathBuf};
+use crate::lmdb_reader::{self, BlockDetailSummary, BlockFilter, BlockSummary, ReaderError, ReaderResult};
+use crate::mmr::{self, MmrKind, MmrLookup};
+
+/// High-level handle on a Tari base node LMDB directory
+///
+/// ```no_run
+/// use tari_lmdb_inspector::Inspector;
+///
+/// let inspector = Inspector::open("/home/me/.tari/mainnet/data/base_node/db")?;
+/// for block in inspector.last_blocks(5)? {
+///     println!("{} {}", block.height, block.hash);
+/// }
+/// # Ok::<(), tari_lmdb_inspector::lmdb_reader::ReaderError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct Inspector {
+    database_path: PathBuf,
+}
+
+impl Inspector {
+    /// Open an inspector on the given LMDB directory
+    pub fn open(database_path: impl Into<PathBuf>) -> ReaderResult<Self> {
+        let database_path = database_path.into();
+        if !database_path.exists() {
+            return Err(ReaderError::InvalidPath(database_path));
+        }
+        Ok(Self { database_path })
+    }
+
+    /// Path to the LMDB directory
+    pub fn database_path(&self) -> &Path {
+        &self.database_path
+    }
+
+    /// Block headers matching a filter, in ascending height order
+    pub fn headers(&self, filter: BlockFilter) -> ReaderResult<Vec<BlockSummary>> {
+        lmdb_reader::read_lmdb_headers_with_filter(&self.database_path, "headers", filter)
+    }
+
+    /// The last `count` block headers, in ascending height order
+    pub fn last_blocks(&self, count: usize) -> ReaderResult<Vec<BlockSummary>> {
+        self.headers(BlockFilter::LastN(count))
+    }
+
+    /// Up to `limit` headers newest first, from `from_height` (the tip when `None`) downwards
+    pub fn headers_desc(&self, from_height: Option<u64>, limit: usize) -> ReaderResult<Vec<BlockSummary>> {
+        lmdb_reader::iter_headers_desc(&self.database_path, from_height, limit)
+    }
+
+    /// Full block detail (header, inputs, outputs, kernels) at `height`
+    pub fn block(&self, height: u64) -> ReaderResult<BlockDetailSummary> {
+        lmdb_reader::read_block_with_transactions(&self.database_path, height)
+    }
+
+    /// Full block detail for a block hash (index lookup, else a whole-chain search)
+    pub fn block_by_hash(&self, hash: &str) -> ReaderResult<Option<BlockDetailSummary>> {
+        lmdb_reader::search_block_by_hash(&self.database_path, hash)
+    }
+
+    /// Resolve which block contains a kernel MMR position or output SMT index
+    pub fn resolve_mmr_position(&self, kind: MmrKind, position: u64) -> ReaderResult<MmrLookup> {
+        mmr::resolve_position(&self.database_path, kind, position)
+    }
+}
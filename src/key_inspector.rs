@@ -1,16 +1,20 @@
 // File: src/key_inspector.rs
-// Version: 1.0.1 - LMDB key structure investigation and debugging tools (FIXED)
+// Version: 1.7.0 - LMDB key structure investigation and debugging tools, table size statistics, key prefix histogram, value type guesser, tip-relative pattern heights, annotated raw headers; raw table scans through KvBackend; typed ReaderError results
 // Tree: tari-lmdb-inspector/src/key_inspector.rs
 //
 // This module provides debugging tools to investigate how Tari stores transaction data
 // in LMDB and understand the key structures used to link blocks to their transactions.
 // Essential for understanding the database schema and building correct data readers.
 
+use std::collections::BTreeMap;
 use std::path::Path;
 use lmdb_zero::{EnvBuilder, Database, ReadTransaction};
 use lmdb_zero::DatabaseOptions;
-use anyhow::Result;
 use hex;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tari_node_components::blocks::BlockHeader;
+
+use crate::lmdb_reader::{env_path, open_backend, HeaderLayout, ReaderError, ReaderResult, TransactionInputRowData, TransactionKernelRowData, TransactionOutputRowData};
 
 /// Check which LMDB databases are available in the Tari data directory
 /// This helps identify what transaction tables exist and can be queried
@@ -19,15 +23,15 @@ use hex;
 /// * `path` - Path to the Tari LMDB database directory
 /// 
 /// # Returns
-/// * `Result<()>` - Success if database can be opened, error otherwise
-pub fn check_database_availability(path: &Path) -> Result<()> {
-    let path_str = path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid path"))?;
+/// * `ReaderResult<()>` - Success if database can be opened, error otherwise
+pub fn check_database_availability(path: &Path) -> ReaderResult<()> {
+    let path_str = env_path(path)?;
 
     let mut builder = EnvBuilder::new()?;
     builder.set_maxdbs(40)?; // Tari uses many sub-databases
 
     let env = unsafe {
-        builder.open(path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o600)?
     };
 
     // List of core Tari LMDB tables we're interested in investigating
@@ -68,7 +72,8 @@ pub fn check_database_availability(path: &Path) -> Result<()> {
     println!("📊 Summary: {}/{} tables available", available_count, total_count);
     
     if available_count == 0 {
-        anyhow::bail!("No Tari LMDB tables found. Check database path.");
+        // Not a base node database; `headers` is the one table every reader needs
+        return Err(ReaderError::TableNotFound("headers".to_string()));
     }
 
     Ok(())
@@ -76,15 +81,15 @@ pub fn check_database_availability(path: &Path) -> Result<()> {
 
 /// Thorough investigation: Compare our linking hash to actual transaction table keys
 /// This will show us if our theory is correct or if we need a different approach
-pub fn investigate_transaction_keys_thoroughly(path: &Path, block_height: u64) -> Result<()> {
-    let path_str = path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid path"))?;
+pub fn investigate_transaction_keys_thoroughly(path: &Path, block_height: u64) -> ReaderResult<()> {
+    let path_str = env_path(path)?;
 
     let mut builder = EnvBuilder::new()?;
     builder.set_maxdbs(5)?;
     builder.set_maxreaders(1)?;
 
     let env = unsafe {
-        builder.open(path_str, lmdb_zero::open::Flags::empty(), 0o444)?
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o444)?
     };
 
     println!("\n🔍 Thorough Transaction Key Investigation for Block {}", block_height);
@@ -97,7 +102,7 @@ pub fn investigate_transaction_keys_thoroughly(path: &Path, block_height: u64) -
 
     let height_bytes = block_height.to_le_bytes();
     let header_data: &[u8] = access.get(&headers_db, &height_bytes)
-        .map_err(|_| anyhow::anyhow!("Block not found"))?;
+        .map_err(|_| ReaderError::BlockNotFound(block_height))?;
 
     let linking_hash_bytes = &header_data[0..32];
     println!("Our linking hash: {}", hex::encode(linking_hash_bytes));
@@ -132,7 +137,7 @@ fn investigate_single_table(
     db: &lmdb_zero::Database,
     table_name: &str,
     our_linking_hash: &[u8],
-) -> Result<()> {
+) -> ReaderResult<()> {
     
     println!("🔍 Investigating {} table structure...", table_name);
     
@@ -227,8 +232,8 @@ fn investigate_single_table(
 
 /// Simple test: Check if our block hash appears as a prefix in transaction tables
 /// This will tell us if the composite key theory is correct
-pub fn test_block_hash_as_prefix(path: &Path, block_height: u64) -> Result<()> {
-    let path_str = path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid path"))?;
+pub fn test_block_hash_as_prefix(path: &Path, block_height: u64) -> ReaderResult<()> {
+    let path_str = env_path(path)?;
 
     let mut builder = EnvBuilder::new()?;
     builder.set_maxdbs(5)?;  // Even fewer databases
@@ -236,7 +241,7 @@ pub fn test_block_hash_as_prefix(path: &Path, block_height: u64) -> Result<()> {
 
     let env = unsafe {
         // Use empty flags but with read permissions
-        builder.open(path_str, lmdb_zero::open::Flags::empty(), 0o444)?
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o444)?
     };
 
     println!("\n🎯 Simple Prefix Test for Block {}", block_height);
@@ -249,7 +254,7 @@ pub fn test_block_hash_as_prefix(path: &Path, block_height: u64) -> Result<()> {
 
     let height_bytes = block_height.to_le_bytes();
     let header_data: &[u8] = access.get(&headers_db, &height_bytes)
-        .map_err(|_| anyhow::anyhow!("Block not found"))?;
+        .map_err(|_| ReaderError::BlockNotFound(block_height))?;
 
     // Extract the LINKING HASH (first 32 bytes of raw data)
     let linking_hash = &header_data[0..32];
@@ -257,7 +262,8 @@ pub fn test_block_hash_as_prefix(path: &Path, block_height: u64) -> Result<()> {
     
     // Also show computed hash for comparison
     use tari_node_components::blocks::BlockHeader;
-    let header: BlockHeader = bincode::deserialize(header_data)?;
+    let header: BlockHeader = bincode::deserialize(header_data)
+        .map_err(|e| ReaderError::UnsupportedHeader { height: block_height, source: e })?;
     let computed_hash = header.hash();
     println!("Computed block hash:            {}", hex::encode(computed_hash.as_slice()));
     println!("🔍 Testing if LINKING HASH appears as transaction prefix...");
@@ -325,15 +331,15 @@ pub fn test_block_hash_as_prefix(path: &Path, block_height: u64) -> Result<()> {
 /// * `max_samples` - Maximum number of sample keys to show
 /// 
 /// # Returns
-/// * `Result<()>` - Success if inspection completed, error otherwise
-pub fn inspect_database_keys(path: &Path, db_name: &str, max_samples: usize) -> Result<()> {
-    let path_str = path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid path"))?;
+/// * `ReaderResult<()>` - Success if inspection completed, error otherwise
+pub fn inspect_database_keys(path: &Path, db_name: &str, max_samples: usize) -> ReaderResult<()> {
+    let path_str = env_path(path)?;
 
     let mut builder = EnvBuilder::new()?;
     builder.set_maxdbs(40)?;
 
     let env = unsafe {
-        builder.open(path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o600)?
     };
 
     let db = Database::open(&env, Some(db_name), &DatabaseOptions::defaults())?;
@@ -395,8 +401,8 @@ pub fn inspect_database_keys(path: &Path, db_name: &str, max_samples: usize) ->
 /// * `path` - Path to the Tari LMDB database directory
 /// 
 /// # Returns
-/// * `Result<()>` - Success if all inspections completed, error otherwise
-pub fn inspect_all_transaction_tables(path: &Path) -> Result<()> {
+/// * `ReaderResult<()>` - Success if all inspections completed, error otherwise
+pub fn inspect_all_transaction_tables(path: &Path) -> ReaderResult<()> {
     println!("🔍 LMDB Key Structure Investigation");
     println!("{}", "=".repeat(60));
     
@@ -430,6 +436,33 @@ pub fn inspect_all_transaction_tables(path: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Height of the last entry in the headers table (keys are u64 little-endian heights)
+pub fn tip_height(path: &Path) -> ReaderResult<u64> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+
+    let (key, _) = read.last("headers")?
+        .ok_or(ReaderError::BlockNotFound(0))?;
+    let bytes: [u8; 8] = key.as_slice().try_into().map_err(|_| ReaderError::SchemaMismatch {
+        table: "headers",
+        message: format!("header key is {} bytes, expected an 8-byte height", key.len()),
+    })?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Heights `inspect --test-patterns` checks: the tip, tip-1, tip-10 and a block from the middle
+/// half of the chain picked by `random`, without duplicates
+pub fn pattern_heights(tip: u64, random: u64) -> Vec<u64> {
+    let mid = tip / 4 + random % (tip / 2 + 1);
+    let mut heights = Vec::new();
+    for height in [tip, tip.saturating_sub(1), tip.saturating_sub(10), mid] {
+        if !heights.contains(&height) {
+            heights.push(height);
+        }
+    }
+    heights
+}
+
 /// Investigate how a specific block height links to its transaction data
 /// Tests different key strategies to understand the storage schema
 /// 
@@ -438,15 +471,15 @@ pub fn inspect_all_transaction_tables(path: &Path) -> Result<()> {
 /// * `block_height` - Block height to investigate
 /// 
 /// # Returns  
-/// * `Result<()>` - Success if investigation completed, error otherwise
-pub fn investigate_block_to_transaction_links(path: &Path, block_height: u64) -> Result<()> {
-    let path_str = path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid path"))?;
+/// * `ReaderResult<()>` - Success if investigation completed, error otherwise
+pub fn investigate_block_to_transaction_links(path: &Path, block_height: u64) -> ReaderResult<()> {
+    let path_str = env_path(path)?;
 
     let mut builder = EnvBuilder::new()?;
     builder.set_maxdbs(40)?;
 
     let env = unsafe {
-        builder.open(path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o600)?
     };
 
     println!("\n🔗 Block-to-Transaction Link Investigation for Height {}", block_height);
@@ -459,11 +492,12 @@ pub fn investigate_block_to_transaction_links(path: &Path, block_height: u64) ->
 
     let height_bytes = block_height.to_le_bytes();
     let header_data: &[u8] = access.get(&headers_db, &height_bytes)
-        .map_err(|_| anyhow::anyhow!("Block not found at height {}", block_height))?;
+        .map_err(|_| ReaderError::BlockNotFound(block_height))?;
 
     // Parse the header using Tari's BlockHeader struct
     use tari_node_components::blocks::BlockHeader;
-    let header: BlockHeader = bincode::deserialize(header_data)?;
+    let header: BlockHeader = bincode::deserialize(header_data)
+        .map_err(|e| ReaderError::UnsupportedHeader { height: block_height, source: e })?;
     let block_hash = header.hash();
     
     println!("📋 Block Information:");
@@ -506,7 +540,7 @@ fn test_transaction_table_keys(
     block_height: u64,
     block_hash: &tari_common_types::types::FixedHash,
     mmr_size: u64,
-) -> Result<()> {
+) -> ReaderResult<()> {
     
     match Database::open(env, Some(table_name), &DatabaseOptions::defaults()) {
         Ok(db) => {
@@ -583,7 +617,7 @@ fn investigate_index_tables(
     access: &lmdb_zero::ConstAccessor,
     block_height: u64,
     block_hash: &tari_common_types::types::FixedHash,
-) -> Result<()> {
+) -> ReaderResult<()> {
     
     // Index tables that may contain block-to-transaction mappings
     let index_tables = vec![
@@ -649,4 +683,347 @@ fn investigate_index_tables(
     println!("  • Index tables may contain arrays/lists of transaction component IDs");
 
     Ok(())
-}
\ No newline at end of file
+}
+/// Minimum, maximum and average size in bytes over a table's keys or values
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SizeStats {
+    pub min: usize,
+    pub max: usize,
+    pub avg: f64,
+    pub total: u64,
+}
+
+impl SizeStats {
+    fn add(&mut self, size: usize, seen: u64) {
+        self.min = if seen == 0 { size } else { self.min.min(size) };
+        self.max = self.max.max(size);
+        self.total += size as u64;
+        self.avg = self.total as f64 / (seen + 1) as f64;
+    }
+}
+
+/// Entry count, key / value sizes and key length distribution of one table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableKeyStats {
+    pub name: String,
+    pub entries: u64,
+    pub key_size: SizeStats,
+    pub value_size: SizeStats,
+    /// Number of keys of each length in bytes
+    pub key_lengths: BTreeMap<usize, u64>,
+}
+
+/// Key and value statistics for every named table in the environment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableStatsReport {
+    pub tables: Vec<TableKeyStats>,
+}
+
+/// Walk every named table once and collect its key and value statistics (`inspect --stats`)
+///
+/// Reads every entry, so expect minutes on a mainnet database.
+pub fn table_stats(path: &Path) -> ReaderResult<TableStatsReport> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+
+    let mut tables = Vec::new();
+    for name in read.tables()? {
+        let mut stats = TableKeyStats {
+            name: name.clone(),
+            entries: 0,
+            key_size: SizeStats::default(),
+            value_size: SizeStats::default(),
+            key_lengths: BTreeMap::new(),
+        };
+
+        let scanned = read.scan(&name, &[], &mut |key, value| {
+            stats.key_size.add(key.len(), stats.entries);
+            stats.value_size.add(value.len(), stats.entries);
+            *stats.key_lengths.entry(key.len()).or_default() += 1;
+            stats.entries += 1;
+            true
+        });
+        match scanned {
+            Err(ReaderError::TableNotFound(_)) => continue,
+            scanned => scanned?,
+        }
+        tables.push(stats);
+    }
+
+    Ok(TableStatsReport { tables })
+}
+
+/// Print the table statistics report
+pub fn print_table_stats(report: &TableStatsReport) {
+    println!("{:<36} {:>12} {:>17} {:>17}", "Table", "Entries", "Key min/avg/max", "Value min/avg/max");
+    println!("{}", "-".repeat(85));
+    for table in &report.tables {
+        println!("{:<36} {:>12} {:>17} {:>17}",
+            table.name, table.entries, format_sizes(&table.key_size), format_sizes(&table.value_size));
+    }
+
+    println!();
+    println!("🔑 Key length distribution:");
+    for table in report.tables.iter().filter(|t| t.entries > 0) {
+        let lengths: Vec<String> = table.key_lengths.iter()
+            .map(|(len, count)| format!("{}B × {} ({:.1}%)", len, count, *count as f64 / table.entries as f64 * 100.0))
+            .collect();
+        println!("  {:<36} {}", table.name, lengths.join(", "));
+    }
+}
+
+fn format_sizes(sizes: &SizeStats) -> String {
+    format!("{}/{:.0}/{}", sizes.min, sizes.avg, sizes.max)
+}
+
+/// Keys sharing one prefix
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrefixBucket {
+    /// Prefix bytes as hex (the whole key when it is shorter than the prefix length)
+    pub prefix: String,
+    pub count: u64,
+}
+
+/// How a table's keys spread over their first `prefix_len` bytes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrefixHistogram {
+    pub table: String,
+    pub prefix_len: usize,
+    pub entries: u64,
+    /// One bucket per distinct prefix, in key order
+    pub buckets: Vec<PrefixBucket>,
+}
+
+impl PrefixHistogram {
+    /// Entries per prefix: min, average and max
+    pub fn bucket_sizes(&self) -> (u64, f64, u64) {
+        let min = self.buckets.iter().map(|b| b.count).min().unwrap_or(0);
+        let max = self.buckets.iter().map(|b| b.count).max().unwrap_or(0);
+        let avg = if self.buckets.is_empty() { 0.0 } else { self.entries as f64 / self.buckets.len() as f64 };
+        (min, avg, max)
+    }
+}
+
+/// Count `table`'s keys per distinct `prefix_len`-byte prefix (`inspect prefix-histogram`)
+///
+/// LMDB keeps keys sorted, so each prefix is one contiguous key range; a single forward
+/// scan closes a bucket whenever the prefix changes.
+pub fn prefix_histogram(path: &Path, table: &str, prefix_len: usize) -> ReaderResult<PrefixHistogram> {
+    if prefix_len == 0 {
+        return Err(ReaderError::InvalidArgument("prefix length must be at least 1 byte".to_string()));
+    }
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+
+    let mut histogram = PrefixHistogram {
+        table: table.to_string(),
+        prefix_len,
+        entries: 0,
+        buckets: Vec::new(),
+    };
+    let mut current: Option<(Vec<u8>, u64)> = None;
+    read.scan(table, &[], &mut |key, _| {
+        let prefix = &key[..key.len().min(prefix_len)];
+        if let Some((_, count)) = current.as_mut().filter(|(bucket_prefix, _)| bucket_prefix.as_slice() == prefix) {
+            *count += 1;
+        } else if let Some((bucket_prefix, count)) = current.replace((prefix.to_vec(), 1)) {
+            histogram.buckets.push(PrefixBucket { prefix: hex::encode(bucket_prefix), count });
+        }
+        histogram.entries += 1;
+        true
+    })?;
+    if let Some((bucket_prefix, count)) = current {
+        histogram.buckets.push(PrefixBucket { prefix: hex::encode(bucket_prefix), count });
+    }
+
+    Ok(histogram)
+}
+
+/// Print the histogram summary and the `top` most common prefixes
+pub fn print_prefix_histogram(histogram: &PrefixHistogram, top: usize) {
+    let (min, avg, max) = histogram.bucket_sizes();
+    println!("Table:              {}", histogram.table);
+    println!("Prefix length:      {} bytes", histogram.prefix_len);
+    println!("Entries:            {}", histogram.entries);
+    println!("Distinct prefixes:  {}", histogram.buckets.len());
+    println!("Entries per prefix: min {}, avg {:.1}, max {}", min, avg, max);
+    println!();
+
+    let mut buckets: Vec<&PrefixBucket> = histogram.buckets.iter().collect();
+    buckets.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.prefix.cmp(&b.prefix)));
+    let width = buckets.first().map(|b| b.prefix.len()).unwrap_or(0).max(6);
+    println!("{:<width$} {:>10} {:>8}", "Prefix", "Entries", "Share", width = width);
+    println!("{}", "-".repeat(width + 20));
+    for bucket in buckets.iter().take(top) {
+        let share = bucket.count as f64 / histogram.entries.max(1) as f64 * 100.0;
+        println!("{:<width$} {:>10} {:>7.2}%", bucket.prefix, bucket.count, share, width = width);
+    }
+    if buckets.len() > top {
+        println!("... and {} more prefixes", buckets.len() - top);
+    }
+
+    println!();
+    if max <= 1 {
+        println!("💡 Every prefix is unique: a {}-byte prefix alone identifies a key", histogram.prefix_len);
+    } else {
+        println!("💡 A prefix scan reads {:.1} entries on average (up to {}) per {}-byte prefix", avg, max, histogram.prefix_len);
+    }
+}
+
+/// Known Tari row types the value guesser tries to decode
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RowType {
+    BlockHeader,
+    KernelRow,
+    OutputRow,
+    InputRow,
+}
+
+impl RowType {
+    pub const ALL: [RowType; 4] = [RowType::BlockHeader, RowType::KernelRow, RowType::OutputRow, RowType::InputRow];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RowType::BlockHeader => "BlockHeader",
+            RowType::KernelRow => "TransactionKernelRowData",
+            RowType::OutputRow => "TransactionOutputRowData",
+            RowType::InputRow => "TransactionInputRowData",
+        }
+    }
+
+    /// `Some(true)` if `value` decodes as this type using every byte, `Some(false)` if it decodes
+    /// with bytes left over, `None` if it does not decode
+    fn decodes(&self, value: &[u8]) -> Option<bool> {
+        match self {
+            RowType::BlockHeader => decodes_as::<BlockHeader>(value),
+            RowType::KernelRow => decodes_as::<TransactionKernelRowData>(value),
+            RowType::OutputRow => decodes_as::<TransactionOutputRowData>(value),
+            RowType::InputRow => decodes_as::<TransactionInputRowData>(value),
+        }
+    }
+}
+
+fn decodes_as<T: DeserializeOwned>(value: &[u8]) -> Option<bool> {
+    use bincode::Options;
+    // Same encoding as `bincode::deserialize`, but trailing bytes are an error
+    let exact = bincode::DefaultOptions::new().with_fixint_encoding().reject_trailing_bytes();
+    if exact.deserialize::<T>(value).is_ok() {
+        return Some(true);
+    }
+    bincode::deserialize::<T>(value).ok().map(|_| false)
+}
+
+/// How many sampled values of a table decoded as one row type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeMatch {
+    pub row_type: RowType,
+    /// Values that decoded (possibly with bytes left over)
+    pub decoded: usize,
+    /// Values that decoded using every byte
+    pub exact: usize,
+}
+
+/// Row type guess for one table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValueGuess {
+    pub table: String,
+    pub sampled: usize,
+    pub matches: Vec<TypeMatch>,
+    /// Type that decoded most sampled values exactly, if it decoded at least half of them
+    pub best: Option<RowType>,
+}
+
+/// Try to bincode-decode the first `samples` values of each table as every known row type
+/// (`inspect guess-values`); all named tables when `tables` is empty
+pub fn guess_value_types(path: &Path, tables: &[String], samples: usize) -> ReaderResult<Vec<ValueGuess>> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+
+    let names = if tables.is_empty() { read.tables()? } else { tables.to_vec() };
+
+    let mut guesses = Vec::new();
+    for name in names {
+        let mut matches: Vec<TypeMatch> = RowType::ALL.iter()
+            .map(|&row_type| TypeMatch { row_type, decoded: 0, exact: 0 })
+            .collect();
+        let mut sampled = 0;
+        let scanned = read.scan(&name, &[], &mut |_, value| {
+            if sampled == samples {
+                return false;
+            }
+            for type_match in matches.iter_mut() {
+                match type_match.row_type.decodes(value) {
+                    Some(true) => { type_match.decoded += 1; type_match.exact += 1; }
+                    Some(false) => type_match.decoded += 1,
+                    None => {}
+                }
+            }
+            sampled += 1;
+            true
+        });
+        match scanned {
+            Err(ReaderError::TableNotFound(_)) if tables.is_empty() => continue,
+            scanned => scanned?,
+        }
+
+        let best = matches.iter()
+            .filter(|m| sampled > 0 && m.exact * 2 >= sampled)
+            .max_by_key(|m| (m.exact, m.decoded))
+            .map(|m| m.row_type);
+        guesses.push(ValueGuess { table: name, sampled, matches, best });
+    }
+
+    Ok(guesses)
+}
+
+/// Print one line per table with the best guess and the per-type decode counts
+pub fn print_value_guesses(guesses: &[ValueGuess]) {
+    println!("{:<36} {:>8}  {:<26} {}", "Table", "Sampled", "Best match", "Decoded exactly / with trailing bytes");
+    println!("{}", "-".repeat(110));
+    for guess in guesses {
+        let best = guess.best.map(|t| t.label()).unwrap_or("?");
+        let counts: Vec<String> = guess.matches.iter()
+            .filter(|m| m.decoded > 0)
+            .map(|m| format!("{} {}/{}", m.row_type.label(), m.exact, m.decoded - m.exact))
+            .collect();
+        println!("{:<36} {:>8}  {:<26} {}", guess.table, guess.sampled, best, counts.join(", "));
+    }
+}
+
+/// Raw `headers` row for `height`
+pub fn raw_header(path: &Path, height: u64) -> ReaderResult<Vec<u8>> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    read.get("headers", &height.to_le_bytes())?
+        .ok_or(ReaderError::BlockNotFound(height))
+}
+
+/// Hex dump of a raw header, 16 bytes per line with offsets
+pub fn print_header_hex(height: u64, data: &[u8]) {
+    println!("Header {} ({} bytes)", height, data.len());
+    for (line, chunk) in data.chunks(16).enumerate() {
+        let bytes: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+        println!("  {:04x}  {}", line * 16, bytes.join(" "));
+    }
+}
+
+/// Field table of an annotated header: offset, length, field name and decoded value
+pub fn print_header_layout(height: u64, layout: &HeaderLayout) {
+    println!("Header {} ({} bytes)", height, layout.length);
+    println!("{:>6} {:>6}  {:<30} {}", "Offset", "Length", "Field", "Value");
+    println!("{}", "-".repeat(110));
+    for field in &layout.fields {
+        println!("{:>6} {:>6}  {:<30} {}", field.offset, field.length, field.name, field.value);
+    }
+    if !layout.unparsed.is_empty() {
+        let offset = layout.length - layout.unparsed.len() / 2;
+        println!("{:>6} {:>6}  {:<30} {}", offset, layout.unparsed.len() / 2, "(unparsed)", layout.unparsed);
+    }
+    println!();
+    if layout.matches {
+        println!("✅ Layout matches this build's header type");
+    } else {
+        println!("⚠️  Layout does not match this build's header type (another Tari version or unknown PoW algorithm)");
+    }
+}
@@ -0,0 +1,131 @@
+// File: src/lib.rs
+// Version: 1.0.0 - Library crate: reusable Tari LMDB reading for other Rust projects
+// Tree: tari-lmdb-inspector/src/lib.rs
+//
+// The `tari-lmdb-inspector` binary is a thin CLI wrapper around this crate. Other projects can
+// embed Tari LMDB reading directly through `lmdb_reader`, `data_models` and the high-level
+// `Inspector` type instead of shelling out to the tool.
+
+// Core reading API
+pub mod lmdb_reader;
+pub mod data_models;
+pub mod inspector;
+
+// Block data sources (LMDB or synthetic demo chain) behind one trait
+pub mod data_provider;
+
+// Progress reporting for long scans (CLI bars, WebSocket events, TUI gauge)
+pub mod progress;
+
+// MMR position / SMT index to block resolution
+pub mod mmr;
+
+// Unified search by height, block hash, output or kernel excess
+pub mod search;
+
+// LMDB environment health check (map usage, table stats, freelist)
+pub mod db_check;
+
+// Startup compatibility check (header layout, schema version, network)
+pub mod preflight;
+
+// Genesis block decoding and network identification
+pub mod genesis;
+
+// Online compacting snapshot of the environment
+pub mod backup;
+
+// Partial chain export/import for sharing bug reports
+pub mod chain_archive;
+
+// One-sided payment scanner (read-only wallet recovery aid)
+pub mod output_scanner;
+
+// Chain analytics (fees, intervals, growth)
+pub mod analytics;
+
+// Consistency checks for suspected corruption (Merkle roots, timestamps, double spends, follow)
+pub mod audit;
+
+// Chronological chain event timeline (reorgs, stalls, spikes, large blocks, burns)
+pub mod chain_events;
+
+// Dashboard snapshots and LMDB change stream shared by web and TUI modes
+pub mod dashboard_engine;
+
+// Interface modes used by the binary
+pub mod cli_interface;
+pub mod tui_dashboard;
+pub mod web_server;
+pub mod web_pages;
+pub mod static_assets;
+
+// Debugging module for LMDB key structure investigation
+pub mod key_inspector;
+
+// Shared LMDB file watcher (web mode and CLI follow)
+pub mod file_watcher;
+
+// SIGINT / SIGTERM handling for graceful shutdown in every mode
+pub mod shutdown;
+
+// Background web server (--daemon) with PID file and log file
+pub mod daemon;
+
+// Color themes for the TUI and CLI (--theme, --no-color, NO_COLOR)
+pub mod theme;
+
+// Time zone for displayed timestamps (--timezone)
+pub mod timezone;
+
+// Human-readable log levels (--verbosity, -q, -v)
+pub mod verbosity;
+
+// µT / T amount display (--denom)
+pub mod denomination;
+
+// Node sync status (synced / lagging / stalled) from the tip's age
+pub mod sync_status;
+
+// Webhook notifications for new tips, reorgs and slow blocks
+pub mod notify;
+
+// Watched commitments / kernel excesses checked against new blocks
+pub mod watchlist;
+
+// Per-IP rate limiting and concurrency caps for the web API
+pub mod rate_limit;
+
+// Caps on blocks, transaction items and WebSocket message size per response
+pub mod limits;
+
+// Optional bearer token authentication for web mode
+pub mod auth;
+
+// WebSocket client registry, heartbeat and /metrics
+pub mod ws_clients;
+
+// Request IDs, per-request logs and per-route /metrics totals for the web server
+pub mod request_trace;
+
+// Build and schema version (/api/version) and the GitHub release check
+pub mod version;
+
+// Chunked WebSocket block range streams with ack-based backpressure
+pub mod block_stream;
+
+// LRU cache for confirmed block detail responses
+pub mod block_cache;
+
+// Sidecar cache directory for derived indexes (--cache-dir)
+pub mod cache;
+
+// Bloom filter over output hashes and commitments for instant negative lookups
+pub mod utxo_bloom;
+
+// Synthetic LMDB environments for integration tests
+#[cfg(feature = "testutil")]
+pub mod testutil;
+
+pub use inspector::Inspector;
+pub use lmdb_reader::BlockFilter;
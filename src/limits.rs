@@ -0,0 +1,98 @@
+// File: src/limits.rs
+// Version: 1.0.0 - Caps on response size (blocks, transaction items, WebSocket messages)
+// Tree: tari-lmdb-inspector/src/limits.rs
+//
+// A single broad request (a wide block range, a batch of large blocks, a block with tens of
+// thousands of outputs) is decoded, cloned into a response and serialized, so it holds several
+// copies of the same rows at once. Every size cap the web server enforces lives here and is
+// configured on `web` through `AppConfig::limits`. Exceeding one answers with a `LimitExceeded`
+// body (400 over REST, a `LimitExceeded` message over the WebSocket) instead of the payload.
+
+use axum::{http::StatusCode, response::{IntoResponse, Response}, Json};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Default for `--max-blocks`: headers in one range response
+pub const DEFAULT_MAX_BLOCKS: usize = 1000;
+
+/// Default for `--max-tx-items`: inputs, outputs and kernels in one response
+pub const DEFAULT_MAX_TX_ITEMS: usize = 50_000;
+
+/// Default for `--max-ws-message`: bytes in one WebSocket message, either direction
+pub const DEFAULT_MAX_WS_MESSAGE_BYTES: usize = 16 * 1024 * 1024;
+
+/// Size caps for web responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Blocks in one response (`/blocks/range`, `/blocks/batch`)
+    pub max_blocks: usize,
+    /// Inputs, outputs and kernels in one response, summed over every block in it
+    pub max_tx_items: usize,
+    /// Bytes in one WebSocket message, sent or received
+    pub max_ws_message_bytes: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_blocks: DEFAULT_MAX_BLOCKS,
+            max_tx_items: DEFAULT_MAX_TX_ITEMS,
+            max_ws_message_bytes: DEFAULT_MAX_WS_MESSAGE_BYTES,
+        }
+    }
+}
+
+/// Which cap a request ran into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LimitKind {
+    Blocks,
+    TransactionItems,
+    WsMessageBytes,
+}
+
+/// Error body for a response over one of the caps
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct LimitExceeded {
+    pub limit: LimitKind,
+    pub max: u64,
+    pub requested: u64,
+    pub message: String,
+}
+
+impl Limits {
+    /// `requested` blocks fit in one response
+    pub fn check_blocks(&self, requested: u64) -> Result<(), LimitExceeded> {
+        check(LimitKind::Blocks, self.max_blocks, requested, || format!(
+            "{} blocks requested, at most {} per response", requested, self.max_blocks
+        ))
+    }
+
+    /// `requested` inputs, outputs and kernels fit in one response
+    pub fn check_tx_items(&self, requested: u64) -> Result<(), LimitExceeded> {
+        check(LimitKind::TransactionItems, self.max_tx_items, requested, || format!(
+            "{} inputs, outputs and kernels exceed the limit of {} per response; use include=counts or the paged /inputs, /outputs and /kernels endpoints",
+            requested, self.max_tx_items
+        ))
+    }
+
+    /// A `requested`-byte WebSocket message may be sent
+    pub fn check_ws_message(&self, requested: u64) -> Result<(), LimitExceeded> {
+        check(LimitKind::WsMessageBytes, self.max_ws_message_bytes, requested, || format!(
+            "{}-byte message exceeds the WebSocket limit of {} bytes", requested, self.max_ws_message_bytes
+        ))
+    }
+}
+
+fn check(limit: LimitKind, max: usize, requested: u64, message: impl FnOnce() -> String) -> Result<(), LimitExceeded> {
+    if requested <= max as u64 {
+        return Ok(());
+    }
+    Err(LimitExceeded { limit, max: max as u64, requested, message: message() })
+}
+
+impl IntoResponse for LimitExceeded {
+    fn into_response(self) -> Response {
+        (StatusCode::BAD_REQUEST, Json(self)).into_response()
+    }
+}
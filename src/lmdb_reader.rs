@@ -1,18 +1,73 @@
 // File: src/lmdb_reader.rs
 // Tree: tari-lmdb-inspector/src/lmdb_reader.rs  
-// Version: 2.23.0 - Added C29 Cuckaroo 29 error handling for 4-way hybrid mining
+// Version: 2.24.0 - Every read goes through KvBackend instead of lmdb-zero handles
 
 use std::path::Path;
-use lmdb_zero::{EnvBuilder, Database, ReadTransaction, ConstAccessor};
-use lmdb_zero::DatabaseOptions;
-use anyhow::Result;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use utoipa::ToSchema;
 use hex;
 use tari_utilities::byte_array::ByteArray;
 
+use tokio_util::sync::CancellationToken;
+
+use crate::denomination;
+use crate::progress::{NoProgress, Progress};
+use crate::verbosity;
+
+// Async facade (spawn_blocking + timeouts) for web and TUI callers
+pub mod r#async;
+
+// Typed reader errors
+pub mod error;
+pub use error::{ReaderError, ReaderResult};
+
+// Composable block filter shared by all interfaces
+pub mod filter;
+pub use filter::BlockFilter;
+
+// Full / headers-only / pruned detection
+pub mod completeness;
+pub use completeness::DataCompleteness;
+
+// Base node chain metadata (tip, accumulated work, pruning horizon)
+pub mod metadata;
+
+// Streaming header and transaction iterators over one read transaction
+pub mod stream;
+pub use stream::{BlockTxIter, HeaderIter, Snapshot, StreamReader};
+
+// Height-range scans split across a rayon thread pool
+pub mod parallel;
+pub use parallel::par_map_chunks;
+
+// Output hash indexes (mined and spent locations of outputs)
+pub(crate) mod txo_index;
+use txo_index::TxoIndexes;
+
+// TariScript opcode listing and pattern detection
+pub mod script;
+pub use script::{ScriptPattern, ScriptSummary};
+
+// LMDB environment path strings (non-UTF-8 paths) and default database locations
+pub mod paths;
+pub use paths::env_path;
+
+// Key-value backend trait (lmdb-zero, or heed with --features heed)
+pub mod backend;
+pub use backend::{open_backend, with_backend, BackendKind, KvBackend, KvCursor, KvRead};
+
+// Zero-copy header decoding for list views
+pub mod header_view;
+pub use header_view::{annotate_header, HeaderField, HeaderLayout, HeaderView};
+
+// Block hash resolution shared by all readers
+pub mod block_hash;
+pub use block_hash::{HashSource, ResolvedHash};
+use block_hash::BlockHashResolver;
+
 // Import Tari's actual structs
 use tari_node_components::blocks::BlockHeader;
-use tari_transaction_components::transaction_components::{TransactionInput, TransactionOutput, TransactionKernel};
+use tari_transaction_components::transaction_components::{KernelFeatures, OutputFeatures, OutputType, TransactionInput, TransactionOutput, TransactionKernel};
 use tari_common_types::types::FixedHash;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,14 +96,7 @@ pub struct TransactionKernelRowData {
     pub hash: FixedHash,
 }
 
-#[derive(Debug)]
-pub enum BlockFilter {
-    LastN(usize),           // Show last N blocks
-    Range(u64, u64),        // Show blocks from start to end (inclusive)
-    Specific(u64),          // Show specific block height
-}
-
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockHeaderLite {
     pub version: u16,
     pub height: u64,
@@ -63,47 +111,351 @@ pub struct BlockHeaderLite {
     pub pow_data_hash: String,
     pub raw_header_length: usize,
     pub pow_algorithm: String,
+    pub kernel_mmr_size: u64,
+    pub output_smt_size: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockSummary {
     pub height: u64,
     pub hash: String,
     pub header: BlockHeaderLite,
+    /// Kernels in this block (kernel MMR size delta), when the previous header is readable
+    pub kernel_count: Option<u64>,
+    /// Inputs spent in this block (prefix scan of the inputs table), when that table exists
+    #[serde(default)]
+    pub input_count: Option<u64>,
+    /// Outputs created in this block (output SMT size delta plus inputs spent)
+    #[serde(default)]
+    pub output_count: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TransactionSummary {
     pub inputs: Vec<InputSummary>,
     pub outputs: Vec<OutputSummary>,
     pub kernels: Vec<KernelSummary>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct InputSummary {
     pub commitment: String,
     pub input_type: String,
+    /// Hash of the output this input spends
+    #[serde(default)]
+    pub output_hash: String,
+    /// Height at which the spent output was mined, when the output indexes are present
+    #[serde(default)]
+    pub spent_output_mined_height: Option<u64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct OutputSummary {
     pub commitment: String,
-    pub features: String,
+    pub features: OutputFeaturesLite,
+    /// Script pattern label (Nop, One-sided, Custom, Empty)
     pub script_type: String,
+    /// Decoded script opcodes and detected pattern
+    #[serde(default)]
+    pub script: ScriptSummary,
+    /// Decoded output type (Standard, Coinbase, Burn, ...)
+    pub output_type: String,
+    /// `output_type` classified for filtering and analytics
+    #[serde(default)]
+    pub kind: OutputKind,
+    /// Height at which the output becomes spendable
+    pub maturity: u64,
+    /// Minimum value promise in µT (the actual value for revealed-value outputs such as coinbases)
+    pub minimum_value_promise: u64,
+    /// Whether a later block spent this output
+    #[serde(default)]
+    pub spend_status: SpendStatus,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Spend state of an output from `deleted_txo_hash_to_header_index`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SpendStatus {
+    /// Spent by the block at `height`
+    Spent { height: u64 },
+    Unspent,
+    /// The spent output index is not in this database
+    #[default]
+    Unknown,
+}
+
+impl SpendStatus {
+    /// Short label for CLI and HTML output
+    pub fn label(&self) -> String {
+        match self {
+            SpendStatus::Spent { height } => format!("Spent at #{}", height),
+            SpendStatus::Unspent => "Unspent".to_string(),
+            SpendStatus::Unknown => "Unknown".to_string(),
+        }
+    }
+}
+
+/// Output types the inspector treats specially; the rest are `Other`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OutputKind {
+    #[default]
+    Standard,
+    Coinbase,
+    /// Burned value, claimable on a sidechain through the burn kernel's commitment
+    Burn,
+    /// Validator node or code template registrations
+    Other,
+}
+
+impl OutputKind {
+    pub fn of(output_type: &OutputType) -> Self {
+        match output_type {
+            OutputType::Standard => OutputKind::Standard,
+            OutputType::Coinbase => OutputKind::Coinbase,
+            OutputType::Burn => OutputKind::Burn,
+            _ => OutputKind::Other,
+        }
+    }
+
+    pub fn is_burn(&self) -> bool {
+        matches!(self, OutputKind::Burn)
+    }
+}
+
+/// Output features decoded for API consumers and display
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub struct OutputFeaturesLite {
+    pub version: String,
+    pub output_type: String,
+    pub kind: OutputKind,
+    pub maturity: u64,
+    /// Coinbase extra bytes as hex (miner or pool tag), empty for non-coinbase outputs
+    pub coinbase_extra: String,
+    /// `coinbase_extra` as text when it is printable UTF-8
+    pub coinbase_extra_text: Option<String>,
+    pub sidechain_data: bool,
+    pub range_proof_type: String,
+}
+
+impl From<&OutputFeatures> for OutputFeaturesLite {
+    fn from(features: &OutputFeatures) -> Self {
+        let extra: &[u8] = features.coinbase_extra.as_ref();
+        let coinbase_extra_text = std::str::from_utf8(extra).ok()
+            .filter(|text| !text.is_empty() && text.chars().all(|c| !c.is_control()))
+            .map(str::to_string);
+        Self {
+            version: format!("{:?}", features.version),
+            output_type: format!("{:?}", features.output_type),
+            kind: OutputKind::of(&features.output_type),
+            maturity: features.maturity,
+            coinbase_extra: hex::encode(extra),
+            coinbase_extra_text,
+            sidechain_data: features.sidechain_feature.is_some(),
+            range_proof_type: format!("{:?}", features.range_proof_type),
+        }
+    }
+}
+
+impl std::fmt::Display for OutputFeaturesLite {
+    /// e.g. `Coinbase, maturity 1720, extra "pool-a"`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.output_type)?;
+        if self.maturity > 0 {
+            write!(f, ", maturity {}", self.maturity)?;
+        }
+        match (&self.coinbase_extra_text, self.coinbase_extra.is_empty()) {
+            (Some(text), _) => write!(f, ", extra \"{}\"", text)?,
+            (None, false) => write!(f, ", extra 0x{}", self.coinbase_extra)?,
+            (None, true) => {}
+        }
+        if self.sidechain_data {
+            write!(f, ", sidechain data")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct KernelSummary {
     pub excess: String,
     pub fee: u64,
+    /// `fee` in µT
+    #[serde(default)]
+    pub fee_ut: u64,
+    /// `fee` in T as an exact decimal string
+    #[serde(default)]
+    pub fee_t: String,
     pub lock_height: u64,
+    /// Decoded kernel feature flags (e.g. COINBASE_KERNEL, BURN_KERNEL)
+    pub features: String,
+    /// Kernel class derived from the feature bits
+    #[serde(default)]
+    pub kind: KernelKind,
+    #[serde(default)]
+    pub is_coinbase: bool,
+    /// Commitment of the burned output, for burn kernels
+    #[serde(default)]
+    pub burn_commitment: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Kernel class from `KernelFeatures`; also the `?kernels=` filter on block endpoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KernelKind {
+    /// No feature bits set
+    #[default]
+    Plain,
+    Coinbase,
+    Burn,
+}
+
+impl KernelKind {
+    pub fn of(features: &KernelFeatures) -> Self {
+        if features.is_coinbase() {
+            KernelKind::Coinbase
+        } else if features.is_burned() {
+            KernelKind::Burn
+        } else {
+            KernelKind::Plain
+        }
+    }
+
+    /// Short label for CLI and HTML output
+    pub fn label(&self) -> &'static str {
+        match self {
+            KernelKind::Plain => "Plain",
+            KernelKind::Coinbase => "Coinbase",
+            KernelKind::Burn => "Burn",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BlockDetailSummary {
     pub height: u64,
     pub hash: String,
     pub header: BlockHeaderLite,
+    /// Decoded rows; empty unless read with `BlockInclude::transactions`
+    pub transactions: TransactionSummary,
+    /// Row counts, when read with `BlockInclude::counts`
+    #[serde(default)]
+    pub counts: Option<BlockCounts>,
+    /// Whether `transactions` is complete or data is missing for this block
+    pub data_completeness: DataCompleteness,
+}
+
+/// What a block detail read loads besides the header (`?include=transactions,counts`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockInclude {
+    /// Decode the block's inputs, outputs and kernels
+    pub transactions: bool,
+    /// Count the block's inputs, outputs and kernels without decoding them
+    pub counts: bool,
+}
+
+impl Default for BlockInclude {
+    /// Transactions without counts, as block details have always been read
+    fn default() -> Self {
+        Self { transactions: true, counts: false }
+    }
+}
+
+impl BlockInclude {
+    /// Header only
+    pub const HEADER: BlockInclude = BlockInclude { transactions: false, counts: false };
+
+    /// Parse a comma-separated list of `transactions` and `counts` (empty for header only)
+    pub fn parse(list: &str) -> Option<Self> {
+        let mut include = Self::HEADER;
+        for part in list.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            match part.to_ascii_lowercase().as_str() {
+                "transactions" => include.transactions = true,
+                "counts" => include.counts = true,
+                _ => return None,
+            }
+        }
+        Some(include)
+    }
+}
+
+/// Rows stored under one block, counted by prefix scan without decoding them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub struct BlockCounts {
+    pub inputs: u64,
+    pub outputs: u64,
+    pub kernels: u64,
+}
+
+impl BlockCounts {
+    /// Counts of already decoded rows
+    pub fn of(transactions: &TransactionSummary) -> Self {
+        Self {
+            inputs: transactions.inputs.len() as u64,
+            outputs: transactions.outputs.len() as u64,
+            kernels: transactions.kernels.len() as u64,
+        }
+    }
+}
+
+/// Entry counts of the whole transaction tables (`None` for a missing table)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TableCounts {
+    pub utxos: Option<usize>,
+    pub inputs: Option<usize>,
+    pub kernels: Option<usize>,
+}
+
+/// Default and largest `limit` of a transaction page
+pub const DEFAULT_PAGE_LIMIT: usize = 50;
+pub const MAX_PAGE_LIMIT: usize = 500;
+
+/// One of a block's transaction tables
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TxComponent {
+    Inputs,
+    Outputs,
+    Kernels,
+}
+
+/// `offset`/`limit` window over a block's rows, in key (row hash) order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Page {
+    pub offset: usize,
+    pub limit: usize,
+}
+
+impl Default for Page {
+    fn default() -> Self {
+        Self { offset: 0, limit: DEFAULT_PAGE_LIMIT }
+    }
+}
+
+impl Page {
+    /// Page from optional request parameters; `limit` is capped at `MAX_PAGE_LIMIT`
+    pub fn new(offset: Option<usize>, limit: Option<usize>) -> Self {
+        Self {
+            offset: offset.unwrap_or(0),
+            limit: limit.unwrap_or(DEFAULT_PAGE_LIMIT).min(MAX_PAGE_LIMIT),
+        }
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        index >= self.offset && index - self.offset < self.limit
+    }
+}
+
+/// One page of a block's inputs, outputs or kernels
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TransactionPage {
+    pub height: u64,
+    pub hash: String,
+    pub component: TxComponent,
+    pub page: Page,
+    /// Rows of `component` in the block
+    pub total: u64,
+    /// The page's rows; only the list for `component` is filled
     pub transactions: TransactionSummary,
 }
 
@@ -126,354 +478,481 @@ impl From<(u64, String, BlockHeader, &[u8])> for BlockSummary {
                 pow_data_hash: if !header.pow.pow_data.is_empty() { hex::encode(&header.pow.pow_data) } else { "empty".to_string() },
                 raw_header_length: header_data.len(),
                 pow_algorithm: format!("{:?}", header.pow.pow_algo),
+                kernel_mmr_size: header.kernel_mmr_size,
+                output_smt_size: header.output_smt_size,
             },
+            kernel_count: None,
+            input_count: None,
+            output_count: None,
         }
     }
 }
 
-/// Search entire blockchain for a block by hash
-pub fn search_block_by_hash(path: &Path, target_hash: &str) -> Result<Option<BlockDetailSummary>> {
-    println!("Searching entire blockchain for hash: {}...", &target_hash[0..20.min(target_hash.len())]);
-    
-    let path_str = path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid path"))?;
-
-    let mut builder = EnvBuilder::new()?;
-    builder.set_maxdbs(32)?;
+/// Find a block by hash: a `block_hashes` index lookup, else a scan of the entire blockchain
+pub fn search_block_by_hash(path: &Path, target_hash: &str) -> ReaderResult<Option<BlockDetailSummary>> {
+    search_block_by_hash_with_progress(path, target_hash, &NoProgress, &CancellationToken::new())
+}
 
-    let env = unsafe {
-        builder.open(path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+/// `search_block_by_hash`, reporting the full-chain scan (in blocks) to `progress`
+/// The scan stops with `ReaderError::Cancelled` once `cancel` fires
+pub fn search_block_by_hash_with_progress(
+    path: &Path,
+    target_hash: &str,
+    progress: &dyn Progress,
+    cancel: &CancellationToken,
+) -> ReaderResult<Option<BlockDetailSummary>> {
+    let backend = open_backend(path)?;
+    let (indexed, total) = {
+        let read = backend.read()?;
+        read.entries("headers")?;
+        // The index answers directly when the database has one
+        let indexed = match (read.has_table("block_hashes")?, hex::decode(target_hash)) {
+            (true, Ok(hash_bytes)) => Some(height_of_block(&*read, &hash_bytes)),
+            _ => None,
+        };
+        (indexed, read.last("headers")?.map(|(key, _)| stream::decode_height(&key) + 1))
     };
+    // Its transaction has ended, so the detail read can open its own
+    drop(backend);
+    if let Some(indexed) = indexed {
+        return match indexed {
+            Some(height) => read_block_with_transactions(path, height).map(Some),
+            None => Ok(None),
+        };
+    }
 
-    let headers_db = Database::open(&env, Some("headers"), &DatabaseOptions::defaults())?;
-    let txn = ReadTransaction::new(&env)?;
-    let access = txn.access();
-    let mut cursor = txn.cursor(&headers_db)?;
+    if verbosity::normal() {
+        println!("Searching entire blockchain for hash: {}...", &target_hash[0..20.min(target_hash.len())]);
+    }
+    let Some(total) = total else { return Ok(None) };
+    let found = scan_headers_for_hash(path, &target_hash.to_lowercase(), total, progress, cancel)?;
 
-    // Convert target hash to lowercase for comparison
-    let target_hash_lower = target_hash.to_lowercase();
-    let mut blocks_searched = 0;
-    let mut blocks_skipped = 0;
+    // The scan transaction has ended, so the detail read can open its own
+    found.map(|height| read_block_with_transactions(path, height)).transpose()
+}
 
-    // Iterate through all blocks to find matching hash
-    if let Ok((mut k, mut v)) = cursor.first::<[u8], [u8]>(&access) {
-        loop {
-            let height = u64::from_le_bytes(k.try_into().unwrap_or([0; 8]));
-            let header_data = v;
-            blocks_searched += 1;
+/// Hash every header in `0..total` on the rayon pool until one matches `target_hash` (lowercase
+/// hex); each worker scans a height chunk in its own read transaction
+fn scan_headers_for_hash(
+    path: &Path,
+    target_hash: &str,
+    total: u64,
+    progress: &dyn Progress,
+    cancel: &CancellationToken,
+) -> ReaderResult<Option<u64>> {
+    use std::sync::atomic::{AtomicU64, Ordering};
 
-            // Show progress every 10,000 blocks
-            if blocks_searched % 10_000 == 0 {
-                println!("  Searched {} blocks (skipped {} with newer formats)...", blocks_searched, blocks_skipped);
-            }
+    let reader = StreamReader::open(path)?;
+    let searched = AtomicU64::new(0);
+    let skipped = AtomicU64::new(0);
+    progress.begin("Searching blocks by hash", Some(total));
 
-            match bincode::deserialize::<BlockHeader>(header_data) {
-                Ok(block_header) => {
-                    // Compute the block hash (same logic as other functions)
-                    let next_height = height + 1;
-                    let next_height_bytes = next_height.to_le_bytes();
-                    
-                    let block_hash = match access.get::<[u8], [u8]>(&headers_db, &next_height_bytes) {
-                        Ok(next_header_data) => {
-                            match bincode::deserialize::<BlockHeader>(next_header_data) {
-                                Ok(next_block_header) => hex::encode(&next_block_header.prev_hash),
-                                Err(_) => hex::encode(block_header.hash().as_slice()),
-                            }
-                        },
-                        Err(_) => {
-                            // This is the latest block, use computed hash
-                            hex::encode(block_header.hash().as_slice())
-                        }
-                    };
-                    
-                    // Check if this hash matches our target
-                    if block_hash.to_lowercase() == target_hash_lower {
-                        println!("Found matching block at height {} after searching {} blocks", height, blocks_searched);
-                        
-                        // Found the block! Now get full details using existing function
-                        return Ok(Some(read_block_with_transactions(path, height)?));
-                    }
-                },
-                Err(e) => {
-                    // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
-                    blocks_skipped += 1;
-                    if blocks_searched % 10_000 == 0 {
-                        eprintln!("Skipping block {} (mined with C29 algorithm): {}", height, e);
-                    }
+    let found = parallel::par_find_chunks(&reader, 0, total - 1, parallel::DEFAULT_CHUNK_SIZE, |snapshot, start, end, stop| {
+        for (i, entry) in snapshot.headers(start, end)?.enumerate() {
+            if i % 1_000 == 0 {
+                if stop.load(Ordering::Relaxed) {
+                    return Ok(None);
                 }
+                if cancel.is_cancelled() {
+                    return Err(ReaderError::Cancelled);
+                }
+            }
+            let done = searched.fetch_add(1, Ordering::Relaxed) + 1;
+            if done % 10_000 == 0 {
+                progress.update(done);
             }
 
-            // Move to next block
-            match cursor.next::<[u8], [u8]>(&access) {
-                Ok((next_k, next_v)) => {
-                    k = next_k;
-                    v = next_v;
+            match entry {
+                Ok((height, header)) => {
+                    if hex::encode(snapshot.block_hash(height, &header)) == target_hash {
+                        return Ok(Some(height));
+                    }
                 }
-                Err(_) => break, // End of database
+                // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                Err(ReaderError::UnsupportedHeader { .. }) => { skipped.fetch_add(1, Ordering::Relaxed); }
+                Err(e) => return Err(e),
             }
         }
-    }
+        Ok(None)
+    });
 
-    println!("Hash not found after searching {} blocks (skipped {} C29-mined blocks)", blocks_searched, blocks_skipped);
-    Ok(None)
+    let searched = searched.load(Ordering::Relaxed);
+    progress.update(searched);
+    match &found {
+        Ok(Some(height)) => progress.finish(&format!("Found block {} after searching {} blocks", height, searched)),
+        Ok(None) => progress.finish(&format!(
+            "Hash not found after searching {} blocks (skipped {} C29-mined blocks)",
+            searched, skipped.load(Ordering::Relaxed)
+        )),
+        Err(ReaderError::Cancelled) => progress.finish(&format!("Cancelled after searching {} blocks", searched)),
+        Err(_) => {}
+    }
+    found
 }
 
 /// Read block headers with filtering options
-pub fn read_lmdb_headers_with_filter(path: &Path, db_name: &str, filter: BlockFilter) -> Result<Vec<BlockSummary>> {
-    let path_str = path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid path"))?;
+pub fn read_lmdb_headers_with_filter(path: &Path, db_name: &str, filter: BlockFilter) -> ReaderResult<Vec<BlockSummary>> {
+    // "Last N" only needs the top of the chain, so walk backwards from the tip instead
+    if let Some(limit) = filter.last_n() {
+        let mut blocks = read_headers_desc(path, db_name, &filter, limit)?;
+        blocks.reverse();
+        return Ok(blocks);
+    }
+
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    let has_inputs = read.has_table("inputs")?;
+    let resolver = BlockHashResolver::new(&*read)?;
+    let mut cursor = read.cursor(db_name)?;
 
-    let mut builder = EnvBuilder::new()?;
-    builder.set_maxdbs(32)?;
+    let mut all_blocks = Vec::new();
+    let mut blocks_skipped = 0;
+    let mut previous_sizes: Option<(u64, u64, u64)> = None;
+    let (min_height, max_height) = filter.height_bounds();
 
-    let env = unsafe {
-        builder.open(path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+    // Start one block below the range so its first block still gets a kernel count
+    let mut entry = match min_height.checked_sub(1) {
+        Some(start) => cursor.seek(&start.to_le_bytes())?,
+        None => cursor.first()?,
     };
 
-    let db = Database::open(&env, Some(db_name), &DatabaseOptions::defaults())?;
-    let txn = ReadTransaction::new(&env)?;
-    let access = txn.access();
-    let mut cursor = txn.cursor(&db)?;
+    while let Some((key, header_data)) = entry {
+        let height = stream::decode_height(key);
 
-    let mut all_blocks = Vec::new();
-    let mut blocks_skipped = 0;
+        // Headers are keyed by height, so nothing past the filter's upper bound can match
+        if height > max_height {
+            break;
+        }
 
-    if let Ok((mut k, mut v)) = cursor.first::<[u8], [u8]>(&access) {
-        loop {
-            let height = u64::from_le_bytes(k.try_into().unwrap_or([0; 8]));
-            let header_data = v;
-
-            match bincode::deserialize::<BlockHeader>(header_data) {
-                Ok(block_header) => {
-                    let next_height = height + 1;
-                    let next_height_bytes = next_height.to_le_bytes();
-                    
-                    let hash = match access.get::<[u8], [u8]>(&db, &next_height_bytes) {
-                        Ok(next_header_data) => {
-                            match bincode::deserialize::<BlockHeader>(next_header_data) {
-                                Ok(next_block_header) => hex::encode(&next_block_header.prev_hash),
-                                Err(_) => hex::encode(block_header.hash().as_slice()),
-                            }
-                        },
-                        Err(_) => hex::encode(block_header.hash().as_slice()),
-                    };
-                    
-                    all_blocks.push(BlockSummary::from((height, hash, block_header, header_data)));
-                },
-                Err(e) => {
-                    // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
-                    blocks_skipped += 1;
+        match summarize_header(&*read, &resolver, height, header_data) {
+            Ok((mut summary, resolved)) => {
+                fill_block_counts(&*read, has_inputs, &resolved.bytes, &mut summary, previous_sizes)?;
+                previous_sizes = Some((height, summary.header.kernel_mmr_size, summary.header.output_smt_size));
+                all_blocks.push(summary);
+            },
+            Err(e) => {
+                // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                blocks_skipped += 1;
+                if verbosity::debug() {
                     eprintln!("Skipping block {} (mined with C29 algorithm): {}", height, e);
                 }
             }
+        }
+
+        entry = cursor.next()?;
+    }
+
+    if blocks_skipped > 0 && verbosity::normal() {
+        println!("Note: Skipped {} blocks mined with C29 algorithm. Update dependencies to view C29-mined blocks.", blocks_skipped);
+    }
+
+    Ok(filter.apply(all_blocks))
+}
 
-            match cursor.next::<[u8], [u8]>(&access) {
-                Ok((next_k, next_v)) => {
-                    k = next_k;
-                    v = next_v;
+/// Newest-first headers from `from_height` (the tip when `None`) down, at most `limit` of them
+/// Walks the cursor backwards with `last()`/`prev()`, so it never reads below the last block returned
+pub fn iter_headers_desc(path: &Path, from_height: Option<u64>, limit: usize) -> ReaderResult<Vec<BlockSummary>> {
+    let filter = match from_height {
+        Some(height) => BlockFilter::Range(0, height),
+        None => BlockFilter::LastN(limit),
+    };
+    read_headers_desc(path, "headers", &filter, limit)
+}
+
+/// Descending scan collecting up to `limit` headers that match `filter`, newest first
+fn read_headers_desc(path: &Path, db_name: &str, filter: &BlockFilter, limit: usize) -> ReaderResult<Vec<BlockSummary>> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    let has_inputs = read.has_table("inputs")?;
+    let resolver = BlockHashResolver::new(&*read)?;
+    let mut cursor = read.cursor(db_name)?;
+
+    let mut blocks = Vec::new();
+    let mut blocks_skipped = 0;
+    let (min_height, max_height) = filter.height_bounds();
+    if limit == 0 {
+        return Ok(blocks);
+    }
+
+    // Position on the highest header at or below the upper bound: one step back from the
+    // first header above it, or the last header when there is none
+    let above_bound = max_height < u64::MAX && cursor.seek(&(max_height + 1).to_le_bytes())?.is_some();
+    let mut entry = if above_bound { cursor.prev()? } else { cursor.last()? };
+
+    while let Some((key, header_data)) = entry {
+        let height = stream::decode_height(key);
+        if height < min_height {
+            break;
+        }
+
+        match summarize_header(&*read, &resolver, height, header_data) {
+            Ok((mut summary, resolved)) => {
+                fill_block_counts(&*read, has_inputs, &resolved.bytes, &mut summary, None)?;
+                if filter.matches(&summary) {
+                    blocks.push(summary);
+                    if blocks.len() >= limit {
+                        break;
+                    }
+                }
+            },
+            Err(e) => {
+                // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                blocks_skipped += 1;
+                if verbosity::debug() {
+                    eprintln!("Skipping block {} (mined with C29 algorithm): {}", height, e);
                 }
-                Err(_) => break,
             }
         }
+
+        entry = cursor.prev()?;
     }
 
-    if blocks_skipped > 0 {
+    if blocks_skipped > 0 && verbosity::normal() {
         println!("Note: Skipped {} blocks mined with C29 algorithm. Update dependencies to view C29-mined blocks.", blocks_skipped);
     }
 
-    // Apply filter without moving all_blocks twice
-    let summaries = match filter {
-        BlockFilter::LastN(n) => {
-            let len = all_blocks.len();
-            all_blocks.into_iter().skip(len.saturating_sub(n)).collect()
-        },
-        BlockFilter::Range(start, end) => {
-            all_blocks.into_iter().filter(|block| block.height >= start && block.height <= end).collect()
-        },
-        BlockFilter::Specific(height) => {
-            all_blocks.into_iter().filter(|block| block.height == height).collect()
-        },
+    Ok(blocks)
+}
+
+/// List-view summary of the header row `data` at `height`, with its resolved hash
+/// Reads a `HeaderView` when the node recorded the block's hash, and decodes the full header
+/// only when the view does not recognise the row or the hash has to be computed
+fn summarize_header(
+    read: &dyn KvRead,
+    resolver: &BlockHashResolver,
+    height: u64,
+    data: &[u8],
+) -> Result<(BlockSummary, ResolvedHash), bincode::Error> {
+    let confirmed = resolver.resolve_confirmed(read, height);
+    match (HeaderView::parse(data), confirmed) {
+        (Some(view), Some(resolved)) => Ok((BlockSummary::from((height, resolved.to_hex(), view)), resolved)),
+        (_, confirmed) => {
+            let header = bincode::deserialize::<BlockHeader>(data)?;
+            let resolved = confirmed.unwrap_or_else(|| ResolvedHash { bytes: header.hash().to_vec(), source: HashSource::Computed });
+            Ok((BlockSummary::from((height, resolved.to_hex(), header, data)), resolved))
+        }
+    }
+}
+
+/// Fill kernel, input and output counts for a freshly decoded summary
+/// `previous_sizes` is (height, kernel MMR size, output SMT size) of the header read just before, if any
+fn fill_block_counts(
+    read: &dyn KvRead,
+    has_inputs: bool,
+    block_hash: &[u8],
+    summary: &mut BlockSummary,
+    previous_sizes: Option<(u64, u64, u64)>,
+) -> ReaderResult<()> {
+    let (height, kernel_mmr_size, output_smt_size) = (summary.height, summary.header.kernel_mmr_size, summary.header.output_smt_size);
+    let input_count = if has_inputs {
+        Some(count_block_rows(read, "inputs", block_hash)?)
+    } else {
+        None
     };
 
-    Ok(summaries)
+    let (kernel_count, smt_delta) = match previous_sizes {
+        Some((prev_height, prev_kernels, prev_smt)) if prev_height + 1 == height => (
+            Some(kernel_mmr_size.saturating_sub(prev_kernels)),
+            Some(output_smt_size as i64 - prev_smt as i64),
+        ),
+        _ => (
+            kernel_count_at(read, height, kernel_mmr_size),
+            smt_delta_at(read, height, output_smt_size),
+        ),
+    };
+    summary.kernel_count = kernel_count;
+    summary.input_count = input_count;
+    // The SMT holds unspent outputs, so it grows by outputs created minus inputs spent
+    summary.output_count = match (smt_delta, input_count) {
+        (Some(delta), Some(inputs)) => Some((delta + inputs as i64).max(0) as u64),
+        _ => None,
+    };
+    Ok(())
 }
 
-/// Read a specific block with transaction details
-pub fn read_block_with_transactions(path: &Path, height: u64) -> Result<BlockDetailSummary> {
-    let path_str = path.to_str().ok_or_else(|| anyhow::anyhow!("Invalid path"))?;
+/// Kernels in the block at `height` from header `kernel_mmr_size` deltas, without reading the kernels table
+pub fn count_block_kernels_fast(path: &Path, height: u64) -> ReaderResult<Option<u64>> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
 
-    let mut builder = EnvBuilder::new()?;
-    builder.set_maxdbs(40)?;
+    let header_data = read.get("headers", &height.to_le_bytes())?
+        .ok_or(ReaderError::BlockNotFound(height))?;
+    let header: BlockHeader = bincode::deserialize(&header_data)
+        .map_err(|e| ReaderError::UnsupportedHeader { height, source: e })?;
+
+    Ok(kernel_count_at(&*read, height, header.kernel_mmr_size))
+}
 
-    let env = unsafe {
-        builder.open(path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+/// Kernel MMR size delta against the previous header; `None` when that header is unreadable
+pub(crate) fn kernel_count_at(read: &dyn KvRead, height: u64, kernel_mmr_size: u64) -> Option<u64> {
+    let Some(prev_height) = height.checked_sub(1) else {
+        return Some(kernel_mmr_size);
     };
+    let prev_data = read.get("headers", &prev_height.to_le_bytes()).ok()??;
+    let (prev_kernels, _) = header_view::mmr_sizes_of(&prev_data)?;
+    Some(kernel_mmr_size.saturating_sub(prev_kernels))
+}
 
-    let headers_db = Database::open(&env, Some("headers"), &DatabaseOptions::defaults())?;
-    let utxos_result = Database::open(&env, Some("utxos"), &DatabaseOptions::defaults());
-    let inputs_result = Database::open(&env, Some("inputs"), &DatabaseOptions::defaults());
-    let kernels_result = Database::open(&env, Some("kernels"), &DatabaseOptions::defaults());
-
-    println!("Database availability:");
-    println!("  headers: Available");
-    println!("  utxos: {}", if utxos_result.is_ok() { "Available" } else { "Not found" });
-    println!("  inputs: {}", if inputs_result.is_ok() { "Available" } else { "Not found" });
-    println!("  kernels: {}", if kernels_result.is_ok() { "Available" } else { "Not found" });
-
-    let txn = ReadTransaction::new(&env)?;
-    let access = txn.access();
-
-    let height_bytes = height.to_le_bytes();
-    let header_data: &[u8] = access.get(&headers_db, &height_bytes)
-        .map_err(|_| anyhow::anyhow!("Block not found at height {}", height))?;
-
-    let block_header: BlockHeader = match bincode::deserialize(header_data) {
-        Ok(header) => header,
-        Err(e) => {
-            return Err(anyhow::anyhow!(
-                "Cannot read block {} - was mined using C29 Cuckaroo 29 algorithm: {}. \
-                Update Tari dependencies to support C29-mined blocks.", 
-                height, e
-            ));
-        }
+/// Output SMT size delta against the previous header; `None` when that header is unreadable
+fn smt_delta_at(read: &dyn KvRead, height: u64, output_smt_size: u64) -> Option<i64> {
+    let Some(prev_height) = height.checked_sub(1) else {
+        return Some(output_smt_size as i64);
     };
-    
-    let next_height = height + 1;
-    let next_height_bytes = next_height.to_le_bytes();
-    
-    let mut is_latest = false;
-    let hash = match access.get::<[u8], [u8]>(&headers_db, &next_height_bytes) {
-        Ok(next_header_data) => {
-            match bincode::deserialize::<BlockHeader>(next_header_data) {
-                Ok(next_block_header) => hex::encode(&next_block_header.prev_hash),
-                Err(_) => hex::encode(block_header.hash().as_slice()),
-            }
-        },
-        Err(_) => {
-            is_latest = true;
-            hex::encode(block_header.hash().as_slice())
+    let prev_data = read.get("headers", &prev_height.to_le_bytes()).ok()??;
+    let (_, prev_smt) = header_view::mmr_sizes_of(&prev_data)?;
+    Some(output_smt_size as i64 - prev_smt as i64)
+}
+
+/// Transaction tables present for block detail reads
+struct BlockTables {
+    utxos: bool,
+    inputs: bool,
+    kernels: bool,
+    indexes: TxoIndexes,
+}
+
+impl BlockTables {
+    fn open(read: &dyn KvRead) -> ReaderResult<Self> {
+        // Without headers this is not a base node database
+        read.entries("headers")?;
+        Ok(Self {
+            utxos: read.has_table("utxos")?,
+            inputs: read.has_table("inputs")?,
+            kernels: read.has_table("kernels")?,
+            indexes: TxoIndexes::open(read)?,
+        })
+    }
+
+    /// Completeness from table presence and the node's pruning horizon
+    fn completeness(&self, read: &dyn KvRead) -> ReaderResult<DataCompleteness> {
+        Ok(DataCompleteness::from_tables(&[self.utxos, self.inputs, self.kernels])
+            .with_pruning(metadata::read_pruning_info(read)?))
+    }
+}
+
+/// Check `height` against the pruning horizon, then decode its header and resolve its hash
+fn block_header_in(
+    read: &dyn KvRead,
+    resolver: &BlockHashResolver,
+    data_completeness: DataCompleteness,
+    height: u64,
+) -> ReaderResult<(Vec<u8>, BlockHeader, ResolvedHash)> {
+    if let DataCompleteness::Pruned { horizon } = data_completeness {
+        if height < horizon {
+            return Err(ReaderError::BelowPruningHorizon { height, horizon });
         }
+    }
+
+    let header_data = read.get("headers", &height.to_le_bytes())?
+        .ok_or(ReaderError::BlockNotFound(height))?;
+    let block_header: BlockHeader = bincode::deserialize(&header_data)
+        .map_err(|e| ReaderError::UnsupportedHeader { height, source: e })?;
+
+    let resolved = resolver.resolve_block_hash(read, height, &block_header);
+    Ok((header_data, block_header, resolved))
+}
+
+fn output_summary(read: &dyn KvRead, tables: &BlockTables, row: TransactionOutputRowData) -> OutputSummary {
+    let script = ScriptSummary::from_opcodes(row.output.script.to_opcodes());
+    OutputSummary {
+        commitment: hex::encode(row.output.commitment.as_bytes()),
+        features: OutputFeaturesLite::from(&row.output.features),
+        script_type: script.pattern.label().to_string(),
+        script,
+        output_type: format!("{:?}", row.output.features.output_type),
+        kind: OutputKind::of(&row.output.features.output_type),
+        maturity: row.output.features.maturity,
+        minimum_value_promise: row.output.minimum_value_promise.as_u64(),
+        spend_status: match (tables.indexes.deleted_txo_index, tables.indexes.spent_height(read, row.hash.as_slice())) {
+            (false, _) => SpendStatus::Unknown,
+            (true, Some(height)) => SpendStatus::Spent { height },
+            (true, None) => SpendStatus::Unspent,
+        },
+    }
+}
+
+fn input_summary(read: &dyn KvRead, tables: &BlockTables, row: TransactionInputRowData) -> ReaderResult<InputSummary> {
+    // Link the input to the output it spends; compact inputs only carry its hash
+    let output_hash = row.input.output_hash();
+    let spent_output = if tables.utxos && tables.indexes.spent_height(read, output_hash.as_slice()).is_some() {
+        tables.indexes.mined_output(read, output_hash.as_slice())
+    } else {
+        None
+    };
+    let commitment = match (row.input.commitment(), &spent_output) {
+        (Ok(commitment), _) => hex::encode(commitment.as_bytes()),
+        (Err(_), Some(output)) => hex::encode(output.output.commitment.as_bytes()),
+        (Err(e), None) => return Err(ReaderError::InvalidRow { table: "inputs", message: e.to_string() }),
     };
-    
-    let block_hash_bytes = block_header.hash();
-    
-    println!("COMPLETE HEADER ANALYSIS for block {}:", height);
-    if is_latest {
-        println!("  Hash (fallback for latest block: computed hash): {}", hash);
+    Ok(InputSummary {
+        commitment,
+        input_type: format!("{:?}", row.input),
+        output_hash: hex::encode(output_hash.as_slice()),
+        spent_output_mined_height: spent_output.map(|output| output.mined_height),
+    })
+}
+
+fn kernel_summary(row: TransactionKernelRowData) -> KernelSummary {
+    KernelSummary {
+        excess: hex::encode(row.kernel.excess.as_bytes()),
+        fee: row.kernel.fee.0,
+        fee_ut: row.kernel.fee.0,
+        fee_t: denomination::tari(row.kernel.fee.0),
+        lock_height: row.kernel.lock_height,
+        features: format!("{:?}", row.kernel.features),
+        kind: KernelKind::of(&row.kernel.features),
+        is_coinbase: row.kernel.features.is_coinbase(),
+        burn_commitment: row.kernel.burn_commitment.as_ref().map(|c| hex::encode(c.as_bytes())),
+    }
+}
+
+/// Decode one block's header and transaction rows inside an open read transaction
+fn block_detail_in(
+    read: &dyn KvRead,
+    tables: &BlockTables,
+    resolver: &BlockHashResolver,
+    data_completeness: DataCompleteness,
+    height: u64,
+    include: BlockInclude,
+) -> ReaderResult<(BlockDetailSummary, HashSource)> {
+    let (header_data, block_header, resolved) = block_header_in(read, resolver, data_completeness, height)?;
+    let block_hash_bytes = resolved.bytes.as_slice();
+
+    let counts = if include.counts {
+        let count = |present: bool, table| if present { count_block_rows(read, table, block_hash_bytes) } else { Ok(0) };
+        Some(BlockCounts {
+            inputs: count(tables.inputs, "inputs")?,
+            outputs: count(tables.utxos, "utxos")?,
+            kernels: count(tables.kernels, "kernels")?,
+        })
     } else {
-        println!("  Hash (from next block's prev_hash): {}", hash);
-    }
-    println!("  Previous hash: {}", hex::encode(&block_header.prev_hash));
-    println!("  Output MR: {}", hex::encode(&block_header.output_mr));
-    println!("  Kernel MR: {}", hex::encode(&block_header.kernel_mr));
-    println!("  Input MR: {}", hex::encode(&block_header.input_mr));
-    println!("  Total kernel offset: {}", hex::encode(block_header.total_kernel_offset.as_bytes()));
-    println!("  Total script offset: {}", hex::encode(block_header.total_script_offset.as_bytes()));
-    println!("  PoW data/hash: {}", if !block_header.pow.pow_data.is_empty() { hex::encode(&block_header.pow.pow_data) } else { "empty".to_string() });
-    println!("  Raw header length: {} bytes", header_data.len());
-    println!("  PoW algorithm: {:?}", block_header.pow.pow_algo);
-    
-    // Keep raw header bytes for console debugging only
-    println!("  Header[0..32]: {}", hex::encode(&header_data[0..32.min(header_data.len())]));
-    println!("  Header[32..64]: {}", if header_data.len() >= 64 { hex::encode(&header_data[32..64]) } else { "insufficient_data".to_string() });
-    println!("  Header[64..96]: {}", if header_data.len() >= 96 { hex::encode(&header_data[64..96]) } else { "insufficient_data".to_string() });
-    println!("  {}", if header_data.len() <= 256 { format!("COMPLETE RAW HEADER: {}", hex::encode(header_data)) } else { format!("FIRST 256 BYTES: {}", hex::encode(&header_data[0..256])) });
+        None
+    };
 
     let mut outputs = Vec::new();
-    if let Ok(ref utxos_db) = utxos_result {
-        let mut cursor = txn.cursor(&*utxos_db)?;
-        if cursor.seek_range_k::<[u8], [u8]>(&access, block_hash_bytes.as_slice()).is_ok() {
-            loop {
-                match cursor.get_current::<[u8], [u8]>(&access) {
-                    Ok((key, value)) => {
-                        if !key.starts_with(block_hash_bytes.as_slice()) {
-                            break;
-                        }
-                        let row: TransactionOutputRowData = bincode::deserialize(value)?;
-                        outputs.push(OutputSummary {
-                            commitment: hex::encode(row.output.commitment.as_bytes()),
-                            features: serde_json::to_string(&row.output.features).unwrap_or_default(),
-                            script_type: format!("{:?}", row.output.script),
-                        });
-                        let _ = cursor.next::<[u8], [u8]>(&access);
-                    }
-                    Err(e) => return Err(anyhow::anyhow!("Cursor error in outputs: {}", e)),
-                }
-            }
+    if tables.utxos && include.transactions {
+        for row in read_block_rows::<TransactionOutputRowData>(read, "utxos", block_hash_bytes)? {
+            outputs.push(output_summary(read, tables, row));
         }
     }
 
     let mut inputs = Vec::new();
-    if let Ok(ref inputs_db) = inputs_result {
-        let mut cursor = txn.cursor(&*inputs_db)?;
-        if cursor.seek_range_k::<[u8], [u8]>(&access, block_hash_bytes.as_slice()).is_ok() {
-            loop {
-                match cursor.get_current::<[u8], [u8]>(&access) {
-                    Ok((key, value)) => {
-                        if !key.starts_with(block_hash_bytes.as_slice()) {
-                            break;
-                        }
-                        let row: TransactionInputRowData = bincode::deserialize(value)?;
-                        inputs.push(InputSummary {
-                            commitment: hex::encode(row.input.commitment()?.as_bytes()),
-                            input_type: format!("{:?}", row.input),
-                        });
-                        let _ = cursor.next::<[u8], [u8]>(&access);
-                    }
-                    Err(e) => return Err(anyhow::anyhow!("Cursor error in inputs: {}", e)),
-                }
-            }
+    if tables.inputs && include.transactions {
+        for row in read_block_rows::<TransactionInputRowData>(read, "inputs", block_hash_bytes)? {
+            inputs.push(input_summary(read, tables, row)?);
         }
     }
 
     let mut kernels = Vec::new();
-    if let Ok(ref kernels_db) = kernels_result {
-        let mut cursor = txn.cursor(&*kernels_db)?;
-        if cursor.seek_range_k::<[u8], [u8]>(&access, block_hash_bytes.as_slice()).is_ok() {
-            loop {
-                match cursor.get_current::<[u8], [u8]>(&access) {
-                    Ok((key, value)) => {
-                        if !key.starts_with(block_hash_bytes.as_slice()) {
-                            break;
-                        }
-                        let row: TransactionKernelRowData = bincode::deserialize(value)?;
-                        kernels.push(KernelSummary {
-                            excess: hex::encode(row.kernel.excess.as_bytes()),
-                            fee: row.kernel.fee.0,
-                            lock_height: row.kernel.lock_height,
-                        });
-                        let _ = cursor.next::<[u8], [u8]>(&access);
-                    }
-                    Err(e) => return Err(anyhow::anyhow!("Cursor error in kernels: {}", e)),
-                }
-            }
+    if tables.kernels && include.transactions {
+        for row in read_block_rows::<TransactionKernelRowData>(read, "kernels", block_hash_bytes)? {
+            kernels.push(kernel_summary(row));
         }
     }
 
-    let utxos_count = if let Ok(utxos_db) = utxos_result {
-        count_database_entries(&txn, &access, &utxos_db, "UTXOs")
-    } else {
-        0
-    };
-    let inputs_count = if let Ok(inputs_db) = inputs_result {
-        count_database_entries(&txn, &access, &inputs_db, "Inputs")
-    } else {
-        0
-    };
-    let kernels_count = if let Ok(kernels_db) = kernels_result {
-        count_database_entries(&txn, &access, &kernels_db, "Kernels")
-    } else {
-        0
-    };
-
-    println!("Transaction Database Summary:");
-    println!("  UTXOs (Outputs):     {:>8} transactions", utxos_count);
-    println!("  Inputs:              {:>8} transactions", inputs_count);
-    println!("  Kernels:             {:>8} transactions", kernels_count);
-    println!("  Total Transactions:  {:>8}", kernels_count);
-    println!("  Total I/O Records:   {:>8}", utxos_count + inputs_count);
-
-    Ok(BlockDetailSummary {
+    let summary = BlockDetailSummary {
         height,
-        hash,
+        hash: resolved.to_hex(),
         header: BlockHeaderLite {
             version: block_header.version,
             height: block_header.height,
@@ -488,64 +967,245 @@ pub fn read_block_with_transactions(path: &Path, height: u64) -> Result<BlockDet
             pow_data_hash: if !block_header.pow.pow_data.is_empty() { hex::encode(&block_header.pow.pow_data) } else { "empty".to_string() },
             raw_header_length: header_data.len(),
             pow_algorithm: format!("{:?}", block_header.pow.pow_algo),
+            kernel_mmr_size: block_header.kernel_mmr_size,
+            output_smt_size: block_header.output_smt_size,
         },
         transactions: TransactionSummary {
             inputs,
             outputs,
             kernels,
         },
+        counts,
+        data_completeness: data_completeness.at_height(height),
+    };
+    Ok((summary, resolved.source))
+}
+
+/// Read a specific block with transaction details
+pub fn read_block_with_transactions(path: &Path, height: u64) -> ReaderResult<BlockDetailSummary> {
+    read_block(path, height, BlockInclude::default())
+}
+
+/// Read a block's header plus the parts in `include`
+/// Transaction rows are found by block hash prefix scans; no table is read in full
+pub fn read_block(path: &Path, height: u64, include: BlockInclude) -> ReaderResult<BlockDetailSummary> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+
+    let tables = BlockTables::open(&*read)?;
+    let available = |present: bool| if present { "Available" } else { "Not found" };
+
+    if verbosity::debug() {
+        println!("Database availability ({}):", backend.name());
+        println!("  headers: Available");
+        println!("  utxos: {}", available(tables.utxos));
+        println!("  inputs: {}", available(tables.inputs));
+        println!("  kernels: {}", available(tables.kernels));
+    }
+    let data_completeness = tables.completeness(&*read)?;
+    let resolver = BlockHashResolver::new(&*read)?;
+
+    let (block, hash_source) = block_detail_in(&*read, &tables, &resolver, data_completeness, height, include)?;
+    let header = &block.header;
+
+    if verbosity::debug() {
+        println!("COMPLETE HEADER ANALYSIS for block {}:", height);
+        match hash_source {
+            HashSource::Metadata => println!("  Hash (from metadata best block): {}", block.hash),
+            HashSource::NextHeader => println!("  Hash (from next block's prev_hash): {}", block.hash),
+            HashSource::Computed => println!("  Hash (computed, not confirmed by the node): {}", block.hash),
+        }
+        println!("  Previous hash: {}", header.previous_hash);
+        println!("  Output MR: {}", header.output_mr);
+        println!("  Kernel MR: {}", header.kernel_mr);
+        println!("  Input MR: {}", header.input_mr);
+        println!("  Total kernel offset: {}", header.total_kernel_offset);
+        println!("  Total script offset: {}", header.total_script_offset);
+        println!("  PoW data/hash: {}", header.pow_data_hash);
+        println!("  Raw header length: {} bytes", header.raw_header_length);
+        println!("  PoW algorithm: {}", header.pow_algorithm);
+        println!("  Raw field layout: inspect header --height {} --annotate", height);
+    }
+
+    Ok(block)
+}
+
+/// Count every entry of the transaction tables, reporting to `progress`
+/// Reads the tables in full, so expect minutes on mainnet; stops with
+/// `ReaderError::Cancelled` once `cancel` fires
+pub fn count_transaction_tables(path: &Path, progress: &dyn Progress, cancel: &CancellationToken) -> ReaderResult<TableCounts> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    let tables = BlockTables::open(&*read)?;
+
+    let count = |present: bool, table, label| {
+        present.then(|| count_database_entries(&*read, table, label, progress, cancel)).transpose()
+    };
+    Ok(TableCounts {
+        utxos: count(tables.utxos, "utxos", "UTXOs")?,
+        inputs: count(tables.inputs, "inputs", "Inputs")?,
+        kernels: count(tables.kernels, "kernels", "Kernels")?,
     })
 }
 
-/// Efficiently count database entries with progress and limits
+/// One page of a block's `component` rows (`/api/v1/block/:height/{inputs,outputs,kernels}?offset=&limit=`)
+/// Rows before the page are skipped without decoding; the rest of the block's rows are only
+/// counted for `total`
+pub fn read_block_transactions(path: &Path, height: u64, component: TxComponent, page: Page) -> ReaderResult<TransactionPage> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    let tables = BlockTables::open(&*read)?;
+    let data_completeness = tables.completeness(&*read)?;
+    let resolver = BlockHashResolver::new(&*read)?;
+
+    let (_, _, resolved) = block_header_in(&*read, &resolver, data_completeness, height)?;
+    let block_hash = resolved.bytes.as_slice();
+
+    let mut transactions = TransactionSummary::default();
+    let mut total = 0;
+    match component {
+        TxComponent::Outputs => if tables.utxos {
+            let (rows, count) = read_block_rows_page::<TransactionOutputRowData>(&*read, "utxos", block_hash, page)?;
+            transactions.outputs = rows.into_iter().map(|row| output_summary(&*read, &tables, row)).collect();
+            total = count;
+        },
+        TxComponent::Inputs => if tables.inputs {
+            let (rows, count) = read_block_rows_page::<TransactionInputRowData>(&*read, "inputs", block_hash, page)?;
+            transactions.inputs = rows.into_iter().map(|row| input_summary(&*read, &tables, row)).collect::<ReaderResult<_>>()?;
+            total = count;
+        },
+        TxComponent::Kernels => if tables.kernels {
+            let (rows, count) = read_block_rows_page::<TransactionKernelRowData>(&*read, "kernels", block_hash, page)?;
+            transactions.kernels = rows.into_iter().map(kernel_summary).collect();
+            total = count;
+        },
+    }
+
+    Ok(TransactionPage { height, hash: resolved.to_hex(), component, page, total, transactions })
+}
+
+/// Read several blocks with transaction details in one environment and read transaction
+/// Each height gets its own result so one missing or pruned block does not fail the batch
+pub fn read_blocks_with_transactions(path: &Path, heights: &[u64]) -> ReaderResult<Vec<ReaderResult<BlockDetailSummary>>> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    let tables = BlockTables::open(&*read)?;
+    let data_completeness = tables.completeness(&*read)?;
+    let resolver = BlockHashResolver::new(&*read)?;
+
+    Ok(heights.iter()
+        .map(|height| block_detail_in(&*read, &tables, &resolver, data_completeness, *height, BlockInclude::default()).map(|(block, _)| block))
+        .collect())
+}
+
+/// Decode every row of a block-hash-prefixed table (`utxos`, `inputs`, `kernels`) for one block
+pub(crate) fn read_block_rows<T: DeserializeOwned>(
+    read: &dyn KvRead,
+    table: &'static str,
+    block_hash: &[u8],
+) -> ReaderResult<Vec<T>> {
+    let mut rows = Vec::new();
+    let mut cursor = read.cursor(table)?;
+    let mut entry = cursor.seek(block_hash)?;
+    while let Some((key, value)) = entry {
+        if !key.starts_with(block_hash) {
+            break;
+        }
+        rows.push(bincode::deserialize(value).map_err(|e| ReaderError::Decode { table, source: e })?);
+        entry = cursor.next()?;
+    }
+    Ok(rows)
+}
+
+/// Rows in `page` under `block_hash` decoded as `T`, and the number of rows under it
+pub(crate) fn read_block_rows_page<T: DeserializeOwned>(
+    read: &dyn KvRead,
+    table: &'static str,
+    block_hash: &[u8],
+    page: Page,
+) -> ReaderResult<(Vec<T>, u64)> {
+    let mut rows = Vec::new();
+    let mut index = 0;
+    let mut cursor = read.cursor(table)?;
+    let mut entry = cursor.seek(block_hash)?;
+    while let Some((key, value)) = entry {
+        if !key.starts_with(block_hash) {
+            break;
+        }
+        if page.contains(index) {
+            rows.push(bincode::deserialize(value).map_err(|e| ReaderError::Decode { table, source: e })?);
+        }
+        index += 1;
+        entry = cursor.next()?;
+    }
+    Ok((rows, index as u64))
+}
+
+/// Height stored in `block_hashes` for a block hash
+pub(crate) fn height_of_block(read: &dyn KvRead, hash: &[u8]) -> Option<u64> {
+    let value = read.get("block_hashes", hash).ok()??;
+    Some(u64::from_le_bytes(value.get(..8)?.try_into().ok()?))
+}
+
+/// Count rows keyed by `block_hash` + row hash without decoding them
+pub(crate) fn count_block_rows(read: &dyn KvRead, table: &str, block_hash: &[u8]) -> ReaderResult<u64> {
+    let mut cursor = read.cursor(table)?;
+    let mut count = 0;
+    let mut entry = cursor.seek(block_hash)?;
+    while let Some((key, _)) = entry {
+        if !key.starts_with(block_hash) {
+            break;
+        }
+        count += 1;
+        entry = cursor.next()?;
+    }
+    Ok(count)
+}
+
+/// Count database entries (capped at 10M), reporting to `progress`
 fn count_database_entries(
-    txn: &ReadTransaction,
-    access: &ConstAccessor,
-    db: &Database,
-    db_type: &str
-) -> usize {
-    print!("Counting {} database entries... ", db_type);
-    
-    match txn.cursor(db) {
-        Ok(mut cursor) => {
-            if let Ok((_key, _value)) = cursor.first::<[u8], [u8]>(access) {
-                let mut count = 1;
-                let max_count = 10_000_000;
-                
-                while count < max_count {
-                    match cursor.next::<[u8], [u8]>(access) {
-                        Ok((_next_key, _next_value)) => {
-                            count += 1;
-                            if count % 250_000 == 0 {
-                                print!("{}M ", count / 1_000_000);
-                            }
-                        }
-                        Err(_) => break,
-                    }
-                }
-                
-                if count >= max_count {
-                    println!("-> 10M+ entries (stopped counting)");
-                    count
-                } else {
-                    println!("-> {} total entries", count);
-                    count
-                }
-            } else {
-                println!("-> 0 entries (empty)");
-                0
+    read: &dyn KvRead,
+    table: &str,
+    db_type: &str,
+    progress: &dyn Progress,
+    cancel: &CancellationToken,
+) -> ReaderResult<usize> {
+    const MAX_COUNT: usize = 10_000_000;
+
+    if cancel.is_cancelled() {
+        return Err(ReaderError::Cancelled);
+    }
+    let Ok(mut cursor) = read.cursor(table) else {
+        eprintln!("Error accessing {} database", db_type);
+        return Ok(0);
+    };
+    progress.begin(&format!("Counting {} entries", db_type), None);
+
+    let mut count = 0;
+    let mut found = cursor.first()?.is_some();
+    while found && count < MAX_COUNT {
+        count += 1;
+        if count % 250_000 == 0 {
+            progress.update(count as u64);
+            if cancel.is_cancelled() {
+                progress.finish(&format!("{}: cancelled after {} entries", db_type, count));
+                return Err(ReaderError::Cancelled);
             }
-        },
-        Err(_) => {
-            println!("-> Error accessing database");
-            0
         }
+        found = cursor.next()?.is_some();
+    }
+
+    progress.update(count as u64);
+    if count >= MAX_COUNT {
+        progress.finish(&format!("{}: 10M+ entries (stopped counting)", db_type));
+    } else {
+        progress.finish(&format!("{}: {} entries", db_type, count));
     }
+    Ok(count)
 }
 
 /// Default function to read last 10 headers
-#[allow(dead_code)]
-pub fn read_lmdb_headers(path: &Path, db_name: &str) -> Result<Vec<BlockSummary>> {
+pub fn read_lmdb_headers(path: &Path, db_name: &str) -> ReaderResult<Vec<BlockSummary>> {
     read_lmdb_headers_with_filter(path, db_name, BlockFilter::LastN(10))
 }
 
@@ -0,0 +1,168 @@
+// File: src/lmdb_reader/async.rs
+// Version: 1.5.0 - Blocking read time accounted per task (measure_reads)
+// Tree: tari-lmdb-inspector/src/lmdb_reader/async.rs
+//
+// LMDB reads are blocking and can take seconds on full scans. This facade runs each read on
+// tokio's blocking pool with a timeout, and turns panics in the reader into ordinary errors
+// so a bad header cannot take down a web worker or the TUI loop. Scanning reads get a child of
+// the reader's `CancellationToken` that fires when the caller stops waiting (timeout, dropped
+// request future), so an abandoned full-chain scan stops instead of holding a blocking thread.
+// Inside `measure_reads` the time spent waiting on the blocking pool is added up, which is how
+// the web server reports LMDB read time per request.
+
+use std::cell::Cell;
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use super::{
+    BlockDetailSummary, BlockFilter, BlockInclude, BlockSummary, Page, ReaderError, ReaderResult, TableCounts, TransactionPage,
+    TxComponent,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::progress::{NoProgress, Progress};
+
+/// Default time allowed for a single read before it is reported as timed out
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+tokio::task_local! {
+    /// Time spent in `run_blocking` by the current `measure_reads` scope
+    static READ_TIME: Cell<Duration>;
+}
+
+/// Run `future` and also return the time it spent waiting on blocking reads
+/// Reads made by tasks it spawns are not counted
+pub async fn measure_reads<F: Future>(future: F) -> (F::Output, Duration) {
+    READ_TIME.scope(Cell::new(Duration::ZERO), async move {
+        let output = future.await;
+        (output, READ_TIME.with(Cell::get))
+    }).await
+}
+
+/// Async handle on an LMDB directory
+#[derive(Debug, Clone)]
+pub struct AsyncReader {
+    database_path: PathBuf,
+    timeout: Duration,
+    progress: Arc<dyn Progress>,
+    cancel: CancellationToken,
+}
+
+impl AsyncReader {
+    pub fn new(database_path: impl Into<PathBuf>, timeout: Duration) -> Self {
+        Self {
+            database_path: database_path.into(),
+            timeout,
+            progress: Arc::new(NoProgress),
+            cancel: CancellationToken::new(),
+        }
+    }
+
+    /// Cancel every scan started by this reader when `cancel` fires (e.g. on Ctrl-C)
+    pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// Report table counting and full-chain hash searches to `progress`
+    pub fn with_progress(mut self, progress: Arc<dyn Progress>) -> Self {
+        self.progress = progress;
+        self
+    }
+
+    /// Async version of `read_lmdb_headers_with_filter`
+    pub async fn headers(&self, db_name: &str, filter: BlockFilter) -> ReaderResult<Vec<BlockSummary>> {
+        let path = self.database_path.clone();
+        let db_name = db_name.to_string();
+        run_blocking(self.timeout, "read headers", move || {
+            super::read_lmdb_headers_with_filter(&path, &db_name, filter)
+        }).await
+    }
+
+    /// Async version of `read_block`
+    pub async fn block(&self, height: u64, include: BlockInclude) -> ReaderResult<BlockDetailSummary> {
+        let path = self.database_path.clone();
+        run_blocking(self.timeout, "read block", move || {
+            super::read_block(&path, height, include)
+        }).await
+    }
+
+    /// Async version of `read_block_transactions`
+    pub async fn transactions(&self, height: u64, component: TxComponent, page: Page) -> ReaderResult<TransactionPage> {
+        let path = self.database_path.clone();
+        run_blocking(self.timeout, "read block transactions", move || {
+            super::read_block_transactions(&path, height, component, page)
+        }).await
+    }
+
+    /// Async version of `count_transaction_tables`
+    pub async fn table_counts(&self) -> ReaderResult<TableCounts> {
+        let path = self.database_path.clone();
+        let progress = self.progress.clone();
+        run_cancellable(self.timeout, "count transaction tables", &self.cancel, move |cancel| {
+            super::count_transaction_tables(&path, progress.as_ref(), &cancel)
+        }).await
+    }
+
+    /// Async version of `read_blocks_with_transactions`
+    pub async fn blocks(&self, heights: Vec<u64>) -> ReaderResult<Vec<ReaderResult<BlockDetailSummary>>> {
+        let path = self.database_path.clone();
+        run_blocking(self.timeout, "read block batch", move || {
+            super::read_blocks_with_transactions(&path, &heights)
+        }).await
+    }
+
+    /// Async version of `search_block_by_hash`
+    pub async fn block_by_hash(&self, hash: &str) -> ReaderResult<Option<BlockDetailSummary>> {
+        let path = self.database_path.clone();
+        let hash = hash.to_string();
+        let progress = self.progress.clone();
+        run_cancellable(self.timeout, "search block by hash", &self.cancel, move |cancel| {
+            super::search_block_by_hash_with_progress(&path, &hash, progress.as_ref(), &cancel)
+        }).await
+    }
+}
+
+/// Run a blocking read on the blocking pool with a timeout, converting panics into errors
+/// Works with any error type that can absorb a `ReaderError` (including `anyhow::Error`)
+pub async fn run_blocking<T, E, F>(timeout: Duration, label: &str, read: F) -> Result<T, E>
+where
+    T: Send + 'static,
+    E: From<ReaderError> + Send + 'static,
+    F: FnOnce() -> Result<T, E> + Send + 'static,
+{
+    let started = Instant::now();
+    let handle = tokio::task::spawn_blocking(read);
+
+    // On timeout the blocking thread keeps running to completion; only the caller gives up
+    let joined = tokio::time::timeout(timeout, handle).await;
+    let _ = READ_TIME.try_with(|total| total.set(total.get() + started.elapsed()));
+    match joined {
+        Ok(Ok(result)) => result,
+        Ok(Err(join_error)) if join_error.is_panic() => {
+            let panic = join_error.into_panic();
+            let message = panic.downcast_ref::<&str>().map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string());
+            Err(ReaderError::Task { operation: label.to_string(), message: format!("panicked: {}", message) }.into())
+        }
+        Ok(Err(join_error)) => Err(ReaderError::Task { operation: label.to_string(), message: join_error.to_string() }.into()),
+        Err(_) => Err(ReaderError::Timeout { operation: label.to_string(), seconds: timeout.as_secs() }.into()),
+    }
+}
+
+/// `run_blocking` for reads that check a `CancellationToken`
+/// The read gets a child of `parent` that is also cancelled when this future completes or is
+/// dropped, so a timed-out or abandoned read stops at its next cancellation check
+pub async fn run_cancellable<T, E, F>(timeout: Duration, label: &str, parent: &CancellationToken, read: F) -> Result<T, E>
+where
+    T: Send + 'static,
+    E: From<ReaderError> + Send + 'static,
+    F: FnOnce(CancellationToken) -> Result<T, E> + Send + 'static,
+{
+    let cancel = parent.child_token();
+    let _cancel_on_drop = cancel.clone().drop_guard();
+    run_blocking(timeout, label, move || read(cancel)).await
+}
@@ -0,0 +1,299 @@
+// File: src/lmdb_reader/backend.rs
+// Version: 1.1.1 - One lmdb-zero accessor per read transaction, lent to lookups and cursors
+// Tree: tari-lmdb-inspector/src/lmdb_reader/backend.rs
+//
+// lmdb-zero is unmaintained. Every reader goes through `KvBackend` instead of lmdb-zero types:
+// point lookups, ordered scans and `KvCursor` for walks that move both ways or stream rows
+// out of an iterator. Moving to heed, or to libmdbx for chains that use it, means adding one
+// implementation here rather than touching every reader. `open_backend` picks heed when the
+// crate is built with `--features heed`, and the lmdb-zero implementation otherwise;
+// `with_backend` overrides that on one thread so tests can run the same reads on both.
+//
+// ```no_run
+// use tari_lmdb_inspector::lmdb_reader::backend::open_backend;
+//
+// let backend = open_backend("/home/me/.tari/mainnet/data/base_node/db".as_ref())?;
+// let read = backend.read()?;
+// let mut kernel_bytes = 0;
+// read.scan("kernels", &[], &mut |_, value| { kernel_bytes += value.len(); true })?;
+// # Ok::<(), tari_lmdb_inspector::lmdb_reader::ReaderError>(())
+// ```
+
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+use lmdb_zero::{ConstAccessor, Cursor, Database, DatabaseOptions, EnvBuilder, Environment, LmdbResultExt, ReadTransaction};
+
+use super::{env_path, ReaderError, ReaderResult};
+
+// heed (maintained LMDB binding) implementation
+#[cfg(feature = "heed")]
+mod heed_kv;
+#[cfg(feature = "heed")]
+pub use heed_kv::HeedBackend;
+
+/// Most named tables an environment is opened with
+pub const MAX_TABLES: u32 = 64;
+
+/// An open key-value environment
+pub trait KvBackend: Send + Sync {
+    /// Binding name, for log output
+    fn name(&self) -> &'static str;
+
+    /// Begin a read transaction; only one may be open per thread
+    fn read(&self) -> ReaderResult<Box<dyn KvRead + '_>>;
+}
+
+/// One read transaction. Tables are named; a missing one is `ReaderError::TableNotFound`.
+pub trait KvRead {
+    /// Names of the named tables, in key order
+    fn tables(&self) -> ReaderResult<Vec<String>>;
+
+    /// Entries in `table`, from the table stats (no scan)
+    fn entries(&self, table: &str) -> ReaderResult<u64>;
+
+    /// Whether `table` exists
+    fn has_table(&self, table: &str) -> ReaderResult<bool> {
+        match self.entries(table) {
+            Ok(_) => Ok(true),
+            Err(ReaderError::TableNotFound(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Value stored under `key`
+    fn get(&self, table: &str, key: &[u8]) -> ReaderResult<Option<Vec<u8>>>;
+
+    /// Entry with the highest key
+    fn last(&self, table: &str) -> ReaderResult<Option<(Vec<u8>, Vec<u8>)>>;
+
+    /// Visit entries in key order from the first key `>= from` (the whole table when empty)
+    /// until `visit` returns `false`; keys and values borrow the memory map, nothing is copied
+    fn scan(&self, table: &str, from: &[u8], visit: &mut dyn FnMut(&[u8], &[u8]) -> bool) -> ReaderResult<()>;
+
+    /// A cursor over `table`. Lookups and other cursors may be used while it is open; every
+    /// implementation must allow that, since readers resolve hashes mid-walk
+    fn cursor(&self, table: &str) -> ReaderResult<Box<dyn KvCursor + '_>>;
+}
+
+/// A position in one table. Every move returns the entry it lands on, or `None` past either
+/// end; the slices borrow the memory map until the next move.
+pub trait KvCursor {
+    /// Entry with the lowest key
+    fn first(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>>;
+
+    /// Entry with the highest key
+    fn last(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>>;
+
+    /// First entry with a key `>= key`
+    fn seek(&mut self, key: &[u8]) -> ReaderResult<Option<(&[u8], &[u8])>>;
+
+    /// Entry after the current one
+    fn next(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>>;
+
+    /// Entry before the current one
+    fn prev(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>>;
+}
+
+/// LMDB bindings `open_backend` can open
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendKind {
+    LmdbZero,
+    #[cfg(feature = "heed")]
+    Heed,
+}
+
+impl BackendKind {
+    /// The binding `open_backend` uses unless overridden
+    #[cfg(feature = "heed")]
+    pub const DEFAULT: BackendKind = BackendKind::Heed;
+    #[cfg(not(feature = "heed"))]
+    pub const DEFAULT: BackendKind = BackendKind::LmdbZero;
+
+    /// Every binding compiled into this build
+    pub fn available() -> Vec<BackendKind> {
+        vec![
+            BackendKind::LmdbZero,
+            #[cfg(feature = "heed")]
+            BackendKind::Heed,
+        ]
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BackendKind::LmdbZero => "lmdb-zero",
+            #[cfg(feature = "heed")]
+            BackendKind::Heed => "heed",
+        }
+    }
+
+    /// Open the environment at `path` with this binding
+    pub fn open(self, path: &Path) -> ReaderResult<Box<dyn KvBackend>> {
+        match self {
+            BackendKind::LmdbZero => Ok(Box::new(LmdbZeroBackend::open(path)?)),
+            #[cfg(feature = "heed")]
+            BackendKind::Heed => Ok(Box::new(HeedBackend::open(path)?)),
+        }
+    }
+}
+
+thread_local! {
+    /// Binding `open_backend` uses on this thread, when overridden
+    static BACKEND_OVERRIDE: Cell<Option<BackendKind>> = const { Cell::new(None) };
+}
+
+/// Run `f` with `open_backend` on this thread opening `kind`
+/// Readers open their environment on the calling thread, so everything `f` reads, including
+/// parallel scans over an opened `StreamReader`, uses `kind`
+pub fn with_backend<T>(kind: BackendKind, f: impl FnOnce() -> T) -> T {
+    let previous = BACKEND_OVERRIDE.with(|current| current.replace(Some(kind)));
+    let result = f();
+    BACKEND_OVERRIDE.with(|current| current.set(previous));
+    result
+}
+
+/// Open the environment at `path` with the backend the crate was built for
+pub fn open_backend(path: &Path) -> ReaderResult<Box<dyn KvBackend>> {
+    BACKEND_OVERRIDE.with(Cell::get).unwrap_or(BackendKind::DEFAULT).open(path)
+}
+
+/// `KvBackend` over lmdb-zero
+/// Named tables are opened once, before any read transaction: lmdb-zero refuses to open a
+/// table whose handle is still alive, and opening one inside a read transaction would need a
+/// second transaction on the same thread
+pub struct LmdbZeroBackend {
+    tables: BTreeMap<String, Database<'static>>,
+    env: Arc<Environment>,
+}
+
+/// lmdb-zero panics if a transaction yields a second accessor while the first is alive, so
+/// the read takes one up front and lends it to every lookup and cursor
+struct LmdbZeroRead<'a> {
+    backend: &'a LmdbZeroBackend,
+    // Declared before `txn` so it is dropped first
+    access: ConstAccessor<'a>,
+    txn: Box<ReadTransaction<'a>>,
+}
+
+struct LmdbZeroCursor<'t> {
+    cursor: Cursor<'t, 't>,
+    access: &'t ConstAccessor<'t>,
+}
+
+impl LmdbZeroBackend {
+    pub fn open(path: &Path) -> ReaderResult<Self> {
+        let path_str = env_path(path)?;
+
+        let mut builder = EnvBuilder::new()?;
+        builder.set_maxdbs(MAX_TABLES)?;
+
+        let env = Arc::new(unsafe {
+            builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+        });
+
+        // Named tables are stored as keys of the unnamed main database
+        let names = {
+            let main_db = Database::open(env.clone(), None, &DatabaseOptions::defaults())?;
+            let txn = ReadTransaction::new(&*env)?;
+            let access = txn.access();
+            let mut cursor = txn.cursor(&main_db)?;
+            let mut names = Vec::new();
+            let mut entry = cursor.first::<[u8], [u8]>(&access);
+            while let Some((key, _)) = entry.to_opt()? {
+                names.push(String::from_utf8_lossy(key).into_owned());
+                entry = cursor.next::<[u8], [u8]>(&access);
+            }
+            names
+        };
+        let tables = names.into_iter()
+            .filter_map(|name| {
+                let db = Database::open(env.clone(), Some(&name), &DatabaseOptions::defaults()).ok()?;
+                Some((name, db))
+            })
+            .collect();
+        Ok(Self { tables, env })
+    }
+}
+
+impl KvBackend for LmdbZeroBackend {
+    fn name(&self) -> &'static str {
+        "lmdb-zero"
+    }
+
+    fn read(&self) -> ReaderResult<Box<dyn KvRead + '_>> {
+        let txn = Box::new(ReadTransaction::new(&*self.env)?);
+        // SAFETY: the transaction is boxed, so its address survives moving `LmdbZeroRead`, it
+        // is never replaced, and `access` is dropped before it
+        let access = unsafe { &*(&*txn as *const ReadTransaction<'_>) }.access();
+        Ok(Box::new(LmdbZeroRead { backend: self, access, txn }))
+    }
+}
+
+impl<'a> LmdbZeroRead<'a> {
+    fn table(&self, name: &str) -> ReaderResult<&'a Database<'static>> {
+        self.backend.tables.get(name).ok_or_else(|| ReaderError::TableNotFound(name.to_string()))
+    }
+}
+
+impl KvRead for LmdbZeroRead<'_> {
+    fn tables(&self) -> ReaderResult<Vec<String>> {
+        Ok(self.backend.tables.keys().cloned().collect())
+    }
+
+    fn entries(&self, table: &str) -> ReaderResult<u64> {
+        Ok(self.txn.db_stat(self.table(table)?)?.entries as u64)
+    }
+
+    fn get(&self, table: &str, key: &[u8]) -> ReaderResult<Option<Vec<u8>>> {
+        let db = self.table(table)?;
+        Ok(self.access.get::<[u8], [u8]>(db, key).to_opt()?.map(<[u8]>::to_vec))
+    }
+
+    fn last(&self, table: &str) -> ReaderResult<Option<(Vec<u8>, Vec<u8>)>> {
+        let mut cursor = self.cursor(table)?;
+        Ok(cursor.last()?.map(|(k, v)| (k.to_vec(), v.to_vec())))
+    }
+
+    fn scan(&self, table: &str, from: &[u8], visit: &mut dyn FnMut(&[u8], &[u8]) -> bool) -> ReaderResult<()> {
+        let mut cursor = self.cursor(table)?;
+        let mut entry = if from.is_empty() { cursor.first()? } else { cursor.seek(from)? };
+        while let Some((key, value)) = entry {
+            if !visit(key, value) {
+                break;
+            }
+            entry = cursor.next()?;
+        }
+        Ok(())
+    }
+
+    fn cursor(&self, table: &str) -> ReaderResult<Box<dyn KvCursor + '_>> {
+        let db = self.table(table)?;
+        Ok(Box::new(LmdbZeroCursor {
+            cursor: self.txn.cursor(db)?,
+            access: &self.access,
+        }))
+    }
+}
+
+impl KvCursor for LmdbZeroCursor<'_> {
+    fn first(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        Ok(self.cursor.first::<[u8], [u8]>(self.access).to_opt()?)
+    }
+
+    fn last(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        Ok(self.cursor.last::<[u8], [u8]>(self.access).to_opt()?)
+    }
+
+    fn seek(&mut self, key: &[u8]) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        Ok(self.cursor.seek_range_k::<[u8], [u8]>(self.access, key).to_opt()?)
+    }
+
+    fn next(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        Ok(self.cursor.next::<[u8], [u8]>(self.access).to_opt()?)
+    }
+
+    fn prev(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        Ok(self.cursor.prev::<[u8], [u8]>(self.access).to_opt()?)
+    }
+}
@@ -0,0 +1,178 @@
+// File: src/lmdb_reader/backend/heed_kv.rs
+// Version: 1.1.0 - KvCursor over heed range iterators, busy errors
+// Tree: tari-lmdb-inspector/src/lmdb_reader/backend/heed_kv.rs
+//
+// heed opens environments from a native `Path`, so non-UTF-8 database paths need no alias here.
+// Its cursor type is not public, so `HeedCursor` walks a forward or reverse range iterator
+// and starts a new one from the current key when the direction changes.
+
+use std::ops::Bound;
+use std::path::Path;
+use heed::types::Bytes;
+use heed::{Database, Env, EnvOpenOptions, RoTxn};
+
+use super::{KvBackend, KvCursor, KvRead, MAX_TABLES};
+use crate::lmdb_reader::{ReaderError, ReaderResult};
+
+/// `KvBackend` over heed
+pub struct HeedBackend {
+    env: Env,
+}
+
+struct HeedRead<'a> {
+    env: &'a Env,
+    txn: RoTxn<'a>,
+}
+
+type Entries<'t> = Box<dyn Iterator<Item = heed::Result<(&'t [u8], &'t [u8])>> + 't>;
+
+struct HeedCursor<'t, 'e> {
+    db: Database<Bytes, Bytes>,
+    txn: &'t RoTxn<'e>,
+    entries: Option<Entries<'t>>,
+    forward: bool,
+    /// Key of the entry the cursor is on
+    current: Option<Vec<u8>>,
+}
+
+fn heed_error(e: heed::Error) -> ReaderError {
+    match e {
+        heed::Error::Mdb(heed::MdbError::ReadersFull | heed::MdbError::MapResized) => ReaderError::LmdbBusy(e.to_string()),
+        other => ReaderError::Backend { backend: "heed", message: other.to_string() },
+    }
+}
+
+impl HeedBackend {
+    pub fn open(path: &Path) -> ReaderResult<Self> {
+        let env = unsafe { EnvOpenOptions::new().max_dbs(MAX_TABLES).open(path) }.map_err(heed_error)?;
+        Ok(Self { env })
+    }
+}
+
+impl KvBackend for HeedBackend {
+    fn name(&self) -> &'static str {
+        "heed"
+    }
+
+    fn read(&self) -> ReaderResult<Box<dyn KvRead + '_>> {
+        Ok(Box::new(HeedRead {
+            env: &self.env,
+            txn: self.env.read_txn().map_err(heed_error)?,
+        }))
+    }
+}
+
+impl HeedRead<'_> {
+    fn table(&self, name: Option<&str>) -> ReaderResult<Database<Bytes, Bytes>> {
+        self.env.open_database::<Bytes, Bytes>(&self.txn, name)
+            .map_err(heed_error)?
+            .ok_or_else(|| ReaderError::TableNotFound(name.unwrap_or_default().to_string()))
+    }
+}
+
+impl KvRead for HeedRead<'_> {
+    fn tables(&self) -> ReaderResult<Vec<String>> {
+        // Named tables are stored as keys of the unnamed main database
+        let main_db = self.table(None)?;
+        let mut names = Vec::new();
+        for entry in main_db.iter(&self.txn).map_err(heed_error)? {
+            let (key, _) = entry.map_err(heed_error)?;
+            names.push(String::from_utf8_lossy(key).into_owned());
+        }
+        Ok(names)
+    }
+
+    fn entries(&self, table: &str) -> ReaderResult<u64> {
+        self.table(Some(table))?.len(&self.txn).map_err(heed_error)
+    }
+
+    fn get(&self, table: &str, key: &[u8]) -> ReaderResult<Option<Vec<u8>>> {
+        let value = self.table(Some(table))?.get(&self.txn, key).map_err(heed_error)?;
+        Ok(value.map(<[u8]>::to_vec))
+    }
+
+    fn last(&self, table: &str) -> ReaderResult<Option<(Vec<u8>, Vec<u8>)>> {
+        let entry = self.table(Some(table))?.last(&self.txn).map_err(heed_error)?;
+        Ok(entry.map(|(k, v)| (k.to_vec(), v.to_vec())))
+    }
+
+    fn scan(&self, table: &str, from: &[u8], visit: &mut dyn FnMut(&[u8], &[u8]) -> bool) -> ReaderResult<()> {
+        let db = self.table(Some(table))?;
+        let range = (Bound::Included(from), Bound::Unbounded);
+        for entry in db.range(&self.txn, &range).map_err(heed_error)? {
+            let (key, value) = entry.map_err(heed_error)?;
+            if !visit(key, value) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    fn cursor(&self, table: &str) -> ReaderResult<Box<dyn KvCursor + '_>> {
+        Ok(Box::new(HeedCursor {
+            db: self.table(Some(table))?,
+            txn: &self.txn,
+            entries: None,
+            forward: true,
+            current: None,
+        }))
+    }
+}
+
+impl<'t> HeedCursor<'t, '_> {
+    /// Continue in `forward` direction with `entries`, returning the first entry
+    fn start(&mut self, forward: bool, entries: heed::Result<Entries<'t>>) -> ReaderResult<Option<(&'t [u8], &'t [u8])>> {
+        self.entries = Some(entries.map_err(heed_error)?);
+        self.forward = forward;
+        self.step()
+    }
+
+    fn step(&mut self) -> ReaderResult<Option<(&'t [u8], &'t [u8])>> {
+        match self.entries.as_mut().and_then(Iterator::next) {
+            Some(Ok((key, value))) => {
+                self.current = Some(key.to_vec());
+                Ok(Some((key, value)))
+            }
+            Some(Err(e)) => Err(heed_error(e)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl KvCursor for HeedCursor<'_, '_> {
+    fn first(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        let entries = self.db.iter(self.txn).map(|iter| Box::new(iter) as Entries);
+        self.start(true, entries)
+    }
+
+    fn last(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        let entries = self.db.rev_iter(self.txn).map(|iter| Box::new(iter) as Entries);
+        self.start(false, entries)
+    }
+
+    fn seek(&mut self, key: &[u8]) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        let range = (Bound::Included(key), Bound::Unbounded);
+        let entries = self.db.range(self.txn, &range).map(|iter| Box::new(iter) as Entries);
+        self.start(true, entries)
+    }
+
+    fn next(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        if self.forward {
+            return self.step();
+        }
+        let Some(current) = self.current.take() else { return self.first() };
+        let range = (Bound::Excluded(current.as_slice()), Bound::Unbounded);
+        let entries = self.db.range(self.txn, &range).map(|iter| Box::new(iter) as Entries);
+        self.start(true, entries)
+    }
+
+    fn prev(&mut self) -> ReaderResult<Option<(&[u8], &[u8])>> {
+        if !self.forward {
+            return self.step();
+        }
+        let Some(current) = self.current.take() else { return self.last() };
+        let range = (Bound::Unbounded, Bound::Excluded(current.as_slice()));
+        let entries = self.db.rev_range(self.txn, &range).map(|iter| Box::new(iter) as Entries);
+        self.start(false, entries)
+    }
+}
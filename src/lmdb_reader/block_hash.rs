@@ -0,0 +1,84 @@
+// File: src/lmdb_reader/block_hash.rs
+// Version: 1.2.0 - Resolves through KvRead
+// Tree: tari-lmdb-inspector/src/lmdb_reader/block_hash.rs
+//
+// Headers do not store their own hash, and hashing a header with the linked Tari version is
+// only right when that version matches the node's. The hash the node committed to is found,
+// in order of preference, in the metadata `best_block` entry (for the tip) or in the next
+// header's `prev_hash`; the computed hash is a last resort.
+
+use tari_node_components::blocks::BlockHeader;
+use super::header_view::prev_hash_of;
+use super::backend::KvRead;
+use super::metadata::{self, ChainMetadata};
+use super::ReaderResult;
+
+/// Where a resolved block hash came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HashSource {
+    /// The metadata table's best block entry (tip only)
+    Metadata,
+    /// The `prev_hash` of the header at `height + 1`
+    NextHeader,
+    /// Hash computed from the header by the linked Tari version
+    Computed,
+}
+
+/// A block hash and its provenance
+#[derive(Debug, Clone)]
+pub struct ResolvedHash {
+    pub bytes: Vec<u8>,
+    pub source: HashSource,
+}
+
+impl ResolvedHash {
+    pub fn to_hex(&self) -> String {
+        hex::encode(&self.bytes)
+    }
+}
+
+/// Resolves block hashes inside a read transaction
+#[derive(Debug, Clone, Default)]
+pub(crate) struct BlockHashResolver {
+    /// Tip height and hash from the metadata table
+    tip: Option<(u64, Vec<u8>)>,
+}
+
+impl BlockHashResolver {
+    /// Load the tip from the metadata table
+    pub(crate) fn new(read: &dyn KvRead) -> ReaderResult<Self> {
+        Ok(Self::from_metadata(metadata::read_chain_metadata_in(read)?.as_ref()))
+    }
+
+    /// Build from already-read chain metadata
+    pub(crate) fn from_metadata(metadata: Option<&ChainMetadata>) -> Self {
+        let tip = metadata.and_then(|m| {
+            let hash = hex::decode(m.best_block_hash.as_deref()?).ok()?;
+            Some((m.best_block_height, hash))
+        });
+        Self { tip }
+    }
+
+    /// Hash of the block at `height` whose decoded header is `header`
+    pub(crate) fn resolve_block_hash(
+        &self,
+        read: &dyn KvRead,
+        height: u64,
+        header: &BlockHeader,
+    ) -> ResolvedHash {
+        self.resolve_confirmed(read, height)
+            .unwrap_or_else(|| ResolvedHash { bytes: header.hash().to_vec(), source: HashSource::Computed })
+    }
+
+    /// Hash of the block at `height` as recorded by the node, without decoding its own header
+    pub(crate) fn resolve_confirmed(&self, read: &dyn KvRead, height: u64) -> Option<ResolvedHash> {
+        if let Some((tip_height, hash)) = &self.tip {
+            if *tip_height == height {
+                return Some(ResolvedHash { bytes: hash.clone(), source: HashSource::Metadata });
+            }
+        }
+
+        let next_data = read.get("headers", &(height + 1).to_le_bytes()).ok()??;
+        Some(ResolvedHash { bytes: prev_hash_of(&next_data)?, source: HashSource::NextHeader })
+    }
+}
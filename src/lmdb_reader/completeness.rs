@@ -0,0 +1,86 @@
+// File: src/lmdb_reader/completeness.rs
+// Version: 1.1.0 - Table presence through KvRead
+// Tree: tari-lmdb-inspector/src/lmdb_reader/completeness.rs
+//
+// Pruned and header-only databases are missing transaction rows. Readers report this
+// explicitly so interfaces can say "not available" instead of showing 0 transactions.
+
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::backend::open_backend;
+use super::ReaderResult;
+use super::metadata::{self, PruningInfo};
+
+/// Tables that hold per-block transaction data
+pub const TRANSACTION_TABLES: [&str; 3] = ["utxos", "inputs", "kernels"];
+
+/// Availability of transaction data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum DataCompleteness {
+    /// All transaction tables are present
+    #[default]
+    Full,
+    /// Only headers are stored; transaction tables are missing
+    HeadersOnly,
+    /// Transaction data below `horizon` has been pruned
+    Pruned { horizon: u64 },
+}
+
+impl DataCompleteness {
+    /// Completeness from the set of transaction tables that could be opened
+    pub fn from_tables(present: &[bool]) -> Self {
+        if present.iter().all(|p| *p) {
+            DataCompleteness::Full
+        } else {
+            DataCompleteness::HeadersOnly
+        }
+    }
+
+    /// Refine full completeness with the node's pruning information
+    pub fn with_pruning(self, pruning: Option<PruningInfo>) -> Self {
+        match (self, pruning) {
+            (DataCompleteness::Full, Some(info)) if info.is_pruned() => DataCompleteness::Pruned { horizon: info.pruned_height },
+            (other, _) => other,
+        }
+    }
+
+    /// Whether transaction rows can be expected for a block at `height`
+    pub fn has_transactions_at(&self, height: u64) -> bool {
+        match self {
+            DataCompleteness::Full => true,
+            DataCompleteness::HeadersOnly => false,
+            DataCompleteness::Pruned { horizon } => height >= *horizon,
+        }
+    }
+
+    /// Completeness for one block at `height` given the database-wide completeness
+    pub fn at_height(&self, height: u64) -> Self {
+        match self {
+            DataCompleteness::Pruned { .. } if self.has_transactions_at(height) => DataCompleteness::Full,
+            other => *other,
+        }
+    }
+
+    /// Short human readable label
+    pub fn label(&self) -> String {
+        match self {
+            DataCompleteness::Full => "full".to_string(),
+            DataCompleteness::HeadersOnly => "headers only (transaction tables missing)".to_string(),
+            DataCompleteness::Pruned { horizon } => format!("pruned (transaction data from height {})", horizon),
+        }
+    }
+}
+
+/// Check which transaction tables exist in the database at `path`
+pub fn detect_completeness(path: &Path) -> ReaderResult<DataCompleteness> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+
+    let present = TRANSACTION_TABLES.iter()
+        .map(|name| read.has_table(name))
+        .collect::<ReaderResult<Vec<bool>>>()?;
+    Ok(DataCompleteness::from_tables(&present).with_pruning(metadata::read_pruning_info(&*read)?))
+}
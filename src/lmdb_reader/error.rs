@@ -0,0 +1,160 @@
+// File: src/lmdb_reader/error.rs
+// Version: 1.2.1 - Retry and status helpers inside impl ReaderError
+// Tree: tari-lmdb-inspector/src/lmdb_reader/error.rs
+//
+// Library callers can match on what went wrong (missing block, C29 header, LMDB failure,
+// timeout) instead of parsing anyhow strings. Interface code still uses anyhow and picks
+// these up through `?`. Web handlers return the error itself: its `IntoResponse` picks the
+// status, so a timeout is a 503 the client can retry rather than a 500.
+
+use std::fmt;
+use std::path::PathBuf;
+use axum::{http::{header, StatusCode}, response::{IntoResponse, Response}};
+
+/// Seconds a client is asked to wait before retrying a busy or timed-out read
+pub const RETRY_AFTER_SECONDS: u64 = 5;
+
+/// Result alias for reader operations
+pub type ReaderResult<T> = std::result::Result<T, ReaderError>;
+
+/// Errors produced while reading the Tari LMDB database
+#[derive(Debug)]
+pub enum ReaderError {
+    /// The database path cannot be handed to LMDB (not UTF-8, and no UTF-8 alias could be made)
+    InvalidPath(PathBuf),
+    /// LMDB environment, database, transaction or cursor failure
+    Lmdb(lmdb_zero::Error),
+    /// LMDB cannot serve the read right now (reader table full, map resized by the node)
+    LmdbBusy(String),
+    /// Failure in a `KvBackend` binding other than lmdb-zero
+    Backend { backend: &'static str, message: String },
+    /// A required table is missing from the database
+    TableNotFound(String),
+    /// No header is stored at this height
+    BlockNotFound(u64),
+    /// The header exists but cannot be decoded by the linked Tari version (e.g. C29-mined)
+    UnsupportedHeader { height: u64, source: bincode::Error },
+    /// A table's key or value layout differs from the one this build reads
+    SchemaMismatch { table: &'static str, message: String },
+    /// A row in a table could not be decoded
+    Decode { table: &'static str, source: bincode::Error },
+    /// A decoded row was rejected by Tari's own validation
+    InvalidRow { table: &'static str, message: String },
+    /// An async read exceeded its timeout
+    Timeout { operation: String, seconds: u64 },
+    /// A blocking read panicked or its task failed
+    Task { operation: String, message: String },
+    /// Transaction data at this height was removed by a pruned node
+    BelowPruningHorizon { height: u64, horizon: u64 },
+    /// The caller cancelled the read (Ctrl-C, client disconnect or timeout)
+    Cancelled,
+    /// A caller-supplied argument is out of range
+    InvalidArgument(String),
+}
+
+impl fmt::Display for ReaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReaderError::InvalidPath(path) => write!(f, "Invalid path: {:?}", path),
+            ReaderError::Lmdb(e) => write!(f, "LMDB error: {}", e),
+            ReaderError::LmdbBusy(reason) => write!(f, "LMDB is busy ({}); retry shortly", reason),
+            ReaderError::Backend { backend, message } => write!(f, "LMDB error ({}): {}", backend, message),
+            ReaderError::TableNotFound(name) => write!(f, "Table '{}' not found in database", name),
+            ReaderError::BlockNotFound(height) => write!(f, "Block not found at height {}", height),
+            ReaderError::UnsupportedHeader { height, source } => write!(
+                f,
+                "Cannot read block {} - was mined using C29 Cuckaroo 29 algorithm: {}. \
+                Update Tari dependencies to support C29-mined blocks.",
+                height, source
+            ),
+            ReaderError::SchemaMismatch { table, message } => write!(
+                f,
+                "Schema mismatch in {}: {}. The database was written by a different base node version.",
+                table, message
+            ),
+            ReaderError::Decode { table, source } => write!(f, "Failed to decode {} row: {}", table, source),
+            ReaderError::InvalidRow { table, message } => write!(f, "Invalid {} row: {}", table, message),
+            ReaderError::Timeout { operation, seconds } => write!(f, "LMDB {} timed out after {}s", operation, seconds),
+            ReaderError::Task { operation, message } => write!(f, "LMDB {} failed: {}", operation, message),
+            ReaderError::BelowPruningHorizon { height, horizon } => write!(
+                f,
+                "Block {} is below the pruning horizon; transaction data is available from height {}",
+                height, horizon
+            ),
+            ReaderError::Cancelled => write!(f, "Read cancelled"),
+            ReaderError::InvalidArgument(message) => write!(f, "Invalid argument: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ReaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReaderError::Lmdb(e) => Some(e),
+            ReaderError::UnsupportedHeader { source, .. } => Some(source.as_ref()),
+            ReaderError::Decode { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<lmdb_zero::Error> for ReaderError {
+    fn from(e: lmdb_zero::Error) -> Self {
+        match e {
+            lmdb_zero::Error::Code(code) if code == lmdb_zero::error::READERS_FULL || code == lmdb_zero::error::MAP_RESIZED => {
+                ReaderError::LmdbBusy(e.to_string())
+            }
+            other => ReaderError::Lmdb(other),
+        }
+    }
+}
+
+impl ReaderError {
+    /// Whether this error means the requested block simply does not exist
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, ReaderError::BlockNotFound(_))
+    }
+
+    /// Whether this error means the data was pruned by the node
+    pub fn is_pruned(&self) -> bool {
+        matches!(self, ReaderError::BelowPruningHorizon { .. })
+    }
+
+    /// Whether the read stopped because it was cancelled
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self, ReaderError::Cancelled)
+    }
+
+    /// Whether the same read may succeed if retried later
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ReaderError::Timeout { .. } | ReaderError::LmdbBusy(_))
+    }
+
+    /// HTTP status the web API answers this error with
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ReaderError::BlockNotFound(_) => StatusCode::NOT_FOUND,
+            ReaderError::BelowPruningHorizon { .. } => StatusCode::GONE,
+            ReaderError::SchemaMismatch { .. } | ReaderError::UnsupportedHeader { .. } => StatusCode::CONFLICT,
+            ReaderError::Timeout { .. } | ReaderError::LmdbBusy(_) => StatusCode::SERVICE_UNAVAILABLE,
+            ReaderError::InvalidArgument(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+/// The one mapping from reader errors to responses; the message is the plain-text body
+impl IntoResponse for ReaderError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        match status {
+            StatusCode::GONE => println!("✂️  {}", self),
+            StatusCode::NOT_FOUND | StatusCode::BAD_REQUEST => {}
+            _ => eprintln!("❌ {}", self),
+        }
+        if self.is_retryable() {
+            return (status, [(header::RETRY_AFTER, RETRY_AFTER_SECONDS.to_string())], self.to_string()).into_response();
+        }
+        (status, self.to_string()).into_response()
+    }
+}
@@ -0,0 +1,99 @@
+// File: src/lmdb_reader/filter.rs
+// Version: 1.0.0 - Unified, composable block filter shared by CLI, web and TUI
+// Tree: tari-lmdb-inspector/src/lmdb_reader/filter.rs
+
+use super::BlockSummary;
+
+/// Block selection filter
+///
+/// Positional filters (`LastN`, `Range`, `Specific`) and predicates (`PowAlgorithm`,
+/// `MinKernels`) can be combined with `and`, e.g.
+/// `BlockFilter::Range(100, 200).and(BlockFilter::PowAlgorithm("Sha3x".into()))`.
+/// `LastN` is applied after every other condition, so it means "the last N matching blocks".
+#[derive(Debug, Clone)]
+pub enum BlockFilter {
+    LastN(usize),           // Show last N blocks
+    Range(u64, u64),        // Show blocks from start to end (inclusive)
+    Specific(u64),          // Show specific block height
+    PowAlgorithm(String),   // Only blocks mined with this PoW algorithm (case-insensitive)
+    MinKernels(u64),        // Only blocks with at least this many kernels
+    All(Vec<BlockFilter>),  // Every contained filter must hold
+}
+
+impl BlockFilter {
+    /// Combine two filters so both must hold
+    pub fn and(self, other: BlockFilter) -> BlockFilter {
+        match (self, other) {
+            (BlockFilter::All(mut left), BlockFilter::All(right)) => {
+                left.extend(right);
+                BlockFilter::All(left)
+            }
+            (BlockFilter::All(mut left), other) => {
+                left.push(other);
+                BlockFilter::All(left)
+            }
+            (this, BlockFilter::All(mut right)) => {
+                right.insert(0, this);
+                BlockFilter::All(right)
+            }
+            (this, other) => BlockFilter::All(vec![this, other]),
+        }
+    }
+
+    /// Add the optional predicates shared by every interface
+    pub fn with_options(self, pow_algorithm: Option<String>, min_kernels: Option<u64>) -> BlockFilter {
+        let mut filter = self;
+        if let Some(algo) = pow_algorithm {
+            filter = filter.and(BlockFilter::PowAlgorithm(algo));
+        }
+        if let Some(min) = min_kernels {
+            filter = filter.and(BlockFilter::MinKernels(min));
+        }
+        filter
+    }
+
+    /// Whether a single block satisfies every non-positional condition
+    pub fn matches(&self, block: &BlockSummary) -> bool {
+        match self {
+            BlockFilter::LastN(_) => true,
+            BlockFilter::Range(start, end) => block.height >= *start && block.height <= *end,
+            BlockFilter::Specific(height) => block.height == *height,
+            BlockFilter::PowAlgorithm(algo) => block.header.pow_algorithm.eq_ignore_ascii_case(algo),
+            BlockFilter::MinKernels(min) => block.kernel_count.map_or(false, |count| count >= *min),
+            BlockFilter::All(filters) => filters.iter().all(|f| f.matches(block)),
+        }
+    }
+
+    /// Smallest `LastN` limit in this filter, if any
+    pub(crate) fn last_n(&self) -> Option<usize> {
+        match self {
+            BlockFilter::LastN(n) => Some(*n),
+            BlockFilter::All(filters) => filters.iter().filter_map(|f| f.last_n()).min(),
+            _ => None,
+        }
+    }
+
+    /// Inclusive height bounds implied by positional filters, for early termination in scans
+    pub fn height_bounds(&self) -> (u64, u64) {
+        match self {
+            BlockFilter::Range(start, end) => (*start, *end),
+            BlockFilter::Specific(height) => (*height, *height),
+            BlockFilter::All(filters) => filters.iter()
+                .map(|f| f.height_bounds())
+                .fold((0, u64::MAX), |(lo, hi), (l, h)| (lo.max(l), hi.min(h))),
+            _ => (0, u64::MAX),
+        }
+    }
+
+    /// Apply the filter to blocks in ascending height order
+    pub fn apply(&self, blocks: Vec<BlockSummary>) -> Vec<BlockSummary> {
+        let matching: Vec<BlockSummary> = blocks.into_iter().filter(|b| self.matches(b)).collect();
+        match self.last_n() {
+            Some(n) => {
+                let len = matching.len();
+                matching.into_iter().skip(len.saturating_sub(n)).collect()
+            }
+            None => matching,
+        }
+    }
+}
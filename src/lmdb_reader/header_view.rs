@@ -0,0 +1,280 @@
+// File: src/lmdb_reader/header_view.rs
+// Version: 1.1.0 - Zero-copy header decoding for list views; annotated field layout
+// Tree: tari-lmdb-inspector/src/lmdb_reader/header_view.rs
+//
+// A 1000-block list scan decodes every header, often three times (the block itself, the next
+// header for its hash, the previous one for kernel and output counts), and a full `BlockHeader`
+// allocates its PoW data and builds key types the list never shows. `HeaderView` reads the
+// fields a `BlockSummary` needs straight out of the LMDB row: integers in place, hashes and PoW
+// data as borrowed slices.
+//
+// The view walks the bincode layout of the linked Tari version's header and requires the row
+// to end exactly where the header does, so an unrecognised layout (another Tari version, an
+// unknown PoW algorithm such as C29) yields `None` and callers decode the full header instead.
+// Block detail views always decode the full header.
+//
+// `annotate_header` walks the same layout but keeps going past fields it does not recognise, and
+// reports each field's offset, length and decoded value for `inspect header --annotate`.
+
+use serde::Serialize;
+use tari_node_components::blocks::BlockHeader;
+
+use super::{BlockHeaderLite, BlockSummary};
+
+/// Length of the fixed-size hash fields (`FixedHash`)
+const HASH_LEN: usize = 32;
+
+/// PoW algorithm names by enum index, as the full header's `{:?}` prints them
+const POW_ALGORITHMS: [&str; 3] = ["RandomXM", "Sha3x", "RandomXT"];
+
+/// Header fields borrowed from an encoded header row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeaderView<'a> {
+    pub version: u16,
+    pub height: u64,
+    pub prev_hash: &'a [u8],
+    pub timestamp: u64,
+    pub output_mr: &'a [u8],
+    pub output_smt_size: u64,
+    pub kernel_mr: &'a [u8],
+    pub kernel_mmr_size: u64,
+    pub input_mr: &'a [u8],
+    pub total_kernel_offset: &'a [u8],
+    pub total_script_offset: &'a [u8],
+    pub nonce: u64,
+    pub pow_algorithm: &'static str,
+    pub pow_data: &'a [u8],
+    /// Length of the whole encoded header
+    pub raw_length: usize,
+}
+
+/// Reads bincode fields off the front of a row
+struct Fields<'a> {
+    rest: &'a [u8],
+}
+
+/// How a header field is encoded
+#[derive(Clone, Copy)]
+enum FieldKind {
+    U16,
+    U64,
+    /// Fixed-size hash
+    Hash,
+    /// u64 length prefix followed by that many bytes
+    Bytes,
+    /// u32 PoW algorithm enum index
+    PowAlgorithm,
+}
+
+/// Header fields in encoding order, as `HeaderView::parse` reads them
+const HEADER_FIELDS: [(&str, FieldKind); 17] = [
+    ("version", FieldKind::U16),
+    ("height", FieldKind::U64),
+    ("prev_hash", FieldKind::Hash),
+    ("timestamp", FieldKind::U64),
+    ("output_mr", FieldKind::Hash),
+    ("block_output_mr", FieldKind::Hash),
+    ("output_smt_size", FieldKind::U64),
+    ("kernel_mr", FieldKind::Hash),
+    ("kernel_mmr_size", FieldKind::U64),
+    ("input_mr", FieldKind::Hash),
+    ("total_kernel_offset", FieldKind::Bytes),
+    ("total_script_offset", FieldKind::Bytes),
+    ("nonce", FieldKind::U64),
+    ("pow.pow_algo", FieldKind::PowAlgorithm),
+    ("pow.pow_data", FieldKind::Bytes),
+    ("validator_node_mr", FieldKind::Hash),
+    ("validator_node_size", FieldKind::U64),
+];
+
+/// One field of an encoded header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeaderField {
+    pub name: String,
+    pub offset: usize,
+    pub length: usize,
+    /// Hex of the field's bytes
+    pub raw: String,
+    /// Decoded value
+    pub value: String,
+}
+
+/// Field-by-field layout of an encoded header
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HeaderLayout {
+    /// Length of the whole row
+    pub length: usize,
+    pub fields: Vec<HeaderField>,
+    /// Hex of bytes left after the last field that could be read
+    pub unparsed: String,
+    /// Whether the row has exactly the layout of the linked Tari version's header
+    pub matches: bool,
+}
+
+impl<'a> Fields<'a> {
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.rest.len() {
+            return None;
+        }
+        let (field, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Some(field)
+    }
+
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N)?.try_into().ok()
+    }
+
+    fn u16(&mut self) -> Option<u16> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> Option<u32> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> Option<u64> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    /// Length-prefixed byte string (`Vec<u8>`, serialized keys)
+    fn bytes(&mut self) -> Option<&'a [u8]> {
+        let len = usize::try_from(self.u64()?).ok()?;
+        self.take(len)
+    }
+}
+
+/// Annotate every field of an encoded header; stops at the first field the row is too short for
+pub fn annotate_header(data: &[u8]) -> HeaderLayout {
+    let mut fields = Fields { rest: data };
+    let mut annotated = Vec::new();
+    let mut recognised = true;
+    let offset = |fields: &Fields| data.len() - fields.rest.len();
+
+    let mut push = |name: String, start: usize, bytes: &[u8], value: String| {
+        annotated.push(HeaderField { name, offset: start, length: bytes.len(), raw: hex::encode(bytes), value });
+    };
+    let complete = HEADER_FIELDS.iter().all(|&(name, kind)| {
+        let start = offset(&fields);
+        let read = match kind {
+            FieldKind::U16 => fields.take(2).map(|b| push(name.to_string(), start, b, u16::from_le_bytes([b[0], b[1]]).to_string())),
+            FieldKind::U64 => fields.take(8).map(|b| push(name.to_string(), start, b, le_u64(b).to_string())),
+            FieldKind::Hash => fields.take(HASH_LEN).map(|b| push(name.to_string(), start, b, hex::encode(b))),
+            FieldKind::PowAlgorithm => fields.take(4).map(|b| {
+                let index = u32::from_le_bytes([b[0], b[1], b[2], b[3]]);
+                let algorithm = POW_ALGORITHMS.get(index as usize);
+                recognised &= algorithm.is_some();
+                push(name.to_string(), start, b, format!("{} ({})", index, algorithm.unwrap_or(&"unknown")));
+            }),
+            FieldKind::Bytes => fields.take(8).and_then(|prefix| {
+                let len = usize::try_from(le_u64(prefix)).ok()?;
+                push(format!("{} (length)", name), start, prefix, len.to_string());
+                let bytes = fields.take(len)?;
+                push(name.to_string(), start + 8, bytes, if bytes.is_empty() { "empty".to_string() } else { hex::encode(bytes) });
+                Some(())
+            }),
+        };
+        read.is_some()
+    });
+
+    HeaderLayout {
+        length: data.len(),
+        fields: annotated,
+        unparsed: hex::encode(fields.rest),
+        matches: complete && recognised && fields.rest.is_empty(),
+    }
+}
+
+fn le_u64(bytes: &[u8]) -> u64 {
+    let mut array = [0u8; 8];
+    array.copy_from_slice(bytes);
+    u64::from_le_bytes(array)
+}
+
+impl<'a> HeaderView<'a> {
+    /// View of an encoded header; `None` when the row does not have the expected layout
+    pub fn parse(data: &'a [u8]) -> Option<Self> {
+        let mut fields = Fields { rest: data };
+        let version = fields.u16()?;
+        let height = fields.u64()?;
+        let prev_hash = fields.take(HASH_LEN)?;
+        let timestamp = fields.u64()?;
+        let output_mr = fields.take(HASH_LEN)?;
+        let _block_output_mr = fields.take(HASH_LEN)?;
+        let output_smt_size = fields.u64()?;
+        let kernel_mr = fields.take(HASH_LEN)?;
+        let kernel_mmr_size = fields.u64()?;
+        let input_mr = fields.take(HASH_LEN)?;
+        let total_kernel_offset = fields.bytes()?;
+        let total_script_offset = fields.bytes()?;
+        let nonce = fields.u64()?;
+        let pow_algorithm = *POW_ALGORITHMS.get(fields.u32()? as usize)?;
+        let pow_data = fields.bytes()?;
+        let _validator_node_mr = fields.take(HASH_LEN)?;
+        let _validator_node_size = fields.u64()?;
+
+        fields.rest.is_empty().then_some(Self {
+            version,
+            height,
+            prev_hash,
+            timestamp,
+            output_mr,
+            output_smt_size,
+            kernel_mr,
+            kernel_mmr_size,
+            input_mr,
+            total_kernel_offset,
+            total_script_offset,
+            nonce,
+            pow_algorithm,
+            pow_data,
+            raw_length: data.len(),
+        })
+    }
+}
+
+impl From<(u64, String, HeaderView<'_>)> for BlockSummary {
+    fn from((height, hash, view): (u64, String, HeaderView<'_>)) -> Self {
+        Self {
+            height,
+            hash,
+            header: BlockHeaderLite {
+                version: view.version,
+                height: view.height,
+                previous_hash: hex::encode(view.prev_hash),
+                timestamp: view.timestamp,
+                nonce: view.nonce,
+                output_mr: hex::encode(view.output_mr),
+                kernel_mr: hex::encode(view.kernel_mr),
+                input_mr: hex::encode(view.input_mr),
+                total_kernel_offset: hex::encode(view.total_kernel_offset),
+                total_script_offset: hex::encode(view.total_script_offset),
+                pow_data_hash: if !view.pow_data.is_empty() { hex::encode(view.pow_data) } else { "empty".to_string() },
+                raw_header_length: view.raw_length,
+                pow_algorithm: view.pow_algorithm.to_string(),
+                kernel_mmr_size: view.kernel_mmr_size,
+                output_smt_size: view.output_smt_size,
+            },
+            kernel_count: None,
+            input_count: None,
+            output_count: None,
+        }
+    }
+}
+
+/// `prev_hash` of an encoded header, decoding the full header only when the view cannot
+pub(crate) fn prev_hash_of(data: &[u8]) -> Option<Vec<u8>> {
+    match HeaderView::parse(data) {
+        Some(view) => Some(view.prev_hash.to_vec()),
+        None => Some(bincode::deserialize::<BlockHeader>(data).ok()?.prev_hash.to_vec()),
+    }
+}
+
+/// (kernel MMR size, output SMT size) of an encoded header, decoding the full header only when
+/// the view cannot
+pub(crate) fn mmr_sizes_of(data: &[u8]) -> Option<(u64, u64)> {
+    match HeaderView::parse(data) {
+        Some(view) => Some((view.kernel_mmr_size, view.output_smt_size)),
+        None => bincode::deserialize::<BlockHeader>(data).ok().map(|h| (h.kernel_mmr_size, h.output_smt_size)),
+    }
+}
@@ -0,0 +1,132 @@
+// File: src/lmdb_reader/metadata.rs
+// Version: 1.3.0 - Reads through KvRead
+// Tree: tari-lmdb-inspector/src/lmdb_reader/metadata.rs
+//
+// The base node keeps chain metadata in a small table keyed by `MetadataKey as u32` (bincode,
+// little-endian). Each value is a bincode `MetadataValue` enum: a u32 variant tag followed by
+// the payload.
+
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::backend::{open_backend, KvRead};
+use super::ReaderResult;
+
+/// Name of the metadata table
+pub const METADATA_TABLE: &str = "metadata";
+
+/// Keys of the metadata table, matching the base node's `MetadataKey`
+#[derive(Debug, Clone, Copy)]
+#[repr(u32)]
+enum MetadataKey {
+    ChainHeight = 0,
+    BestBlock = 1,
+    AccumulatedWork = 2,
+    PruningHorizon = 3,
+    PrunedHeight = 4,
+    BestBlockTimestamp = 6,
+    MigrationVersion = 7,
+}
+
+/// Chain tip and node state as recorded by the base node
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ChainMetadata {
+    /// Height of the longest chain
+    pub best_block_height: u64,
+    /// Hash of the tip block (hex)
+    pub best_block_hash: Option<String>,
+    /// Timestamp of the tip block
+    pub best_block_timestamp: Option<u64>,
+    /// Total accumulated proof of work of the chain (as stored, usually 0x-prefixed hex)
+    pub accumulated_work: Option<String>,
+    pub pruning: PruningInfo,
+    /// Schema version written by the base node's database migrations
+    #[serde(default)]
+    pub migration_version: Option<u64>,
+}
+
+/// Pruning configuration and progress of the node that wrote the database
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PruningInfo {
+    /// Blocks of transaction data kept behind the tip (0 for archival nodes)
+    pub pruning_horizon: u64,
+    /// Lowest height that still has transaction data
+    pub pruned_height: u64,
+}
+
+impl PruningInfo {
+    pub fn is_pruned(&self) -> bool {
+        self.pruning_horizon > 0 && self.pruned_height > 0
+    }
+}
+
+/// Read pruning information in an open read transaction; `None` when the table is absent
+pub(crate) fn read_pruning_info(read: &dyn KvRead) -> ReaderResult<Option<PruningInfo>> {
+    if !read.has_table(METADATA_TABLE)? {
+        return Ok(None);
+    }
+
+    Ok(Some(PruningInfo {
+        pruning_horizon: read_u64(read, MetadataKey::PruningHorizon)?.unwrap_or(0),
+        pruned_height: read_u64(read, MetadataKey::PrunedHeight)?.unwrap_or(0),
+    }))
+}
+
+/// Read the chain metadata of the database at `path`; `None` when the table is absent
+pub fn read_chain_metadata(path: &Path) -> ReaderResult<Option<ChainMetadata>> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    read_chain_metadata_in(&*read)
+}
+
+/// Read the chain metadata in an open read transaction
+pub(crate) fn read_chain_metadata_in(read: &dyn KvRead) -> ReaderResult<Option<ChainMetadata>> {
+    if !read.has_table(METADATA_TABLE)? {
+        return Ok(None);
+    }
+
+    let Some(best_block_height) = read_u64(read, MetadataKey::ChainHeight)? else {
+        return Ok(None);
+    };
+
+    Ok(Some(ChainMetadata {
+        best_block_height,
+        best_block_hash: read_payload(read, MetadataKey::BestBlock)?.as_deref().and_then(decode_hash).map(hex::encode),
+        best_block_timestamp: read_u64(read, MetadataKey::BestBlockTimestamp)?,
+        accumulated_work: read_payload(read, MetadataKey::AccumulatedWork)?.as_deref().and_then(decode_string),
+        pruning: PruningInfo {
+            pruning_horizon: read_u64(read, MetadataKey::PruningHorizon)?.unwrap_or(0),
+            pruned_height: read_u64(read, MetadataKey::PrunedHeight)?.unwrap_or(0),
+        },
+        migration_version: read_u64(read, MetadataKey::MigrationVersion)?,
+    }))
+}
+
+/// Payload of a `MetadataValue` with the u32 variant tag stripped
+fn read_payload(read: &dyn KvRead, key: MetadataKey) -> ReaderResult<Option<Vec<u8>>> {
+    let value = read.get(METADATA_TABLE, &(key as u32).to_le_bytes())?;
+    Ok(value.and_then(|value| value.get(4..).map(<[u8]>::to_vec)))
+}
+
+/// A 32-byte hash, stored either as a raw array or as length-prefixed bytes
+fn decode_hash(payload: &[u8]) -> Option<&[u8]> {
+    match payload.len() {
+        32 => Some(payload),
+        40 if payload[..8] == 32u64.to_le_bytes() => Some(&payload[8..]),
+        _ => None,
+    }
+}
+
+/// A bincode string: u64 length followed by UTF-8 bytes
+fn decode_string(payload: &[u8]) -> Option<String> {
+    let len = u64::from_le_bytes(payload.get(..8)?.try_into().ok()?) as usize;
+    let bytes = payload.get(8..8 + len)?;
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+/// Decode a `MetadataValue` whose payload is a single u64
+fn read_u64(read: &dyn KvRead, key: MetadataKey) -> ReaderResult<Option<u64>> {
+    let payload = read_payload(read, key)?;
+    Ok(payload.as_deref().and_then(|payload| Some(u64::from_le_bytes(payload.get(..8)?.try_into().ok()?))))
+}
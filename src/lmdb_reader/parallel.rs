@@ -0,0 +1,98 @@
+// File: src/lmdb_reader/parallel.rs
+// Version: 1.1.0 - Chunked height-range scans on a rayon thread pool, with early-exit search
+// Tree: tari-lmdb-inspector/src/lmdb_reader/parallel.rs
+//
+// A read transaction is tied to its thread, so a big scan is split into height chunks and
+// each rayon worker takes its own `Snapshot` of the shared `StreamReader`. Chunk results are
+// collected in chunk order and flattened, so callers see the same order as a sequential scan.
+// `par_find_chunks` is the search variant: the first match stops every worker.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use rayon::prelude::*;
+
+use super::{ReaderResult, Snapshot, StreamReader};
+
+/// Heights handled by one worker transaction
+pub const DEFAULT_CHUNK_SIZE: u64 = 2_000;
+
+/// Ranges at most this long are scanned on the calling thread
+pub const PARALLEL_THRESHOLD: u64 = 5_000;
+
+/// Run `f(snapshot, chunk_start, chunk_end)` over `start..=end` in chunks of `chunk_size`
+/// heights and concatenate the results in height order. The first error wins.
+pub fn par_map_chunks<T, F>(
+    reader: &StreamReader,
+    start: u64,
+    end: u64,
+    chunk_size: u64,
+    f: F,
+) -> ReaderResult<Vec<T>>
+where
+    T: Send,
+    F: Fn(&Snapshot<'_>, u64, u64) -> ReaderResult<Vec<T>> + Sync,
+{
+    if start > end {
+        return Ok(Vec::new());
+    }
+    if end - start < PARALLEL_THRESHOLD {
+        return f(&reader.snapshot()?, start, end);
+    }
+
+    let chunk_size = chunk_size.max(1);
+    let chunks: Vec<(u64, u64)> = (start..=end)
+        .step_by(chunk_size as usize)
+        .map(|chunk_start| (chunk_start, chunk_start.saturating_add(chunk_size - 1).min(end)))
+        .collect();
+
+    let results = chunks
+        .into_par_iter()
+        .map(|(chunk_start, chunk_end)| f(&reader.snapshot()?, chunk_start, chunk_end))
+        .collect::<ReaderResult<Vec<Vec<T>>>>()?;
+
+    Ok(results.into_iter().flatten().collect())
+}
+
+/// Run `f(snapshot, chunk_start, chunk_end, stop)` over `start..=end` in chunks of `chunk_size`
+/// heights until one chunk returns `Some`. Once a match (or an error) is found no new chunks
+/// start and `stop` is set, so running workers should check it and return `Ok(None)` early.
+/// Which match wins when several chunks have one is unspecified.
+pub fn par_find_chunks<T, F>(
+    reader: &StreamReader,
+    start: u64,
+    end: u64,
+    chunk_size: u64,
+    f: F,
+) -> ReaderResult<Option<T>>
+where
+    T: Send,
+    F: Fn(&Snapshot<'_>, u64, u64, &AtomicBool) -> ReaderResult<Option<T>> + Sync,
+{
+    let stop = AtomicBool::new(false);
+    if start > end {
+        return Ok(None);
+    }
+    if end - start < PARALLEL_THRESHOLD {
+        return f(&reader.snapshot()?, start, end, &stop);
+    }
+
+    let chunk_size = chunk_size.max(1);
+    let chunks: Vec<(u64, u64)> = (start..=end)
+        .step_by(chunk_size as usize)
+        .map(|chunk_start| (chunk_start, chunk_start.saturating_add(chunk_size - 1).min(end)))
+        .collect();
+
+    let found = chunks
+        .into_par_iter()
+        .find_map_any(|(chunk_start, chunk_end)| {
+            if stop.load(Ordering::Relaxed) {
+                return None;
+            }
+            let result = reader.snapshot().and_then(|snapshot| f(&snapshot, chunk_start, chunk_end, &stop)).transpose();
+            if result.is_some() {
+                stop.store(true, Ordering::Relaxed);
+            }
+            result
+        });
+
+    found.transpose()
+}
@@ -0,0 +1,131 @@
+// File: src/lmdb_reader/paths.rs
+// Version: 1.1.0 - Per-call aliases in a private per-user directory, removed on drop
+// Tree: tari-lmdb-inspector/src/lmdb_reader/paths.rs
+//
+// lmdb-zero opens environments from a `&str`, so a database under a directory whose name is not
+// valid UTF-8 (possible on Linux and macOS) could not be opened at all. `env_path` passes UTF-8
+// paths through unchanged and, on Unix, opens any other path through a symlink with a UTF-8
+// name; LMDB follows it to the real files. The symlinks live in a 0700 directory under the
+// cache directory that must be owned by the current user, and each one is removed when the
+// `EnvPath` holding it is dropped. Windows paths are UTF-16 and LMDB converts its UTF-8
+// argument back to UTF-16, so only unpaired surrogates are rejected.
+
+use std::borrow::Cow;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+use super::{ReaderError, ReaderResult};
+
+/// Directory in the cache directory holding UTF-8 aliases of non-UTF-8 environment paths
+const ALIAS_DIR: &str = "paths";
+
+/// An environment path as the string the LMDB binding opens; an alias made for a non-UTF-8
+/// path is removed on drop, so keep this alive until the environment is open
+#[derive(Debug)]
+pub struct EnvPath<'a> {
+    path: Cow<'a, str>,
+    alias: bool,
+}
+
+impl Deref for EnvPath<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.path
+    }
+}
+
+impl EnvPath<'_> {
+    /// Detach from the borrowed path; the alias, if any, now lives as long as the result
+    pub fn into_owned(mut self) -> EnvPath<'static> {
+        let path = std::mem::take(&mut self.path).into_owned();
+        EnvPath { path: Cow::Owned(path), alias: std::mem::take(&mut self.alias) }
+    }
+}
+
+impl Drop for EnvPath<'_> {
+    fn drop(&mut self) {
+        if self.alias {
+            let _ = std::fs::remove_file(&*self.path);
+        }
+    }
+}
+
+/// `path` as the string the LMDB binding opens
+pub fn env_path(path: &Path) -> ReaderResult<EnvPath<'_>> {
+    match path.to_str() {
+        Some(path_str) => Ok(EnvPath { path: Cow::Borrowed(path_str), alias: false }),
+        None => utf8_alias(path)
+            .map(|alias| EnvPath { path: Cow::Owned(alias), alias: true })
+            .ok_or_else(|| ReaderError::InvalidPath(path.to_path_buf())),
+    }
+}
+
+/// A UTF-8 symlink to `path`, unique to this call
+#[cfg(unix)]
+fn utf8_alias(path: &Path) -> Option<String> {
+    use std::os::unix::ffi::OsStrExt;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    static NEXT_ALIAS: AtomicU64 = AtomicU64::new(0);
+
+    let target = std::path::absolute(path).ok()?;
+    let digest = blake3::hash(target.as_os_str().as_bytes());
+    let name = format!(
+        "{}-{}-{}",
+        hex::encode(&digest.as_bytes()[..8]),
+        std::process::id(),
+        NEXT_ALIAS.fetch_add(1, Ordering::Relaxed)
+    );
+    let alias = alias_dir()?.join(name);
+
+    // A leftover from an earlier process with the same PID; the directory is ours alone
+    if std::os::unix::fs::symlink(&target, &alias).is_err() {
+        std::fs::remove_file(&alias).ok()?;
+        std::os::unix::fs::symlink(&target, &alias).ok()?;
+    }
+    alias.into_os_string().into_string().ok()
+}
+
+/// The alias directory, created 0700; `None` unless it is a real directory owned by this user
+/// that no one else can write to
+#[cfg(unix)]
+fn alias_dir() -> Option<PathBuf> {
+    use std::os::unix::fs::{DirBuilderExt, MetadataExt};
+
+    let dir = crate::cache::default_dir().join(ALIAS_DIR);
+    std::fs::create_dir_all(dir.parent()?).ok()?;
+    match std::fs::DirBuilder::new().mode(0o700).create(&dir) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+        Err(_) => return None,
+    }
+
+    let meta = std::fs::symlink_metadata(&dir).ok()?;
+    let private = meta.is_dir() && meta.uid() == unsafe { libc::geteuid() } && meta.mode() & 0o077 == 0;
+    private.then_some(dir)
+}
+
+#[cfg(not(unix))]
+fn utf8_alias(_path: &Path) -> Option<String> {
+    None
+}
+
+/// The user's home directory (`HOME`, or `USERPROFILE` on Windows)
+pub fn home_dir() -> Option<PathBuf> {
+    let var = if cfg!(windows) { "USERPROFILE" } else { "HOME" };
+    std::env::var_os(var).filter(|home| !home.is_empty()).map(PathBuf::from)
+}
+
+/// Where a base node keeps its database for `network` with the default data directory:
+/// `~/.tari/<network>/data/base_node/db` on Linux and macOS,
+/// `%USERPROFILE%\.tari\<network>\data\base_node\db` on Windows
+pub fn default_database_path(network: &str) -> PathBuf {
+    home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".tari")
+        .join(network.to_ascii_lowercase())
+        .join("data")
+        .join("base_node")
+        .join("db")
+}
@@ -0,0 +1,65 @@
+// File: src/lmdb_reader/script.rs
+// Version: 1.0.0 - TariScript opcode listing and common pattern detection
+// Tree: tari-lmdb-inspector/src/lmdb_reader/script.rs
+//
+// Works on the opcode strings from `TariScript::to_opcodes`, e.g. `["Nop"]` or
+// `["PushPubKey(<hex>)"]`. Most outputs use one of two scripts: `Nop` (interactive payments
+// and coinbases) or a single `PushPubKey` (one-sided payments to the pushed key).
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Recognised script shapes
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "pattern", rename_all = "snake_case")]
+pub enum ScriptPattern {
+    /// No opcodes
+    Empty,
+    /// `Nop`: spendable by whoever knows the spending key
+    #[default]
+    Nop,
+    /// `PushPubKey(K)`: one-sided payment to `public_key`
+    OneSided { public_key: String },
+    /// Anything else; see the opcode list
+    Custom,
+}
+
+impl ScriptPattern {
+    /// Short label for CLI and HTML output
+    pub fn label(&self) -> &'static str {
+        match self {
+            ScriptPattern::Empty => "Empty",
+            ScriptPattern::Nop => "Nop",
+            ScriptPattern::OneSided { .. } => "One-sided",
+            ScriptPattern::Custom => "Custom",
+        }
+    }
+}
+
+/// Decoded script of an output
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+pub struct ScriptSummary {
+    pub pattern: ScriptPattern,
+    /// One entry per opcode, in execution order
+    pub opcodes: Vec<String>,
+}
+
+impl ScriptSummary {
+    pub fn from_opcodes(opcodes: Vec<String>) -> Self {
+        let pattern = match opcodes.as_slice() {
+            [] => ScriptPattern::Empty,
+            [op] if op == "Nop" => ScriptPattern::Nop,
+            [op] => match op.strip_prefix("PushPubKey(").and_then(|rest| rest.strip_suffix(')')) {
+                Some(public_key) => ScriptPattern::OneSided { public_key: public_key.to_string() },
+                None => ScriptPattern::Custom,
+            },
+            _ => ScriptPattern::Custom,
+        };
+        Self { pattern, opcodes }
+    }
+
+    /// Opcodes joined with spaces, as the script is usually written
+    pub fn text(&self) -> String {
+        self.opcodes.join(" ")
+    }
+}
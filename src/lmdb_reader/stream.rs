@@ -0,0 +1,203 @@
+// File: src/lmdb_reader/stream.rs
+// Version: 1.3.0 - Reads through KvBackend cursors instead of lmdb-zero handles
+// Tree: tari-lmdb-inspector/src/lmdb_reader/stream.rs
+//
+// The `read_*` functions collect into `Vec`s, which is fine for a page of blocks but not for
+// exports and analytics over the whole chain. `StreamReader` owns the `KvBackend`, a
+// `Snapshot` holds one read transaction, and `HeaderIter` / `BlockTxIter` walk a cursor in
+// that transaction, decoding one row per `next()` so memory stays constant.
+//
+// ```no_run
+// use tari_lmdb_inspector::lmdb_reader::stream::StreamReader;
+// use tari_lmdb_inspector::lmdb_reader::TransactionKernelRowData;
+//
+// let reader = StreamReader::open("/home/me/.tari/mainnet/data/base_node/db".as_ref())?;
+// let snapshot = reader.snapshot()?;
+// let fees: u64 = snapshot.kernels::<TransactionKernelRowData>(&[])?
+//     .filter_map(Result::ok)
+//     .map(|row| row.kernel.fee.0)
+//     .sum();
+// # Ok::<(), tari_lmdb_inspector::lmdb_reader::ReaderError>(())
+// ```
+
+use std::marker::PhantomData;
+use std::path::Path;
+use serde::de::DeserializeOwned;
+
+use tari_node_components::blocks::BlockHeader;
+use super::{ReaderError, ReaderResult};
+use super::backend::{open_backend, KvBackend, KvCursor, KvRead};
+use super::block_hash::BlockHashResolver;
+
+/// An open environment, shareable across threads; each thread takes its own `Snapshot`
+pub struct StreamReader {
+    resolver: BlockHashResolver,
+    backend: Box<dyn KvBackend>,
+}
+
+/// One read transaction on a `StreamReader`
+pub struct Snapshot<'r> {
+    read: Box<dyn KvRead + 'r>,
+    reader: &'r StreamReader,
+}
+
+/// Headers in ascending height order; undecodable (C29) headers yield `UnsupportedHeader`
+pub struct HeaderIter<'txn> {
+    cursor: Box<dyn KvCursor + 'txn>,
+    start: u64,
+    end: u64,
+    started: bool,
+    done: bool,
+}
+
+/// Rows of a block-hash-prefixed table (`utxos`, `inputs`, `kernels`) decoded as `T`
+/// An empty prefix streams the whole table
+pub struct BlockTxIter<'txn, T> {
+    cursor: Box<dyn KvCursor + 'txn>,
+    table: &'static str,
+    prefix: Vec<u8>,
+    started: bool,
+    done: bool,
+    _row: PhantomData<T>,
+}
+
+impl StreamReader {
+    pub fn open(path: &Path) -> ReaderResult<Self> {
+        let backend = open_backend(path)?;
+        let resolver = {
+            let read = backend.read()?;
+            // Fails with `TableNotFound` when this is not a base node database
+            read.entries("headers")?;
+            BlockHashResolver::new(&*read)?
+        };
+        Ok(Self { resolver, backend })
+    }
+
+    /// Begin a read transaction; only one may be open per thread
+    pub fn snapshot(&self) -> ReaderResult<Snapshot<'_>> {
+        Ok(Snapshot {
+            read: self.backend.read()?,
+            reader: self,
+        })
+    }
+}
+
+impl<'r> Snapshot<'r> {
+    /// Highest header key, if any
+    pub fn tip_height(&self) -> ReaderResult<Option<u64>> {
+        Ok(self.read.last("headers")?.map(|(key, _)| decode_height(&key)))
+    }
+
+    /// Raw block hash of a decoded header, as used to prefix the transaction tables
+    pub fn block_hash(&self, height: u64, header: &BlockHeader) -> Vec<u8> {
+        self.reader.resolver.resolve_block_hash(&*self.read, height, header).bytes
+    }
+
+    /// Headers with heights in `start..=end`
+    pub fn headers(&self, start: u64, end: u64) -> ReaderResult<HeaderIter<'_>> {
+        Ok(HeaderIter {
+            cursor: self.read.cursor("headers")?,
+            start,
+            end,
+            started: false,
+            done: start > end,
+        })
+    }
+
+    /// Output rows under `block_hash` (all outputs when empty)
+    pub fn outputs<T: DeserializeOwned>(&self, block_hash: &[u8]) -> ReaderResult<BlockTxIter<'_, T>> {
+        self.rows("utxos", block_hash)
+    }
+
+    /// Rows in the `utxos` table, from the table stats (no scan)
+    pub fn output_count(&self) -> ReaderResult<u64> {
+        self.read.entries("utxos")
+    }
+
+    /// Input rows under `block_hash` (all inputs when empty)
+    pub fn inputs<T: DeserializeOwned>(&self, block_hash: &[u8]) -> ReaderResult<BlockTxIter<'_, T>> {
+        self.rows("inputs", block_hash)
+    }
+
+    /// Kernel rows under `block_hash` (all kernels when empty)
+    pub fn kernels<T: DeserializeOwned>(&self, block_hash: &[u8]) -> ReaderResult<BlockTxIter<'_, T>> {
+        self.rows("kernels", block_hash)
+    }
+
+    fn rows<T: DeserializeOwned>(&self, table: &'static str, prefix: &[u8]) -> ReaderResult<BlockTxIter<'_, T>> {
+        Ok(BlockTxIter {
+            cursor: self.read.cursor(table)?,
+            table,
+            prefix: prefix.to_vec(),
+            started: false,
+            done: false,
+            _row: PhantomData,
+        })
+    }
+}
+
+impl Iterator for HeaderIter<'_> {
+    type Item = ReaderResult<(u64, BlockHeader)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let entry = if self.started {
+            self.cursor.next()
+        } else {
+            self.started = true;
+            self.cursor.seek(&self.start.to_le_bytes())
+        };
+
+        let (key, value) = match entry {
+            Ok(Some(entry)) => entry,
+            other => {
+                self.done = true;
+                return other.err().map(Err);
+            }
+        };
+        let height = decode_height(key);
+        if height > self.end {
+            self.done = true;
+            return None;
+        }
+        Some(bincode::deserialize::<BlockHeader>(value)
+            .map(|header| (height, header))
+            .map_err(|e| ReaderError::UnsupportedHeader { height, source: e }))
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for BlockTxIter<'_, T> {
+    type Item = ReaderResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let entry = match (self.started, self.prefix.is_empty()) {
+            (true, _) => self.cursor.next(),
+            (false, true) => self.cursor.first(),
+            (false, false) => self.cursor.seek(&self.prefix),
+        };
+        self.started = true;
+
+        // The end of the table ends the stream quietly; a read error is yielded once
+        let (key, value) = match entry {
+            Ok(Some(entry)) => entry,
+            other => {
+                self.done = true;
+                return other.err().map(Err);
+            }
+        };
+        if !key.starts_with(&self.prefix) {
+            self.done = true;
+            return None;
+        }
+        Some(bincode::deserialize(value).map_err(|e| ReaderError::Decode { table: self.table, source: e }))
+    }
+}
+
+pub(crate) fn decode_height(key: &[u8]) -> u64 {
+    u64::from_le_bytes(key.try_into().unwrap_or([0; 8]))
+}
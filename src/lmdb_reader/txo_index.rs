@@ -0,0 +1,54 @@
+// File: src/lmdb_reader/txo_index.rs
+// Version: 1.1.0 - Lookups through KvRead
+// Tree: tari-lmdb-inspector/src/lmdb_reader/txo_index.rs
+//
+// `txos_hash_to_index` maps an output hash to its `utxos` key (mined block hash + output
+// hash); spent outputs stay in `utxos`. `deleted_txo_hash_to_header_index` maps the hash of
+// every spent output to the height and hash of the block that spent it. Index values are
+// located by their leading or trailing bytes, so raw and length-prefixed bincode values both
+// decode.
+
+use super::backend::KvRead;
+use super::{ReaderResult, TransactionOutputRowData};
+
+/// Index table mapping output hashes to their `utxos` key
+pub(crate) const TXO_INDEX_TABLE: &str = "txos_hash_to_index";
+
+/// Index table mapping spent output hashes to the spending block
+pub(crate) const DELETED_TXO_INDEX_TABLE: &str = "deleted_txo_hash_to_header_index";
+
+/// Output linking through the index tables; lookups in an absent table find nothing
+pub(crate) struct TxoIndexes {
+    /// Whether the spent output index exists, so an output missing from it is known unspent
+    pub(crate) deleted_txo_index: bool,
+}
+
+impl TxoIndexes {
+    pub(crate) fn open(read: &dyn KvRead) -> ReaderResult<Self> {
+        Ok(Self { deleted_txo_index: read.has_table(DELETED_TXO_INDEX_TABLE)? })
+    }
+
+    /// Height of the block that spent `output_hash`, or `None` while it is unspent
+    pub(crate) fn spent_height(&self, read: &dyn KvRead, output_hash: &[u8]) -> Option<u64> {
+        let value = read.get(DELETED_TXO_INDEX_TABLE, output_hash).ok()??;
+        Some(u64::from_le_bytes(value.get(..8)?.try_into().ok()?))
+    }
+
+    /// The `utxos` row that created `output_hash`, spent or not
+    pub(crate) fn mined_output(&self, read: &dyn KvRead, output_hash: &[u8]) -> Option<TransactionOutputRowData> {
+        let key = output_key(read, output_hash)?;
+        let value = read.get("utxos", &key).ok()??;
+        bincode::deserialize(&value).ok()
+    }
+}
+
+/// `utxos` key for `output_hash` from `txos_hash_to_index`
+pub(crate) fn output_key(read: &dyn KvRead, output_hash: &[u8]) -> Option<Vec<u8>> {
+    let value = read.get(TXO_INDEX_TABLE, output_hash).ok()??;
+    trailing(&value, 64).filter(|key| key.ends_with(output_hash)).map(<[u8]>::to_vec)
+}
+
+/// Last `len` bytes of an index value
+pub(crate) fn trailing(value: &[u8], len: usize) -> Option<&[u8]> {
+    value.len().checked_sub(len).map(|start| &value[start..])
+}
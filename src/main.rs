@@ -1,34 +1,118 @@
 // File: src/main.rs
-// Version: 3.1.1 - Multi-interface Tari blockchain explorer with LMDB key structure investigation (FIXED CLI)
+// Version: 3.27.3 - --version from version::VERSION
 // Tree: tari-lmdb-inspector/src/main.rs
 
 use std::path::PathBuf;
+use std::str::FromStr;
 use clap::{Parser, Subcommand};
-use anyhow::Result;
+use anyhow::{Context, Result};
+use tari_common::configuration::Network;
 
-// Core modules for multi-interface functionality
-mod lmdb_reader;
-mod cli_interface;
-mod tui_dashboard;
-mod web_server;
-mod data_models;
-
-// New debugging module for LMDB key structure investigation
-mod key_inspector;
-
-use crate::data_models::AppConfig;
+// All functionality lives in the library crate; this binary only parses arguments
+use tari_lmdb_inspector::{
+    audit, auth, backup, chain_archive, chain_events, cli_interface, daemon, db_check, genesis, key_inspector, mmr, notify, output_scanner, preflight, rate_limit, request_trace, shutdown, tui_dashboard, version, web_server,
+    analytics::{burns, coinbase, state_at, top_blocks},
+    cache::CacheDir,
+    denomination::Denomination,
+    limits::{self, Limits},
+    verbosity::Verbosity,
+    data_models::AppConfig,
+    lmdb_reader::{self, paths},
+    file_watcher::WatchMode,
+    progress::TerminalProgress,
+    theme::{Theme, ThemeName},
+    timezone::DisplayZone,
+};
 
 /// Command-line interface definition for the Tari LMDB Inspector
 /// Supports multiple interface modes: CLI, TUI, Web, and debugging
 #[derive(Parser)]
 #[command(name = "tari-lmdb-inspector")]
 #[command(about = "Multi-interface Tari blockchain explorer with TUI and Web dashboards")]
-#[command(version = "3.1.1")]
+// The crate version, as reported by /api/version and compared by the update check
+#[command(version = version::VERSION)]
 pub struct Cli {
     /// Path to the Tari LMDB database directory
-    /// Default: ~/.tari/mainnet/data/base_node/db
+    /// Default: ~/.tari/<network>/data/base_node/db (%USERPROFILE%\.tari\... on Windows)
     #[arg(short, long, value_name = "DB_PATH")]
-    pub database: PathBuf,
+    pub database: Option<PathBuf>,
+
+    /// Webhook URL to POST JSON alerts to on new tip, reorg or slow block (web and cli --follow)
+    #[arg(long, value_name = "URL")]
+    pub webhook_url: Option<String>,
+
+    /// Block interval in seconds above which a slow-block alert is sent
+    #[arg(long, value_name = "SECONDS", default_value_t = notify::DEFAULT_INTERVAL_THRESHOLD_SECS)]
+    pub webhook_interval_threshold: u64,
+
+    /// TOML file of `[[watch]]` commitments / kernel excesses to alert on in web mode
+    #[arg(long, value_name = "FILE")]
+    pub watchlist: Option<PathBuf>,
+
+    /// Network the database belongs to (mainnet, nextnet, esmeralda, ...); selects the emission schedule
+    #[arg(long, default_value = "mainnet")]
+    pub network: String,
+
+    /// Run with a synthetic demo chain instead of reading the database (cli, tui, web)
+    #[arg(long)]
+    pub demo: bool,
+
+    /// Directory for derived indexes and series kept between runs (default ~/.tari-lmdb-inspector)
+    #[arg(long, value_name = "DIR")]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Skip the startup check that the database decodes with this build and matches --network
+    #[arg(long)]
+    pub skip_preflight: bool,
+
+    /// Seconds allowed for a single LMDB read in web and TUI modes before it times out
+    #[arg(long, value_name = "SECONDS", default_value = "30")]
+    pub read_timeout: u64,
+
+    /// Color theme for the TUI and CLI output
+    #[arg(long, value_enum, default_value = "default")]
+    pub theme: ThemeName,
+
+    /// Disable colors (same as --theme monochrome; also honored via NO_COLOR or TERM=dumb)
+    #[arg(long)]
+    pub no_color: bool,
+
+    /// Time zone for CLI and TUI timestamps: utc, local or an IANA name (e.g. Europe/Berlin);
+    /// other than utc, API responses also carry a `timestamp_local` field
+    #[arg(long, value_name = "ZONE", default_value = "utc", value_parser = DisplayZone::parse)]
+    pub timezone: DisplayZone,
+
+    /// Unit for fees and amounts in CLI tables, the TUI and web pages (the JSON API always
+    /// carries both, e.g. `fee_ut` and `fee_t`)
+    #[arg(long, value_enum, default_value = "microtari")]
+    pub denom: Denomination,
+
+    /// Most dashboard / follow updates per second when the node writes continuously (e.g. 0.2
+    /// for one every 5 seconds while syncing); unlimited by default
+    #[arg(long, value_name = "PER_SECOND")]
+    pub max_update_rate: Option<f64>,
+
+    /// How to detect database changes: file events, falling back to polling on network
+    /// filesystems or when events are unavailable (auto), file events only, or polling only
+    #[arg(long, value_enum, default_value = "auto")]
+    pub watch: WatchMode,
+
+    /// Seconds between tip height reads when polling for changes
+    #[arg(long, value_name = "SECONDS", default_value = "5")]
+    pub poll_interval: u64,
+
+    /// Human-readable logging: quiet (errors and warnings only), normal, or debug (per-request
+    /// and per-block diagnostics such as the header analysis of every block read)
+    #[arg(long, value_enum, default_value = "normal")]
+    pub verbosity: Verbosity,
+
+    /// Same as --verbosity quiet
+    #[arg(short, long, conflicts_with_all = ["verbosity", "verbose"])]
+    pub quiet: bool,
+
+    /// Same as --verbosity debug
+    #[arg(short, long, conflicts_with = "verbosity")]
+    pub verbose: bool,
 
     /// Interface mode selection
     #[command(subcommand)]
@@ -49,6 +133,10 @@ pub enum InterfaceMode {
         /// Show specific block with transaction details
         #[arg(short, long)]
         detail: Option<u64>,
+
+        /// Show block with transaction details by its 64 hex character hash
+        #[arg(long, conflicts_with = "detail")]
+        hash: Option<String>,
         
         /// Show blocks in range (format: start-end)
         #[arg(short, long)]
@@ -57,14 +145,46 @@ pub enum InterfaceMode {
         /// Show specific block height
         #[arg(short, long)]
         block: Option<u64>,
+
+        /// Keep running and print each new block as it lands (like tail -f)
+        #[arg(short, long)]
+        follow: bool,
+
+        /// Only show blocks mined with this PoW algorithm (e.g. Sha3x, RandomXM)
+        #[arg(long)]
+        pow: Option<String>,
+
+        /// Only show blocks with at least this many kernels
+        #[arg(long)]
+        min_kernels: Option<u64>,
+
+        /// Report coinbase outputs still inside their maturity window and the total locked emission
+        #[arg(long)]
+        locked_coinbase: bool,
+
+        /// Report burn outputs, burn kernels and the cumulative burned amount over recent blocks
+        #[arg(long)]
+        burns: bool,
+
+        /// Chain state (kernel count, UTXO count, emission) at these heights, e.g. 10000,50000
+        #[arg(long, value_delimiter = ',', value_name = "HEIGHT")]
+        at_height: Vec<u64>,
     },
     
     /// Terminal UI dashboard (ratatui)
     /// Real-time blockchain monitoring with interactive interface
     Tui {
-        /// Refresh interval in seconds
+        /// Refresh interval in seconds when the LMDB file watcher is unavailable (and in --demo)
         #[arg(short, long, default_value = "5")]
         refresh: u64,
+
+        /// Only show blocks mined with this PoW algorithm (e.g. Sha3x, RandomXM)
+        #[arg(long)]
+        pow: Option<String>,
+
+        /// Only show blocks with at least this many kernels
+        #[arg(long)]
+        min_kernels: Option<u64>,
     },
     
     /// Web server with dashboard (axum + WebSocket)
@@ -81,8 +201,196 @@ pub enum InterfaceMode {
         /// Enable CORS for development
         #[arg(short, long)]
         cors: bool,
+
+        /// Requests per minute allowed per client IP (0 disables)
+        #[arg(long, default_value = "120")]
+        rate_limit: u32,
+
+        /// Maximum concurrent LMDB-backed API requests
+        #[arg(long, default_value = "4")]
+        max_concurrent: usize,
+
+        /// Require this bearer token on the dashboard, /api and /ws (browsers: open /?token=...);
+        /// printable ASCII without space, '"', ',', ';' or '\'
+        #[arg(long, value_parser = auth::parse_token)]
+        auth_token: Option<String>,
+
+        /// PEM certificate chain; serves HTTPS and WSS directly (requires --tls-key)
+        #[arg(long, requires = "tls_key")]
+        tls_cert: Option<PathBuf>,
+
+        /// PEM private key for --tls-cert
+        #[arg(long, requires = "tls_cert")]
+        tls_key: Option<PathBuf>,
+
+        /// Another base node database to serve as NAME=PATH (repeatable), under /node/NAME
+        #[arg(long = "node", value_name = "NAME=PATH")]
+        nodes: Vec<String>,
+
+        /// Most blocks in one /blocks/range or /blocks/batch response
+        #[arg(long, default_value_t = limits::DEFAULT_MAX_BLOCKS)]
+        max_blocks: usize,
+
+        /// Most inputs, outputs and kernels in one response, summed over its blocks
+        #[arg(long, default_value_t = limits::DEFAULT_MAX_TX_ITEMS)]
+        max_tx_items: usize,
+
+        /// Largest WebSocket message in bytes, sent or received
+        #[arg(long, default_value_t = limits::DEFAULT_MAX_WS_MESSAGE_BYTES)]
+        max_ws_message: usize,
+
+        /// Do not ask GitHub whether a newer inspector release exists at startup
+        #[arg(long)]
+        no_update_check: bool,
+
+        /// Detach into the background (Unix), writing a PID file and sending output to a log file
+        #[arg(long)]
+        daemon: bool,
+
+        /// PID file for --daemon (default <cache-dir>/web.pid)
+        #[arg(long, value_name = "FILE", requires = "daemon")]
+        pid_file: Option<PathBuf>,
+
+        /// Log file for --daemon, appended to (default <cache-dir>/web.log)
+        #[arg(long, value_name = "FILE", requires = "daemon")]
+        log_file: Option<PathBuf>,
     },
     
+    /// Chain event timeline (reorgs, stalls, difficulty spikes, large blocks, burns)
+    /// Scans recent blocks and prints the locally persisted event history
+    Events {
+        /// Show the last N events
+        #[arg(short, long, default_value = "50")]
+        limit: usize,
+
+        /// Number of recent blocks to scan for new events
+        #[arg(short, long, default_value_t = chain_events::DEFAULT_SCAN_WINDOW)]
+        window: u64,
+
+        /// Only print the stored history without scanning the database
+        #[arg(long)]
+        no_scan: bool,
+    },
+
+    /// Largest / busiest blocks among recent blocks (find spam bursts and heavy usage)
+    Top {
+        /// Ranking criterion
+        #[arg(long, value_enum, default_value = "kernels")]
+        by: top_blocks::TopBy,
+
+        /// Number of blocks listed
+        #[arg(short, long, default_value_t = top_blocks::DEFAULT_LIMIT)]
+        limit: usize,
+
+        /// Number of recent blocks ranked
+        #[arg(short, long, default_value_t = top_blocks::DEFAULT_INDEX_WINDOW)]
+        window: u64,
+    },
+
+    /// Check LMDB environment health (mdb_stat style)
+    /// Reports map usage, per-table entries/depth/pages and free pages, flagging likely problems
+    Check {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Decode and print the genesis block and identify the network from its hash
+    /// Lists the hard-coded genesis hash of every known network; exits with status 2 on a mismatch
+    Genesis {
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Consistency checks for deep-diving suspected database corruption
+    /// Exits with status 2 when a check fails
+    Audit {
+        /// Recompute the kernel MR of --block from the kernels table and compare it with the header
+        #[arg(long, requires = "block", conflicts_with_all = ["timestamps", "double_spends", "follow"])]
+        verify_roots: bool,
+
+        /// Flag recent timestamps earlier than the median of the previous 11 blocks or beyond the FTL
+        #[arg(long, conflicts_with_all = ["double_spends", "follow"])]
+        timestamps: bool,
+
+        /// Scan the whole inputs table for outputs spent more than once
+        #[arg(long, conflicts_with = "follow")]
+        double_spends: bool,
+
+        /// Keep running and check each new block (hash chain, MMR sizes, timestamps); failing
+        /// blocks go to --webhook-url
+        #[arg(short, long)]
+        follow: bool,
+
+        /// With --follow, serve the audit status at /api/audit/status on this port
+        #[arg(long, requires = "follow")]
+        status_port: Option<u16>,
+
+        /// Bind address for --status-port
+        #[arg(long, default_value = "127.0.0.1", requires = "status_port")]
+        bind: String,
+
+        /// With --timestamps, number of recent blocks checked
+        #[arg(short, long, default_value_t = audit::timestamps::DEFAULT_WINDOW, requires = "timestamps")]
+        window: u64,
+
+        /// Block to check
+        #[arg(short, long)]
+        block: Option<u64>,
+
+        /// With --verify-roots, also recompute the block output MR from the block's outputs
+        #[arg(long, requires = "verify_roots")]
+        outputs: bool,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Snapshot the LMDB environment with a compacting online copy
+    /// Safe while the base node is running; free pages are dropped from the copy
+    Backup {
+        /// Directory to write the snapshot (data.mdb) into
+        #[arg(short, long, value_name = "DIR")]
+        out: PathBuf,
+    },
+
+    /// Export raw header/kernel/utxo/input rows for a height range into a .tar.zst archive
+    /// Lets block-parsing bugs be reproduced without sharing the whole database
+    ExportChain {
+        /// Heights to export (format: start-end)
+        #[arg(short, long)]
+        range: String,
+
+        /// Archive file to write
+        #[arg(short, long, value_name = "FILE", default_value = "chain.tar.zst")]
+        out: PathBuf,
+    },
+
+    /// Find one-sided payments to a public key in the utxos table (read-only wallet recovery aid)
+    /// Matches outputs whose script is `PushPubKey(key)`; stealth payments are not detected
+    Scan {
+        /// Public key the payments were sent to (64 hex characters)
+        #[arg(short, long)]
+        key: String,
+
+        /// Heights to scan (format: start-end); defaults to the whole chain
+        #[arg(short, long)]
+        range: Option<String>,
+
+        /// Print the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Load an export-chain archive into a new LMDB environment at the --database path
+    ImportChain {
+        /// Archive file produced by export-chain
+        #[arg(short, long, value_name = "FILE")]
+        input: PathBuf,
+    },
+
     /// Investigate LMDB key structures (debugging tool)
     /// Helps understand how transaction data is stored and linked
     Inspect {
@@ -94,9 +402,13 @@ pub enum InterfaceMode {
         #[arg(short = 'a', long)]
         all_tables: bool,
         
-        /// Test multiple block heights to find patterns
+        /// Test multiple block heights to find patterns (tip, tip-1, tip-10 and a random mid-chain block)
         #[arg(short = 'p', long)]
         test_patterns: bool,
+
+        /// Heights for --test-patterns instead of the tip-relative defaults, e.g. 64754,64000
+        #[arg(long, value_delimiter = ',', value_name = "HEIGHT", requires = "test_patterns")]
+        heights: Vec<u64>,
         
         /// Simple prefix test - check if block hash is used as key prefix
         #[arg(short = 's', long)]
@@ -105,58 +417,463 @@ pub enum InterfaceMode {
         /// Thorough investigation - compare linking hash to actual transaction keys
         #[arg(short = 't', long)]
         thorough: bool,
+
+        /// Report entry counts, key/value sizes and key length distributions for every table
+        #[arg(long)]
+        stats: bool,
+
+        /// Print the --stats report as JSON
+        #[arg(long, requires = "stats")]
+        json: bool,
+
+        /// Targeted investigation tools
+        #[command(subcommand)]
+        action: Option<InspectAction>,
+    },
+}
+
+/// Targeted investigation tools available under `inspect`
+#[derive(Subcommand)]
+pub enum InspectAction {
+    /// Resolve which block contains a kernel MMR position or output SMT index
+    Mmr {
+        /// Leaf position / index to resolve
+        #[arg(short, long)]
+        position: u64,
+
+        /// Which structure the position belongs to
+        #[arg(short, long, value_enum, default_value = "kernel")]
+        kind: mmr::MmrKind,
+    },
+
+    /// Count a table's keys per distinct prefix (are prefix-based block queries feasible?)
+    PrefixHistogram {
+        /// Table to scan
+        #[arg(short, long, default_value = "kernels")]
+        table: String,
+
+        /// Prefix length in bytes (32 = block hash for block-keyed tables)
+        #[arg(short, long, default_value = "4")]
+        prefix_len: usize,
+
+        /// Most common prefixes to list
+        #[arg(long, default_value = "20")]
+        top: usize,
+
+        /// Print the full histogram as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Print a raw header row, optionally as a field layout (offset, length, name, decoded value)
+    Header {
+        /// Height of the header to print
+        #[arg(long)]
+        height: u64,
+
+        /// Break the bytes down field by field following this build's header layout
+        #[arg(short, long)]
+        annotate: bool,
+
+        /// Print the --annotate layout as JSON
+        #[arg(long, requires = "annotate")]
+        json: bool,
+    },
+
+    /// Guess each table's row type by bincode-decoding sample values as known Tari rows
+    GuessValues {
+        /// Tables to check (repeatable; default all tables)
+        #[arg(short, long)]
+        table: Vec<String>,
+
+        /// Values sampled per table
+        #[arg(short, long, default_value = "20")]
+        samples: usize,
+
+        /// Print the guesses as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
 /// Main application entry point
-/// Routes to appropriate interface mode based on CLI arguments
-#[tokio::main]
-async fn main() -> Result<()> {
+/// Detaches for `web --daemon`, then starts the runtime and runs the selected mode
+fn main() -> Result<()> {
     let cli = Cli::parse();
+
+    // Fork before the runtime starts its worker threads; the guard removes the PID file on exit
+    let _pid_file = match &cli.mode {
+        InterfaceMode::Web { daemon: true, pid_file, log_file, .. } => {
+            let cache = cli.cache_dir.clone().map(CacheDir::new).unwrap_or_default();
+            Some(daemon::detach(&daemon::DaemonConfig {
+                pid_file: pid_file.clone().unwrap_or_else(|| cache.file(daemon::DEFAULT_PID_FILE)),
+                log_file: log_file.clone().unwrap_or_else(|| cache.file(daemon::DEFAULT_LOG_FILE)),
+            })?)
+        }
+        _ => None,
+    };
+
+    tokio::runtime::Runtime::new()?.block_on(run(cli))
+}
+
+/// Route to the interface mode selected on the command line
+async fn run(cli: Cli) -> Result<()> {
+    let database = cli.database.clone().unwrap_or_else(|| paths::default_database_path(&cli.network));
     
     // Validate database path (but allow web mode to work with demo data)
-    if !cli.database.exists() {
+    let mut demo = cli.demo;
+    if !database.exists() && !demo {
         match cli.mode {
             InterfaceMode::Web { .. } => {
-                println!("⚠️  Database path does not exist: {:?}", cli.database);
+                println!("⚠️  Database path does not exist: {:?}", database);
                 println!("🌐 Web mode will start with demo data");
+                demo = true;
             },
             InterfaceMode::Inspect { .. } => {
-                println!("⚠️  Database path does not exist: {:?}", cli.database);
+                println!("⚠️  Database path does not exist: {:?}", database);
                 println!("🔍 Inspector mode will show available investigation options");
             },
+            InterfaceMode::ImportChain { .. } => {},
             _ => {
-                anyhow::bail!("Database path does not exist: {:?}", cli.database);
+                anyhow::bail!("Database path does not exist: {:?}", database);
             }
         }
     }
     
     // Create app configuration
     let config = AppConfig {
-        database_path: cli.database,
+        database_path: database,
+        notify: cli.webhook_url.map(|webhook_url| notify::NotifyConfig {
+            webhook_url,
+            interval_threshold_secs: cli.webhook_interval_threshold,
+        }),
+        read_timeout: std::time::Duration::from_secs(cli.read_timeout),
+        demo,
+        watchlist_path: cli.watchlist,
+        network: Network::from_str(&cli.network).map_err(|e| anyhow::anyhow!("Unknown network {}: {}", cli.network, e))?,
+        cache: cli.cache_dir.map(CacheDir::new).unwrap_or_default(),
+        theme: Theme::detect(cli.theme, cli.no_color),
+        max_update_rate: cli.max_update_rate,
+        watch_mode: cli.watch,
+        poll_interval: std::time::Duration::from_secs(cli.poll_interval.max(1)),
+        timezone: cli.timezone,
+        denom: cli.denom,
+        verbosity: match (cli.quiet, cli.verbose) {
+            (true, _) => Verbosity::Quiet,
+            (_, true) => Verbosity::Debug,
+            _ => cli.verbosity,
+        },
+        limits: Limits::default(),
     };
+    config.verbosity.apply();
+
+    // Fail fast on an incompatible database; the raw-table modes, `genesis` and `audit` exist to look at those
+    let raw_mode = matches!(cli.mode,
+        InterfaceMode::Check { .. } | InterfaceMode::Genesis { .. } | InterfaceMode::Audit { .. } | InterfaceMode::Backup { .. } | InterfaceMode::ImportChain { .. } | InterfaceMode::Inspect { .. });
+    if !config.demo && !raw_mode && !cli.skip_preflight {
+        let report = preflight::preflight(&config.database_path, config.network)?;
+        preflight::print_preflight(&report);
+    }
     
     // Route to appropriate interface based on selected mode
     match cli.mode {
-        InterfaceMode::Cli { count, detail, range, block } => {
+        InterfaceMode::Cli { count, detail, hash, range, block, follow, pow, min_kernels, locked_coinbase, burns: show_burns, at_height } => {
             println!("🔍 Tari LMDB Inspector - CLI Mode");
-            cli_interface::run_cli_mode(&config, count, detail, range, block).await
+            if locked_coinbase {
+                let report = coinbase::locked_coinbase(&config.database_path, coinbase::DEFAULT_WINDOW)?;
+                coinbase::print_locked_coinbase(&report);
+                Ok(())
+            } else if !at_height.is_empty() {
+                let mut previous = None;
+                for height in at_height {
+                    let state = state_at::state_at_height(&config.database_path, height, config.network)?;
+                    state_at::print_state(&state, previous.as_ref());
+                    println!();
+                    previous = Some(state);
+                }
+                Ok(())
+            } else if show_burns {
+                let report = burns::burn_analytics(&config.database_path, burns::DEFAULT_WINDOW)?;
+                burns::print_burns(&report);
+                Ok(())
+            } else if follow {
+                if detail.is_some() || hash.is_some() || range.is_some() || block.is_some() {
+                    anyhow::bail!("--follow cannot be combined with --detail, --hash, --range or --block");
+                }
+                cli_interface::run_follow_mode(&config, count).await
+            } else if let Some(hash) = hash {
+                cli_interface::show_block_by_hash(&config, &hash).await
+            } else {
+                cli_interface::run_cli_mode(&config, count, detail, range, block, pow, min_kernels).await
+            }
         },
         
-        InterfaceMode::Tui { refresh } => {
+        InterfaceMode::Tui { refresh, pow, min_kernels } => {
             println!("📊 Tari LMDB Inspector - Terminal Dashboard");
-            tui_dashboard::run_tui_mode(&config, refresh).await
+            tui_dashboard::run_tui_mode(&config, refresh, pow, min_kernels).await
         },
         
-        InterfaceMode::Web { port, bind, cors } => {
+        InterfaceMode::Web { port, bind, cors, rate_limit, max_concurrent, auth_token, tls_cert, tls_key, nodes, max_blocks, max_tx_items, max_ws_message, no_update_check, .. } => {
             println!("🌐 Tari LMDB Inspector - Web Server Mode");
-            println!("Starting server at http://{}:{}", bind, port);
-            web_server::run_web_mode(&config, &bind, port, cors).await
+            request_trace::init_logging(config.verbosity);
+            if !no_update_check {
+                version::spawn_update_check();
+            }
+            let limits = Limits { max_blocks, max_tx_items, max_ws_message_bytes: max_ws_message };
+            let config = AppConfig { limits, ..config.clone() };
+            let mut profiles = Vec::new();
+            for spec in &nodes {
+                let profile = web_server::NodeProfile::parse(spec, &config)?;
+                if !config.demo && !cli.skip_preflight {
+                    let report = preflight::preflight(&profile.config.database_path, profile.config.network)
+                        .with_context(|| format!("Node '{}'", profile.name))?;
+                    preflight::print_preflight(&report);
+                }
+                profiles.push(profile);
+            }
+            let tls = tls_cert.zip(tls_key).map(|(cert_path, key_path)| web_server::TlsConfig { cert_path, key_path });
+            println!("Starting server at {}://{}:{}", if tls.is_some() { "https" } else { "http" }, bind, port);
+            let rate_limit = rate_limit::RateLimitConfig {
+                requests_per_minute: rate_limit,
+                max_concurrent,
+            };
+            let auth = auth::AuthToken::new(auth_token).with_secure_cookie(tls.is_some());
+            web_server::run_web_mode(&config, profiles, &bind, port, cors, rate_limit, auth, tls).await
         },
         
-        InterfaceMode::Inspect { block_height, all_tables, test_patterns, simple_test, thorough } => {
+        InterfaceMode::Events { limit, window, no_scan } => {
+            println!("🗓️  Tari LMDB Inspector - Chain Event Timeline");
+            let store_path = config.cache.file(chain_events::STORE_FILE);
+            let store = if no_scan {
+                chain_events::EventStore::load(&store_path)?
+            } else {
+                chain_events::refresh_events(&config.database_path, &store_path, window)?
+            };
+            let mut events = store.recent(limit);
+            events.reverse();
+            chain_events::print_timeline(&events, &config.timezone);
+            println!("History file: {}", store_path.display());
+            Ok(())
+        },
+
+        InterfaceMode::Top { by, limit, window } => {
+            let top = top_blocks::top_blocks(&config.database_path, &config.cache, by, limit, window)?;
+            top_blocks::print_top_blocks(&top);
+            Ok(())
+        },
+
+        InterfaceMode::Check { json } => {
+            let report = db_check::check_database(&config.database_path)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("🩺 Tari LMDB Inspector - Database Check");
+                println!("Database path: {:?}", config.database_path);
+                db_check::print_report(&report);
+            }
+            if report.warnings.is_empty() {
+                Ok(())
+            } else {
+                std::process::exit(2);
+            }
+        },
+
+        InterfaceMode::Genesis { json } => {
+            let report = genesis::genesis_report(&config.database_path, config.network)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("🌱 Tari LMDB Inspector - Genesis Block");
+                genesis::print_genesis(&report, &config.timezone);
+            }
+            if report.warnings.is_empty() {
+                Ok(())
+            } else {
+                std::process::exit(2);
+            }
+        },
+
+        InterfaceMode::Audit { timestamps: true, window, json, .. } => {
+            let now = chrono::Utc::now().timestamp() as u64;
+            let report = audit::timestamps::timestamp_anomalies(&config.database_path, window, now)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("🕰️  Tari LMDB Inspector - Timestamp Anomalies");
+                audit::timestamps::print_timestamp_anomalies(&report, &config.timezone);
+            }
+            if report.anomalies.is_empty() {
+                Ok(())
+            } else {
+                std::process::exit(2);
+            }
+        },
+
+        InterfaceMode::Audit { follow: true, status_port, bind, .. } => {
+            println!("🧾 Tari LMDB Inspector - Continuous Audit");
+            let status = std::sync::Arc::new(tokio::sync::RwLock::new(
+                audit::follow::AuditStatus::new(chrono::Utc::now().timestamp() as u64)
+            ));
+            let serve_status = async {
+                match status_port {
+                    Some(port) => web_server::serve_audit_status(&bind, port, status.clone()).await,
+                    None => std::future::pending().await,
+                }
+            };
+            tokio::select! {
+                result = audit::follow::follow_chain(&config, status.clone()) => result,
+                result = serve_status => result,
+                signal = shutdown::signal() => {
+                    println!("🛑 {} received, audit stopped", signal.name());
+                    Ok(())
+                }
+            }
+        },
+
+        InterfaceMode::Audit { double_spends: true, json, .. } => {
+            let cancel = cli_interface::cancel_on_ctrl_c();
+            let report = audit::double_spends::find_double_spends(&config.database_path, &TerminalProgress::new(), &cancel)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("🔁 Tari LMDB Inspector - Double-Spend Check");
+                audit::double_spends::print_double_spends(&report);
+            }
+            if report.is_ok() {
+                Ok(())
+            } else {
+                std::process::exit(2);
+            }
+        },
+
+        InterfaceMode::Audit { verify_roots, block, outputs, json, .. } => {
+            let (true, Some(height)) = (verify_roots, block) else {
+                anyhow::bail!("Choose a check, e.g. audit --verify-roots --block 1000, audit --timestamps, audit --double-spends or audit --follow");
+            };
+            let cancel = cli_interface::cancel_on_ctrl_c();
+            let report = audit::roots::verify_roots(&config.database_path, height, outputs, &TerminalProgress::new(), &cancel)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("🧾 Tari LMDB Inspector - Merkle Root Verification");
+                audit::roots::print_roots(&report);
+            }
+            if report.is_ok() {
+                Ok(())
+            } else {
+                std::process::exit(2);
+            }
+        },
+
+        InterfaceMode::Backup { out } => {
+            println!("💾 Tari LMDB Inspector - Database Backup");
+            let summary = backup::backup_database(&config.database_path, &out)?;
+            backup::print_summary(&summary);
+            Ok(())
+        },
+
+        InterfaceMode::ExportChain { range, out } => {
+            println!("📤 Tari LMDB Inspector - Chain Export");
+            let (start, end) = cli_interface::parse_height_range(&range)?;
+            let cancel = cli_interface::cancel_on_ctrl_c();
+            let manifest = chain_archive::export_chain(&config.database_path, start, end, &out, &TerminalProgress::new(), &cancel)?;
+            chain_archive::print_manifest(&manifest);
+            println!("✅ Wrote {}", out.display());
+            Ok(())
+        },
+
+        InterfaceMode::Scan { key, range, json } => {
+            let Some(public_key) = output_scanner::parse_public_key(&key) else {
+                anyhow::bail!("--key must be a 32-byte public key in hex (64 characters)");
+            };
+            let range = range.as_deref().map(cli_interface::parse_height_range).transpose()?;
+            let cancel = cli_interface::cancel_on_ctrl_c();
+            let report = output_scanner::scan_outputs(&config.database_path, &public_key, range, &TerminalProgress::new(), &cancel)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("🔎 Tari LMDB Inspector - One-sided Payment Scan");
+                output_scanner::print_scan_report(&report);
+            }
+            Ok(())
+        },
+
+        InterfaceMode::ImportChain { input } => {
+            println!("📥 Tari LMDB Inspector - Chain Import");
+            let manifest = chain_archive::import_chain(&input, &config.database_path)?;
+            chain_archive::print_manifest(&manifest);
+            println!("✅ Imported into {:?}", config.database_path);
+            Ok(())
+        },
+
+        InterfaceMode::Inspect { action: Some(action), .. } => {
+            run_inspect_action(&config, action).await
+        },
+
+        InterfaceMode::Inspect { stats: true, json, action: None, .. } => {
+            let report = key_inspector::table_stats(&config.database_path)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("📏 Tari LMDB Inspector - Table Statistics");
+                println!("Database path: {:?}", config.database_path);
+                key_inspector::print_table_stats(&report);
+            }
+            Ok(())
+        },
+
+        InterfaceMode::Inspect { block_height, all_tables, test_patterns, heights, simple_test, thorough, action: None, .. } => {
             println!("🔍 Tari LMDB Inspector - Key Structure Investigation");
-            run_inspector_mode(&config, block_height, all_tables, test_patterns, simple_test, thorough).await
+            let pattern_heights = test_patterns.then_some(heights);
+            run_inspector_mode(&config, block_height, all_tables, pattern_heights, simple_test, thorough).await
+        },
+    }
+}
+
+/// Run a targeted `inspect` subcommand
+async fn run_inspect_action(config: &AppConfig, action: InspectAction) -> Result<()> {
+    match action {
+        InspectAction::Mmr { position, kind } => {
+            println!("🌲 Tari LMDB Inspector - MMR Position Resolver");
+            let resolution = mmr::resolve_position(&config.database_path, kind, position)?;
+            mmr::print_resolution(kind, position, &resolution);
+            Ok(())
+        },
+        InspectAction::PrefixHistogram { table, prefix_len, top, json } => {
+            let histogram = key_inspector::prefix_histogram(&config.database_path, &table, prefix_len)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&histogram)?);
+            } else {
+                println!("🧮 Tari LMDB Inspector - Key Prefix Histogram");
+                key_inspector::print_prefix_histogram(&histogram, top);
+            }
+            Ok(())
+        },
+        InspectAction::Header { height, annotate, json } => {
+            let data = key_inspector::raw_header(&config.database_path, height)?;
+            if !annotate {
+                println!("🧬 Tari LMDB Inspector - Raw Header");
+                key_inspector::print_header_hex(height, &data);
+                return Ok(());
+            }
+            let layout = lmdb_reader::annotate_header(&data);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&layout)?);
+            } else {
+                println!("🧬 Tari LMDB Inspector - Header Field Layout");
+                key_inspector::print_header_layout(height, &layout);
+            }
+            Ok(())
+        },
+        InspectAction::GuessValues { table, samples, json } => {
+            let guesses = key_inspector::guess_value_types(&config.database_path, &table, samples)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&guesses)?);
+            } else {
+                println!("🧩 Tari LMDB Inspector - Value Type Guesser");
+                key_inspector::print_value_guesses(&guesses);
+            }
+            Ok(())
         },
     }
 }
@@ -168,14 +885,14 @@ async fn main() -> Result<()> {
 /// * `config` - Application configuration with database path
 /// * `block_height` - Optional specific block to investigate
 /// * `all_tables` - Whether to show sample keys from all tables
-/// * `test_patterns` - Whether to test multiple blocks for patterns
+/// * `pattern_heights` - Test multiple blocks for patterns (empty = derive from the tip)
 /// * `simple_test` - Whether to run simple prefix test
 /// * `thorough` - Whether to run thorough key investigation
 async fn run_inspector_mode(
     config: &AppConfig, 
     block_height: Option<u64>, 
     all_tables: bool, 
-    test_patterns: bool,
+    pattern_heights: Option<Vec<u64>>,
     simple_test: bool,
     thorough: bool,
 ) -> Result<()> {
@@ -214,10 +931,17 @@ async fn run_inspector_mode(
         key_inspector::investigate_block_to_transaction_links(db_path, height)?;
     }
     
-    if test_patterns {
+    let test_patterns = pattern_heights.is_some();
+    if let Some(heights) = pattern_heights {
         println!("\n📊 Testing multiple blocks for key/linking patterns...");
-        // Test the last few blocks to find patterns
-        let test_heights = [64754, 64753, 64752]; // Adjust to current tip as needed
+        let test_heights = if heights.is_empty() {
+            let tip = key_inspector::tip_height(db_path)?;
+            let random = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.subsec_nanos() as u64;
+            key_inspector::pattern_heights(tip, random)
+        } else {
+            heights
+        };
+        println!("Heights: {:?}", test_heights);
         for height in test_heights {
             println!("\n--- Testing Block {} ---", height);
             match key_inspector::investigate_block_to_transaction_links(db_path, height) {
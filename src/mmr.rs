@@ -0,0 +1,254 @@
+// File: src/mmr.rs
+// Version: 1.2.0 - Linear output search, beyond-tip reported in the lookup
+// Tree: tari-lmdb-inspector/src/mmr.rs
+//
+// Every Tari header records the running size of the kernel MMR (`kernel_mmr_size`) and the
+// output SMT (`output_smt_size`) *after* the block has been applied. The kernel MMR is
+// append-only, so its size is monotonic in height and the block holding a leaf is found by
+// binary search. The output SMT shrinks when outputs are spent or burned, so its size is not
+// monotonic; output positions are resolved by a forward scan to the first block whose size
+// exceeds the position.
+
+use std::path::Path;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use tari_node_components::blocks::BlockHeader;
+use crate::lmdb_reader::{open_backend, KvRead, ReaderError, ReaderResult};
+use crate::lmdb_reader::stream::decode_height;
+
+/// Which accumulated structure a position refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MmrKind {
+    /// Kernel MMR leaf position (`kernel_mmr_size`)
+    Kernel,
+    /// Output SMT leaf index (`output_smt_size`)
+    Output,
+}
+
+impl MmrKind {
+    /// Accumulated size recorded in the header for this kind
+    fn size_of(&self, header: &BlockHeader) -> u64 {
+        match self {
+            MmrKind::Kernel => header.kernel_mmr_size,
+            MmrKind::Output => header.output_smt_size,
+        }
+    }
+}
+
+/// Result of resolving a position to a block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MmrResolution {
+    pub kind: MmrKind,
+    pub position: u64,
+    pub height: u64,
+    /// Accumulated size at the end of the previous block (first position in this block)
+    pub block_start: u64,
+    /// Accumulated size at the end of this block (exclusive upper bound)
+    pub block_end: u64,
+    /// Offset of the position within the block's leaves
+    pub index_in_block: u64,
+    /// Number of headers decoded during the search
+    pub probes: usize,
+}
+
+/// Outcome of a position lookup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum MmrLookup {
+    /// The block containing the position
+    Found(MmrResolution),
+    /// No block grew the structure past the position; carries the size at the tip
+    BeyondTip { tip_height: u64, tip_size: u64 },
+    /// The headers table is empty
+    NoHeaders,
+}
+
+/// Resolve which block contains the given MMR/SMT position
+///
+/// Kernel positions are binary-searched. Output positions are scanned forward from genesis,
+/// since `output_smt_size` is not monotonic; the result is the first block after which the SMT
+/// held more than `position` leaves.
+pub fn resolve_position(path: &Path, kind: MmrKind, position: u64) -> ReaderResult<MmrLookup> {
+    let backend = open_backend(path)?;
+    let read = backend.read()?;
+    match kind {
+        MmrKind::Kernel => resolve_kernel_position(&*read, position),
+        MmrKind::Output => resolve_output_position(&*read, position),
+    }
+}
+
+/// Binary search over `kernel_mmr_size`, which only grows with height
+fn resolve_kernel_position(read: &dyn KvRead, position: u64) -> ReaderResult<MmrLookup> {
+    let kind = MmrKind::Kernel;
+    let mut cursor = read.cursor("headers")?;
+
+    let Some(first_height) = cursor.first()?.map(|(key, _)| decode_height(key)) else {
+        return Ok(MmrLookup::NoHeaders);
+    };
+    let Some(last_height) = cursor.last()?.map(|(key, _)| decode_height(key)) else {
+        return Ok(MmrLookup::NoHeaders);
+    };
+
+    let mut probes = 0;
+
+    // Position beyond the tip cannot be resolved
+    let (tip_height, tip_size) = match decodable_size_at_or_after(read, kind, last_height, last_height, &mut probes) {
+        Some(found) => found,
+        None => match decodable_size_at_or_before(read, kind, last_height, first_height, &mut probes) {
+            Some(found) => found,
+            None => return Err(ReaderError::BlockNotFound(last_height)),
+        },
+    };
+    if position >= tip_size {
+        return Ok(MmrLookup::BeyondTip { tip_height, tip_size });
+    }
+
+    // Find the lowest height whose accumulated size exceeds the position
+    let mut low = first_height;
+    let mut high = tip_height;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        match decodable_size_at_or_after(read, kind, mid, high, &mut probes) {
+            Some((found_height, size)) => {
+                if size > position {
+                    high = found_height;
+                } else {
+                    low = found_height + 1;
+                }
+            }
+            None => {
+                // Nothing decodable between mid and high, so the answer is at or below mid
+                high = mid;
+            }
+        }
+    }
+
+    let height = low;
+    let header_data = read.get("headers", &height.to_le_bytes())?
+        .ok_or(ReaderError::BlockNotFound(height))?;
+    let block_end = match bincode::deserialize::<BlockHeader>(&header_data) {
+        Ok(header) => kind.size_of(&header),
+        Err(source) => return Err(ReaderError::UnsupportedHeader { height, source }),
+    };
+    let block_start = if height == 0 {
+        0
+    } else {
+        decodable_size_at_or_before(read, kind, height - 1, first_height, &mut probes)
+            .map(|(_, size)| size)
+            .unwrap_or(0)
+    };
+
+    Ok(MmrLookup::Found(MmrResolution {
+        kind,
+        position,
+        height,
+        block_start,
+        block_end,
+        index_in_block: position.saturating_sub(block_start),
+        probes,
+    }))
+}
+
+/// Forward scan over `output_smt_size`, checking every decodable header since the size can shrink
+fn resolve_output_position(read: &dyn KvRead, position: u64) -> ReaderResult<MmrLookup> {
+    let kind = MmrKind::Output;
+    let mut cursor = read.cursor("headers")?;
+    let mut probes = 0;
+    let mut previous_size = 0;
+    let mut tip = None;
+
+    let mut entry = cursor.first()?;
+    while let Some((key, data)) = entry {
+        let height = decode_height(key);
+        probes += 1;
+        // Undecodable (C29) headers are skipped; the next decodable block spans their leaves
+        if let Ok(header) = bincode::deserialize::<BlockHeader>(data) {
+            let size = kind.size_of(&header);
+            if size > position {
+                return Ok(MmrLookup::Found(MmrResolution {
+                    kind,
+                    position,
+                    height,
+                    block_start: previous_size,
+                    block_end: size,
+                    index_in_block: position.saturating_sub(previous_size),
+                    probes,
+                }));
+            }
+            previous_size = size;
+            tip = Some((height, size));
+        }
+        entry = cursor.next()?;
+    }
+
+    Ok(match tip {
+        Some((tip_height, tip_size)) => MmrLookup::BeyondTip { tip_height, tip_size },
+        None => MmrLookup::NoHeaders,
+    })
+}
+
+/// Decode the header at `height`, skipping forward past undecodable (C29) headers up to `limit`
+fn decodable_size_at_or_after(
+    read: &dyn KvRead,
+    kind: MmrKind,
+    height: u64,
+    limit: u64,
+    probes: &mut usize,
+) -> Option<(u64, u64)> {
+    let mut current = height;
+    while current <= limit {
+        *probes += 1;
+        if let Ok(Some(data)) = read.get("headers", &current.to_le_bytes()) {
+            if let Ok(header) = bincode::deserialize::<BlockHeader>(&data) {
+                return Some((current, kind.size_of(&header)));
+            }
+        }
+        current += 1;
+    }
+    None
+}
+
+/// Decode the header at `height`, skipping backward past undecodable (C29) headers down to `limit`
+fn decodable_size_at_or_before(
+    read: &dyn KvRead,
+    kind: MmrKind,
+    height: u64,
+    limit: u64,
+    probes: &mut usize,
+) -> Option<(u64, u64)> {
+    let mut current = height;
+    loop {
+        *probes += 1;
+        if let Ok(Some(data)) = read.get("headers", &current.to_le_bytes()) {
+            if let Ok(header) = bincode::deserialize::<BlockHeader>(&data) {
+                return Some((current, kind.size_of(&header)));
+            }
+        }
+        if current <= limit {
+            return None;
+        }
+        current -= 1;
+    }
+}
+
+/// Print a lookup result for the CLI
+pub fn print_resolution(kind: MmrKind, position: u64, lookup: &MmrLookup) {
+    match lookup {
+        MmrLookup::Found(r) => {
+            println!("✅ {:?} position {} is in block {}", r.kind, r.position, r.height);
+            println!("  Block leaf range: [{}, {})", r.block_start, r.block_end);
+            println!("  Index within block: {}", r.index_in_block);
+            println!("  Headers probed: {}", r.probes);
+            if r.kind == MmrKind::Output {
+                println!("  Note: the output SMT shrinks as outputs are spent; this is the first block where its size passed the position");
+            }
+        }
+        MmrLookup::BeyondTip { tip_height, tip_size } => {
+            println!("❌ {:?} position {} is beyond the {:?} size at tip height {} ({})", kind, position, kind, tip_height, tip_size);
+        }
+        MmrLookup::NoHeaders => {
+            println!("❌ {:?} position {} not found: the database has no headers", kind, position);
+        }
+    }
+}
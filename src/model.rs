@@ -22,12 +22,8 @@ pub struct BlockSummary {
     pub header: BlockHeaderLite,
 }
 
-#[derive(Debug)]
-pub enum BlockFilter {
-    LastN(usize),
-    Range(u64, u64),
-    Specific(u64),
-}
+// Single filter type shared with the reader (supports composition)
+pub use crate::lmdb_reader::BlockFilter;
 
 #[derive(Debug)]
 pub struct TransactionSummary {
@@ -0,0 +1,243 @@
+// File: src/notify.rs
+// Version: 1.3.0 - Audit failure notifications (audit --follow)
+// Tree: tari-lmdb-inspector/src/notify.rs
+//
+// POSTs a small JSON payload to a user-supplied webhook (Slack/Discord bridges, custom
+// alerting) whenever the watched database gets a new tip, reorganises, produces a block
+// after an unusually long interval, includes a watchlisted commitment or excess, stops
+// advancing (stalled sync), or fails a consistency check under `audit --follow`.
+
+use std::time::Duration;
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::audit::follow::AuditFinding;
+use crate::data_models::BlockInfo;
+use crate::sync_status::SyncStatus;
+use crate::watchlist::WatchHit;
+
+/// Default interval (seconds) above which a new block triggers a slow-block alert
+pub const DEFAULT_INTERVAL_THRESHOLD_SECS: u64 = 600;
+
+/// Webhook configuration from the command line
+#[derive(Debug, Clone)]
+pub struct NotifyConfig {
+    pub webhook_url: String,
+    pub interval_threshold_secs: u64,
+}
+
+/// Events delivered to the webhook
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum NotifyEvent {
+    /// A new block became the chain tip
+    NewTip {
+        height: u64,
+        hash: String,
+        timestamp: u64,
+        pow_algorithm: Option<String>,
+    },
+    /// A previously seen block was replaced
+    Reorg {
+        fork_height: u64,
+        previous_tip_height: u64,
+        previous_tip_hash: String,
+        new_tip_height: u64,
+        new_tip_hash: String,
+    },
+    /// The new tip arrived after an interval above the configured threshold
+    SlowBlock {
+        height: u64,
+        interval_seconds: i64,
+        threshold_seconds: u64,
+    },
+    /// A watchlisted commitment or excess appeared in a block
+    WatchHit { hit: WatchHit },
+    /// The node's tip is old and its height stopped advancing
+    Stalled {
+        height: u64,
+        minutes_behind: u64,
+    },
+    /// A new block broke an `audit --follow` rule
+    AuditFailure {
+        height: u64,
+        hash: String,
+        findings: Vec<AuditFinding>,
+    },
+}
+
+/// Payload envelope POSTed to the webhook
+#[derive(Debug, Serialize)]
+struct WebhookPayload<'a> {
+    source: &'static str,
+    sent_at: u64,
+    /// Plain-text summary so chat webhooks render something useful
+    text: String,
+    #[serde(flatten)]
+    event: &'a NotifyEvent,
+}
+
+/// Tracks the last seen chain and POSTs events to the webhook
+pub struct Notifier {
+    config: NotifyConfig,
+    client: reqwest::Client,
+    /// Last seen blocks (height → hash), newest first
+    last_seen: Vec<(u64, String)>,
+    /// Whether the last sync status was stalled (alerts fire once per stall)
+    stalled: bool,
+}
+
+impl Notifier {
+    pub fn new(config: NotifyConfig) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()?;
+
+        Ok(Self {
+            config,
+            client,
+            last_seen: Vec::new(),
+            stalled: false,
+        })
+    }
+
+    /// Compare freshly read blocks (newest first) with the previous observation
+    /// The first observation only primes the state and produces no events
+    pub fn observe(&mut self, blocks: &[BlockInfo]) -> Vec<NotifyEvent> {
+        let mut events = Vec::new();
+        let Some(tip) = blocks.first() else { return events };
+
+        if let Some((old_height, old_hash)) = self.last_seen.first().cloned() {
+            // Reorg: a remembered height now has a different hash
+            let fork = self.last_seen.iter()
+                .filter_map(|(height, hash)| {
+                    blocks.iter()
+                        .find(|b| b.height == *height)
+                        .filter(|b| &b.hash != hash)
+                        .map(|_| *height)
+                })
+                .min();
+
+            if let Some(fork_height) = fork {
+                events.push(NotifyEvent::Reorg {
+                    fork_height,
+                    previous_tip_height: old_height,
+                    previous_tip_hash: old_hash.clone(),
+                    new_tip_height: tip.height,
+                    new_tip_hash: tip.hash.clone(),
+                });
+            }
+
+            if tip.height != old_height || tip.hash != old_hash {
+                events.push(NotifyEvent::NewTip {
+                    height: tip.height,
+                    hash: tip.hash.clone(),
+                    timestamp: tip.timestamp,
+                    pow_algorithm: tip.pow_algorithm.clone(),
+                });
+
+                if let Some(interval) = tip.interval_seconds {
+                    if interval > self.config.interval_threshold_secs as i64 {
+                        events.push(NotifyEvent::SlowBlock {
+                            height: tip.height,
+                            interval_seconds: interval,
+                            threshold_seconds: self.config.interval_threshold_secs,
+                        });
+                    }
+                }
+            }
+        }
+
+        self.last_seen = blocks.iter()
+            .take(100)
+            .map(|b| (b.height, b.hash.clone()))
+            .collect();
+
+        events
+    }
+
+    /// Compare the sync status with the previous one; entering a stall produces an event
+    pub fn observe_sync(&mut self, height: u64, status: SyncStatus) -> Option<NotifyEvent> {
+        let was_stalled = std::mem::replace(&mut self.stalled, status.is_stalled());
+        match status {
+            SyncStatus::Stalled { minutes } if !was_stalled => Some(NotifyEvent::Stalled { height, minutes_behind: minutes }),
+            _ => None,
+        }
+    }
+
+    /// Observe new blocks and deliver any resulting events, logging delivery failures
+    pub async fn observe_and_send(&mut self, blocks: &[BlockInfo]) {
+        let events = self.observe(blocks);
+        self.send_all(&events).await;
+    }
+
+    /// Deliver events, logging delivery failures
+    pub async fn send_all(&self, events: &[NotifyEvent]) {
+        for event in events {
+            if let Err(e) = self.send(event).await {
+                eprintln!("⚠️  Webhook delivery failed: {}", e);
+            }
+        }
+    }
+
+    /// POST a single event to the webhook
+    async fn send(&self, event: &NotifyEvent) -> Result<()> {
+        let payload = WebhookPayload {
+            source: "tari-lmdb-inspector",
+            sent_at: chrono::Utc::now().timestamp() as u64,
+            text: summary_text(event),
+            event,
+        };
+
+        let response = self.client
+            .post(&self.config.webhook_url)
+            .json(&payload)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("webhook returned HTTP {}", response.status());
+        }
+
+        println!("🔔 Webhook sent: {}", payload.text);
+        Ok(())
+    }
+}
+
+/// One-line human readable summary of an event
+fn summary_text(event: &NotifyEvent) -> String {
+    match event {
+        NotifyEvent::NewTip { height, hash, pow_algorithm, .. } => format!(
+            "New Tari tip {} ({}) mined with {}",
+            height,
+            &hash[..hash.len().min(16)],
+            pow_algorithm.as_deref().unwrap_or("unknown PoW")
+        ),
+        NotifyEvent::Reorg { fork_height, previous_tip_height, new_tip_height, .. } => format!(
+            "Reorg at height {}: tip {} → {}",
+            fork_height, previous_tip_height, new_tip_height
+        ),
+        NotifyEvent::SlowBlock { height, interval_seconds, threshold_seconds } => format!(
+            "Block {} took {}s (threshold {}s)",
+            height, interval_seconds, threshold_seconds
+        ),
+        NotifyEvent::Stalled { height, minutes_behind } => format!(
+            "Node stalled at height {}: tip is {} min old",
+            height, minutes_behind
+        ),
+        NotifyEvent::AuditFailure { height, hash, findings } => format!(
+            "Block {} ({}) failed audit: {}",
+            height,
+            &hash[..hash.len().min(16)],
+            findings.iter().map(|finding| finding.message.as_str()).collect::<Vec<_>>().join("; ")
+        ),
+        NotifyEvent::WatchHit { hit } => format!(
+            "Watched {:?} {}{} seen as {:?} in block {}",
+            hit.item.kind,
+            &hit.item.value[..hit.item.value.len().min(16)],
+            hit.item.label.as_ref().map(|label| format!(" ({})", label)).unwrap_or_default(),
+            hit.location,
+            hit.height
+        ),
+    }
+}
@@ -0,0 +1,154 @@
+// File: src/output_scanner.rs
+// Version: 1.0.0 - One-sided payment scanner over the utxos table
+// Tree: tari-lmdb-inspector/src/output_scanner.rs
+//
+// A one-sided payment locks the output with the script `PushPubKey(K)`, where K is the
+// recipient's published key. Scanning every block's outputs for that script finds payments a
+// wallet may have missed, without any wallet state. This is read-only: it reports heights and
+// commitments but cannot recover values, which need the wallet's private keys. Stealth
+// addresses derive a fresh key per payment and are not matched.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use serde::{Deserialize, Serialize};
+use tari_utilities::byte_array::ByteArray;
+use tokio_util::sync::CancellationToken;
+
+use crate::lmdb_reader::{
+    OutputKind, ReaderError, ReaderResult, ScriptPattern, ScriptSummary, StreamReader, TransactionOutputRowData,
+};
+use crate::lmdb_reader::parallel::{par_map_chunks, DEFAULT_CHUNK_SIZE};
+use crate::progress::Progress;
+
+/// An output whose script pays the scanned key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanMatch {
+    pub height: u64,
+    pub output_hash: String,
+    pub commitment: String,
+    pub output_kind: OutputKind,
+    /// Minimum value promise in µT (zero unless the range proof reveals the value)
+    pub minimum_value_promise: u64,
+}
+
+/// Result of scanning a height range for one key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanReport {
+    pub public_key: String,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub matches: Vec<ScanMatch>,
+    /// Headers that could not be decoded (C29-mined blocks)
+    pub blocks_skipped: usize,
+}
+
+/// Normalise a 32-byte public key given as 64 hex characters
+pub fn parse_public_key(key: &str) -> Option<String> {
+    let key = key.trim().trim_start_matches("0x").to_ascii_lowercase();
+    (key.len() == 64 && key.chars().all(|c| c.is_ascii_hexdigit())).then_some(key)
+}
+
+/// Whether `script` is a one-sided payment to `public_key` (lowercase hex)
+pub fn pays_to(script: &ScriptSummary, public_key: &str) -> bool {
+    matches!(&script.pattern, ScriptPattern::OneSided { public_key: key } if key.eq_ignore_ascii_case(public_key))
+}
+
+/// Scan outputs mined in `start..=end` (default: the whole chain) for one-sided payments to
+/// `public_key`. Blocks are reported to `progress`; the scan stops with
+/// `ReaderError::Cancelled` once `cancel` fires.
+pub fn scan_outputs(
+    path: &Path,
+    public_key: &str,
+    range: Option<(u64, u64)>,
+    progress: &dyn Progress,
+    cancel: &CancellationToken,
+) -> ReaderResult<ScanReport> {
+    let reader = StreamReader::open(path)?;
+    let tip = reader.snapshot()?.tip_height()?.unwrap_or(0);
+    let (start_height, end_height) = match range {
+        Some((start, end)) => (start, end.min(tip)),
+        None => (0, tip),
+    };
+
+    progress.begin("Scanning outputs", Some(end_height.saturating_sub(start_height) + 1));
+    let done = AtomicU64::new(0);
+
+    // `None` marks a header that could not be decoded
+    let per_block = par_map_chunks(&reader, start_height, end_height, DEFAULT_CHUNK_SIZE, |snapshot, start, end| {
+        let mut rows = Vec::new();
+        for entry in snapshot.headers(start, end)? {
+            if cancel.is_cancelled() {
+                return Err(ReaderError::Cancelled);
+            }
+            progress.update(done.fetch_add(1, Ordering::Relaxed) + 1);
+            let (height, header) = match entry {
+                Ok(entry) => entry,
+                // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                Err(ReaderError::UnsupportedHeader { .. }) => {
+                    rows.push(None);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+
+            let block_hash = snapshot.block_hash(height, &header);
+            let mut matches = Vec::new();
+            for row in snapshot.outputs::<TransactionOutputRowData>(&block_hash)? {
+                let row = row?;
+                let script = ScriptSummary::from_opcodes(row.output.script.to_opcodes());
+                if pays_to(&script, public_key) {
+                    matches.push(ScanMatch {
+                        height,
+                        output_hash: hex::encode(row.hash.as_slice()),
+                        commitment: hex::encode(row.output.commitment.as_bytes()),
+                        output_kind: OutputKind::of(&row.output.features.output_type),
+                        minimum_value_promise: row.output.minimum_value_promise.as_u64(),
+                    });
+                }
+            }
+            rows.push(Some(matches));
+        }
+        Ok(rows)
+    });
+
+    let per_block = match per_block {
+        Ok(per_block) => per_block,
+        Err(e) => {
+            progress.finish(&format!("Scan stopped: {}", e));
+            return Err(e);
+        }
+    };
+
+    let blocks_skipped = per_block.iter().filter(|block| block.is_none()).count();
+    let matches: Vec<ScanMatch> = per_block.into_iter().flatten().flatten().collect();
+    progress.finish(&format!("{} matching outputs", matches.len()));
+
+    Ok(ScanReport {
+        public_key: public_key.to_string(),
+        start_height,
+        end_height,
+        matches,
+        blocks_skipped,
+    })
+}
+
+/// Print the scan report for the CLI
+pub fn print_scan_report(report: &ScanReport) {
+    println!("🔑 One-sided outputs paying {}", report.public_key);
+    println!("Heights scanned: {}..={}", report.start_height, report.end_height);
+    println!("┌─────────┬──────────────────────┬──────────────────────┬────────────┐");
+    println!("│ Height  │ Output hash          │ Commitment           │ Type       │");
+    println!("├─────────┼──────────────────────┼──────────────────────┼────────────┤");
+    for found in &report.matches {
+        println!("│ {:>7} │ {:<20} │ {:<20} │ {:<10} │",
+            found.height,
+            &found.output_hash[..20.min(found.output_hash.len())],
+            &found.commitment[..20.min(found.commitment.len())],
+            format!("{:?}", found.output_kind));
+    }
+    println!("└─────────┴──────────────────────┴──────────────────────┴────────────┘");
+    println!("Matching outputs: {}", report.matches.len());
+    if report.blocks_skipped > 0 {
+        println!("Note: Skipped {} blocks mined with C29 algorithm.", report.blocks_skipped);
+    }
+}
@@ -0,0 +1,145 @@
+// File: src/preflight.rs
+// Version: 1.1.1 - Summary line hidden with --verbosity quiet
+// Tree: tari-lmdb-inspector/src/preflight.rs
+//
+// Run before entering a mode: open the environment, read and decode the newest headers, read
+// the schema (migration) version and identify the network from the genesis hash. A database
+// written by an incompatible base node version otherwise shows up as a bincode error in the
+// middle of a session; here it stops startup with a message saying what to do.
+
+use std::path::Path;
+use anyhow::{bail, Result};
+use serde::Serialize;
+
+use tari_common::configuration::Network;
+use crate::genesis;
+use crate::lmdb_reader::metadata::read_chain_metadata;
+use crate::lmdb_reader::{ReaderError, StreamReader};
+use crate::verbosity;
+
+/// Newest headers tried before deciding the header layout does not match
+const HEADERS_TRIED: u64 = 10;
+
+/// What the preflight check found
+#[derive(Debug, Clone, Serialize)]
+pub struct PreflightReport {
+    /// `None` for an empty headers table
+    pub tip_height: Option<u64>,
+    /// Newest header that decoded with the linked Tari version
+    pub decoded_height: Option<u64>,
+    /// Schema version from the metadata table
+    pub migration_version: Option<u64>,
+    pub genesis_hash: Option<String>,
+    /// Network whose genesis block matches, if any
+    pub network: Option<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Check the database at `path` can be read as a chain for `expected`
+pub fn preflight(path: &Path, expected: Network) -> Result<PreflightReport> {
+    let reader = match StreamReader::open(path) {
+        Ok(reader) => reader,
+        Err(ReaderError::TableNotFound(table)) => bail!(
+            "No `{}` table in {}: this is not a Tari base node database. Point --database at the \
+            node's `data/base_node/db` directory, or run `inspect` to list the tables it has.",
+            table, path.display()
+        ),
+        Err(e) => bail!(
+            "Cannot open the LMDB environment at {} ({}). Check the directory contains data.mdb \
+            and is readable by this user.",
+            path.display(), e
+        ),
+    };
+    let snapshot = reader.snapshot()?;
+    let mut warnings = Vec::new();
+
+    let metadata = read_chain_metadata(path).unwrap_or(None);
+    let migration_version = metadata.as_ref().and_then(|m| m.migration_version);
+    if metadata.is_none() {
+        warnings.push("No chain metadata table; the tip is found by scanning headers".to_string());
+    }
+
+    let Some(tip_height) = snapshot.tip_height()? else {
+        warnings.push("The headers table is empty (node not synced yet)".to_string());
+        return Ok(PreflightReport {
+            tip_height: None,
+            decoded_height: None,
+            migration_version,
+            genesis_hash: None,
+            network: None,
+            warnings,
+        });
+    };
+
+    // C29-mined blocks never decode, so one failure near the tip is expected; a whole run of
+    // failures means the header layout differs from the linked Tari version
+    let mut decoded_height = None;
+    let mut last_error = None;
+    for entry in snapshot.headers(tip_height.saturating_sub(HEADERS_TRIED - 1), tip_height)? {
+        match entry {
+            Ok((height, _)) => decoded_height = Some(height),
+            Err(ReaderError::UnsupportedHeader { source, .. }) => last_error = Some(source.to_string()),
+            Err(e) => return Err(e.into()),
+        }
+    }
+    match (decoded_height, last_error) {
+        (None, Some(error)) => bail!(
+            "Header layout mismatch: none of the newest {} headers (tip {}) decode with this build's \
+            Tari types ({}). The database was written by a different base node version{}; rebuild \
+            the inspector against the matching Tari release, or pass --skip-preflight to continue \
+            anyway.",
+            HEADERS_TRIED, tip_height, error,
+            migration_version.map(|v| format!(" (schema version {})", v)).unwrap_or_default()
+        ),
+        (Some(height), Some(_)) if height < tip_height => {
+            warnings.push(format!("Tip header {} does not decode (C29-mined); newest readable is {}", tip_height, height));
+        }
+        _ => {}
+    }
+
+    // The genesis hash identifies the network the node was run on
+    let genesis_hash = match snapshot.headers(0, 0)?.next() {
+        Some(Ok((_, header))) => Some(hex::encode(snapshot.block_hash(0, &header))),
+        _ => None,
+    };
+    let network = genesis_hash.as_deref().and_then(genesis::network_of);
+    match network {
+        Some(network) if network != expected => bail!(
+            "This is a {} database but --network is {}. Pass --network {} so emission and \
+            consensus values match the chain.",
+            network, expected, network
+        ),
+        Some(_) => {}
+        None => warnings.push(format!(
+            "Genesis block does not match any known network; assuming --network {}",
+            expected
+        )),
+    }
+
+    Ok(PreflightReport {
+        tip_height: Some(tip_height),
+        decoded_height,
+        migration_version,
+        genesis_hash,
+        network: network.map(|n| n.to_string()),
+        warnings,
+    })
+}
+
+/// One-line summary plus warnings for startup output
+pub fn print_preflight(report: &PreflightReport) {
+    if verbosity::normal() {
+        match report.tip_height {
+            Some(tip) => println!(
+                "✅ Preflight: tip {}, network {}, schema version {}",
+                tip,
+                report.network.as_deref().unwrap_or("unknown"),
+                report.migration_version.map(|v| v.to_string()).unwrap_or_else(|| "unknown".to_string())
+            ),
+            None => println!("✅ Preflight: empty database"),
+        }
+    }
+    for warning in &report.warnings {
+        println!("⚠️  {}", warning);
+    }
+}
@@ -0,0 +1,165 @@
+// File: src/progress.rs
+// Version: 1.0.0 - Progress reporting for long scans (CLI bars, WebSocket events, TUI gauge)
+// Tree: tari-lmdb-inspector/src/progress.rs
+//
+// Table counting, full-chain hash searches and chain exports can run for minutes. They report
+// through `Progress` instead of printing, and each interface picks a sink: `TerminalProgress`
+// draws indicatif bars in CLI mode, `SharedProgress` feeds the TUI gauge, and web mode
+// broadcasts `ProgressEvent`s to WebSocket clients. `NoProgress` is the silent default.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::{Deserialize, Serialize};
+
+/// Receiver of progress from a long-running operation
+/// One operation is `begin` → any number of `update`s → `finish`
+pub trait Progress: Send + Sync {
+    /// Start `operation`; `total` is the number of steps when known up front
+    fn begin(&self, operation: &str, total: Option<u64>);
+
+    /// `done` steps of the current operation are complete
+    fn update(&self, done: u64);
+
+    /// The current operation ended; `message` summarises the outcome
+    fn finish(&self, message: &str);
+}
+
+impl fmt::Debug for dyn Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dyn Progress")
+    }
+}
+
+/// Discards all progress
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn begin(&self, _operation: &str, _total: Option<u64>) {}
+    fn update(&self, _done: u64) {}
+    fn finish(&self, _message: &str) {}
+}
+
+/// State of one operation, as sent to WebSocket clients and read by the TUI
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgressEvent {
+    pub operation: String,
+    pub done: u64,
+    pub total: Option<u64>,
+    pub finished: bool,
+    /// Outcome message once `finished`
+    pub message: Option<String>,
+}
+
+impl ProgressEvent {
+    /// Completed fraction (0.0..=1.0) when the total is known
+    pub fn ratio(&self) -> Option<f64> {
+        match self.total {
+            Some(total) if total > 0 => Some((self.done as f64 / total as f64).min(1.0)),
+            _ => None,
+        }
+    }
+}
+
+/// Keeps the latest `ProgressEvent` for a renderer to poll (the TUI gauge)
+#[derive(Debug, Clone, Default)]
+pub struct SharedProgress {
+    state: Arc<Mutex<Option<ProgressEvent>>>,
+}
+
+impl SharedProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Latest event, or `None` before the first operation starts
+    pub fn current(&self) -> Option<ProgressEvent> {
+        self.lock().clone()
+    }
+
+    /// Whether an operation is running
+    pub fn is_active(&self) -> bool {
+        self.lock().as_ref().is_some_and(|event| !event.finished)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<ProgressEvent>> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Progress for SharedProgress {
+    fn begin(&self, operation: &str, total: Option<u64>) {
+        *self.lock() = Some(ProgressEvent {
+            operation: operation.to_string(),
+            total,
+            ..ProgressEvent::default()
+        });
+    }
+
+    fn update(&self, done: u64) {
+        if let Some(event) = self.lock().as_mut() {
+            event.done = done;
+        }
+    }
+
+    fn finish(&self, message: &str) {
+        if let Some(event) = self.lock().as_mut() {
+            event.finished = true;
+            event.message = Some(message.to_string());
+        }
+    }
+}
+
+/// indicatif bar (known total) or spinner (unknown total) on stderr
+#[derive(Debug, Default)]
+pub struct TerminalProgress {
+    bar: Mutex<Option<ProgressBar>>,
+}
+
+impl TerminalProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<ProgressBar>> {
+        self.bar.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Progress for TerminalProgress {
+    fn begin(&self, operation: &str, total: Option<u64>) {
+        let bar = match total {
+            Some(total) => ProgressBar::new(total).with_style(
+                ProgressStyle::with_template("{msg} [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
+                    .unwrap_or_else(|_| ProgressStyle::default_bar())
+                    .progress_chars("=> "),
+            ),
+            None => ProgressBar::new_spinner().with_style(
+                ProgressStyle::with_template("{spinner} {msg} {pos} ({per_sec})")
+                    .unwrap_or_else(|_| ProgressStyle::default_spinner()),
+            ),
+        };
+        bar.set_message(operation.to_string());
+        if total.is_none() {
+            bar.enable_steady_tick(Duration::from_millis(120));
+        }
+        if let Some(previous) = self.lock().replace(bar) {
+            previous.finish_and_clear();
+        }
+    }
+
+    fn update(&self, done: u64) {
+        if let Some(bar) = self.lock().as_ref() {
+            bar.set_position(done);
+        }
+    }
+
+    fn finish(&self, message: &str) {
+        if let Some(bar) = self.lock().take() {
+            bar.finish_with_message(message.to_string());
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// File: src/rate_limit.rs
+// Version: 1.0.1 - Rejection logs follow --verbosity
+// Tree: tari-lmdb-inspector/src/rate_limit.rs
+//
+// Range and hash queries can trigger full header scans. These middleware layers keep a
+// single client from flooding the server (429 Too Many Requests) and bound how many
+// LMDB-backed requests run at once (503 Service Unavailable), both with Retry-After.
+
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tokio::sync::Semaphore;
+
+use crate::verbosity;
+
+/// Forget idle clients once the table grows beyond this many entries
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// Rate limit settings from the command line
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    /// Sustained requests per minute per client IP (0 disables per-IP limiting)
+    pub requests_per_minute: u32,
+    /// Maximum concurrent LMDB-backed requests across all clients
+    pub max_concurrent: usize,
+}
+
+/// Token bucket for a single client
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+/// Shared limiter state used by both middleware functions
+#[derive(Clone)]
+pub struct RateLimiter {
+    config: RateLimitConfig,
+    buckets: Arc<Mutex<HashMap<IpAddr, Bucket>>>,
+    semaphore: Arc<Semaphore>,
+}
+
+impl RateLimiter {
+    pub fn new(config: RateLimitConfig) -> Self {
+        let semaphore = Arc::new(Semaphore::new(config.max_concurrent.max(1)));
+        Self {
+            config,
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+            semaphore,
+        }
+    }
+
+    /// Take a token for `ip`, returning the seconds to wait if the bucket is empty
+    fn check(&self, ip: IpAddr) -> Result<(), u64> {
+        if self.config.requests_per_minute == 0 {
+            return Ok(());
+        }
+
+        // Allow short bursts of up to one minute's worth of requests
+        let capacity = self.config.requests_per_minute as f64;
+        let refill_per_sec = capacity / 60.0;
+        let now = Instant::now();
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        if buckets.len() > MAX_TRACKED_CLIENTS {
+            buckets.retain(|_, b| now.duration_since(b.last_refill).as_secs() < 60);
+        }
+
+        let bucket = buckets.entry(ip).or_insert(Bucket { tokens: capacity, last_refill: now });
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * refill_per_sec).min(capacity);
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(((1.0 - bucket.tokens) / refill_per_sec).ceil().max(1.0) as u64)
+        }
+    }
+}
+
+/// Per-IP rate limit middleware (429 with Retry-After when exceeded)
+pub async fn limit_per_ip(
+    State(limiter): State<RateLimiter>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match limiter.check(addr.ip()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            if verbosity::normal() {
+                println!("🚦 Rate limit exceeded for {} ({})", addr.ip(), request.uri().path());
+            }
+            reject(StatusCode::TOO_MANY_REQUESTS, retry_after, "Rate limit exceeded")
+        }
+    }
+}
+
+/// Global concurrency cap for LMDB-backed routes (503 with Retry-After when saturated)
+pub async fn limit_concurrency(
+    State(limiter): State<RateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    // Fail fast instead of queueing blocking LMDB reads behind each other
+    match limiter.semaphore.clone().try_acquire_owned() {
+        Ok(_permit) => next.run(request).await,
+        Err(_) => {
+            if verbosity::normal() {
+                println!("🚦 LMDB request cap reached ({} in flight), rejecting {}", limiter.config.max_concurrent, request.uri().path());
+            }
+            reject(StatusCode::SERVICE_UNAVAILABLE, 1, "Server busy, too many concurrent database requests")
+        }
+    }
+}
+
+/// Build an error response with a Retry-After header
+fn reject(status: StatusCode, retry_after_secs: u64, message: &str) -> Response {
+    let mut response = (status, message.to_string()).into_response();
+    if let Ok(value) = HeaderValue::from_str(&retry_after_secs.to_string()) {
+        response.headers_mut().insert(header::RETRY_AFTER, value);
+    }
+    response
+}
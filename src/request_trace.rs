@@ -0,0 +1,177 @@
+// File: src/request_trace.rs
+// Version: 1.0.1 - Span URIs with the ?token= auth secret redacted
+// Tree: tari-lmdb-inspector/src/request_trace.rs
+//
+// Every web request gets an `x-request-id` (kept when the client sends one, echoed in the
+// response) and a tracing span carrying it. `record_request` runs inside that span and logs one
+// event per request with the matched route, status, total latency and the part of it spent in
+// LMDB reads, so a slow block-detail query can be told apart from a slow client or a long queue
+// behind the concurrency cap. The same numbers are summed per route and rendered on `/metrics`.
+//
+// Events are `debug` normally and `warn` for server errors and requests slower than
+// `SLOW_REQUEST`; `RUST_LOG` overrides the level picked from `--verbosity`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{HeaderName, Uri},
+    middleware::Next,
+    response::Response,
+};
+use tracing_subscriber::EnvFilter;
+
+use crate::lmdb_reader::r#async::measure_reads;
+use crate::verbosity::Verbosity;
+
+/// Header carrying the request ID
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Requests slower than this are logged as warnings
+pub const SLOW_REQUEST: Duration = Duration::from_secs(1);
+
+/// Route label for requests no route matched
+const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// Totals for one route
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RouteStats {
+    pub requests: u64,
+    /// Requests answered 4xx
+    pub client_errors: u64,
+    /// Requests answered 5xx
+    pub server_errors: u64,
+    pub slow: u64,
+    pub latency_total: Duration,
+    pub latency_max: Duration,
+    pub lmdb_total: Duration,
+    pub lmdb_max: Duration,
+}
+
+/// Per-route request totals, shared through `AppState`
+#[derive(Clone, Default)]
+pub struct RequestMetrics {
+    routes: Arc<Mutex<BTreeMap<String, RouteStats>>>,
+}
+
+impl RequestMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeMap<String, RouteStats>> {
+        self.routes.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Add one finished request to its route's totals
+    pub fn record(&self, route: &str, status: u16, latency: Duration, lmdb: Duration) {
+        let mut routes = self.lock();
+        let stats = routes.entry(route.to_string()).or_default();
+        stats.requests += 1;
+        match status {
+            400..=499 => stats.client_errors += 1,
+            500..=599 => stats.server_errors += 1,
+            _ => {}
+        }
+        if latency >= SLOW_REQUEST {
+            stats.slow += 1;
+        }
+        stats.latency_total += latency;
+        stats.latency_max = stats.latency_max.max(latency);
+        stats.lmdb_total += lmdb;
+        stats.lmdb_max = stats.lmdb_max.max(lmdb);
+    }
+
+    /// Totals for `route`, if it has been requested
+    pub fn route(&self, route: &str) -> Option<RouteStats> {
+        self.lock().get(route).cloned()
+    }
+
+    /// Prometheus text exposition of the per-route totals
+    pub fn render_prometheus(&self) -> String {
+        let routes = self.lock();
+        let mut out = String::new();
+
+        let counters: [(&str, &str, fn(&RouteStats) -> u64); 4] = [
+            ("inspector_http_requests_total", "HTTP requests answered", |s| s.requests),
+            ("inspector_http_client_errors_total", "HTTP requests answered with a 4xx status", |s| s.client_errors),
+            ("inspector_http_server_errors_total", "HTTP requests answered with a 5xx status", |s| s.server_errors),
+            ("inspector_http_slow_requests_total", "HTTP requests slower than 1s", |s| s.slow),
+        ];
+        for (name, help, value) in counters {
+            let _ = writeln!(out, "# HELP {} {}", name, help);
+            let _ = writeln!(out, "# TYPE {} counter", name);
+            for (route, stats) in routes.iter() {
+                let _ = writeln!(out, "{}{{route=\"{}\"}} {}", name, route, value(stats));
+            }
+        }
+
+        let seconds: [(&str, &str, &str, fn(&RouteStats) -> Duration); 4] = [
+            ("inspector_http_request_seconds_total", "Time spent answering HTTP requests", "counter", |s| s.latency_total),
+            ("inspector_http_request_seconds_max", "Slowest HTTP request", "gauge", |s| s.latency_max),
+            ("inspector_http_lmdb_read_seconds_total", "Time HTTP requests spent in LMDB reads", "counter", |s| s.lmdb_total),
+            ("inspector_http_lmdb_read_seconds_max", "Most LMDB read time of one HTTP request", "gauge", |s| s.lmdb_max),
+        ];
+        for (name, help, kind, value) in seconds {
+            let _ = writeln!(out, "# HELP {} {}", name, help);
+            let _ = writeln!(out, "# TYPE {} {}", name, kind);
+            for (route, stats) in routes.iter() {
+                let _ = writeln!(out, "{}{{route=\"{}\"}} {:.6}", name, route, value(stats).as_secs_f64());
+            }
+        }
+
+        out
+    }
+}
+
+/// Middleware logging and recording route, status, latency and LMDB read time of each request
+/// Runs inside the `TraceLayer` span, so its events carry the request ID
+pub async fn record_request(State(metrics): State<RequestMetrics>, request: Request, next: Next) -> Response {
+    let route = request.extensions().get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_ROUTE.to_string());
+    let started = Instant::now();
+
+    let (response, lmdb) = measure_reads(next.run(request)).await;
+    let latency = started.elapsed();
+    let status = response.status();
+
+    metrics.record(&route, status.as_u16(), latency, lmdb);
+    let latency_ms = latency.as_secs_f64() * 1000.0;
+    let lmdb_ms = lmdb.as_secs_f64() * 1000.0;
+    if status.is_server_error() || latency >= SLOW_REQUEST {
+        tracing::warn!(route = %route, status = status.as_u16(), latency_ms, lmdb_ms, "request finished");
+    } else {
+        tracing::debug!(route = %route, status = status.as_u16(), latency_ms, lmdb_ms, "request finished");
+    }
+    response
+}
+
+/// `uri` for the request span, with the value of any `token` query parameter (the
+/// `--auth-token` secret browsers log in with) replaced by `REDACTED`
+pub fn redacted_uri(uri: &Uri) -> String {
+    let Some(query) = uri.query() else {
+        return uri.to_string();
+    };
+    let query: Vec<&str> = query.split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some(("token", _)) => "token=REDACTED",
+            _ => pair,
+        })
+        .collect();
+    format!("{}?{}", uri.path(), query.join("&"))
+}
+
+/// Install the log subscriber for request events; `RUST_LOG` wins over `verbosity`
+/// Does nothing if a subscriber is already installed (e.g. by a library user)
+pub fn init_logging(verbosity: Verbosity) {
+    let default = match verbosity {
+        Verbosity::Quiet | Verbosity::Normal => "warn",
+        Verbosity::Debug => "warn,tari_lmdb_inspector=debug",
+    };
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(default));
+    let _ = tracing_subscriber::fmt().with_env_filter(filter).with_target(false).try_init();
+}
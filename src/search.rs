@@ -0,0 +1,223 @@
+// File: src/search.rs
+// Version: 1.5.0 - Lookups through KvBackend
+// Tree: tari-lmdb-inspector/src/search.rs
+//
+// A query is either a height or 32 bytes of hex. Hex is tried, in order, as a block hash
+// (`block_hashes`), an output hash or commitment (`txos_hash_to_index`,
+// `utxo_commitment_index`, else a key scan of `utxos`) and a kernel excess
+// (`kernel_excess_index`). Index values are located by their trailing bytes so both raw and
+// length-prefixed bincode encodings are accepted.
+
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use tari_utilities::byte_array::ByteArray;
+
+use tari_node_components::blocks::BlockHeader;
+use crate::lmdb_reader::block_hash::BlockHashResolver;
+use crate::lmdb_reader::txo_index::trailing;
+use crate::lmdb_reader::{
+    height_of_block, open_backend, search_block_by_hash_with_progress, KvRead, OutputFeaturesLite, ReaderError, ReaderResult, TransactionKernelRowData, TransactionOutputRowData,
+};
+use crate::denomination::{self, Denomination};
+use crate::progress::{NoProgress, Progress};
+use tokio_util::sync::CancellationToken;
+
+/// A parsed search box query
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchQuery {
+    Height(u64),
+    Hash([u8; 32]),
+}
+
+impl SearchQuery {
+    /// Numeric → height, 64 hex characters → hash; anything else is rejected
+    pub fn parse(query: &str) -> Option<Self> {
+        let query = query.trim();
+        if let Ok(height) = query.parse::<u64>() {
+            return Some(SearchQuery::Height(height));
+        }
+        if query.len() != 64 {
+            return None;
+        }
+        let bytes = hex::decode(query).ok()?;
+        Some(SearchQuery::Hash(bytes.try_into().ok()?))
+    }
+}
+
+/// What a query resolved to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SearchResult {
+    Block {
+        height: u64,
+        hash: String,
+    },
+    Output {
+        height: u64,
+        block_hash: String,
+        output_hash: String,
+        commitment: String,
+        output_type: String,
+        minimum_value_promise: u64,
+        features: OutputFeaturesLite,
+    },
+    Kernel {
+        /// Height of the containing block, when `block_hashes` knows it
+        height: Option<u64>,
+        block_hash: String,
+        excess: String,
+        fee: u64,
+        /// `fee` in µT
+        #[serde(default)]
+        fee_ut: u64,
+        /// `fee` in T as an exact decimal string
+        #[serde(default)]
+        fee_t: String,
+        features: String,
+    },
+    NotFound,
+}
+
+impl SearchResult {
+    /// Height of the block to open for this result
+    pub fn height(&self) -> Option<u64> {
+        match self {
+            SearchResult::Block { height, .. } | SearchResult::Output { height, .. } => Some(*height),
+            SearchResult::Kernel { height, .. } => *height,
+            SearchResult::NotFound => None,
+        }
+    }
+
+    /// One-line description for the TUI and the search page, with amounts in `denom`
+    pub fn describe(&self, denom: Denomination) -> String {
+        match self {
+            SearchResult::Block { height, hash } => format!("Block {} ({}...)", height, &hash[..16.min(hash.len())]),
+            SearchResult::Output { height, features, .. } => format!("Output mined in block {} ({})", height, features),
+            SearchResult::Kernel { height: Some(height), fee, .. } => format!("Kernel in block {} (fee {})", height, denom.format(*fee)),
+            SearchResult::Kernel { fee, .. } => format!("Kernel in an unindexed block (fee {})", denom.format(*fee)),
+            SearchResult::NotFound => "No block, output or kernel matches".to_string(),
+        }
+    }
+}
+
+/// Resolve a query against the database at `path`
+pub fn search(path: &Path, query: &SearchQuery) -> ReaderResult<SearchResult> {
+    search_with_progress(path, query, &NoProgress, &CancellationToken::new())
+}
+
+/// `search`, reporting the header scan used when `block_hashes` is missing to `progress`
+/// Index-less scans stop with `ReaderError::Cancelled` once `cancel` fires
+pub fn search_with_progress(
+    path: &Path,
+    query: &SearchQuery,
+    progress: &dyn Progress,
+    cancel: &CancellationToken,
+) -> ReaderResult<SearchResult> {
+    let backend = open_backend(path)?;
+    let has_hashes = {
+        let read = backend.read()?;
+        // Without a headers table this is not a base node database
+        read.entries("headers")?;
+        let has_hashes = read.has_table("block_hashes")?;
+        let resolver = BlockHashResolver::new(&*read)?;
+
+        let hash: &[u8] = match query {
+            SearchQuery::Height(height) => {
+                let Some(data) = read.get("headers", &height.to_le_bytes())? else {
+                    return Ok(SearchResult::NotFound);
+                };
+                let hash = match resolver.resolve_confirmed(&*read, *height) {
+                    Some(resolved) => resolved.to_hex(),
+                    None => bincode::deserialize::<BlockHeader>(&data)
+                        .map(|header| hex::encode(header.hash().as_slice()))
+                        .map_err(|e| ReaderError::UnsupportedHeader { height: *height, source: e })?,
+                };
+                return Ok(SearchResult::Block { height: *height, hash });
+            }
+            SearchQuery::Hash(hash) => &hash[..],
+        };
+        // Block hash
+        if let Some(height) = height_of_block(&*read, hash) {
+            return Ok(SearchResult::Block { height, hash: hex::encode(hash) });
+        }
+
+        // Output hash, or a commitment mapped to its output hash
+        if read.has_table("utxos")? {
+            let commitment_output = read.get("utxo_commitment_index", hash).ok().flatten();
+            let output_hash = commitment_output.as_deref()
+                .and_then(|value| trailing(value, 32))
+                .unwrap_or(hash);
+            let key = match read.get("txos_hash_to_index", output_hash).ok().flatten() {
+                Some(value) => trailing(&value, 64).filter(|key| key.ends_with(output_hash)).map(<[u8]>::to_vec),
+                None => find_key_with_suffix(&*read, "utxos", output_hash, cancel)?,
+            };
+            if let Some(value) = key.and_then(|key| read.get("utxos", &key).ok().flatten()) {
+                let row: TransactionOutputRowData = bincode::deserialize(&value)
+                    .map_err(|e| ReaderError::Decode { table: "utxos", source: e })?;
+                return Ok(SearchResult::Output {
+                    height: row.mined_height,
+                    block_hash: hex::encode(row.header_hash.as_slice()),
+                    output_hash: hex::encode(row.hash.as_slice()),
+                    commitment: hex::encode(row.output.commitment.as_bytes()),
+                    output_type: format!("{:?}", row.output.features.output_type),
+                    minimum_value_promise: row.output.minimum_value_promise.as_u64(),
+                    features: OutputFeaturesLite::from(&row.output.features),
+                });
+            }
+        }
+
+        // Kernel excess; the index value is the kernels key (block hash + kernel hash)
+        if let Some(index_value) = read.get("kernel_excess_index", hash).ok().flatten() {
+            let key = trailing(&index_value, 64);
+            if let Some(value) = key.and_then(|key| read.get("kernels", key).ok().flatten()) {
+                let row: TransactionKernelRowData = bincode::deserialize(&value)
+                    .map_err(|e| ReaderError::Decode { table: "kernels", source: e })?;
+                return Ok(SearchResult::Kernel {
+                    height: height_of_block(&*read, row.header_hash.as_slice()),
+                    block_hash: hex::encode(row.header_hash.as_slice()),
+                    excess: hex::encode(row.kernel.excess.as_bytes()),
+                    fee: row.kernel.fee.0,
+                    fee_ut: row.kernel.fee.0,
+                    fee_t: denomination::tari(row.kernel.fee.0),
+                    features: format!("{:?}", row.kernel.features),
+                });
+            }
+        }
+        has_hashes
+    };
+    // The scan opens the environment itself
+    drop(backend);
+
+    // Without the hash index, fall back to scanning every header
+    if !has_hashes {
+        if let Some(block) = search_block_by_hash_with_progress(path, &hex::encode(hash), progress, cancel)? {
+            return Ok(SearchResult::Block { height: block.height, hash: block.hash });
+        }
+    }
+
+    Ok(SearchResult::NotFound)
+}
+
+/// Key scan of a block-hash-prefixed table for a row hash; used when the index table is absent
+fn find_key_with_suffix(
+    read: &dyn KvRead,
+    table: &str,
+    row_hash: &[u8],
+    cancel: &CancellationToken,
+) -> ReaderResult<Option<Vec<u8>>> {
+    let mut cursor = read.cursor(table)?;
+    let mut entry = cursor.first()?;
+    let mut scanned: u64 = 0;
+    while let Some((key, _)) = entry {
+        if key.len() == 64 && key.ends_with(row_hash) {
+            return Ok(Some(key.to_vec()));
+        }
+        scanned += 1;
+        if scanned % 10_000 == 0 && cancel.is_cancelled() {
+            return Err(ReaderError::Cancelled);
+        }
+        entry = cursor.next()?;
+    }
+    Ok(None)
+}
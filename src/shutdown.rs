@@ -0,0 +1,72 @@
+// File: src/shutdown.rs
+// Version: 1.0.0 - SIGINT / SIGTERM handling shared by every mode
+// Tree: tari-lmdb-inspector/src/shutdown.rs
+//
+// Service managers stop a process with SIGTERM, which tokio does not catch by default, so a
+// stopped web server skipped its warm-start save and WebSocket clients saw a dropped socket. Every
+// mode waits on `signal` instead of `tokio::signal::ctrl_c`: the web server drains connections
+// and tells WebSocket clients it is going away, the TUI restores the terminal, and scans stop at
+// their next cancellation check. Whatever flushes caches runs after the signal, not in a handler.
+
+use tokio_util::sync::CancellationToken;
+
+/// Signal that requested the shutdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Signal {
+    /// SIGINT (Ctrl-C)
+    Interrupt,
+    /// SIGTERM (service manager, `kill`)
+    Terminate,
+}
+
+impl Signal {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Interrupt => "SIGINT",
+            Self::Terminate => "SIGTERM",
+        }
+    }
+}
+
+/// Resolve on the next SIGINT or SIGTERM (Ctrl-C only on non-Unix platforms)
+/// Never resolves if no handler can be installed
+pub async fn signal() -> Signal {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let terminate = async {
+            match signal(SignalKind::terminate()) {
+                Ok(mut terminate) => {
+                    terminate.recv().await;
+                }
+                Err(_) => std::future::pending::<()>().await,
+            }
+        };
+        tokio::select! {
+            _ = interrupt() => Signal::Interrupt,
+            _ = terminate => Signal::Terminate,
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        interrupt().await;
+        Signal::Interrupt
+    }
+}
+
+async fn interrupt() {
+    if tokio::signal::ctrl_c().await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Token cancelled by the first SIGINT or SIGTERM
+pub fn on_signal() -> CancellationToken {
+    let token = CancellationToken::new();
+    let cancel = token.clone();
+    tokio::spawn(async move {
+        signal().await;
+        cancel.cancel();
+    });
+    token
+}
@@ -0,0 +1,1420 @@
+// Tari LMDB Inspector dashboard logic (served from /static, embedded in the binary)
+
+let ws = null;
+let lastUpdateTime = Date.now();
+let blockMonitoring = true;
+let blocksToShow = 15;
+
+// Search-related global variables
+let isSearchMode = false;
+let currentSearchTerm = '';
+let allBlocks = [];
+let filteredBlocks = [];
+let currentPage = 1;
+let totalPages = 1;
+let blocksPerPage = 20;
+
+// Node being viewed (?node=NAME); the server's /api/v1/nodes lists the others
+let apiBase = '/api/v1';
+let wsPath = '/ws';
+
+// Analytics chart state
+let feeChart = null;
+let intervalChart = null;
+let utxoGrowthChart = null;
+let powShareChart = null;
+let analyticsHeight = 0;
+
+// WebSocket connection
+function connectWebSocket() {
+    const protocol = window.location.protocol === 'https:' ? 'wss:' : 'ws:';
+    const wsUrl = `${protocol}//${window.location.host}${wsPath}`;
+    
+    ws = new WebSocket(wsUrl);
+    
+    ws.onopen = function() {
+        console.log('WebSocket connected - Block monitoring active');
+        updateConnectionStatus('connected');
+        requestDashboardData();
+        startConnectionHeartbeat();
+    };
+    
+    ws.onmessage = function(event) {
+        try {
+            const message = JSON.parse(event.data);
+            handleWebSocketMessage(message);
+        } catch (e) {
+            console.error('Error parsing WebSocket message:', e);
+        }
+    };
+    
+    ws.onclose = function() {
+        console.log('WebSocket disconnected - Attempting reconnection...');
+        updateConnectionStatus('disconnected');
+        setTimeout(connectWebSocket, 5000);
+    };
+    
+    ws.onerror = function(error) {
+        console.error('WebSocket error:', error);
+        showError('WebSocket connection error');
+    };
+}
+
+function updateConnectionStatus(status) {
+    const statusElement = document.getElementById('connectionStatus');
+    if (status === 'connected') {
+        statusElement.textContent = '🟢 Connected (Block Monitor)';
+        statusElement.className = 'connection-status connected';
+    } else if (status === 'monitoring') {
+        statusElement.textContent = '🔵 Monitoring Blocks';
+        statusElement.className = 'connection-status monitoring';
+    } else {
+        statusElement.textContent = '🔴 Disconnected';
+        statusElement.className = 'connection-status disconnected';
+    }
+}
+
+// Tip age badge: synced (green), lagging while catching up (orange), stalled (red)
+function updateSyncStatus(sync) {
+    const element = document.getElementById('syncStatus');
+    const labels = {
+        synced: '✅ Node synced',
+        lagging: `⏳ Node lagging ${sync.minutes} min`,
+        stalled: `🛑 Node stalled ${sync.minutes} min`,
+    };
+    element.textContent = labels[sync.status] || sync.status;
+    element.className = `connection-status sync-${sync.status}`;
+    element.style.display = 'inline-block';
+}
+
+function startConnectionHeartbeat() {
+    // Send ping every 30 seconds to keep connection alive
+    function sendPing() {
+        if (ws && ws.readyState === WebSocket.OPEN) {
+            ws.send(JSON.stringify({ type: 'Ping' }));
+            setTimeout(sendPing, 30000);
+        }
+    }
+    setTimeout(sendPing, 30000);
+}
+
+function requestDashboardData() {
+    if (ws && ws.readyState === WebSocket.OPEN) {
+        ws.send(JSON.stringify({ type: 'GetDashboard' }));
+    }
+}
+
+function handleWebSocketMessage(message) {
+    switch (message.type) {
+        case 'DashboardData':
+            handleDashboardUpdate(message.data);
+            break;
+        case 'Pong':
+            // Connection heartbeat response
+            break;
+        case 'Error':
+            showError(message.message);
+            break;
+        case 'Progress':
+            showScanProgress(message.progress);
+            break;
+        case 'WatchHit':
+            showWatchHit(message.hit);
+            break;
+        case 'Shutdown':
+            // The socket closes next; onclose keeps trying to reconnect
+            showError('Server is shutting down');
+            break;
+        default:
+            console.log('Unknown message type:', message.type);
+    }
+}
+
+// Long server-side scans (table counting, hash search) report here
+let scanProgressTimer = null;
+function showScanProgress(progress) {
+    const element = document.getElementById('scanProgress');
+    if (!element) return;
+
+    let text = progress.operation;
+    if (progress.finished) {
+        text = progress.message || `${progress.operation} done`;
+    } else if (progress.total) {
+        text += `: ${Math.floor(progress.done * 100 / progress.total)}% (${progress.done.toLocaleString()} / ${progress.total.toLocaleString()})`;
+    } else {
+        text += `: ${progress.done.toLocaleString()}`;
+    }
+    element.textContent = `⏳ ${text}`;
+    element.style.display = 'block';
+
+    clearTimeout(scanProgressTimer);
+    if (progress.finished) {
+        scanProgressTimer = setTimeout(() => { element.style.display = 'none'; }, 5000);
+    }
+}
+
+// Watchlisted commitment / excess seen in a new block
+function showWatchHit(hit) {
+    const name = hit.item.label || `${hit.item.value.substring(0, 16)}...`;
+    showSuccess(`👀 Watched ${hit.item.kind} ${name} seen as ${hit.location} in block ${hit.height.toLocaleString()}`);
+}
+
+function handleDashboardUpdate(data) {
+    const isInitialLoad = document.getElementById('dashboard').style.display === 'none';
+    
+    if (isInitialLoad) {
+        // Initial load
+        updateDashboard(data);
+    } else {
+        // Real-time update - check if this is a new block
+        const currentLatestHeight = parseInt(document.getElementById('latestHeight').textContent.replace(/,/g, '')) || 0;
+        const newLatestHeight = data.network_stats?.latest_block_height || 0;
+        
+        if (newLatestHeight > currentLatestHeight) {
+            // New block detected!
+            console.log('🆕 New block detected:', currentLatestHeight, '->', newLatestHeight);
+            showNewBlockNotification(newLatestHeight);
+            updateDashboard(data);
+        } else {
+            // Just a regular data update
+            updateDashboard(data);
+        }
+    }
+}
+
+function showNewBlockNotification(height) {
+    // Show success notification
+    showSuccess(`🆕 New block detected: ${height.toLocaleString()}`);
+    
+    // Add glow effect to stats grid temporarily
+    const statsGrid = document.querySelector('.stats-grid');
+    if (statsGrid) {
+        statsGrid.classList.add('new-block-notification');
+        setTimeout(() => {
+            statsGrid.classList.remove('new-block-notification');
+        }, 2000);
+    }
+}
+
+function updateDashboard(data) {
+    document.getElementById('loadingScreen').style.display = 'none';
+    document.getElementById('dashboard').style.display = 'block';
+    
+    // Update statistics
+    const stats = data.database_stats;
+    const completeness = data.data_completeness || { status: 'full' };
+    if (completeness.status === 'headers_only') {
+        // Transaction tables are missing: zeros would be misleading
+        document.getElementById('pruningHorizon').style.display = 'none';
+        ['utxosCount', 'inputsCount', 'transactionsCount', 'totalRecords'].forEach(id => {
+            const el = document.getElementById(id);
+            el.textContent = 'N/A';
+            el.title = 'Headers-only database: transaction tables are missing';
+        });
+    } else {
+        const prunedNote = completeness.status === 'pruned'
+            ? `Pruned node: transaction data from height ${completeness.horizon.toLocaleString()}`
+            : '';
+        document.getElementById('utxosCount').textContent = stats.utxos_count.toLocaleString();
+        document.getElementById('inputsCount').textContent = stats.inputs_count.toLocaleString();
+        document.getElementById('transactionsCount').textContent = stats.kernels_count.toLocaleString();
+        document.getElementById('totalRecords').textContent = stats.total_io_records.toLocaleString();
+        ['utxosCount', 'inputsCount', 'transactionsCount', 'totalRecords'].forEach(id => {
+            document.getElementById(id).title = prunedNote;
+        });
+        const horizonBanner = document.getElementById('pruningHorizon');
+        horizonBanner.textContent = prunedNote ? `✂️ ${prunedNote}` : '';
+        horizonBanner.style.display = prunedNote ? 'block' : 'none';
+    }
+    
+    // Update network stats
+    if (data.network_stats) {
+        document.getElementById('latestHeight').textContent = data.network_stats.latest_block_height.toLocaleString();
+        document.getElementById('avgBlockTime').textContent = data.network_stats.average_block_time + 's';
+        updateSyncStatus(data.network_stats.sync_status || { status: 'synced' });
+    }
+    
+    // Update last updated time with full date/time format
+    const lastUpdated = new Date(data.last_updated * 1000);
+    // Warm start: the server is still reading LMDB and showing the previous run's data
+    const staleNote = data.stale ? ' (saved from previous run - refreshing…)' : '';
+    const dateTimeString = lastUpdated.toLocaleDateString('en-US', {
+        weekday: 'short',
+        year: 'numeric', 
+        month: 'short',
+        day: 'numeric'
+    }) + ' at ' + lastUpdated.toLocaleTimeString('en-US', {
+        hour: '2-digit',
+        minute: '2-digit',
+        second: '2-digit'
+    }) + staleNote;
+    
+    const lastUpdatedElement = document.getElementById('lastUpdated');
+    if (lastUpdatedElement) {
+        lastUpdatedElement.textContent = dateTimeString;
+    }
+    
+    const lastUpdatedCompactElement = document.getElementById('lastUpdatedCompact');
+    if (lastUpdatedCompactElement) {
+        lastUpdatedCompactElement.textContent = dateTimeString;
+    }
+    
+    lastUpdateTime = Date.now();
+    
+    // Update blocks data but DON'T override search results
+    allBlocks = Array.isArray(data.recent_blocks) ? data.recent_blocks : [];
+    console.log('Received blocks:', allBlocks.length, allBlocks);
+    
+    // Only update table if we're not in search mode
+    if (!isSearchMode) {
+        console.log('Normal mode: updating table with recent blocks');
+        currentPage = 1;
+        applyTextSearch(currentSearchTerm);
+    } else {
+        console.log('Search mode: preserving search results, not updating table');
+    }
+    
+    // Update insights
+    updateInsights(data);
+    
+    // Refresh analytics charts when the tip moves
+    const tipHeight = data.network_stats?.latest_block_height || 0;
+    if (tipHeight !== analyticsHeight) {
+        analyticsHeight = tipHeight;
+        loadAnalytics();
+    }
+}
+
+function handleSearch(event) {
+    const searchTerm = document.getElementById('blockSearch').value.trim();
+    
+    if (event.key === 'Enter') {
+        // Immediate search on Enter
+        performSearch();
+    } else {
+        // For block height searches, wait longer before auto-searching
+        // For text searches, search more quickly
+        const blockHeight = parseInt(searchTerm);
+        const isBlockHeight = !isNaN(blockHeight) && blockHeight > 0;
+        const isRange = /^\d+-\d+$/.test(searchTerm);
+        
+        clearTimeout(window.searchTimeout);
+        
+        if (isBlockHeight || isRange) {
+            // Wait 1 second for block height/range searches to avoid multiple API calls
+            window.searchTimeout = setTimeout(performSearch, 1000);
+        } else {
+            // Quick search for text/hash searches in loaded blocks
+            window.searchTimeout = setTimeout(performSearch, 300);
+        }
+    }
+}
+
+function performSearch() {
+    const searchTerm = document.getElementById('blockSearch').value.trim();
+    
+    // Cancel any previous API request
+    if (window.currentSearchController) {
+        window.currentSearchController.abort();
+    }
+    
+    // Check if search term is a RANGE (e.g., "5-10", "100-200")
+    const rangePattern = /^(\d+)-(\d+)$/;
+    const rangeMatch = searchTerm.match(rangePattern);
+    
+    if (rangeMatch) {
+        const startHeight = parseInt(rangeMatch[1]);
+        const endHeight = parseInt(rangeMatch[2]);
+        
+        // Validate range
+        if (startHeight > 0 && endHeight > 0 && startHeight <= endHeight) {
+            searchBlockRange(startHeight, endHeight);
+            return;
+        } else {
+            displaySearchResult([], `❌ Invalid range: start (${startHeight}) must be ≤ end (${endHeight})`);
+            return;
+        }
+    }
+    
+    // Check if search term is a FULL HASH (64 hex characters)
+    const hashPattern = /^[a-fA-F0-9]{64}$/;
+    if (hashPattern.test(searchTerm)) {
+        // Search for specific hash via API (entire blockchain)
+        searchSpecificHash(searchTerm);
+        return;
+    }
+    
+    // Check if search term is a single block height (number)
+    const blockHeight = parseInt(searchTerm);
+    if (!isNaN(blockHeight) && blockHeight > 0) {
+        // Search for specific block height via API
+        searchSpecificBlock(blockHeight);
+    } else if (searchTerm.length > 0) {
+        // Regular text search in loaded blocks (partial hashes, PoW algorithms, etc.)
+        applyTextSearch(searchTerm);
+    } else {
+        // Empty search - show all blocks
+        clearSearch();
+    }
+}
+
+// New function to handle range searches
+async function searchBlockRange(startHeight, endHeight) {
+    try {
+        console.log('Searching for block range:', startHeight, 'to', endHeight);
+        
+        // Validate range size (max 1000 as per backend)
+        const rangeSize = endHeight - startHeight + 1;
+        if (rangeSize > 1000) {
+            displaySearchResult([], `❌ Range too large: ${rangeSize} blocks (max 1000)`);
+            return;
+        }
+        
+        // Enter search mode
+        isSearchMode = true;
+        currentSearchTerm = `${startHeight}-${endHeight}`;
+        
+        // Create new AbortController for this search
+        window.currentSearchController = new AbortController();
+        
+        // Show loading in table
+        const tbody = document.getElementById('blocksTable');
+        if (tbody) {
+            tbody.innerHTML = `<tr><td colspan="9" class="loading">🔍 Searching blockchain for blocks ${startHeight} to ${endHeight}...</td></tr>`;
+        }
+        
+        // Update pagination info
+        const paginationInfo = document.getElementById('paginationInfo');
+        if (paginationInfo) {
+            paginationInfo.textContent = 'Searching blockchain range...';
+        }
+        
+        const response = await fetch(`${apiBase}/blocks/range?start=${startHeight}&end=${endHeight}`, {
+            signal: window.currentSearchController.signal
+        });
+        
+        if (response.ok) {
+            const rangeData = await response.json();
+            console.log('Found range:', rangeData);
+            
+            // Convert API response to our block format
+            const blocks = rangeData.blocks.map(block => ({
+                height: block.height,
+                hash: block.hash,
+                timestamp: block.timestamp,
+                transaction_count: block.kernel_count,
+                outputs_count: block.output_count,
+                inputs_count: block.input_count,
+                interval_seconds: null, // We don't calculate intervals for arbitrary ranges
+                pow_algorithm: 'N/A' // Range API doesn't include PoW algorithm
+            }));
+            
+            // Sort by height descending (newest first)
+            blocks.sort((a, b) => b.height - a.height);
+            
+            // Display range results
+            const message = `✅ Found ${blocks.length} blocks in range ${startHeight}-${endHeight}`;
+            displaySearchResult(blocks, message);
+            
+        } else if (response.status === 400) {
+            displaySearchResult([], `❌ Invalid range request: ${startHeight}-${endHeight}`);
+        } else if (response.status === 404) {
+            displaySearchResult([], `❌ No blocks found in range ${startHeight}-${endHeight}`);
+        } else {
+            throw new Error(`HTTP ${response.status}`);
+        }
+        
+    } catch (error) {
+        if (error.name === 'AbortError') {
+            console.log('Range search cancelled');
+            return;
+        }
+        console.error('Range search error:', error);
+        displaySearchResult([], `❌ Error searching range ${startHeight}-${endHeight}: ${error.message}`);
+    }
+}
+
+async function searchSpecificHash(hash) {
+    try {
+        console.log('Searching blocks, outputs and kernels for hash:', hash.substring(0, 20) + '...');
+        
+        // Enter search mode
+        isSearchMode = true;
+        currentSearchTerm = hash;
+        
+        // Create new AbortController for this search
+        window.currentSearchController = new AbortController();
+        
+        // Show loading in table
+        const tbody = document.getElementById('blocksTable');
+        if (tbody) {
+            tbody.innerHTML = '<tr><td colspan="9" class="loading">🔍 Searching entire blockchain for hash ' + hash.substring(0, 20) + '...</td></tr>';
+        }
+        
+        // Update pagination info
+        const paginationInfo = document.getElementById('paginationInfo');
+        if (paginationInfo) {
+            paginationInfo.textContent = 'Searching entire blockchain by hash...';
+        }
+        
+        const response = await fetch(`${apiBase}/search?q=${hash}`, {
+            signal: window.currentSearchController.signal
+        });
+        
+        if (response.ok) {
+            const result = await response.json();
+            console.log('Search result:', result);
+            
+            const shortHash = hash.substring(0, 20);
+            const labels = {
+                block: `✅ Block found by hash ${shortHash}...`,
+                output: `✅ Output ${shortHash}... (${result.output_type}) mined in block ${result.height}`,
+                kernel: `✅ Kernel with excess ${shortHash}... in block ${result.height}`
+            };
+            
+            if (result.kind === 'not_found') {
+                displaySearchResult([], `❌ No block, output or kernel matches ${shortHash}...`);
+            } else if (result.height == null) {
+                displaySearchResult([], `⚠️ Kernel found (fee ${result.fee} µT) but its block is not indexed`);
+            } else {
+                // Show the containing block
+                await searchSpecificBlock(result.height, labels[result.kind]);
+            }
+            
+        } else if (response.status === 400) {
+            // Invalid hash format
+            displaySearchResult([], `❌ Invalid hash format: must be 64 hex characters`);
+        } else {
+            throw new Error(`HTTP ${response.status}`);
+        }
+        
+    } catch (error) {
+        if (error.name === 'AbortError') {
+            console.log('Hash search cancelled');
+            return;
+        }
+        console.error('Hash search error:', error);
+        displaySearchResult([], `❌ Error searching for hash: ${error.message}`);
+    }
+}
+
+async function searchSpecificBlock(height, foundMessage) {
+    try {
+        console.log('Searching for block height:', height);
+        
+        // Enter search mode
+        isSearchMode = true;
+        currentSearchTerm = height.toString();
+        
+        // Create new AbortController for this search
+        window.currentSearchController = new AbortController();
+        
+        // Show loading in table
+        const tbody = document.getElementById('blocksTable');
+        if (tbody) {
+            tbody.innerHTML = '<tr><td colspan="9" class="loading">🔍 Searching blockchain for block ' + height + '...</td></tr>';
+        }
+        
+        // Update pagination info
+        const paginationInfo = document.getElementById('paginationInfo');
+        if (paginationInfo) {
+            paginationInfo.textContent = 'Searching entire blockchain...';
+        }
+        
+        const response = await fetch(`${apiBase}/block/${height}`, {
+            signal: window.currentSearchController.signal
+        });
+        
+        if (response.ok) {
+            const blockData = await response.json();
+            console.log('Found block:', blockData);
+            
+            // Convert API response to our block format
+            const block = {
+                height: blockData.height,
+                hash: blockData.hash,
+                timestamp: blockData.header.timestamp,
+                transaction_count: blockData.transactions?.kernels?.length,
+                outputs_count: blockData.transactions?.outputs?.length,
+                inputs_count: blockData.transactions?.inputs?.length,
+                interval_seconds: null, // We don't have previous block context
+                pow_algorithm: blockData.header.pow_algorithm || 'N/A'
+            };
+            
+            // Display single block result
+            displaySearchResult([block], foundMessage || `✅ Block ${height} found in blockchain`);
+            
+        } else if (response.status === 404) {
+            // Block not found
+            displaySearchResult([], `❌ Block ${height} not found in blockchain`);
+        } else if (response.status === 410) {
+            // Pruned node: transaction data below the horizon is gone
+            displaySearchResult([], `✂️ Block ${height} is below this node's pruning horizon`);
+        } else {
+            throw new Error(`HTTP ${response.status}`);
+        }
+        
+    } catch (error) {
+        if (error.name === 'AbortError') {
+            console.log('Search cancelled');
+            return;
+        }
+        console.error('Search error:', error);
+        displaySearchResult([], `❌ Error searching for block ${height}: ${error.message}`);
+    }
+}
+
+function displaySearchResult(blocks, message) {
+    console.log('Displaying search result:', blocks.length, 'blocks');
+    
+    // Set search results as filtered blocks
+    filteredBlocks = blocks;
+    currentPage = 1;
+    
+    // Update pagination info with back button
+    const paginationInfo = document.getElementById('paginationInfo');
+    if (paginationInfo) {
+        if (blocks.length === 0) {
+            paginationInfo.innerHTML = `${message} • <button onclick="exitSearchMode()" style="background: #4CAF50; color: white; border: none; padding: 2px 8px; border-radius: 4px; cursor: pointer; font-size: 0.8em;">← Back to Recent Blocks</button>`;
+        } else {
+            paginationInfo.innerHTML = `${message} • <button onclick="exitSearchMode()" style="background: #4CAF50; color: white; border: none; padding: 2px 8px; border-radius: 4px; cursor: pointer; font-size: 0.8em;">← Back to Recent Blocks</button>`;
+        }
+    }
+    
+    // Update table
+    updateBlocksTable();
+    
+    // Hide pagination controls for single results
+    const paginationButtons = document.querySelector('.pagination-buttons');
+    if (paginationButtons) {
+        paginationButtons.style.display = blocks.length <= 1 ? 'none' : 'flex';
+    }
+}
+
+function exitSearchMode() {
+    // Exit search mode and return to recent blocks
+    isSearchMode = false;
+    currentSearchTerm = '';
+    
+    // Clear search input
+    document.getElementById('blockSearch').value = '';
+    
+    // Reset to showing all recent blocks
+    filteredBlocks = [...allBlocks];
+    currentPage = 1;
+    updatePagination();
+    updateBlocksTable();
+    
+    // Show pagination controls
+    const paginationButtons = document.querySelector('.pagination-buttons');
+    if (paginationButtons) {
+        paginationButtons.style.display = 'flex';
+    }
+}
+
+function applyTextSearch(searchTerm) {
+    const lowerSearchTerm = searchTerm.toLowerCase();
+    
+    if (!lowerSearchTerm) {
+        filteredBlocks = [...allBlocks];
+    } else {
+        filteredBlocks = allBlocks.filter(block => 
+            block.height.toString().includes(lowerSearchTerm) ||
+            (block.hash && block.hash.toLowerCase().includes(lowerSearchTerm)) ||
+            (block.pow_algorithm && block.pow_algorithm.toLowerCase().includes(lowerSearchTerm))
+        );
+    }
+    
+    // Reset to first page when search changes
+    currentPage = 1;
+    updatePagination();
+    updateBlocksTable();
+    
+    // Show pagination controls for normal searches
+    const paginationButtons = document.querySelector('.pagination-buttons');
+    if (paginationButtons) {
+        paginationButtons.style.display = 'flex';
+    }
+}
+
+function updatePagination() {
+    // Ensure we have valid data
+    if (!Array.isArray(filteredBlocks)) {
+        console.error('filteredBlocks is not an array:', filteredBlocks);
+        filteredBlocks = [];
+    }
+    
+    // Ensure blocksPerPage is a valid number
+    if (!blocksPerPage || blocksPerPage <= 0 || isNaN(blocksPerPage)) {
+        console.warn('Invalid blocksPerPage:', blocksPerPage, 'using default 20');
+        blocksPerPage = 20;
+    }
+    
+    console.log('Updating pagination: blocks =', filteredBlocks.length, 'per page =', blocksPerPage);
+    
+    totalPages = Math.max(1, Math.ceil(filteredBlocks.length / blocksPerPage));
+    
+    console.log('Calculated totalPages:', totalPages);
+    
+    // Ensure currentPage is valid
+    if (currentPage < 1) currentPage = 1;
+    if (currentPage > totalPages) currentPage = totalPages;
+    
+    // Update pagination info
+    const start = filteredBlocks.length === 0 ? 0 : (currentPage - 1) * blocksPerPage + 1;
+    const end = Math.min(currentPage * blocksPerPage, filteredBlocks.length);
+    
+    console.log('Pagination: showing', start, 'to', end, 'of', filteredBlocks.length);
+    
+    const paginationInfo = document.getElementById('paginationInfo');
+    if (paginationInfo && !isSearchMode) {
+        paginationInfo.textContent = `Showing ${start} - ${end} of ${filteredBlocks.length} blocks`;
+    }
+    
+    // Update pagination buttons
+    const firstBtn = document.getElementById('firstPageBtn');
+    const prevBtn = document.getElementById('prevPageBtn');
+    const nextBtn = document.getElementById('nextPageBtn');
+    const lastBtn = document.getElementById('lastPageBtn');
+    
+    if (firstBtn) firstBtn.disabled = currentPage <= 1;
+    if (prevBtn) prevBtn.disabled = currentPage <= 1;
+    if (nextBtn) nextBtn.disabled = currentPage >= totalPages;
+    if (lastBtn) lastBtn.disabled = currentPage >= totalPages;
+    
+    // Update page numbers
+    const pageNumbers = document.getElementById('pageNumbers');
+    if (pageNumbers) {
+        pageNumbers.innerHTML = '';
+        
+        // Show page numbers (max 5 around current page)
+        const startPage = Math.max(1, currentPage - 2);
+        const endPage = Math.min(totalPages, currentPage + 2);
+        
+        for (let i = startPage; i <= endPage; i++) {
+            const button = document.createElement('button');
+            button.className = 'page-button' + (i === currentPage ? ' active' : '');
+            button.textContent = i;
+            button.onclick = () => goToPage(i);
+            pageNumbers.appendChild(button);
+        }
+    }
+}
+
+function goToPage(page) {
+    if (page >= 1 && page <= totalPages) {
+        currentPage = page;
+        updatePagination();
+        updateBlocksTable();
+    }
+}
+
+function changePageSize() {
+    const select = document.getElementById('blocksPerPage');
+    if (select && select.value) {
+        blocksPerPage = parseInt(select.value) || 20;
+    } else {
+        blocksPerPage = 20; // fallback
+    }
+    console.log('Changed page size to:', blocksPerPage);
+    currentPage = 1; // Reset to first page
+    updatePagination();
+    updateBlocksTable();
+}
+
+function initializePageSize() {
+    const select = document.getElementById('blocksPerPage');
+    if (select) {
+        blocksPerPage = parseInt(select.value) || 20;
+        console.log('Initialized page size to:', blocksPerPage);
+    } else {
+        console.warn('blocksPerPage select not found, using default');
+        blocksPerPage = 20;
+    }
+}
+
+function clearSearch() {
+    document.getElementById('blockSearch').value = '';
+    
+    // Exit search mode
+    exitSearchMode();
+}
+
+function updateBlocksTable() {
+    const tbody = document.getElementById('blocksTable');
+    if (!tbody) {
+        console.error('Table body not found');
+        return;
+    }
+    
+    tbody.innerHTML = '';
+    
+    // Ensure we have valid pagination values
+    if (!blocksPerPage || blocksPerPage <= 0 || isNaN(blocksPerPage)) {
+        console.warn('Invalid blocksPerPage in table update:', blocksPerPage);
+        blocksPerPage = 20;
+    }
+    
+    if (!currentPage || currentPage <= 0 || isNaN(currentPage)) {
+        console.warn('Invalid currentPage in table update:', currentPage);
+        currentPage = 1;
+    }
+    
+    console.log('Updating table with', filteredBlocks.length, 'filtered blocks, page', currentPage, 'of', totalPages, 'blocksPerPage:', blocksPerPage);
+    
+    if (!Array.isArray(filteredBlocks) || filteredBlocks.length === 0) {
+        const row = document.createElement('tr');
+        const searchTerm = document.getElementById('blockSearch')?.value || '';
+        const message = searchTerm ? `No blocks found matching "${searchTerm}"` : 'No blocks data available';
+        row.innerHTML = `<td colspan="9" class="loading">${message}</td>`;
+        tbody.appendChild(row);
+        return;
+    }
+
+    // Calculate which blocks to show for current page
+    const start = (currentPage - 1) * blocksPerPage;
+    const end = start + blocksPerPage;
+    const blocksToDisplay = filteredBlocks.slice(start, end);
+    
+    console.log('Displaying blocks', start, 'to', end, '(', blocksToDisplay.length, 'blocks )');
+
+    if (blocksToDisplay.length === 0) {
+        const row = document.createElement('tr');
+        row.innerHTML = `<td colspan="9" class="loading">No blocks to display on this page</td>`;
+        tbody.appendChild(row);
+        return;
+    }
+
+    blocksToDisplay.forEach((block, index) => {
+        if (!block) {
+            console.warn('Invalid block at index', index);
+            return;
+        }
+        
+        const row = document.createElement('tr');
+        
+        const blockDate = new Date(block.timestamp * 1000);
+        const timeStr = blockDate.toLocaleTimeString();
+        const ageMs = Date.now() - (block.timestamp * 1000);
+        const ageStr = formatAge(ageMs);
+        
+        let intervalStr = '-';
+        if (block.interval_seconds) {
+            intervalStr = formatInterval(block.interval_seconds);
+        }
+        
+        const shortHash = (block.hash && block.hash.length > 20) ? 
+            block.hash.substring(0, 20) + '...' : 
+            (block.hash || 'Unknown');
+        
+        row.innerHTML = `
+            <td class="height-cell">${block.height ? block.height.toLocaleString() : 'N/A'}</td>
+            <td class="pow-cell">${block.pow_algorithm || 'N/A'}</td>
+            <td class="hash-cell" title="${block.hash || ''}">${shortHash}</td>
+            <td class="timestamp-cell">${timeStr}</td>
+            <td class="age-cell">${ageStr}</td>
+            <td class="interval-cell">${intervalStr}</td>
+            <td class="tx-cell">${block.transaction_count != null ? block.transaction_count.toLocaleString() : '-'}</td>
+            <td class="tx-cell">${block.outputs_count != null ? block.outputs_count.toLocaleString() : '-'}</td>
+            <td class="tx-cell">${block.inputs_count != null ? block.inputs_count.toLocaleString() : '-'}</td>
+        `;
+        
+        row.onclick = () => showBlockDetail(block.height);
+        tbody.appendChild(row);
+    });
+}
+
+function updateInsights(data) {
+    const container = document.getElementById('compactInsightsContainer');
+    if (!container) return;
+    
+    const insights = [];
+
+    // Analyze block intervals
+    const blocks = data.recent_blocks || [];
+    const intervals = blocks.filter(b => b.interval_seconds).map(b => b.interval_seconds);
+    
+    if (intervals.length > 0) {
+        const avgInterval = intervals.reduce((a, b) => a + b, 0) / intervals.length;
+        const consistency = intervals.every(i => Math.abs(i - avgInterval) < 60);
+        
+        if (consistency) {
+            insights.push({
+                status: 'good',
+                text: `Block times consistent (~${Math.round(avgInterval)}s)`
+            });
+        } else {
+            insights.push({
+                status: 'warning',
+                text: 'Block time variations detected'
+            });
+        }
+    }
+
+    // Network activity
+    if (data.network_stats) {
+        const tps = data.network_stats.transactions_per_second;
+        if (tps > 0.1) {
+            insights.push({
+                status: 'good',
+                text: `Network active: ${tps.toFixed(2)} TPS`
+            });
+        } else {
+            insights.push({
+                status: 'info',
+                text: 'Low transaction activity'
+            });
+        }
+    }
+
+    // Latest height
+    if (data.network_stats && data.network_stats.latest_block_height > 0) {
+        insights.push({
+            status: 'info',
+            text: `Latest: ${data.network_stats.latest_block_height.toLocaleString()}`
+        });
+    }
+
+    // Render compact insights (max 3)
+    container.innerHTML = insights.slice(0, 3).map(insight => `
+        <div class="compact-insight">
+            <div class="compact-insight-status status-${insight.status}"></div>
+            <div>${insight.text}</div>
+        </div>
+    `).join('');
+}
+
+function loadAnalytics() {
+    loadFeeAnalytics();
+    loadIntervalAnalytics();
+    loadUtxoGrowth();
+    loadPowShare();
+    loadLockedCoinbase();
+}
+
+async function loadLockedCoinbase() {
+    try {
+        const response = await fetch(`${apiBase}/analytics/locked-coinbase`);
+        if (!response.ok) throw new Error(`HTTP ${response.status}`);
+        const data = await response.json();
+
+        const locked = data.total_locked / 1000000;
+        document.getElementById('lockedCoinbase').textContent =
+            locked.toLocaleString(undefined, { maximumFractionDigits: 0 });
+        document.getElementById('lockedCoinbaseLabel').textContent =
+            `Immature Emission (T) • ${data.outputs.length} outputs`;
+    } catch (e) {
+        console.error('Locked coinbase error:', e);
+        document.getElementById('lockedCoinbase').textContent = 'N/A';
+    }
+}
+
+async function loadUtxoGrowth() {
+    try {
+        const response = await fetch(`${apiBase}/analytics/utxo-growth?samples=200`);
+        if (!response.ok) throw new Error(`HTTP ${response.status}`);
+        const data = await response.json();
+
+        const latest = data.samples[data.samples.length - 1];
+        document.getElementById('utxoGrowthSummary').textContent = latest
+            ? `${latest.utxo_set_size.toLocaleString()} UTXOs at height ${latest.height.toLocaleString()} • sampled every ${data.step.toLocaleString()} blocks`
+            : 'No headers available';
+
+        const labels = data.samples.map(s => s.height);
+        const sizes = data.samples.map(s => s.utxo_set_size);
+
+        if (utxoGrowthChart) {
+            utxoGrowthChart.data.labels = labels;
+            utxoGrowthChart.data.datasets[0].data = sizes;
+            utxoGrowthChart.update('none');
+        } else {
+            const options = chartOptions('UTXOs');
+            options.plugins.legend.display = false;
+            utxoGrowthChart = new Chart(document.getElementById('utxoGrowthChart'), {
+                type: 'line',
+                data: {
+                    labels,
+                    datasets: [{
+                        label: 'UTXO set size',
+                        data: sizes,
+                        borderColor: '#4CAF50',
+                        backgroundColor: 'rgba(76, 175, 80, 0.2)',
+                        borderWidth: 1,
+                        pointRadius: 0,
+                        fill: true,
+                    }],
+                },
+                options,
+            });
+        }
+    } catch (e) {
+        console.error('UTXO growth error:', e);
+        document.getElementById('utxoGrowthSummary').textContent = 'UTXO growth unavailable';
+    }
+}
+
+// Stacked percentage of blocks per PoW algorithm over a rolling window
+const POW_COLORS = ['#FF6B6B', '#4CAF50', '#FFD700', '#2196F3', '#AB47BC', '#9E9E9E'];
+
+async function loadPowShare() {
+    try {
+        const response = await fetch(`${apiBase}/analytics/pow-share?window=720`);
+        if (!response.ok) throw new Error(`HTTP ${response.status}`);
+        const data = await response.json();
+
+        const latest = data.points[data.points.length - 1];
+        document.getElementById('powShareSummary').textContent = latest
+            ? `Last ${data.window} blocks at ${latest.height.toLocaleString()}: ` +
+              Object.entries(latest.shares).map(([algo, share]) => `${algo} ${share.toFixed(1)}%`).join(' • ')
+            : 'No headers available';
+
+        const labels = data.points.map(p => p.height);
+        const datasets = data.algorithms.map((algo, i) => ({
+            label: algo,
+            data: data.points.map(p => p.shares[algo] || 0),
+            borderColor: POW_COLORS[i % POW_COLORS.length],
+            backgroundColor: POW_COLORS[i % POW_COLORS.length] + '99',
+            borderWidth: 1,
+            pointRadius: 0,
+            fill: true,
+        }));
+
+        if (powShareChart) {
+            powShareChart.data.labels = labels;
+            powShareChart.data.datasets = datasets;
+            powShareChart.update('none');
+        } else {
+            const options = chartOptions('Share (%)');
+            options.scales.y.stacked = true;
+            options.scales.y.max = 100;
+            powShareChart = new Chart(document.getElementById('powShareChart'), {
+                type: 'line',
+                data: { labels, datasets },
+                options,
+            });
+        }
+    } catch (e) {
+        console.error('PoW share error:', e);
+        document.getElementById('powShareSummary').textContent = 'PoW share unavailable';
+    }
+}
+
+async function loadIntervalAnalytics() {
+    try {
+        const response = await fetch(`${apiBase}/analytics/intervals?window=1000`);
+        if (!response.ok) throw new Error(`HTTP ${response.status}`);
+        const data = await response.json();
+
+        document.getElementById('intervalSummary').textContent =
+            `Blocks ${data.start_height.toLocaleString()} - ${data.end_height.toLocaleString()} • ` +
+            `avg ${Math.round(data.average_seconds)}s • median ${data.median_seconds}s`;
+
+        const labels = data.buckets.map(b => b.label);
+        const counts = data.buckets.map(b => b.count);
+
+        if (intervalChart) {
+            intervalChart.data.labels = labels;
+            intervalChart.data.datasets[0].data = counts;
+            intervalChart.update('none');
+        } else {
+            const options = chartOptions('Blocks');
+            options.plugins.legend.display = false;
+            intervalChart = new Chart(document.getElementById('intervalChart'), {
+                type: 'bar',
+                data: {
+                    labels,
+                    datasets: [{ label: 'Blocks', data: counts, backgroundColor: 'rgba(255, 215, 0, 0.6)' }],
+                },
+                options,
+            });
+        }
+    } catch (e) {
+        console.error('Interval analytics error:', e);
+        document.getElementById('intervalSummary').textContent = 'Interval analytics unavailable';
+    }
+}
+
+async function loadFeeAnalytics() {
+    try {
+        const response = await fetch(`${apiBase}/analytics/fees?window=1000`);
+        if (!response.ok) throw new Error(`HTTP ${response.status}`);
+        const data = await response.json();
+
+        const summary = data.summary;
+        document.getElementById('feeSummary').textContent =
+            `Last ${data.blocks.length} blocks • ${summary.kernel_count.toLocaleString()} kernels • ` +
+            `median ${summary.median_fee.toLocaleString()} µT • p90 ${summary.p90_fee.toLocaleString()} µT • ` +
+            `max ${summary.max_fee.toLocaleString()} µT`;
+
+        const labels = data.blocks.map(b => b.height);
+        const datasets = [
+            { label: 'Median', data: data.blocks.map(b => b.median_fee), borderColor: '#4CAF50' },
+            { label: 'p90', data: data.blocks.map(b => b.p90_fee), borderColor: '#FFD700' },
+            { label: 'Max', data: data.blocks.map(b => b.max_fee), borderColor: '#FF6B6B' },
+        ].map(d => ({ ...d, borderWidth: 1, pointRadius: 0, fill: false }));
+
+        if (feeChart) {
+            feeChart.data.labels = labels;
+            feeChart.data.datasets = datasets;
+            feeChart.update('none');
+        } else {
+            feeChart = new Chart(document.getElementById('feeChart'), {
+                type: 'line',
+                data: { labels, datasets },
+                options: chartOptions('Fee (µT)', true),
+            });
+        }
+    } catch (e) {
+        console.error('Fee analytics error:', e);
+        document.getElementById('feeSummary').textContent = 'Fee analytics unavailable';
+    }
+}
+
+function chartOptions(yTitle, logarithmic = false) {
+    return {
+        responsive: true,
+        maintainAspectRatio: false,
+        animation: false,
+        plugins: { legend: { labels: { color: '#E0E0E0' } } },
+        scales: {
+            x: { ticks: { color: '#E0E0E0', maxTicksLimit: 8 }, grid: { color: 'rgba(255,255,255,0.1)' } },
+            y: {
+                type: logarithmic ? 'logarithmic' : 'linear',
+                title: { display: true, text: yTitle, color: '#E0E0E0' },
+                ticks: { color: '#E0E0E0' },
+                grid: { color: 'rgba(255,255,255,0.1)' },
+            },
+        },
+    };
+}
+
+function formatAge(ageMs) {
+    const seconds = Math.floor(ageMs / 1000);
+    const minutes = Math.floor(seconds / 60);
+    const hours = Math.floor(minutes / 60);
+    const days = Math.floor(hours / 24);
+    
+    if (days > 0) return `${days}d ${hours % 24}h`;
+    if (hours > 0) return `${hours}h ${minutes % 60}m`;
+    if (minutes > 0) return `${minutes}m`;
+    return `${seconds}s`;
+}
+
+function formatInterval(seconds) {
+    const minutes = Math.floor(seconds / 60);
+    const hours = Math.floor(minutes / 60);
+    
+    if (hours > 0) return `${hours}h ${minutes % 60}m`;
+    if (minutes > 0) return `${minutes}m ${seconds % 60}s`;
+    return `${seconds}s`;
+}
+
+// Enhanced Block Detail Modal Functions
+async function showBlockDetail(height) {
+    try {
+        // Show modal with loading state
+        showBlockModal();
+        document.getElementById('modalTitle').textContent = `🔍 Loading Block ${height.toLocaleString()}`;
+        
+        const response = await fetch(`${apiBase}/block/${height}`);
+        if (response.status === 410) {
+            throw new Error('Block is below the pruning horizon; its transaction data was pruned by the node');
+        }
+        if (!response.ok) {
+            throw new Error(`HTTP ${response.status}`);
+        }
+        
+        const blockData = await response.json();
+        displayBlockDetails(blockData);
+        
+    } catch (error) {
+        console.error('Error fetching block details:', error);
+        showBlockModal(`
+            <div class="modal-error">
+                <h3>❌ Error Loading Block ${height}</h3>
+                <p>${error.message}</p>
+                <button onclick="closeBlockModal()" class="modal-button">Close</button>
+            </div>
+        `);
+    }
+}
+
+function showBlockModal(content = null) {
+    const modal = document.getElementById('blockModal');
+    const modalContent = document.getElementById('modalContent');
+    
+    if (content) {
+        modalContent.innerHTML = content;
+    } else {
+        modalContent.innerHTML = `
+            <div class="modal-loading">
+                <div class="pulsing">🔍 Loading block details...</div>
+            </div>
+        `;
+    }
+    
+    modal.style.display = 'block';
+    document.body.style.overflow = 'hidden';
+}
+
+function closeBlockModal(event) {
+    if (event && event.target !== event.currentTarget) {
+        return;
+    }
+    
+    const modal = document.getElementById('blockModal');
+    modal.style.display = 'none';
+    document.body.style.overflow = 'auto';
+}
+
+function transactionDataNote(completeness) {
+    if (!completeness || completeness.status === 'full') return '';
+    const text = completeness.status === 'headers_only'
+        ? 'Transaction tables are missing from this database; counts below are unavailable.'
+        : `Transaction data for this block was pruned (available from height ${completeness.horizon.toLocaleString()}).`;
+    return `<div class="detail-item"><span class="detail-value" style="color: #ff9800;">⚠️ ${text}</span></div>`;
+}
+
+function displayBlockDetails(block) {
+    const header = block.header || {};
+    
+    const blockDate = new Date(header.timestamp * 1000);
+    const dateStr = blockDate.toLocaleDateString('en-US', {
+        weekday: 'long',
+        year: 'numeric', 
+        month: 'long',
+        day: 'numeric'
+    });
+    const timeStr = blockDate.toLocaleTimeString('en-US');
+    
+    // Update modal title
+    document.getElementById('modalTitle').textContent = `🔍 Block ${block.height.toLocaleString()}`;
+    
+    const modalContent = `
+        <div class="detail-sections">
+            <!-- Basic Info -->
+            <div class="detail-section">
+                <h3 class="section-title">📊 Basic Information</h3>
+                <div class="detail-item">
+                    <span class="detail-label">Height:</span>
+                    <span class="detail-value highlight">${block.height.toLocaleString()}</span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Date:</span>
+                    <span class="detail-value timestamp">${dateStr}</span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Time:</span>
+                    <span class="detail-value timestamp">${timeStr}</span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Version:</span>
+                    <span class="detail-value">${header.version || 'N/A'}</span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Nonce:</span>
+                    <span class="detail-value">${header.nonce || 'N/A'}</span>
+                </div>
+            </div>
+
+            <!-- PoW Details -->
+            <div class="detail-section">
+                <h3 class="section-title">⚡ Proof of Work</h3>
+                <div class="detail-item">
+                    <span class="detail-label">Algorithm:</span>
+                    <span class="detail-value pow">${header.pow_algorithm || 'N/A'}</span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Header Length:</span>
+                    <span class="detail-value">${header.raw_header_length || 'N/A'} bytes</span>
+                </div>
+            </div>
+
+            <!-- Block Hash -->
+            <div class="detail-section full-width">
+                <h3 class="section-title">🔗 Block Hash</h3>
+                <div class="detail-item">
+                    <span class="detail-label">Hash:</span>
+                    <span class="detail-value hash">${block.hash}
+                        <button class="copy-button" onclick="copyToClipboard('${block.hash}')">Copy</button>
+                    </span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Previous:</span>
+                    <span class="detail-value hash">${header.previous_hash || 'N/A'}
+                        ${header.previous_hash ? `<button class="copy-button" onclick="copyToClipboard('${header.previous_hash}')">Copy</button>` : ''}
+                    </span>
+                </div>
+            </div>
+
+            <!-- Merkle Roots -->
+            <div class="detail-section full-width">
+                <h3 class="section-title">🌳 Merkle Roots</h3>
+                <div class="detail-item">
+                    <span class="detail-label">Input MR:</span>
+                    <span class="detail-value hash">${header.input_mr || 'N/A'}
+                        ${header.input_mr ? `<button class="copy-button" onclick="copyToClipboard('${header.input_mr}')">Copy</button>` : ''}
+                    </span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Output MR:</span>
+                    <span class="detail-value hash">${header.output_mr || 'N/A'}
+                        ${header.output_mr ? `<button class="copy-button" onclick="copyToClipboard('${header.output_mr}')">Copy</button>` : ''}
+                    </span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Kernel MR:</span>
+                    <span class="detail-value hash">${header.kernel_mr || 'N/A'}
+                        ${header.kernel_mr ? `<button class="copy-button" onclick="copyToClipboard('${header.kernel_mr}')">Copy</button>` : ''}
+                    </span>
+                </div>
+            </div>
+
+            <!-- Blockchain Data -->
+            <div class="detail-section full-width">
+                <h3 class="section-title">🔒 Blockchain Data</h3>
+                <div class="detail-item">
+                    <span class="detail-label">Kernel Offset:</span>
+                    <span class="detail-value hash">${header.total_kernel_offset || 'N/A'}
+                        ${header.total_kernel_offset ? `<button class="copy-button" onclick="copyToClipboard('${header.total_kernel_offset}')">Copy</button>` : ''}
+                    </span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Script Offset:</span>
+                    <span class="detail-value hash">${header.total_script_offset || 'N/A'}
+                        ${header.total_script_offset ? `<button class="copy-button" onclick="copyToClipboard('${header.total_script_offset}')">Copy</button>` : ''}
+                    </span>
+                </div>
+            </div>
+
+            <!-- Transaction Summary -->
+            <div class="detail-section">
+                <h3 class="section-title">💼 Transactions</h3>
+                ${transactionDataNote(block.data_completeness)}
+                <div class="detail-item">
+                    <span class="detail-label">Inputs:</span>
+                    <span class="detail-value highlight">${block.transactions?.inputs?.length || 0}</span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Outputs:</span>
+                    <span class="detail-value highlight">${block.transactions?.outputs?.length || 0}</span>
+                </div>
+                <div class="detail-item">
+                    <span class="detail-label">Kernels:</span>
+                    <span class="detail-value highlight">${block.transactions?.kernels?.length || 0}</span>
+                </div>
+            </div>
+
+            <!-- PoW Data Hash (if available) -->
+            ${header.pow_data_hash ? `
+            <div class="detail-section full-width">
+                <h3 class="section-title">🔐 PoW Data Hash</h3>
+                <div class="detail-item">
+                    <span class="detail-label">PoW Hash:</span>
+                    <span class="detail-value hash" style="font-size: 0.7em; line-height: 1.3;">${header.pow_data_hash}
+                        <button class="copy-button" onclick="copyToClipboard('${header.pow_data_hash}')">Copy</button>
+                    </span>
+                </div>
+            </div>
+            ` : ''}
+        </div>
+    `;
+    
+    document.getElementById('modalContent').innerHTML = modalContent;
+}
+
+function copyToClipboard(text) {
+    navigator.clipboard.writeText(text).then(() => {
+        showSuccess('Copied to clipboard!');
+    }).catch(err => {
+        console.error('Failed to copy:', err);
+        showError('Failed to copy to clipboard');
+    });
+}
+
+function refreshData() {
+    requestDashboardData();
+    showSuccess('Data refreshed!');
+}
+
+function exportData() {
+    showSuccess('Export feature coming soon!');
+}
+
+function showSettings() {
+    alert('Settings panel coming soon!');
+}
+
+function toggleBlockMonitoring() {
+    blockMonitoring = !blockMonitoring;
+    event.target.textContent = `Block Monitor: ${blockMonitoring ? 'ON' : 'OFF'}`;
+    
+    if (blockMonitoring) {
+        showSuccess('Block monitoring enabled - will update on new blocks');
+    } else {
+        showSuccess('Block monitoring disabled - manual refresh only');
+    }
+}
+
+function showError(message) {
+    const errorDiv = document.getElementById('errorMessages');
+    errorDiv.innerHTML = `<div class="error">❌ ${message}</div>`;
+    setTimeout(() => errorDiv.innerHTML = '', 5000);
+}
+
+function showSuccess(message) {
+    const errorDiv = document.getElementById('errorMessages');
+    errorDiv.innerHTML = `<div class="success">✅ ${message}</div>`;
+    setTimeout(() => errorDiv.innerHTML = '', 3000);
+}
+
+// Fill the node selector and point the API and WebSocket at the selected node
+async function loadNodes() {
+    try {
+        const response = await fetch('/api/v1/nodes');
+        if (!response.ok) return;
+        const nodes = await response.json();
+        const selected = new URLSearchParams(window.location.search).get('node');
+        const node = nodes.find(n => n.name === selected) || nodes[0];
+        if (node) {
+            apiBase = node.api_base;
+            wsPath = node.ws_path;
+        }
+        if (nodes.length < 2) return;
+
+        const select = document.getElementById('nodeSelect');
+        select.innerHTML = nodes.map(n =>
+            `<option value="${n.name}"${n === node ? ' selected' : ''}>🖧 ${n.name}</option>`
+        ).join('');
+        select.style.display = '';
+    } catch (error) {
+        console.error('Error loading nodes:', error);
+    }
+}
+
+function changeNode() {
+    const name = document.getElementById('nodeSelect').value;
+    window.location.search = `?node=${encodeURIComponent(name)}`;
+}
+
+// Initialize
+document.addEventListener('DOMContentLoaded', async function() {
+    console.log('DOM loaded, initializing event-driven dashboard...');
+    
+    // Initialize page size from select element
+    initializePageSize();
+
+    await loadNodes();
+    
+    // Connect WebSocket for real-time block monitoring
+    connectWebSocket();
+    
+    console.log('📡 Event-driven block monitoring initialized');
+});
+
+// Handle ESC key to close modal
+document.addEventListener('keydown', function(event) {
+    if (event.key === 'Escape') {
+        closeBlockModal();
+    }
+});
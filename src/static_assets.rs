@@ -0,0 +1,38 @@
+// File: src/static_assets.rs
+// Version: 1.0.0 - Dashboard CSS/JS/chart assets compiled into the binary
+// Tree: tari-lmdb-inspector/src/static_assets.rs
+//
+// Everything under `src/static/` is embedded with rust-embed and served at `/static/*`, so the
+// web dashboard needs no files next to the binary. Vendored chart libraries placed in
+// `src/static/vendor/` are picked up at build time and let the dashboard work offline.
+
+use axum::{
+    extract::Path,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, Router},
+};
+use rust_embed::RustEmbed;
+
+#[derive(RustEmbed)]
+#[folder = "src/static/"]
+struct Assets;
+
+/// Static asset route; needs no application state
+pub fn routes<S: Clone + Send + Sync + 'static>() -> Router<S> {
+    Router::new().route("/static/*path", get(static_asset))
+}
+
+/// Serve one embedded file with its guessed content type
+async fn static_asset(Path(path): Path<String>) -> Response {
+    match Assets::get(&path) {
+        Some(file) => (
+            [
+                (header::CONTENT_TYPE, file.metadata.mimetype().to_string()),
+                (header::CACHE_CONTROL, "public, max-age=3600".to_string()),
+            ],
+            file.data,
+        ).into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
@@ -0,0 +1,73 @@
+// File: src/sync_status.rs
+// Version: 1.0.0 - Node sync status from tip age and tip height progress
+// Tree: tari-lmdb-inspector/src/sync_status.rs
+//
+// A base node that falls behind keeps serving an old tip. Comparing the tip's timestamp with the
+// wall clock shows how far behind it is; watching whether the height still moves tells a node
+// that is catching up (lagging) from one that stopped writing blocks (stalled).
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Tip age (seconds) below which the node counts as synced (five target block times)
+pub const LAGGING_AFTER_SECS: u64 = 10 * 60;
+
+/// Time (seconds) without a new tip height after which a lagging node counts as stalled
+pub const STALLED_AFTER_SECS: u64 = 30 * 60;
+
+/// How current the database's tip is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SyncStatus {
+    /// The tip is recent
+    #[default]
+    Synced,
+    /// The tip is old but the height is still advancing (catching up)
+    Lagging { minutes: u64 },
+    /// The tip is old and the height has not changed for `STALLED_AFTER_SECS`
+    Stalled { minutes: u64 },
+}
+
+impl SyncStatus {
+    /// Short human readable label
+    pub fn label(&self) -> String {
+        match self {
+            SyncStatus::Synced => "synced".to_string(),
+            SyncStatus::Lagging { minutes } => format!("lagging ({} min behind)", minutes),
+            SyncStatus::Stalled { minutes } => format!("stalled ({} min behind)", minutes),
+        }
+    }
+
+    pub fn is_stalled(&self) -> bool {
+        matches!(self, SyncStatus::Stalled { .. })
+    }
+}
+
+/// Remembers when the tip height last changed, across dashboard updates
+#[derive(Debug, Clone, Default)]
+pub struct SyncTracker {
+    /// Last seen tip height and the Unix time it was first seen
+    last_change: Option<(u64, u64)>,
+}
+
+impl SyncTracker {
+    /// Classify the tip at `height` mined at `tip_timestamp`, as seen at Unix time `now`
+    pub fn observe(&mut self, height: u64, tip_timestamp: u64, now: u64) -> SyncStatus {
+        // Without an earlier observation the tip's own timestamp is the last known change
+        let changed_at = match self.last_change {
+            Some((seen_height, seen_at)) if seen_height == height => seen_at,
+            Some(_) => now,
+            None => tip_timestamp,
+        };
+        self.last_change = Some((height, changed_at));
+
+        let lag = now.saturating_sub(tip_timestamp);
+        if lag < LAGGING_AFTER_SECS {
+            SyncStatus::Synced
+        } else if now.saturating_sub(changed_at) < STALLED_AFTER_SECS {
+            SyncStatus::Lagging { minutes: lag / 60 }
+        } else {
+            SyncStatus::Stalled { minutes: lag / 60 }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+// File: src/testutil.rs
+// Version: 1.2.1 - Integer keys on headers and metadata, as the base node creates them
+// Tree: tari-lmdb-inspector/src/testutil.rs
+//
+// Builds a small LMDB environment with the same table names, key layouts and bincode row
+// encodings as a base node: `headers` keyed by LE height, `kernels` / `utxos` keyed by
+// block hash + row hash, `block_hashes` mapping hash → height, the output hash indexes
+// (`txos_hash_to_index`, `deleted_txo_hash_to_header_index`) and a `metadata` table
+// describing the tip. Enabled with the
+// `testutil` feature so it never ships in the binary.
+
+use std::path::Path;
+use lmdb_zero::{EnvBuilder, Database, WriteTransaction};
+use lmdb_zero::DatabaseOptions;
+
+use tari_common_types::types::FixedHash;
+use tari_node_components::blocks::BlockHeader;
+use tari_transaction_components::transaction_components::{KernelFeatures, OutputType, TransactionInput, TransactionKernel, TransactionOutput};
+use crate::lmdb_reader::{env_path, ReaderResult, TransactionInputRowData, TransactionKernelRowData, TransactionOutputRowData};
+
+/// Tables created in every fixture
+const FIXTURE_TABLES: [&str; 8] = [
+    "headers", "kernels", "utxos", "inputs", "block_hashes", "metadata", "txos_hash_to_index", "deleted_txo_hash_to_header_index",
+];
+
+/// Accumulated work recorded in the fixture's metadata table
+pub const FIXTURE_ACCUMULATED_WORK: &str = "0x2a";
+
+/// Revealed value (µT) of every fixture burn output
+pub const FIXTURE_BURN_VALUE: u64 = 1_000_000;
+
+/// Shape of the synthetic chain
+#[derive(Debug, Clone)]
+pub struct ChainSpec {
+    /// Number of blocks, starting at genesis (height 0)
+    pub blocks: u64,
+    /// Timestamp of the genesis block
+    pub start_timestamp: u64,
+    /// Seconds between consecutive blocks
+    pub block_time: u64,
+    /// Non-coinbase kernels per block; kernel `i` pays a fee of `(i + 1) * 100` µT
+    pub kernels_per_block: u64,
+    /// Outputs per block
+    pub outputs_per_block: u64,
+    /// Inputs per block after genesis, each spending one of the previous block's outputs
+    pub spends_per_block: u64,
+    /// Burn outputs per block, taken from the end of the block's outputs, each with a burn kernel
+    pub burns_per_block: u64,
+}
+
+impl Default for ChainSpec {
+    fn default() -> Self {
+        Self {
+            blocks: 20,
+            start_timestamp: 1_700_000_000,
+            block_time: 120,
+            kernels_per_block: 2,
+            outputs_per_block: 3,
+            spends_per_block: 0,
+            burns_per_block: 0,
+        }
+    }
+}
+
+/// What the fixture wrote for one block, for assertions
+#[derive(Debug, Clone)]
+pub struct FixtureBlock {
+    pub height: u64,
+    pub hash: String,
+    pub timestamp: u64,
+    pub kernel_fees: Vec<u64>,
+    pub output_count: u64,
+}
+
+/// Write a synthetic chain into a new LMDB environment at `path`
+pub fn build_chain(path: &Path, spec: &ChainSpec) -> ReaderResult<Vec<FixtureBlock>> {
+    std::fs::create_dir_all(path).map_err(|_| crate::lmdb_reader::ReaderError::InvalidPath(path.to_path_buf()))?;
+    let path_str = env_path(path)?;
+
+    let mut builder = EnvBuilder::new()?;
+    builder.set_maxdbs(40)?;
+    builder.set_mapsize(64 * 1024 * 1024)?;
+    let env = unsafe {
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+    };
+
+    let mut dbs = Vec::new();
+    for name in FIXTURE_TABLES {
+        // The base node keys these by native integer, so LMDB orders them numerically, not bytewise
+        let flags = match name {
+            "headers" | "metadata" => lmdb_zero::db::CREATE | lmdb_zero::db::INTEGERKEY,
+            _ => lmdb_zero::db::CREATE,
+        };
+        dbs.push(Database::open(&env, Some(name), &DatabaseOptions::new(flags))?);
+    }
+    let [headers_db, kernels_db, utxos_db, inputs_db, hashes_db, metadata_db, txo_index_db, deleted_txo_db] = &dbs[..] else {
+        unreachable!()
+    };
+
+    let txn = WriteTransaction::new(&env)?;
+    let mut blocks = Vec::new();
+    {
+        let mut access = txn.access();
+        let mut prev_hash = FixedHash::default();
+        let mut kernel_mmr_size = 0;
+        let mut output_smt_size = 0;
+
+        for height in 0..spec.blocks {
+            let timestamp = spec.start_timestamp + height * spec.block_time;
+            // The output SMT only holds unspent, unburned outputs
+            let spends = if height == 0 { 0 } else { spec.spends_per_block.min(spec.outputs_per_block) };
+            let burns = spec.burns_per_block.min(spec.outputs_per_block);
+            kernel_mmr_size += spec.kernels_per_block + burns;
+            output_smt_size = output_smt_size + spec.outputs_per_block - spends - burns;
+
+            let mut header = BlockHeader::new(0);
+            header.height = height;
+            header.prev_hash = prev_hash;
+            header.timestamp = timestamp.into();
+            header.nonce = height;
+            header.kernel_mmr_size = kernel_mmr_size;
+            header.output_smt_size = output_smt_size;
+
+            let block_hash = header.hash();
+            let header_bytes = bincode::serialize(&header).expect("header encodes");
+            access.put(headers_db, &height.to_le_bytes(), &header_bytes[..], lmdb_zero::put::Flags::empty())?;
+            access.put(hashes_db, block_hash.as_slice(), &height.to_le_bytes(), lmdb_zero::put::Flags::empty())?;
+
+            let mut kernel_fees = Vec::new();
+            for i in 0..spec.kernels_per_block {
+                let fee = (i + 1) * 100;
+                let kernel = TransactionKernel::new_current_version(
+                    KernelFeatures::empty(),
+                    fee.into(),
+                    0,
+                    Default::default(),
+                    Default::default(),
+                    None,
+                );
+                let row_hash = row_hash(b"kernel", height, i);
+                let row = TransactionKernelRowData {
+                    kernel,
+                    header_hash: block_hash,
+                    mmr_position: kernel_mmr_size - spec.kernels_per_block - burns + i,
+                    hash: row_hash,
+                };
+                put_block_row(&mut access, kernels_db, &block_hash, &row_hash, &row)?;
+                kernel_fees.push(fee);
+            }
+
+            for i in 0..burns {
+                let kernel = TransactionKernel::new_current_version(
+                    KernelFeatures::BURN_KERNEL,
+                    0.into(),
+                    0,
+                    Default::default(),
+                    Default::default(),
+                    Some(Default::default()),
+                );
+                let row_hash = row_hash(b"burn_kernel", height, i);
+                let row = TransactionKernelRowData {
+                    kernel,
+                    header_hash: block_hash,
+                    mmr_position: kernel_mmr_size - burns + i,
+                    hash: row_hash,
+                };
+                put_block_row(&mut access, kernels_db, &block_hash, &row_hash, &row)?;
+                kernel_fees.push(0);
+            }
+
+            for i in 0..spec.outputs_per_block {
+                let row_hash = row_hash(b"output", height, i);
+                let mut output = TransactionOutput::default();
+                if i >= spec.outputs_per_block - burns {
+                    output.features.output_type = OutputType::Burn;
+                    output.minimum_value_promise = FIXTURE_BURN_VALUE.into();
+                }
+                let row = TransactionOutputRowData {
+                    output,
+                    header_hash: block_hash,
+                    hash: row_hash,
+                    mined_height: height,
+                    mined_timestamp: timestamp,
+                };
+                put_block_row(&mut access, utxos_db, &block_hash, &row_hash, &row)?;
+                let key = [block_hash.as_slice(), row_hash.as_slice()].concat();
+                access.put(txo_index_db, row_hash.as_slice(), &key[..], lmdb_zero::put::Flags::empty())?;
+            }
+
+            // Spend the first outputs of the previous block
+            for i in 0..spends {
+                let output_hash = row_hash(b"output", height - 1, i);
+                let row_hash = row_hash(b"input", height, i);
+                let row = TransactionInputRowData {
+                    input: TransactionInput::new_with_output_hash(output_hash, Default::default(), Default::default()),
+                    header_hash: block_hash,
+                    spent_timestamp: timestamp,
+                    spent_height: height,
+                    hash: row_hash,
+                };
+                put_block_row(&mut access, inputs_db, &block_hash, &row_hash, &row)?;
+                let spent_at = [&height.to_le_bytes()[..], block_hash.as_slice()].concat();
+                access.put(deleted_txo_db, output_hash.as_slice(), &spent_at[..], lmdb_zero::put::Flags::empty())?;
+            }
+
+            blocks.push(FixtureBlock {
+                height,
+                hash: hex::encode(block_hash.as_slice()),
+                timestamp,
+                kernel_fees,
+                output_count: spec.outputs_per_block,
+            });
+            prev_hash = block_hash;
+        }
+
+        if let Some(tip) = blocks.last() {
+            put_metadata(&mut access, metadata_db, 0, &tip.height.to_le_bytes())?;
+            put_metadata(&mut access, metadata_db, 1, prev_hash.as_slice())?;
+            let work = FIXTURE_ACCUMULATED_WORK.as_bytes();
+            put_metadata(&mut access, metadata_db, 2, &[&(work.len() as u64).to_le_bytes()[..], work].concat())?;
+            put_metadata(&mut access, metadata_db, 3, &0u64.to_le_bytes())?;
+            put_metadata(&mut access, metadata_db, 4, &0u64.to_le_bytes())?;
+            put_metadata(&mut access, metadata_db, 6, &tip.timestamp.to_le_bytes())?;
+        }
+    }
+    txn.commit()?;
+
+    Ok(blocks)
+}
+
+/// Delete table `name` from the environment at `path`, to test fallbacks for databases without it
+pub fn drop_table(path: &Path, name: &str) -> ReaderResult<()> {
+    let path_str = env_path(path)?;
+
+    let mut builder = EnvBuilder::new()?;
+    builder.set_maxdbs(40)?;
+    let env = unsafe {
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+    };
+    Database::open(&env, Some(name), &DatabaseOptions::defaults())?.delete()?;
+    Ok(())
+}
+
+/// Add an input to block `height` spending output `index` of block `spent_from` again, as a
+/// damaged inputs table would hold; `block_hash` is the hex hash of block `height`
+pub fn add_duplicate_spend(path: &Path, height: u64, block_hash: &str, spent_from: u64, index: u64) -> ReaderResult<()> {
+    let path_str = env_path(path)?;
+
+    let mut builder = EnvBuilder::new()?;
+    builder.set_maxdbs(40)?;
+    let env = unsafe {
+        builder.open(&path_str, lmdb_zero::open::Flags::empty(), 0o600)?
+    };
+    let inputs_db = Database::open(&env, Some("inputs"), &DatabaseOptions::defaults())?;
+    let block_hash = FixedHash::try_from(hex::decode(block_hash).expect("hex block hash").as_slice()).expect("32-byte block hash");
+    let row_hash = row_hash(b"duplicate", height, index);
+    let row = TransactionInputRowData {
+        input: TransactionInput::new_with_output_hash(row_hash(b"output", spent_from, index), Default::default(), Default::default()),
+        header_hash: block_hash,
+        spent_timestamp: 0,
+        spent_height: height,
+        hash: row_hash,
+    };
+
+    let txn = WriteTransaction::new(&env)?;
+    {
+        let mut access = txn.access();
+        put_block_row(&mut access, &inputs_db, &block_hash, &row_hash, &row)?;
+    }
+    txn.commit()?;
+    Ok(())
+}
+
+/// Deterministic 32-byte row hash for the `index`th row of a kind in a block
+fn row_hash(kind: &[u8], height: u64, index: u64) -> FixedHash {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(kind);
+    hasher.update(&height.to_le_bytes());
+    hasher.update(&index.to_le_bytes());
+    FixedHash::from(*hasher.finalize().as_bytes())
+}
+
+/// Insert a `MetadataValue` (u32 LE variant tag + payload) under its u32 LE key
+fn put_metadata(access: &mut lmdb_zero::WriteAccessor, db: &Database, key: u32, payload: &[u8]) -> ReaderResult<()> {
+    let mut value = key.to_le_bytes().to_vec();
+    value.extend_from_slice(payload);
+    access.put(db, &key.to_le_bytes(), &value[..], lmdb_zero::put::Flags::empty())?;
+    Ok(())
+}
+
+/// Insert a row keyed by block hash + row hash, as the base node does
+fn put_block_row<T: serde::Serialize>(
+    access: &mut lmdb_zero::WriteAccessor,
+    db: &Database,
+    block_hash: &FixedHash,
+    row_hash: &FixedHash,
+    row: &T,
+) -> ReaderResult<()> {
+    let mut key = block_hash.to_vec();
+    key.extend_from_slice(row_hash.as_slice());
+    let value = bincode::serialize(row).expect("row encodes");
+    access.put(db, &key[..], &value[..], lmdb_zero::put::Flags::empty())?;
+    Ok(())
+}
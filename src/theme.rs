@@ -0,0 +1,238 @@
+// File: src/theme.rs
+// Version: 1.0.0 - Color themes for the TUI and CLI output (--theme, --no-color)
+// Tree: tari-lmdb-inspector/src/theme.rs
+//
+// Every color the terminal interfaces use comes from a `Theme` role (title, heading, warn, ...)
+// instead of a literal, so switching palettes is one flag. The monochrome theme uses only bold,
+// dim, underline and reverse video, and is picked automatically when NO_COLOR is set or
+// TERM=dumb (https://no-color.org).
+
+use std::fmt::Display;
+use std::io::IsTerminal;
+use clap::ValueEnum;
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::chain_events::ChainEventKind;
+use crate::lmdb_reader::KernelKind;
+use crate::sync_status::SyncStatus;
+
+/// Selectable palettes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ThemeName {
+    /// Colors on a dark terminal background
+    #[default]
+    Default,
+    /// Bright colors and reverse-video selection for low-vision users and washed-out terminals
+    HighContrast,
+    /// No colors, only bold / dim / underline / reverse
+    Monochrome,
+}
+
+impl ThemeName {
+    /// Theme that applies given the --no-color flag and the NO_COLOR and TERM environment values
+    pub fn resolve(self, no_color: bool, no_color_env: Option<&str>, term: Option<&str>) -> ThemeName {
+        // NO_COLOR only counts when set to a non-empty value
+        let env_disables = no_color_env.is_some_and(|value| !value.is_empty()) || term == Some("dumb");
+        if no_color || env_disables {
+            ThemeName::Monochrome
+        } else {
+            self
+        }
+    }
+}
+
+/// Styles for each role in the terminal interfaces
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    pub name: ThemeName,
+    /// Whether CLI output may contain ANSI escape codes (a terminal that is not TERM=dumb)
+    pub ansi: bool,
+    /// Application name in the header
+    pub title: Style,
+    /// Regular values
+    pub text: Style,
+    /// Labels, timestamps and help text
+    pub muted: Style,
+    /// Table column headings
+    pub heading: Style,
+    /// Icons, bar charts and other highlights
+    pub accent: Style,
+    pub ok: Style,
+    pub warn: Style,
+    pub error: Style,
+    /// Search results, progress and coinbase kernels
+    pub info: Style,
+    /// Block interval sparkline and stall events
+    pub highlight: Style,
+    /// Input counts
+    pub secondary: Style,
+    /// Burn kernels and burn events
+    pub burn: Style,
+    /// Selected table row
+    pub selected: Style,
+    /// Active tab title
+    pub tab_active: Style,
+    /// Values drawn on top of bars
+    pub bar_value: Style,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::named(ThemeName::Default)
+    }
+}
+
+impl Theme {
+    /// Palette for `name`, with ANSI output allowed
+    pub fn named(name: ThemeName) -> Self {
+        let fg = |color: Color| Style::default().fg(color);
+        let bold = Modifier::BOLD;
+        match name {
+            ThemeName::Default => Theme {
+                name,
+                ansi: true,
+                title: fg(Color::Green).add_modifier(bold),
+                text: fg(Color::White),
+                muted: fg(Color::Gray),
+                heading: fg(Color::Yellow).add_modifier(bold),
+                accent: fg(Color::Yellow),
+                ok: fg(Color::Green),
+                warn: fg(Color::Yellow),
+                error: fg(Color::Red),
+                info: fg(Color::Cyan),
+                highlight: fg(Color::Magenta),
+                secondary: fg(Color::Blue),
+                burn: fg(Color::LightRed),
+                selected: Style::default().bg(Color::DarkGray).add_modifier(bold),
+                tab_active: fg(Color::Green).add_modifier(bold),
+                bar_value: fg(Color::Black).bg(Color::Yellow),
+            },
+            ThemeName::HighContrast => Theme {
+                name,
+                ansi: true,
+                title: fg(Color::LightGreen).add_modifier(bold),
+                text: fg(Color::White),
+                muted: fg(Color::White),
+                heading: fg(Color::LightYellow).add_modifier(bold | Modifier::UNDERLINED),
+                accent: fg(Color::LightYellow),
+                ok: fg(Color::LightGreen).add_modifier(bold),
+                warn: fg(Color::LightYellow).add_modifier(bold),
+                error: fg(Color::LightRed).add_modifier(bold),
+                info: fg(Color::LightCyan),
+                highlight: fg(Color::LightMagenta),
+                secondary: fg(Color::LightBlue),
+                burn: fg(Color::LightRed),
+                selected: fg(Color::Black).bg(Color::White).add_modifier(bold),
+                tab_active: fg(Color::Black).bg(Color::LightGreen).add_modifier(bold),
+                bar_value: fg(Color::Black).bg(Color::LightYellow).add_modifier(bold),
+            },
+            ThemeName::Monochrome => {
+                let plain = Style::default();
+                Theme {
+                    name,
+                    ansi: true,
+                    title: plain.add_modifier(bold),
+                    text: plain,
+                    muted: plain.add_modifier(Modifier::DIM),
+                    heading: plain.add_modifier(bold | Modifier::UNDERLINED),
+                    accent: plain,
+                    ok: plain,
+                    warn: plain.add_modifier(bold),
+                    error: plain.add_modifier(bold | Modifier::REVERSED),
+                    info: plain,
+                    highlight: plain,
+                    secondary: plain,
+                    burn: plain.add_modifier(bold),
+                    selected: plain.add_modifier(Modifier::REVERSED),
+                    tab_active: plain.add_modifier(bold | Modifier::REVERSED),
+                    bar_value: plain.add_modifier(Modifier::REVERSED),
+                }
+            }
+        }
+    }
+
+    /// Theme for the requested name after applying --no-color, NO_COLOR and TERM; CLI output
+    /// is plain text when stdout is not a terminal
+    pub fn detect(requested: ThemeName, no_color: bool) -> Self {
+        let no_color_env = std::env::var("NO_COLOR").ok();
+        let term = std::env::var("TERM").ok();
+        let name = requested.resolve(no_color, no_color_env.as_deref(), term.as_deref());
+        Theme {
+            ansi: term.as_deref() != Some("dumb") && std::io::stdout().is_terminal(),
+            ..Theme::named(name)
+        }
+    }
+
+    pub fn sync_status(&self, status: &SyncStatus) -> Style {
+        match status {
+            SyncStatus::Synced => self.ok,
+            SyncStatus::Lagging { .. } => self.warn,
+            SyncStatus::Stalled { .. } => self.error,
+        }
+    }
+
+    pub fn kernel_kind(&self, kind: KernelKind) -> Style {
+        match kind {
+            KernelKind::Plain => self.text,
+            KernelKind::Coinbase => self.info,
+            KernelKind::Burn => self.burn,
+        }
+    }
+
+    pub fn chain_event(&self, kind: ChainEventKind) -> Style {
+        match kind {
+            ChainEventKind::Reorg => self.error,
+            ChainEventKind::Stall => self.highlight,
+            ChainEventKind::DifficultySpike => self.warn,
+            ChainEventKind::LargeBlock => self.info,
+            ChainEventKind::Burn => self.burn,
+        }
+    }
+
+    /// `text` wrapped in the ANSI codes for `style` (CLI output), or unchanged without ANSI
+    pub fn paint(&self, style: Style, text: impl Display) -> String {
+        let mut codes = Vec::new();
+        for (modifier, code) in [
+            (Modifier::BOLD, 1),
+            (Modifier::DIM, 2),
+            (Modifier::UNDERLINED, 4),
+            (Modifier::REVERSED, 7),
+        ] {
+            if style.add_modifier.contains(modifier) {
+                codes.push(code);
+            }
+        }
+        codes.extend(style.fg.and_then(|color| ansi_color(color, 30)));
+        codes.extend(style.bg.and_then(|color| ansi_color(color, 40)));
+
+        if !self.ansi || codes.is_empty() {
+            return text.to_string();
+        }
+        let codes: Vec<String> = codes.iter().map(|code| code.to_string()).collect();
+        format!("\x1b[{}m{}\x1b[0m", codes.join(";"), text)
+    }
+}
+
+/// SGR code of a basic 16-color `color` (`base` 30 for foreground, 40 for background)
+fn ansi_color(color: Color, base: u8) -> Option<u8> {
+    let (offset, bright) = match color {
+        Color::Black => (0, false),
+        Color::Red => (1, false),
+        Color::Green => (2, false),
+        Color::Yellow => (3, false),
+        Color::Blue => (4, false),
+        Color::Magenta => (5, false),
+        Color::Cyan => (6, false),
+        Color::Gray => (7, false),
+        Color::DarkGray => (0, true),
+        Color::LightRed => (1, true),
+        Color::LightGreen => (2, true),
+        Color::LightYellow => (3, true),
+        Color::LightBlue => (4, true),
+        Color::LightMagenta => (5, true),
+        Color::LightCyan => (6, true),
+        Color::White => (7, true),
+        _ => return None,
+    };
+    Some(if bright { base + 60 + offset } else { base + offset })
+}
@@ -0,0 +1,61 @@
+// File: src/timezone.rs
+// Version: 1.0.0 - Display time zone for CLI, TUI and API timestamps (--timezone)
+// Tree: tari-lmdb-inspector/src/timezone.rs
+//
+// Block timestamps are stored as Unix seconds and were always shown in UTC. `--timezone local`
+// uses the machine's zone and an IANA name (`Europe/Berlin`) a fixed one, for CLI tables, the
+// TUI and server-rendered pages. API responses keep `timestamp` in Unix seconds and add a
+// `timestamp_local` RFC 3339 string when the zone is not UTC.
+
+use chrono::{DateTime, Local};
+use chrono_tz::Tz;
+
+/// Zone timestamps are rendered in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DisplayZone {
+    #[default]
+    Utc,
+    /// The system's local zone
+    Local,
+    /// An IANA zone
+    Named(Tz),
+}
+
+impl DisplayZone {
+    /// `utc`, `local` or an IANA name such as `America/New_York` (case-insensitive)
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value.to_ascii_lowercase().as_str() {
+            "utc" => Ok(Self::Utc),
+            "local" => Ok(Self::Local),
+            _ => Tz::from_str_insensitive(value)
+                .map(Self::Named)
+                .map_err(|_| format!("unknown time zone '{}' (use local, utc or an IANA name like Europe/Berlin)", value)),
+        }
+    }
+
+    /// `timestamp` in this zone with a strftime `format`; `None` when it is out of range
+    pub fn format(&self, timestamp: u64, format: &str) -> Option<String> {
+        let utc = DateTime::from_timestamp(timestamp as i64, 0)?;
+        Some(match self {
+            Self::Utc => utc.format(format).to_string(),
+            Self::Local => utc.with_timezone(&Local).format(format).to_string(),
+            Self::Named(tz) => utc.with_timezone(tz).format(format).to_string(),
+        })
+    }
+
+    /// Date, time and zone, e.g. `2025-06-01 14:03:11 UTC` or `2025-06-01 16:03:11 CEST`
+    pub fn datetime(&self, timestamp: u64) -> String {
+        self.format(timestamp, "%Y-%m-%d %H:%M:%S %Z")
+            .unwrap_or_else(|| format!("Invalid: {}", timestamp))
+    }
+
+    /// RFC 3339 time for `timestamp_local` API fields; `None` in UTC
+    pub fn local_time(&self, timestamp: u64) -> Option<String> {
+        let utc = DateTime::from_timestamp(timestamp as i64, 0)?;
+        match self {
+            Self::Utc => None,
+            Self::Local => Some(utc.with_timezone(&Local).to_rfc3339()),
+            Self::Named(tz) => Some(utc.with_timezone(tz).to_rfc3339()),
+        }
+    }
+}
@@ -1,202 +1,1088 @@
 // File: src/tui_dashboard.rs
 // Terminal UI dashboard using ratatui
+//
+// Key presses become `TuiMessage`s and `TuiApp::update` applies them, so every state change
+// goes through one place; rendering only reads the app state.
+//
+// Enter on the Blocks tab opens the selected block; there `c` / `C` copy its hash / JSON to the
+// system clipboard and `e` writes the JSON to `block-<height>.json`, in the `/api/v1/block/:height`
+// response format.
+//
+// The terminal is restored however the loop ends: quitting, SIGTERM, an error, or a panic (through
+// a panic hook that runs before the panic message prints).
+//
+// Reader errors and panics do not end the loop: they open an error popup over the dashboard ('r'
+// retries the read, 'q' quits) and their details, with the panic backtrace, are appended to
+// `tui-errors.log` in the cache directory.
 
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+    event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, MouseButton, MouseEvent, MouseEventKind},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
-    widgets::{Block, Borders, Gauge, Paragraph, Table, Row, Cell},
+    widgets::{BarChart, Block, Borders, Clear, Gauge, Paragraph, Sparkline, Table, Tabs, Row, Cell, Wrap},
     Frame, Terminal,
 };
 use std::{
+    collections::VecDeque,
     io,
+    panic::AssertUnwindSafe,
+    path::{Path, PathBuf},
+    sync::{atomic::{AtomicBool, Ordering}, Arc, Mutex, Once},
     time::{Duration, Instant},
 };
-use tokio::time::sleep;
+use futures::{FutureExt, StreamExt};
+use tokio::{sync::mpsc, time::sleep};
 
-use crate::data_models::{AppConfig, DashboardData, DatabaseStats};
-use crate::lmdb_reader::{read_lmdb_headers_with_filter, BlockFilter};
+use crate::data_models::{AppConfig, BlockDetailResponse, BlockInfo, DashboardData, DatabaseStats};
+use crate::lmdb_reader::{BlockDetailSummary, BlockFilter, DataCompleteness, KernelSummary};
+use crate::lmdb_reader::r#async;
+use crate::data_provider::{self, DataProvider};
+use crate::shutdown;
+use crate::chain_events::{self, ChainEvent};
+use crate::analytics::intervals::{self, IntervalHistogram};
+use crate::progress::SharedProgress;
+use tokio_util::sync::CancellationToken;
+use crate::search::SearchQuery;
+use crate::sync_status::SyncStatus;
+use crate::theme::Theme;
+use crate::timezone::DisplayZone;
+use crate::denomination::Denomination;
+use crate::dashboard_engine::DashboardEngine;
+use crate::file_watcher::LmdbWatcher;
+
+/// Blocks drawn in the Overview sparklines
+const SPARKLINE_BLOCKS: usize = 60;
+
+/// Blocks listed on the Blocks tab; one more than the sparklines so every one has an interval
+const RECENT_BLOCK_COUNT: usize = SPARKLINE_BLOCKS + 1;
+
+/// Newest blocks whose kernels fill the Transactions tab
+const TRANSACTION_BLOCK_COUNT: usize = 10;
+
+/// Older blocks fetched per page when the Blocks tab scrolls past what is loaded
+const BLOCK_PAGE_SIZE: u64 = 100;
+
+/// Rows from the end of the loaded blocks at which the next page is fetched
+const BLOCK_PREFETCH_ROWS: usize = 10;
+
+/// Rows above the first block row on the Blocks tab (border, header, header margin)
+const BLOCK_TABLE_HEADER_ROWS: u16 = 3;
+
+/// Entries kept on the Logs tab
+const MAX_LOG_ENTRIES: usize = 200;
+
+/// Error log in the cache directory, appended to whenever the error popup opens
+pub const ERROR_LOG_FILE: &str = "tui-errors.log";
+
+/// Tabs available in the TUI, switchable with number keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TuiTab {
+    Overview,
+    Blocks,
+    Transactions,
+    Timeline,
+    Logs,
+}
+
+impl TuiTab {
+    /// Tabs in display order; tab `n` is selected with key `n + 1`
+    pub const ALL: [TuiTab; 5] = [TuiTab::Overview, TuiTab::Blocks, TuiTab::Transactions, TuiTab::Timeline, TuiTab::Logs];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            TuiTab::Overview => "Overview",
+            TuiTab::Blocks => "Blocks",
+            TuiTab::Transactions => "Transactions",
+            TuiTab::Timeline => "Timeline",
+            TuiTab::Logs => "Logs",
+        }
+    }
+
+    fn index(&self) -> usize {
+        Self::ALL.iter().position(|tab| tab == self).unwrap_or(0)
+    }
+
+    /// Tab selected by a number key ('1' = Overview)
+    pub fn from_key(c: char) -> Option<Self> {
+        let n = c.to_digit(10)? as usize;
+        Self::ALL.get(n.checked_sub(1)?).copied()
+    }
+
+    fn next(&self) -> Self {
+        Self::ALL[(self.index() + 1) % Self::ALL.len()]
+    }
+}
+
+/// State changes requested by input (and applied by `TuiApp::update`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TuiMessage {
+    SwitchTab(TuiTab),
+    NextTab,
+    Refresh,
+    OpenSearch,
+    /// Edit or submit the open search box
+    SearchKey(KeyCode),
+    CancelSearch,
+    /// Move the Blocks tab selection by this many rows
+    MoveSelection(i64),
+    /// Select the Blocks tab row at this terminal row
+    ClickRow(u16),
+    /// Show the selected block's details
+    OpenDetail,
+    CloseDetail,
+    /// Copy the open block's hash to the clipboard
+    CopyHash,
+    /// Copy the open block as JSON to the clipboard
+    CopyJson,
+    /// Write the open block as JSON to `export_dir`
+    ExportBlock,
+    /// Close the error popup and read again
+    Retry,
+    Quit,
+}
+
+/// Severity of a Logs tab entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+/// One line on the Logs tab
+#[derive(Debug, Clone)]
+pub struct LogEntry {
+    pub timestamp: u64,
+    pub level: LogLevel,
+    pub message: String,
+}
+
+/// Reader error or panic shown in a popup until the user retries or quits
+#[derive(Debug, Clone)]
+pub struct TuiError {
+    pub message: String,
+    /// Error log the details were appended to (`None` if it could not be written)
+    pub log_file: Option<PathBuf>,
+}
+
+/// A kernel on the Transactions tab with the block it was mined in
+#[derive(Debug, Clone)]
+pub struct RecentKernel {
+    pub height: u64,
+    pub kernel: KernelSummary,
+}
 
 /// Application state for TUI
 pub struct TuiApp {
     pub config: AppConfig,
+    pub provider: Arc<dyn DataProvider>,
     pub dashboard_data: DashboardData,
+    pub chain_events: Vec<ChainEvent>,
+    pub interval_histogram: Option<IntervalHistogram>,
+    /// Kernels of the newest blocks, newest first
+    pub recent_kernels: Vec<RecentKernel>,
+    /// Inspector events shown on the Logs tab, newest last
+    pub logs: VecDeque<LogEntry>,
+    pub active_tab: TuiTab,
     pub refresh_interval: u64,
+    pub pow_filter: Option<String>,
+    pub min_kernels: Option<u64>,
     pub last_update: Instant,
     pub should_quit: bool,
+    /// Blocks below the live window, fetched page by page as the Blocks tab scrolls (newest first)
+    pub older_blocks: Vec<BlockInfo>,
+    /// Selected row on the Blocks tab (0 = tip)
+    pub selected_block: usize,
+    /// First row shown on the Blocks tab
+    pub block_scroll: usize,
+    /// An older page should be fetched
+    load_older: bool,
+    /// Genesis (or the filter's oldest match) is loaded
+    history_complete: bool,
+    /// Terminal area, for mapping mouse clicks to rows
+    pub viewport: Rect,
+    /// Block shown in place of the Blocks tab list (Enter on a row)
+    pub block_detail: Option<BlockDetailSummary>,
+    /// Height of the opened block while it is being read
+    pending_detail: Option<u64>,
+    /// Outcome of the last copy or export
+    pub detail_status: Option<String>,
+    /// Directory exported block JSON files are written to
+    pub export_dir: PathBuf,
+    /// Kept open so copied text stays available (on X11 this process serves the clipboard)
+    clipboard: Option<arboard::Clipboard>,
+    /// Search box contents while typing (opened with '/')
+    pub search_input: Option<String>,
+    /// Submitted query waiting for the event loop to run it
+    pub pending_search: Option<String>,
+    /// Outcome of the last search
+    pub search_status: Option<String>,
+    /// Search running in the background; the loop keeps drawing while it scans
+    search_task: Option<tokio::task::JoinHandle<String>>,
+    /// Progress of long provider scans, drawn as a gauge in the footer
+    pub progress: SharedProgress,
+    /// Builds the snapshots (shared with web mode)
+    engine: Arc<DashboardEngine>,
+    /// Snapshot read running in the background (table counts take a while on mainnet)
+    refresh_task: Option<tokio::task::JoinHandle<Result<DashboardData>>>,
+    /// Snapshots read by the LMDB file watcher; without one the refresh interval applies
+    changes: Option<mpsc::Receiver<Result<DashboardData>>>,
+    /// Task forwarding the engine's change stream into `changes`
+    watch_task: Option<tokio::task::JoinHandle<()>>,
+    /// Error popup drawn over the dashboard; keys only retry or quit while it is open
+    pub error: Option<TuiError>,
 }
 
 impl TuiApp {
-    pub fn new(config: AppConfig, refresh_interval: u64) -> Self {
+    pub fn new(config: AppConfig, refresh_interval: u64, pow_filter: Option<String>, min_kernels: Option<u64>) -> Self {
+        let progress = SharedProgress::new();
+        let provider = data_provider::from_config_with(&config, Arc::new(progress.clone()), CancellationToken::new());
+        let engine = DashboardEngine::new(config.clone(), provider.clone())
+            .with_display_blocks(RECENT_BLOCK_COUNT)
+            .with_filter(pow_filter.clone(), min_kernels)
+            .quiet();
         Self {
+            provider,
             config,
             dashboard_data: DashboardData::default(),
+            chain_events: Vec::new(),
+            interval_histogram: None,
+            recent_kernels: Vec::new(),
+            logs: VecDeque::new(),
+            active_tab: TuiTab::Overview,
             refresh_interval,
+            pow_filter,
+            min_kernels,
             last_update: Instant::now(),
             should_quit: false,
+            older_blocks: Vec::new(),
+            selected_block: 0,
+            block_scroll: 0,
+            load_older: false,
+            history_complete: false,
+            viewport: Rect::default(),
+            block_detail: None,
+            pending_detail: None,
+            detail_status: None,
+            export_dir: PathBuf::from("."),
+            clipboard: None,
+            search_input: None,
+            pending_search: None,
+            search_status: None,
+            search_task: None,
+            progress,
+            engine: Arc::new(engine),
+            refresh_task: None,
+            changes: None,
+            watch_task: None,
+            error: None,
         }
     }
 
-    /// Update dashboard data
+    /// Read a fresh snapshot and wait for it
     pub async fn update_data(&mut self) -> Result<()> {
-        // Simulate data loading - replace with actual LMDB calls
-        let blocks = read_lmdb_headers_with_filter(&self.config.database_path, "headers", BlockFilter::LastN(10))?;
-        
-        // Convert to our data format
-        self.dashboard_data.recent_blocks = blocks.into_iter().map(|block| {
-            crate::data_models::BlockInfo {
-                height: block.height,
-                hash: block.hash,
-                timestamp: block.header.timestamp,
-                transaction_count: 5, // Placeholder
-                interval_seconds: None,
-                pow_algorithm: Some("MockAlgo".to_string()),
+        self.last_update = Instant::now();
+        match self.engine.read().await {
+            Ok(data) => {
+                self.apply_dashboard(data).await;
+                Ok(())
             }
-        }).collect();
-
-        // Mock database stats - replace with real data
-        self.dashboard_data.database_stats = DatabaseStats {
-            utxos_count: 4_340_719,
-            inputs_count: 3_336_822,
-            kernels_count: 1_404_641,
-            total_transactions: 1_404_641,
-            total_io_records: 7_677_541,
-        };
+            Err(e) => {
+                self.report_error(format!("Reading {} failed: {}", self.provider.name(), e), format!("{:?}", e));
+                Err(e)
+            }
+        }
+    }
 
-        self.dashboard_data.last_updated = chrono::Utc::now().timestamp() as u64;
+    /// Start a read in the background; the loop keeps drawing while table counts run
+    pub fn start_refresh(&mut self) {
         self.last_update = Instant::now();
-        
-        Ok(())
+        if self.refresh_task.is_none() {
+            let engine = self.engine.clone();
+            self.refresh_task = Some(tokio::spawn(async move { engine.read().await }));
+        }
+    }
+
+    /// Show the background read's snapshot once it finishes
+    pub async fn collect_refresh(&mut self) {
+        if !self.refresh_task.as_ref().is_some_and(|task| task.is_finished()) {
+            return;
+        }
+        let Some(task) = self.refresh_task.take() else { return };
+        match task.await {
+            Ok(Ok(data)) => self.apply_dashboard(data).await,
+            Ok(Err(e)) => self.report_error(format!("Reading {} failed: {}", self.provider.name(), e), format!("{:?}", e)),
+            Err(e) => self.report_error(format!("Refresh failed: {}", e), e.to_string()),
+        }
+    }
+
+    /// Refresh whenever the LMDB files change instead of on the refresh interval; returns how
+    /// changes are detected
+    pub fn watch_changes(&mut self) -> Result<String> {
+        let watcher = LmdbWatcher::from_config(&self.config)?;
+        let mode = watcher.describe();
+        let changes = self.engine.clone().changes_from(watcher);
+        let (tx, rx) = mpsc::channel(4);
+        self.watch_task = Some(tokio::spawn(async move {
+            let mut changes = std::pin::pin!(changes);
+            while let Some(update) = changes.next().await {
+                if tx.send(update).await.is_err() {
+                    break;
+                }
+            }
+        }));
+        self.changes = Some(rx);
+        Ok(mode)
+    }
+
+    /// Whether refreshes follow the file watcher (else the refresh interval)
+    pub fn is_watching(&self) -> bool {
+        self.changes.is_some()
+    }
+
+    /// Show snapshots the file watcher produced since the last frame
+    pub async fn collect_changes(&mut self) {
+        let Some(changes) = self.changes.as_mut() else { return };
+        let mut latest = None;
+        // Only the newest snapshot matters if several arrived
+        while let Ok(update) = changes.try_recv() {
+            latest = Some(update);
+        }
+        match latest {
+            Some(Ok(data)) => {
+                self.last_update = Instant::now();
+                self.apply_dashboard(data).await;
+            }
+            Some(Err(e)) => self.report_error(format!("Reading {} failed: {}", self.provider.name(), e), format!("{:?}", e)),
+            None => {}
+        }
+    }
+
+    /// Show a snapshot from the engine and refresh the TUI-only panels
+    async fn apply_dashboard(&mut self, data: DashboardData) {
+        let tip_message = match (self.dashboard_data.recent_blocks.first(), data.recent_blocks.first()) {
+            (Some(old), Some(tip)) if tip.height != old.height => Some(format!("New tip {} (was {})", tip.height, old.height)),
+            (None, Some(tip)) => Some(format!("Loaded {} blocks, tip {}", data.recent_blocks.len(), tip.height)),
+            _ => None,
+        };
+        if let Some(message) = tip_message {
+            self.log(LogLevel::Info, message);
+        }
+        let status = data.network_stats.sync_status;
+        if status != self.dashboard_data.network_stats.sync_status {
+            let level = if status == SyncStatus::Synced { LogLevel::Info } else { LogLevel::Warn };
+            self.log(level, format!("Sync status: {}", status.label()));
+        }
+        if data.database_stats.utxos_count != self.dashboard_data.database_stats.utxos_count {
+            let stats = &data.database_stats;
+            self.log(LogLevel::Info, format!("Table counts: {} UTXOs, {} inputs, {} kernels", stats.utxos_count, stats.inputs_count, stats.kernels_count));
+        }
+        self.dashboard_data = data;
+
+        // Older pages must continue right below the live window; after a gap or reorg they are refetched
+        if let Some(lowest) = self.dashboard_data.recent_blocks.last().map(|b| b.height) {
+            self.older_blocks.retain(|b| b.height < lowest);
+            if self.older_blocks.first().is_some_and(|b| b.height + 1 != lowest) && self.pow_filter.is_none() && self.min_kernels.is_none() {
+                self.older_blocks.clear();
+                self.history_complete = false;
+            }
+        }
+        self.select_block(self.selected_block);
+
+        // Kernels of the newest blocks for the Transactions tab (failures keep the previous list)
+        let heights: Vec<u64> = self.dashboard_data.recent_blocks.iter().take(TRANSACTION_BLOCK_COUNT).map(|b| b.height).collect();
+        match self.provider.blocks(heights).await {
+            Ok(details) => {
+                self.recent_kernels = details.into_iter()
+                    .filter_map(|detail| detail.ok())
+                    .flat_map(|detail| {
+                        let height = detail.height;
+                        detail.transactions.kernels.into_iter().map(move |kernel| RecentKernel { height, kernel })
+                    })
+                    .collect();
+            }
+            Err(e) => self.log(LogLevel::Warn, format!("Could not read recent kernels: {}", e)),
+        }
+
+        // Event timeline and interval analytics need a real database
+        if self.config.demo {
+            return;
+        }
+
+        // Chain event timeline (failures keep the previous events on screen)
+        let db_path = self.config.database_path.clone();
+        let store_path = self.config.cache.file(chain_events::STORE_FILE);
+        let refresh = r#async::run_blocking(self.config.read_timeout, "refresh chain events", move || {
+            chain_events::refresh_events(&db_path, &store_path, chain_events::DEFAULT_SCAN_WINDOW)
+        });
+        match refresh.await {
+            Ok(store) => self.chain_events = store.recent(100),
+            Err(e) => self.log(LogLevel::Warn, format!("Chain events not refreshed: {}", e)),
+        }
+
+        // Block interval distribution (failures keep the previous chart)
+        let db_path = self.config.database_path.clone();
+        let histogram = r#async::run_blocking(self.config.read_timeout, "interval analytics", move || {
+            intervals::interval_histogram(&db_path, intervals::DEFAULT_WINDOW)
+        });
+        match histogram.await {
+            Ok(histogram) => self.interval_histogram = Some(histogram),
+            Err(e) => self.log(LogLevel::Warn, format!("Interval analytics not refreshed: {}", e)),
+        }
+    }
+
+    /// Add an entry to the Logs tab
+    pub fn log(&mut self, level: LogLevel, message: String) {
+        if self.logs.len() == MAX_LOG_ENTRIES {
+            self.logs.pop_front();
+        }
+        self.logs.push_back(LogEntry { timestamp: chrono::Utc::now().timestamp() as u64, level, message });
+    }
+
+    /// Open the error popup with `message` and append it to the error log with `details` and the
+    /// last recorded panic, if any
+    pub fn report_error(&mut self, message: String, details: String) {
+        self.log(LogLevel::Error, message.clone());
+        let details = match take_panic() {
+            Some(panic) => format!("{}\n{}", details, panic),
+            None => details,
+        };
+        let path = self.config.cache.file(ERROR_LOG_FILE);
+        let log_file = match append_error_log(&path, &message, &details) {
+            Ok(()) => Some(path),
+            Err(e) => {
+                self.log(LogLevel::Warn, format!("Could not write {}: {}", path.display(), e));
+                None
+            }
+        };
+        self.error = Some(TuiError { message, log_file });
+    }
+
+    /// Start a submitted search in the background and collect a finished one
+    pub async fn run_pending_search(&mut self) {
+        if self.search_task.as_ref().is_some_and(|task| task.is_finished()) {
+            if let Some(task) = self.search_task.take() {
+                let status = task.await.unwrap_or_else(|e| format!("Search failed: {}", e));
+                self.log(LogLevel::Info, status.clone());
+                self.search_status = Some(status);
+            }
+        }
+
+        // One search at a time; a new query waits for the running one
+        if self.search_task.is_some() {
+            return;
+        }
+        let Some(query) = self.pending_search.take() else { return };
+        let Some(parsed) = SearchQuery::parse(&query) else {
+            self.search_status = Some(format!("'{}' is not a height or 64-character hex hash", query));
+            return;
+        };
+
+        let provider = self.provider.clone();
+        let denom = self.config.denom;
+        self.log(LogLevel::Info, format!("Search: {}", query));
+        self.search_status = Some(format!("Searching for {}...", query));
+        self.search_task = Some(tokio::spawn(async move {
+            match provider.search(parsed).await {
+                Ok(result) => result.describe(denom),
+                Err(e) => format!("Search failed: {}", e),
+            }
+        }));
+    }
+
+    /// Abort a running search; dropping its read cancels the LMDB scan
+    pub fn cancel_search(&mut self) {
+        if let Some(task) = self.search_task.take() {
+            task.abort();
+            self.log(LogLevel::Info, "Search cancelled".to_string());
+            self.search_status = Some("Search cancelled".to_string());
+        }
     }
 
     /// Handle keyboard input
     pub fn handle_input(&mut self, key: KeyCode) {
+        if let Some(message) = self.message_for_key(key) {
+            self.update(message);
+        }
+    }
+
+    /// Translate a key press into a message for the current mode
+    pub fn message_for_key(&self, key: KeyCode) -> Option<TuiMessage> {
+        // The error popup takes every key until it is dismissed
+        if self.error.is_some() {
+            return match key {
+                KeyCode::Char('r') | KeyCode::Enter => Some(TuiMessage::Retry),
+                KeyCode::Char('q') | KeyCode::Esc => Some(TuiMessage::Quit),
+                _ => None,
+            };
+        }
+
+        // While the search box is open every key edits it
+        if self.search_input.is_some() {
+            return Some(TuiMessage::SearchKey(key));
+        }
+
+        if self.detail_open() {
+            match key {
+                KeyCode::Esc | KeyCode::Backspace => return Some(TuiMessage::CloseDetail),
+                KeyCode::Char('c') => return Some(TuiMessage::CopyHash),
+                KeyCode::Char('C') => return Some(TuiMessage::CopyJson),
+                KeyCode::Char('e') => return Some(TuiMessage::ExportBlock),
+                KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown => return None,
+                _ => {}
+            }
+        }
+
         match key {
-            KeyCode::Char('q') | KeyCode::Esc => {
-                self.should_quit = true;
+            KeyCode::Char('/') => Some(TuiMessage::OpenSearch),
+            KeyCode::Esc if self.search_task.is_some() => Some(TuiMessage::CancelSearch),
+            KeyCode::Char('q') | KeyCode::Esc => Some(TuiMessage::Quit),
+            KeyCode::Char('r') => Some(TuiMessage::Refresh),
+            KeyCode::Tab => Some(TuiMessage::NextTab),
+            KeyCode::Char(c) => TuiTab::from_key(c).map(TuiMessage::SwitchTab),
+            KeyCode::Enter if self.active_tab == TuiTab::Blocks => Some(TuiMessage::OpenDetail),
+            KeyCode::Up if self.active_tab == TuiTab::Blocks => Some(TuiMessage::MoveSelection(-1)),
+            KeyCode::Down if self.active_tab == TuiTab::Blocks => Some(TuiMessage::MoveSelection(1)),
+            KeyCode::PageUp if self.active_tab == TuiTab::Blocks => Some(TuiMessage::MoveSelection(-(self.visible_block_rows() as i64))),
+            KeyCode::PageDown if self.active_tab == TuiTab::Blocks => Some(TuiMessage::MoveSelection(self.visible_block_rows() as i64)),
+            _ => None,
+        }
+    }
+
+    /// Handle mouse input
+    pub fn handle_mouse(&mut self, mouse: MouseEvent) {
+        if let Some(message) = self.message_for_mouse(mouse) {
+            self.update(message);
+        }
+    }
+
+    /// Translate a mouse event into a message; only the Blocks tab list reacts to the mouse
+    pub fn message_for_mouse(&self, mouse: MouseEvent) -> Option<TuiMessage> {
+        if self.active_tab != TuiTab::Blocks || self.search_input.is_some() || self.detail_open() || self.error.is_some() {
+            return None;
+        }
+        match mouse.kind {
+            MouseEventKind::ScrollDown => Some(TuiMessage::MoveSelection(3)),
+            MouseEventKind::ScrollUp => Some(TuiMessage::MoveSelection(-3)),
+            MouseEventKind::Down(MouseButton::Left) => Some(TuiMessage::ClickRow(mouse.row)),
+            _ => None,
+        }
+    }
+
+    /// Whether the Blocks tab shows a block's details instead of the list
+    pub fn detail_open(&self) -> bool {
+        self.active_tab == TuiTab::Blocks && (self.block_detail.is_some() || self.pending_detail.is_some())
+    }
+
+    /// Read the block the detail view was opened on
+    pub async fn load_block_detail(&mut self) {
+        let Some(height) = self.pending_detail else { return };
+        match self.provider.block(height).await {
+            Ok(detail) => self.block_detail = Some(detail),
+            Err(e) => self.log(LogLevel::Warn, format!("Could not read block {}: {}", height, e)),
+        }
+        self.pending_detail = None;
+    }
+
+    /// Copy the open block's hash, or the whole block as JSON, to the system clipboard
+    fn copy_detail(&mut self, json: bool) {
+        let Some(block) = self.block_detail.as_ref() else { return };
+        let height = block.height;
+        let text = if json {
+            serde_json::to_string_pretty(&BlockDetailResponse::from(block.clone())).map_err(anyhow::Error::from)
+        } else {
+            Ok(block.hash.clone())
+        };
+        let what = if json { "JSON" } else { "hash" };
+        match text.and_then(|text| self.set_clipboard(text)) {
+            Ok(()) => self.detail_status = Some(format!("Copied block {} {} to the clipboard", height, what)),
+            Err(e) => {
+                self.log(LogLevel::Warn, format!("Clipboard unavailable: {}", e));
+                self.detail_status = Some(format!("Could not copy to the clipboard: {}", e));
             }
-            KeyCode::Char('r') => {
-                // Force refresh
-                self.last_update = Instant::now() - Duration::from_secs(self.refresh_interval);
+        }
+    }
+
+    fn set_clipboard(&mut self, text: String) -> Result<()> {
+        if self.clipboard.is_none() {
+            self.clipboard = Some(arboard::Clipboard::new()?);
+        }
+        if let Some(clipboard) = self.clipboard.as_mut() {
+            clipboard.set_text(text)?;
+        }
+        Ok(())
+    }
+
+    /// Write the open block as JSON to `export_dir`
+    fn export_detail(&mut self) {
+        let Some(block) = self.block_detail.as_ref() else { return };
+        let status = match export_block_json(block, &self.export_dir) {
+            Ok(path) => format!("Exported block {} to {}", block.height, path.display()),
+            Err(e) => format!("Could not export block {}: {}", block.height, e),
+        };
+        self.log(LogLevel::Info, status.clone());
+        self.detail_status = Some(status);
+    }
+
+    /// Live window followed by the older pages, newest first
+    pub fn block_list(&self) -> impl Iterator<Item = &BlockInfo> {
+        self.dashboard_data.recent_blocks.iter().chain(self.older_blocks.iter())
+    }
+
+    fn block_count(&self) -> usize {
+        self.dashboard_data.recent_blocks.len() + self.older_blocks.len()
+    }
+
+    /// Block rows that fit on the Blocks tab
+    fn visible_block_rows(&self) -> usize {
+        let area = layout(self.viewport)[2];
+        area.height.saturating_sub(BLOCK_TABLE_HEADER_ROWS + 1).max(1) as usize
+    }
+
+    /// Select a row, keep it on screen and ask for the next page near the end
+    fn select_block(&mut self, row: usize) {
+        let count = self.block_count();
+        if count == 0 {
+            return;
+        }
+        self.selected_block = row.min(count - 1);
+        let visible = self.visible_block_rows();
+        if self.selected_block < self.block_scroll {
+            self.block_scroll = self.selected_block;
+        } else if self.selected_block >= self.block_scroll + visible {
+            self.block_scroll = self.selected_block + 1 - visible;
+        }
+        if !self.history_complete && self.selected_block + BLOCK_PREFETCH_ROWS >= count {
+            self.load_older = true;
+        }
+    }
+
+    /// Fetch the next page of older blocks if scrolling asked for it
+    pub async fn load_older_blocks(&mut self) {
+        if !std::mem::take(&mut self.load_older) {
+            return;
+        }
+        let Some(oldest) = self.block_list().last().map(|b| b.height) else { return };
+        if oldest == 0 {
+            self.history_complete = true;
+            return;
+        }
+        let end = oldest - 1;
+        let filter = BlockFilter::Range(end.saturating_sub(BLOCK_PAGE_SIZE - 1), end)
+            .with_options(self.pow_filter.clone(), self.min_kernels);
+        match self.provider.headers(filter).await {
+            Ok(blocks) => {
+                let page = data_provider::to_block_infos(&blocks);
+                if end < BLOCK_PAGE_SIZE {
+                    self.history_complete = true;
+                }
+                self.log(LogLevel::Info, format!("Loaded {} older blocks below {}", page.len(), oldest));
+                self.older_blocks.extend(page);
+                // A filtered page can be empty; keep going while the selection sits at the end
+                self.select_block(self.selected_block);
             }
-            _ => {}
+            Err(e) => self.log(LogLevel::Warn, format!("Could not load blocks below {}: {}", oldest, e)),
         }
     }
+
+    /// Apply a message to the app state
+    pub fn update(&mut self, message: TuiMessage) {
+        match message {
+            TuiMessage::SwitchTab(tab) => self.active_tab = tab,
+            TuiMessage::NextTab => self.active_tab = self.active_tab.next(),
+            TuiMessage::Refresh => self.start_refresh(),
+            TuiMessage::OpenSearch => self.search_input = Some(String::new()),
+            TuiMessage::SearchKey(key) => {
+                let Some(input) = self.search_input.as_mut() else { return };
+                match key {
+                    KeyCode::Enter => self.pending_search = self.search_input.take(),
+                    KeyCode::Esc => self.search_input = None,
+                    KeyCode::Backspace => { input.pop(); }
+                    KeyCode::Char(c) => input.push(c),
+                    _ => {}
+                }
+            }
+            TuiMessage::CancelSearch => self.cancel_search(),
+            TuiMessage::MoveSelection(delta) => {
+                let row = self.selected_block as i64 + delta;
+                self.select_block(row.max(0) as usize);
+            }
+            TuiMessage::ClickRow(y) => {
+                let area = layout(self.viewport)[2];
+                let first_row = area.y + BLOCK_TABLE_HEADER_ROWS;
+                if y >= first_row && y < (area.y + area.height).saturating_sub(1) {
+                    self.select_block(self.block_scroll + (y - first_row) as usize);
+                }
+            }
+            TuiMessage::OpenDetail => {
+                if let Some(height) = self.block_list().nth(self.selected_block).map(|b| b.height) {
+                    self.pending_detail = Some(height);
+                    self.detail_status = None;
+                }
+            }
+            TuiMessage::CloseDetail => {
+                self.block_detail = None;
+                self.pending_detail = None;
+                self.detail_status = None;
+            }
+            TuiMessage::CopyHash => self.copy_detail(false),
+            TuiMessage::CopyJson => self.copy_detail(true),
+            TuiMessage::ExportBlock => self.export_detail(),
+            TuiMessage::Retry => {
+                self.error = None;
+                self.start_refresh();
+            }
+            TuiMessage::Quit => {
+                self.cancel_search();
+                // Aborting drops the read, which stops its table count
+                if let Some(task) = self.refresh_task.take() {
+                    task.abort();
+                }
+                if let Some(task) = self.watch_task.take() {
+                    task.abort();
+                }
+                self.should_quit = true;
+            }
+        }
+    }
+}
+
+/// Write `block` as pretty JSON (a `BlockDetailResponse`) to `dir/block-<height>.json`
+pub fn export_block_json(block: &BlockDetailSummary, dir: &Path) -> Result<PathBuf> {
+    let path = dir.join(format!("block-{}.json", block.height));
+    std::fs::write(&path, serde_json::to_vec_pretty(&BlockDetailResponse::from(block.clone()))?)?;
+    Ok(path)
+}
+
+/// Append a timestamped error with its details to the error log at `path`
+fn append_error_log(path: &Path, message: &str, details: &str) -> io::Result<()> {
+    use std::io::Write;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "[{}] {}\n{}\n", chrono::Utc::now().to_rfc3339(), message, details.trim_end())
 }
 
 /// Run the TUI dashboard
 pub async fn run_tui_mode(
     config: &AppConfig,
     refresh: u64,
+    pow: Option<String>,
+    min_kernels: Option<u64>,
 ) -> Result<()> {
     // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
+    install_panic_hook();
+    if let Err(e) = enter_terminal() {
+        restore_terminal();
+        return Err(e.into());
+    }
+    let backend = CrosstermBackend::new(io::stdout());
     let mut terminal = Terminal::new(backend)?;
 
     // Create app state
-    let mut app = TuiApp::new(config.clone(), refresh);
+    let mut app = TuiApp::new(config.clone(), refresh, pow, min_kernels);
+    let shutdown = shutdown::on_signal();
+    let result = run_app(&mut terminal, &mut app, config, refresh, &shutdown).await;
+
+    // Restore terminal, also when the loop failed
+    restore_terminal();
+    if shutdown.is_cancelled() {
+        println!("🛑 Shutdown signal received");
+    }
+
+    // Saved for a warm start next time
+    if !config.demo {
+        if let Err(e) = config.cache.store_dashboard(&app.dashboard_data) {
+            eprintln!("⚠️  Could not save dashboard for warm start: {}", e);
+        }
+    }
+
+    println!("👋 Tari LMDB Inspector - Dashboard closed");
     
-    // Initial data load
-    app.update_data().await?;
+    result
+}
+
+/// Set while the TUI owns the terminal (raw mode, alternate screen)
+static TERMINAL_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Raw mode, alternate screen and mouse capture for the TUI
+fn enter_terminal() -> io::Result<()> {
+    enable_raw_mode()?;
+    TERMINAL_ACTIVE.store(true, Ordering::SeqCst);
+    execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)
+}
+
+/// Leave raw mode, the alternate screen and mouse capture if the TUI set them up
+pub fn restore_terminal() {
+    if TERMINAL_ACTIVE.swap(false, Ordering::SeqCst) {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, crossterm::cursor::Show);
+    }
+}
+
+/// Message, location and backtrace of the last panic, recorded by the hook for the error log
+static LAST_PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+fn take_panic() -> Option<String> {
+    LAST_PANIC.lock().unwrap_or_else(|e| e.into_inner()).take()
+}
+
+/// Text of a caught panic's payload
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+/// Restore the terminal before the panic message prints, so it is readable and the shell usable,
+/// and record the panic for the error log
+/// The hook also runs for panics the loop or a background read recovers from; the loop then
+/// sets the terminal up again
+fn install_panic_hook() {
+    static INSTALLED: Once = Once::new();
+    INSTALLED.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            restore_terminal();
+            let backtrace = std::backtrace::Backtrace::force_capture();
+            *LAST_PANIC.lock().unwrap_or_else(|e| e.into_inner()) = Some(format!("{}\n{}", info, backtrace));
+            previous(info);
+        }));
+    });
+}
+
+/// Draw and handle input until the user quits or `shutdown` fires
+async fn run_app(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut TuiApp,
+    config: &AppConfig,
+    refresh: u64,
+    shutdown: &tokio_util::sync::CancellationToken,
+) -> Result<()> {
+    // Initial data load; with a saved dashboard the first frame shows it (marked stale) and the
+    // read runs straight after
+    match config.cache.load_dashboard().filter(|_| !config.demo) {
+        Some(saved) => {
+            app.dashboard_data = saved;
+            app.start_refresh();
+        }
+        // A failed read opens the error popup, from which it can be retried
+        None => {
+            let _ = app.update_data().await;
+        }
+    }
+
+    // New blocks show up as soon as the node writes them; the interval is only a fallback
+    if !config.demo {
+        match app.watch_changes() {
+            Ok(mode) => app.log(LogLevel::Info, format!("Watching {} for changes ({})", config.database_path.display(), mode)),
+            Err(e) => app.log(LogLevel::Warn, format!("File watcher unavailable ({}), refreshing every {}s", e, refresh)),
+        }
+    }
 
     // Main event loop
     let tick_rate = Duration::from_millis(250);
     let mut last_tick = Instant::now();
 
     loop {
-        // Draw UI
-        terminal.draw(|f| ui(f, &app))?;
-
-        // Handle events
-        let timeout = tick_rate
-            .checked_sub(last_tick.elapsed())
-            .unwrap_or_else(|| Duration::from_secs(0));
-
-        if crossterm::event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                app.handle_input(key.code);
-            }
+        // A panic (in this loop or a blocking read) restored the terminal; take it back
+        if !TERMINAL_ACTIVE.load(Ordering::SeqCst) {
+            enter_terminal()?;
+            terminal.clear()?;
         }
 
-        if last_tick.elapsed() >= tick_rate {
-            last_tick = Instant::now();
+        // A panic while drawing or handling input opens the error popup instead of ending the TUI
+        match AssertUnwindSafe(tick(terminal, app, tick_rate, &mut last_tick)).catch_unwind().await {
+            Ok(result) => result?,
+            Err(payload) => app.report_error(format!("Panic: {}", panic_message(&*payload)), String::new()),
+        }
+        // Panics recovered elsewhere without an error reaching the popup (e.g. a kernel read)
+        if let Some(details) = take_panic() {
+            app.report_error("A background read panicked".to_string(), details);
         }
 
         // Check if should quit
-        if app.should_quit {
+        if app.should_quit || shutdown.is_cancelled() {
             break;
         }
 
-        // Update data if needed
-        if app.last_update.elapsed() >= Duration::from_secs(app.refresh_interval) {
-            app.update_data().await?;
+        // Without a file watcher, poll on the refresh interval
+        if !app.is_watching() && app.last_update.elapsed() >= Duration::from_secs(app.refresh_interval) {
+            app.start_refresh();
         }
 
         // Small async sleep to prevent busy waiting
         sleep(Duration::from_millis(100)).await;
     }
 
-    // Restore terminal
-    disable_raw_mode()?;
-    execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+    Ok(())
+}
+
+/// Draw one frame, handle input and collect finished background work
+async fn tick(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut TuiApp,
+    tick_rate: Duration,
+    last_tick: &mut Instant,
+) -> Result<()> {
+    // Draw UI
+    let (width, height) = crossterm::terminal::size()?;
+    app.viewport = Rect::new(0, 0, width, height);
+    terminal.draw(|f| ui(f, app))?;
 
-    println!("👋 Tari LMDB Inspector - Dashboard closed");
-    
+    // Handle events
+    let timeout = tick_rate
+        .checked_sub(last_tick.elapsed())
+        .unwrap_or_else(|| Duration::from_secs(0));
+
+    if crossterm::event::poll(timeout)? {
+        match event::read()? {
+            Event::Key(key) => app.handle_input(key.code),
+            Event::Mouse(mouse) => app.handle_mouse(mouse),
+            _ => {}
+        }
+    }
+    app.run_pending_search().await;
+    app.collect_refresh().await;
+    app.collect_changes().await;
+    app.load_older_blocks().await;
+    app.load_block_detail().await;
+
+    if last_tick.elapsed() >= tick_rate {
+        *last_tick = Instant::now();
+    }
     Ok(())
 }
 
-/// Render the UI
-fn ui(f: &mut Frame, app: &TuiApp) {
-    let chunks = Layout::default()
+/// Header, tab bar, active tab and footer areas (shared with mouse hit-testing)
+fn layout(area: Rect) -> std::rc::Rc<[Rect]> {
+    Layout::default()
         .direction(Direction::Vertical)
         .margin(1)
         .constraints([
             Constraint::Length(3),  // Header
-            Constraint::Length(8),  // Database stats
-            Constraint::Min(10),    // Recent blocks
+            Constraint::Length(3),  // Tab bar
+            Constraint::Min(10),    // Active tab
             Constraint::Length(3),  // Footer
         ])
-        .split(f.area());
+        .split(area)
+}
+
+/// Render the UI
+fn ui(f: &mut Frame, app: &TuiApp) {
+    let chunks = layout(f.area());
+    let theme = &app.config.theme;
 
     // Header
     render_header(f, chunks[0], app);
+    render_tabs(f, chunks[1], app.active_tab, theme);
     
-    // Database statistics
-    render_database_stats(f, chunks[1], &app.dashboard_data.database_stats);
-    
-    // Recent blocks
-    render_recent_blocks(f, chunks[2], &app.dashboard_data.recent_blocks);
+    match app.active_tab {
+        TuiTab::Overview => {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(4), Constraint::Length(7), Constraint::Min(10)])
+                .split(chunks[2]);
+
+            // Database statistics
+            render_database_stats(f, rows[0], &app.dashboard_data.database_stats, theme);
+
+            // Block intervals and kernel counts of the newest blocks
+            render_sparklines(f, rows[1], &app.dashboard_data.recent_blocks, theme);
+            
+            // Recent blocks with the interval distribution alongside
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .split(rows[2]);
+            render_recent_blocks(f, columns[0], &app.dashboard_data.recent_blocks, &app.config.timezone, theme);
+            render_interval_histogram(f, columns[1], app.interval_histogram.as_ref(), theme);
+        }
+        TuiTab::Blocks if app.detail_open() => render_block_detail(f, chunks[2], app),
+        TuiTab::Blocks => render_block_list(f, chunks[2], app),
+        TuiTab::Transactions => render_transactions(f, chunks[2], &app.recent_kernels, app.config.denom, theme),
+        TuiTab::Timeline => render_timeline(f, chunks[2], &app.chain_events, &app.config.timezone, theme),
+        TuiTab::Logs => render_logs(f, chunks[2], &app.logs, &app.config.timezone, theme),
+    }
     
     // Footer
-    render_footer(f, chunks[3]);
+    render_footer(f, chunks[3], app);
+
+    if let Some(error) = &app.error {
+        render_error_popup(f, chunks[2], error, theme);
+    }
+}
+
+/// Render the error popup centered over the active tab
+fn render_error_popup(f: &mut Frame, area: Rect, error: &TuiError, theme: &Theme) {
+    let width = area.width.saturating_sub(8).min(80);
+    let height = area.height.min(9);
+    let popup = Rect::new(area.x + (area.width - width) / 2, area.y + (area.height - height) / 2, width, height);
+
+    let mut lines = vec![Line::from(Span::styled(error.message.clone(), theme.text)), Line::from("")];
+    if let Some(path) = &error.log_file {
+        lines.push(Line::from(Span::styled(format!("Details written to {}", path.display()), theme.muted)));
+    }
+    lines.push(Line::from(vec![
+        Span::styled("'r'", theme.accent),
+        Span::styled(" to retry, ", theme.muted),
+        Span::styled("'q'", theme.accent),
+        Span::styled(" to quit", theme.muted),
+    ]));
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(Block::default().borders(Borders::ALL).border_style(theme.error).title(Span::styled("Error", theme.error)));
+
+    f.render_widget(Clear, popup);
+    f.render_widget(paragraph, popup);
+}
+
+/// Render the tab bar (number keys select a tab)
+fn render_tabs(f: &mut Frame, area: Rect, active: TuiTab, theme: &Theme) {
+    let titles: Vec<String> = TuiTab::ALL.iter()
+        .enumerate()
+        .map(|(i, tab)| format!("{} {}", i + 1, tab.title()))
+        .collect();
+    let tabs = Tabs::new(titles)
+        .block(Block::default().borders(Borders::ALL))
+        .select(active.index())
+        .style(theme.muted)
+        .highlight_style(theme.tab_active);
+
+    f.render_widget(tabs, area);
 }
 
 /// Render header section
 fn render_header(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let theme = &app.config.theme;
+    let sync_status = &app.dashboard_data.network_stats.sync_status;
     let header = Paragraph::new(vec![
         Line::from(vec![
-            Span::styled("🔍 ", Style::default().fg(Color::Yellow)),
-            Span::styled("Tari LMDB Inspector", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD)),
-            Span::styled(" - Terminal Dashboard", Style::default().fg(Color::Gray)),
+            Span::styled("🔍 ", theme.accent),
+            Span::styled("Tari LMDB Inspector", theme.title),
+            Span::styled(" - Terminal Dashboard", theme.muted),
+            Span::styled(format!("  ● {}", sync_status.label()), theme.sync_status(sync_status)),
         ]),
         Line::from(vec![
-            Span::styled("Database: ", Style::default().fg(Color::Gray)),
-            Span::styled(format!("{:?}", app.config.database_path), Style::default().fg(Color::White)),
+            Span::styled("Database: ", theme.muted),
+            Span::styled(format!("{:?}", app.config.database_path), theme.text),
+            match app.dashboard_data.data_completeness {
+                DataCompleteness::Full => Span::raw(""),
+                completeness => Span::styled(format!("  ⚠ {}", completeness.label()), theme.warn),
+            },
+            if app.dashboard_data.stale {
+                Span::styled("  ♨ Saved data from the previous run - reading LMDB...", theme.warn)
+            } else {
+                Span::raw("")
+            },
         ]),
     ])
     .block(Block::default().borders(Borders::ALL).title("Tari Blockchain Explorer"));
@@ -205,60 +1091,199 @@ fn render_header(f: &mut Frame, area: Rect, app: &TuiApp) {
 }
 
 /// Render database statistics
-fn render_database_stats(f: &mut Frame, area: Rect, stats: &DatabaseStats) {
+fn render_database_stats(f: &mut Frame, area: Rect, stats: &DatabaseStats, theme: &Theme) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(33), Constraint::Percentage(33), Constraint::Percentage(34)])
         .split(area);
 
-    // UTXOs
-    let utxos_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title("💰 UTXOs"))
-        .gauge_style(Style::default().fg(Color::Green))
-        .percent(((stats.utxos_count as f64 / 5_000_000.0) * 100.0) as u16)
-        .label(format!("{}", stats.utxos_count));
-    f.render_widget(utxos_gauge, chunks[0]);
-
-    // Inputs
-    let inputs_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title("📥 Inputs"))
-        .gauge_style(Style::default().fg(Color::Blue))
-        .percent(((stats.inputs_count as f64 / 4_000_000.0) * 100.0) as u16)
-        .label(format!("{}", stats.inputs_count));
-    f.render_widget(inputs_gauge, chunks[1]);
-
-    // Kernels (Transactions)
-    let kernels_gauge = Gauge::default()
-        .block(Block::default().borders(Borders::ALL).title("⚡ Transactions"))
-        .gauge_style(Style::default().fg(Color::Yellow))
-        .percent(((stats.kernels_count as f64 / 2_000_000.0) * 100.0) as u16)
-        .label(format!("{}", stats.kernels_count));
-    f.render_widget(kernels_gauge, chunks[2]);
+    let counts = [
+        ("💰 UTXOs", stats.utxos_count, theme.ok),
+        ("📥 Inputs", stats.inputs_count, theme.secondary),
+        ("⚡ Transactions", stats.kernels_count, theme.accent),
+    ];
+    for ((title, count, style), area) in counts.into_iter().zip(chunks.iter()) {
+        let paragraph = Paragraph::new(count.to_string())
+            .style(style.add_modifier(Modifier::BOLD))
+            .block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(paragraph, *area);
+    }
+}
+
+/// Render block intervals and kernel counts of the newest blocks, oldest on the left
+fn render_sparklines(f: &mut Frame, area: Rect, blocks: &[crate::data_models::BlockInfo], theme: &Theme) {
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+
+    let newest: Vec<_> = blocks.iter().take(SPARKLINE_BLOCKS).rev().collect();
+    let intervals: Vec<u64> = newest.iter().filter_map(|b| b.interval_seconds).map(|s| s as u64).collect();
+    let kernels: Vec<u64> = newest.iter().map(|b| b.transaction_count as u64).collect();
+
+    let interval_title = match intervals.iter().max() {
+        Some(max) => format!("⏱️ Block intervals (last {}, max {}s)", intervals.len(), max),
+        None => "⏱️ Block intervals".to_string(),
+    };
+    let interval_sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(interval_title))
+        .data(&intervals)
+        .style(theme.highlight);
+    f.render_widget(interval_sparkline, columns[0]);
+
+    let kernel_title = match kernels.iter().max() {
+        Some(max) => format!("⚡ Kernels per block (last {}, max {})", kernels.len(), max),
+        None => "⚡ Kernels per block".to_string(),
+    };
+    let kernel_sparkline = Sparkline::default()
+        .block(Block::default().borders(Borders::ALL).title(kernel_title))
+        .data(&kernels)
+        .style(theme.accent);
+    f.render_widget(kernel_sparkline, columns[1]);
 }
 
 /// Render recent blocks
-fn render_recent_blocks(f: &mut Frame, area: Rect, blocks: &[crate::data_models::BlockInfo]) {
-    let header_cells = ["Height", "Hash", "Timestamp", "TXs"]
+fn render_recent_blocks(f: &mut Frame, area: Rect, blocks: &[BlockInfo], zone: &DisplayZone, theme: &Theme) {
+    let table = Table::new(blocks.iter().map(|block| block_row(block, zone)), block_widths())
+        .header(block_header(theme))
+        .block(Block::default().borders(Borders::ALL).title("📊 Recent Blocks"));
+
+    f.render_widget(table, area);
+}
+
+/// Render the scrollable block list with the selected row highlighted
+fn render_block_list(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let visible = area.height.saturating_sub(BLOCK_TABLE_HEADER_ROWS + 1) as usize;
+    let rows = app.block_list()
+        .enumerate()
+        .skip(app.block_scroll)
+        .take(visible)
+        .map(|(i, block)| {
+            let row = block_row(block, &app.config.timezone);
+            if i == app.selected_block {
+                row.style(app.config.theme.selected)
+            } else {
+                row
+            }
+        });
+
+    let more = if app.history_complete { "" } else { "+" };
+    let title = format!("📊 Blocks ({} / {}{} loaded, ↑↓ PgUp PgDn or mouse wheel to scroll)",
+        app.selected_block + 1, app.block_count(), more);
+    let table = Table::new(rows, block_widths())
+        .header(block_header(&app.config.theme))
+        .block(Block::default().borders(Borders::ALL).title(title));
+
+    f.render_widget(table, area);
+}
+
+/// Render the block opened from the Blocks tab
+fn render_block_detail(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let theme = &app.config.theme;
+    let Some(block) = app.block_detail.as_ref() else {
+        let height = app.pending_detail.map(|h| h.to_string()).unwrap_or_default();
+        let loading = Paragraph::new(format!("Reading block {}...", height))
+            .style(theme.muted)
+            .block(Block::default().borders(Borders::ALL).title("🔍 Block"));
+        f.render_widget(loading, area);
+        return;
+    };
+
+    let field = |label: &str, value: String| Line::from(vec![
+        Span::styled(format!("{:<15}", label), theme.muted),
+        Span::styled(value, theme.text),
+    ]);
+    let timestamp = app.config.timezone.datetime(block.header.timestamp);
+    let transactions = &block.transactions;
+
+    let mut lines = vec![
+        field("Height", block.height.to_string()),
+        field("Hash", block.hash.clone()),
+        field("Previous hash", block.header.previous_hash.clone()),
+        field("Timestamp", timestamp),
+        field("PoW", block.header.pow_algorithm.clone()),
+        field("Nonce", block.header.nonce.to_string()),
+        field("Transactions", format!("{} inputs, {} outputs, {} kernels",
+            transactions.inputs.len(), transactions.outputs.len(), transactions.kernels.len())),
+    ];
+    if block.data_completeness != DataCompleteness::Full {
+        lines.push(Line::from(Span::styled(format!("⚠ Transaction data unavailable: {}", block.data_completeness.label()), theme.warn)));
+    }
+    lines.push(Line::raw(""));
+    lines.push(Line::from(Span::styled("Kernels", theme.heading)));
+    for kernel in &transactions.kernels {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}  ", kernel.excess), theme.text),
+            Span::styled(format!("{:>15}  ", app.config.denom.format(kernel.fee)), theme.text),
+            Span::styled(kernel.kind.label(), theme.kernel_kind(kernel.kind)),
+        ]));
+    }
+
+    let detail = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .block(Block::default().borders(Borders::ALL).title(format!("🔍 Block {}", block.height)));
+    f.render_widget(detail, area);
+}
+
+fn block_header(theme: &Theme) -> Row<'static> {
+    let header_cells = ["Height", "Hash", "Timestamp", "TXs", "Out", "In"]
         .iter()
-        .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+        .map(|h| Cell::from(*h).style(theme.heading));
+    Row::new(header_cells).height(1).bottom_margin(1)
+}
+
+fn block_widths() -> [Constraint; 6] {
+    [
+        Constraint::Length(8),
+        Constraint::Length(20),
+        Constraint::Length(12),
+        Constraint::Length(6),
+        Constraint::Length(6),
+        Constraint::Length(6),
+    ]
+}
+
+fn block_row(block: &BlockInfo, zone: &DisplayZone) -> Row<'static> {
+    let hash_short = if block.hash.len() > 16 {
+        format!("{}...", &block.hash[..16])
+    } else {
+        block.hash.clone()
+    };
+    
+    let timestamp = zone.format(block.timestamp, "%H:%M:%S")
+        .unwrap_or_else(|| "Invalid".to_string());
+
+    Row::new(vec![
+        Cell::from(block.height.to_string()),
+        Cell::from(hash_short),
+        Cell::from(timestamp),
+        Cell::from(block.transaction_count.to_string()),
+        Cell::from(block.outputs_count.map_or("-".to_string(), |n| n.to_string())),
+        Cell::from(block.inputs_count.map_or("-".to_string(), |n| n.to_string())),
+    ])
+}
+
+/// Render kernels of the newest blocks with their fees
+fn render_transactions(f: &mut Frame, area: Rect, kernels: &[RecentKernel], denom: Denomination, theme: &Theme) {
+    let fee_heading = format!("Fee ({})", denom.unit());
+    let header_cells = ["Height", "Excess", fee_heading.as_str(), "Kind", "Lock"]
+        .into_iter()
+        .map(|h| Cell::from(h.to_string()).style(theme.heading));
     let header = Row::new(header_cells).height(1).bottom_margin(1);
 
-    let rows = blocks.iter().map(|block| {
-        let hash_short = if block.hash.len() > 16 {
-            format!("{}...", &block.hash[..16])
+    let rows = kernels.iter().map(|recent| {
+        let kernel = &recent.kernel;
+        let excess_short = if kernel.excess.len() > 16 {
+            format!("{}...", &kernel.excess[..16])
         } else {
-            block.hash.clone()
+            kernel.excess.clone()
         };
-        
-        let timestamp = chrono::DateTime::from_timestamp(block.timestamp as i64, 0)
-            .map(|dt| dt.format("%H:%M:%S").to_string())
-            .unwrap_or_else(|| "Invalid".to_string());
-
         Row::new(vec![
-            Cell::from(block.height.to_string()),
-            Cell::from(hash_short),
-            Cell::from(timestamp),
-            Cell::from(block.transaction_count.to_string()),
+            Cell::from(recent.height.to_string()),
+            Cell::from(excess_short),
+            Cell::from(denom.amount(kernel.fee)),
+            Cell::from(kernel.kind.label()).style(theme.kernel_kind(kernel.kind)),
+            Cell::from(kernel.lock_height.to_string()),
         ])
     });
 
@@ -266,21 +1291,130 @@ fn render_recent_blocks(f: &mut Frame, area: Rect, blocks: &[crate::data_models:
         Constraint::Length(8),
         Constraint::Length(20),
         Constraint::Length(12),
-        Constraint::Length(6),
+        Constraint::Length(14),
+        Constraint::Length(8),
     ];
 
+    let title = format!("💸 Kernels (last {} blocks)", TRANSACTION_BLOCK_COUNT);
     let table = Table::new(rows, widths)
         .header(header)
-        .block(Block::default().borders(Borders::ALL).title("📊 Recent Blocks"));
+        .block(Block::default().borders(Borders::ALL).title(title));
 
     f.render_widget(table, area);
 }
 
-/// Render footer
-fn render_footer(f: &mut Frame, area: Rect) {
-    let footer = Paragraph::new("Press 'q' to quit, 'r' to refresh")
-        .style(Style::default().fg(Color::Gray))
+/// Render the inspector's own events, newest first
+fn render_logs(f: &mut Frame, area: Rect, logs: &VecDeque<LogEntry>, zone: &DisplayZone, theme: &Theme) {
+    let lines: Vec<Line> = logs.iter().rev().map(|entry| {
+        let time = zone.format(entry.timestamp, "%H:%M:%S")
+            .unwrap_or_else(|| "Invalid".to_string());
+        let (label, style) = match entry.level {
+            LogLevel::Info => ("INFO ", theme.ok),
+            LogLevel::Warn => ("WARN ", theme.warn),
+            LogLevel::Error => ("ERROR", theme.error),
+        };
+        Line::from(vec![
+            Span::styled(format!("{} ", time), theme.muted),
+            Span::styled(format!("{} ", label), style),
+            Span::raw(entry.message.clone()),
+        ])
+    }).collect();
+
+    let logs = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL).title("📜 Logs"));
+
+    f.render_widget(logs, area);
+}
+
+/// Render block interval distribution as a bar chart
+fn render_interval_histogram(f: &mut Frame, area: Rect, histogram: Option<&IntervalHistogram>, theme: &Theme) {
+    let title = match histogram {
+        Some(h) => format!("⏱️ Intervals (last {} blocks, avg {:.0}s)", h.sample_count, h.average_seconds),
+        None => "⏱️ Intervals (loading...)".to_string(),
+    };
+
+    let data: Vec<(&str, u64)> = histogram
+        .map(|h| h.buckets.iter().map(|b| (b.label.as_str(), b.count as u64)).collect())
+        .unwrap_or_default();
+
+    let chart = BarChart::default()
+        .block(Block::default().borders(Borders::ALL).title(title))
+        .data(&data)
+        .bar_width(6)
+        .bar_gap(1)
+        .bar_style(theme.accent)
+        .value_style(theme.bar_value);
+
+    f.render_widget(chart, area);
+}
+
+/// Render chain event timeline (newest first)
+fn render_timeline(f: &mut Frame, area: Rect, events: &[ChainEvent], zone: &DisplayZone, theme: &Theme) {
+    let header_cells = ["Time", "Event", "Height", "Details"]
+        .iter()
+        .map(|h| Cell::from(*h).style(theme.heading));
+    let header = Row::new(header_cells).height(1).bottom_margin(1);
+
+    let rows = events.iter().map(|event| {
+        let time = zone.format(event.timestamp, "%m-%d %H:%M:%S")
+            .unwrap_or_else(|| "Invalid".to_string());
+
+        Row::new(vec![
+            Cell::from(time),
+            Cell::from(event.kind.label()).style(theme.chain_event(event.kind)),
+            Cell::from(event.height.to_string()),
+            Cell::from(event.description.clone()),
+        ])
+    });
+
+    let widths = [
+        Constraint::Length(16),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(Block::default().borders(Borders::ALL).title("🗓️ Chain Events"));
+
+    f.render_widget(table, area);
+}
+
+/// Render footer (search box while typing, else the last search result or key help)
+fn render_footer(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let theme = &app.config.theme;
+    // A long scan (hash search without the index) takes over the footer as a gauge
+    if let Some(event) = app.progress.current().filter(|_| app.progress.is_active() && app.search_input.is_none()) {
+        let mut label = match event.total {
+            Some(total) => format!("{}: {} / {}", event.operation, event.done, total),
+            None => format!("{}: {}", event.operation, event.done),
+        };
+        if app.search_task.is_some() {
+            label.push_str("  (Esc to cancel)");
+        }
+        let gauge = Gauge::default()
+            .block(Block::default().borders(Borders::ALL))
+            .gauge_style(theme.info)
+            .ratio(event.ratio().unwrap_or(0.0))
+            .label(label);
+        f.render_widget(gauge, area);
+        return;
+    }
+
+    let (text, style) = match (&app.search_input, &app.search_status) {
+        (Some(input), _) => (format!("Search (height / block hash / output / excess): {}_", input), theme.text),
+        (None, _) if app.detail_open() && app.search_task.is_none() => match &app.detail_status {
+            Some(status) => (format!("{}  (Esc to go back)", status), theme.info),
+            None => ("Esc to go back, 'c' to copy the hash, 'C' to copy JSON, 'e' to export JSON".to_string(), theme.muted),
+        },
+        (None, Some(status)) if app.search_task.is_some() => (format!("{}  (Esc to cancel)", status), theme.info),
+        (None, Some(status)) => (format!("{}  ('/' to search again)", status), theme.info),
+        (None, None) => ("Press 'q' to quit, 'r' to refresh, 1-5 or Tab to switch tabs, '/' to search".to_string(), theme.muted),
+    };
+    let footer = Paragraph::new(text)
+        .style(style)
         .block(Block::default().borders(Borders::ALL));
     
     f.render_widget(footer, area);
-}
\ No newline at end of file
+}
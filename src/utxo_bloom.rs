@@ -0,0 +1,165 @@
+// File: src/utxo_bloom.rs
+// Version: 1.0.0 - Bloom filter over output hashes and commitments
+// Tree: tari-lmdb-inspector/src/utxo_bloom.rs
+//
+// "Does this commitment exist?" has no index on most nodes, so answering "no" means scanning
+// the whole `utxos` table. A bloom filter over every output hash and commitment answers that
+// case from memory: a miss is definite, a hit still needs the real lookup. The filter lives in
+// the cache directory and is extended from the last block it covers, like the height → hash
+// map; a reorg below that block discards it.
+
+use std::path::Path;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::cache::{CacheDir, TipKey};
+use crate::lmdb_reader::{ReaderError, ReaderResult, StreamReader, TransactionOutputRowData};
+
+/// Bloom filter artifact file
+pub const UTXO_BLOOM_FILE: &str = "utxo_bloom.bin";
+
+/// Most heights added per refresh; a new filter catches up over several refreshes
+pub const MAX_BLOCKS_PER_REFRESH: u64 = 100_000;
+
+/// Smallest capacity a filter is sized for (keys, ~1.2 MB of bits)
+pub const MIN_CAPACITY: u64 = 1_000_000;
+
+/// Bits per key and probes per lookup for a ~1% false positive rate
+const BITS_PER_KEY: u64 = 10;
+const PROBES: u32 = 7;
+
+/// Answer to an output hash / commitment existence query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct OutputExistence {
+    pub exists: bool,
+    /// Answered by the bloom filter without touching the database (always a negative answer)
+    pub from_filter: bool,
+    /// Height of the block that mined the output, when found
+    pub height: Option<u64>,
+}
+
+/// Bloom filter over the output hashes and commitments of blocks `0..=covered_to`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UtxoBloom {
+    bits: Vec<u64>,
+    /// Keys the filter was sized for; past this the false positive rate climbs
+    capacity: u64,
+    /// Keys inserted (two per output)
+    keys: u64,
+    /// Next height to add
+    next_height: u64,
+}
+
+impl UtxoBloom {
+    /// Empty filter sized for `capacity` keys
+    pub fn with_capacity(capacity: u64) -> Self {
+        let capacity = capacity.max(MIN_CAPACITY);
+        Self {
+            bits: vec![0; (capacity * BITS_PER_KEY).div_ceil(64) as usize],
+            capacity,
+            keys: 0,
+            next_height: 0,
+        }
+    }
+
+    /// Highest height whose outputs are in the filter
+    pub fn covered_to(&self) -> Option<u64> {
+        self.next_height.checked_sub(1)
+    }
+
+    /// Keys inserted so far
+    pub fn keys(&self) -> u64 {
+        self.keys
+    }
+
+    /// More keys than the filter was sized for; it is rebuilt larger on the next refresh
+    pub fn is_saturated(&self) -> bool {
+        self.keys > self.capacity
+    }
+
+    pub fn insert(&mut self, key: &[u8]) {
+        let len = self.bits.len() as u64 * 64;
+        if len == 0 {
+            return;
+        }
+        for bit in probes(key, len) {
+            self.bits[(bit / 64) as usize] |= 1 << (bit % 64);
+        }
+        self.keys += 1;
+    }
+
+    /// `false` only if `key` was never inserted; an empty (unbuilt) filter answers `true`
+    pub fn might_contain(&self, key: &[u8]) -> bool {
+        let len = self.bits.len() as u64 * 64;
+        len == 0 || probes(key, len).all(|bit| self.bits[(bit / 64) as usize] & (1 << (bit % 64)) != 0)
+    }
+
+    /// Whether `key` is definitely not an output hash or commitment in a chain with tip `tip`;
+    /// only a filter that covers the tip can say so
+    pub fn definitely_absent(&self, key: &[u8], tip: u64) -> bool {
+        self.covered_to().is_some_and(|covered| covered >= tip) && !self.might_contain(key)
+    }
+
+    /// Add the outputs of heights from `next_height` towards the tip, at most
+    /// `MAX_BLOCKS_PER_REFRESH` of them; returns the key of the last decodable block covered
+    fn extend(&mut self, db_path: &Path) -> ReaderResult<Option<TipKey>> {
+        let reader = StreamReader::open(db_path)?;
+        let snapshot = reader.snapshot()?;
+        let Some(tip) = snapshot.tip_height()? else {
+            return Ok(None);
+        };
+        let start = self.next_height;
+        let end = tip.min(start + MAX_BLOCKS_PER_REFRESH - 1);
+        let mut last = None;
+        if start <= end {
+            for entry in snapshot.headers(start, end)? {
+                match entry {
+                    Ok((height, header)) => {
+                        let block_hash = snapshot.block_hash(height, &header);
+                        for row in snapshot.outputs::<TransactionOutputRowData>(&block_hash)? {
+                            let row = row?;
+                            self.insert(row.hash.as_slice());
+                            self.insert(row.output.commitment.as_bytes());
+                        }
+                        last = Some(TipKey { height, hash: hex::encode(&block_hash) });
+                    }
+                    // Skip blocks mined with C29 Cuckaroo 29 algorithm (variant 3)
+                    Err(ReaderError::UnsupportedHeader { .. }) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            self.next_height = end + 1;
+        }
+        Ok(last)
+    }
+}
+
+/// Probe positions for `key` in a filter of `len` bits (double hashing over blake3)
+fn probes(key: &[u8], len: u64) -> impl Iterator<Item = u64> {
+    let digest = blake3::hash(key);
+    let bytes = digest.as_bytes();
+    let h1 = u64::from_le_bytes(bytes[0..8].try_into().expect("8 bytes"));
+    let h2 = u64::from_le_bytes(bytes[8..16].try_into().expect("8 bytes")) | 1;
+    (0..PROBES as u64).map(move |i| h1.wrapping_add(i.wrapping_mul(h2)) % len)
+}
+
+/// Load the filter from `cache`, extend it from the database and store it back. A missing,
+/// stale or saturated filter is rebuilt with room for twice the outputs now in `utxos`.
+pub fn refresh_utxo_bloom(db_path: &Path, cache: &CacheDir) -> Result<UtxoBloom> {
+    let loaded = cache.load::<UtxoBloom>(UTXO_BLOOM_FILE, db_path)
+        .map(|(_, bloom)| bloom)
+        .filter(|bloom| !bloom.is_saturated());
+    let mut bloom = match loaded {
+        Some(bloom) => bloom,
+        None => {
+            let reader = StreamReader::open(db_path)?;
+            let outputs = reader.snapshot()?.output_count().unwrap_or(0);
+            UtxoBloom::with_capacity(outputs * 4)
+        }
+    };
+    if let Some(tip) = bloom.extend(db_path)? {
+        cache.store(UTXO_BLOOM_FILE, &tip, &bloom)?;
+    }
+    Ok(bloom)
+}
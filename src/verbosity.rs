@@ -0,0 +1,56 @@
+// File: src/verbosity.rs
+// Version: 1.0.0 - Output verbosity for human-readable logs (--verbosity, -q, -v)
+// Tree: tari-lmdb-inspector/src/verbosity.rs
+//
+// Reader functions run on every block fetch in web mode and inside library consumers, where
+// their table availability and header analysis prints are noise. Human-readable logs are gated
+// by level: `quiet` keeps errors and warnings only, `normal` adds startup and status notes, and
+// `debug` adds the per-request and per-block diagnostics. `AppConfig::verbosity` carries the
+// setting; `apply` makes it the process-wide level that reader functions, which only get a
+// path, consult. Command output (tables, reports, JSON) is never gated.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+/// Process-wide level, `normal` until a config is applied
+static LEVEL: AtomicU8 = AtomicU8::new(Verbosity::Normal as u8);
+
+/// How much human-readable logging is printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verbosity {
+    /// Errors and warnings only
+    Quiet,
+    /// Startup and status notes
+    #[default]
+    Normal,
+    /// Per-request and per-block diagnostics too
+    Debug,
+}
+
+impl Verbosity {
+    /// Make this the process-wide level
+    pub fn apply(self) {
+        LEVEL.store(self as u8, Ordering::Relaxed);
+    }
+
+    /// The process-wide level
+    pub fn current() -> Self {
+        match LEVEL.load(Ordering::Relaxed) {
+            0 => Self::Quiet,
+            1 => Self::Normal,
+            _ => Self::Debug,
+        }
+    }
+}
+
+/// Whether startup and status notes are printed
+pub fn normal() -> bool {
+    Verbosity::current() >= Verbosity::Normal
+}
+
+/// Whether per-request and per-block diagnostics are printed
+pub fn debug() -> bool {
+    Verbosity::current() >= Verbosity::Debug
+}
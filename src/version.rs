@@ -0,0 +1,148 @@
+// File: src/version.rs
+// Version: 1.0.0 - Build and schema version for /api/version, GitHub release check
+// Tree: tari-lmdb-inspector/src/version.rs
+//
+// A bug report against the web API needs three things to be reproducible: the inspector build,
+// the Tari types its decoders were compiled against, and the database schema the node wrote.
+// `VersionInfo` carries all three and is served at `/api/v1/version`; `build.rs` supplies the
+// git commit and the Tari revision from `Cargo.lock`.
+//
+// `web` also asks GitHub for the latest release once at startup and prints a note when it is
+// newer than this build (`--no-update-check` turns that off). The check runs in the background
+// and never delays or fails startup.
+
+use std::time::Duration;
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::verbosity;
+
+/// Version of this build (`Cargo.toml`)
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Git commit this build was made from, if built from a checkout
+pub const GIT_HASH: Option<&str> = option_env!("INSPECTOR_GIT_HASH");
+
+/// `tari_core` version the header and transaction decoders are built against
+pub const TARI_VERSION: Option<&str> = option_env!("INSPECTOR_TARI_VERSION");
+
+/// Tari repository commit of the linked `tari_core`
+pub const TARI_REVISION: Option<&str> = option_env!("INSPECTOR_TARI_REVISION");
+
+/// Latest release of the inspector on GitHub
+pub const RELEASES_URL: &str = "https://api.github.com/repos/OIEIEIO/tari-lmdb-inspector/releases/latest";
+
+/// Time the startup release check may take before it is given up
+const UPDATE_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Which database layout this build reads and what the served database reports
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct SchemaProfile {
+    /// `tari_core` version of the decoders
+    pub tari_version: Option<String>,
+    /// Tari repository commit of the decoders
+    pub tari_revision: Option<String>,
+    /// Network the database is read as (`--network`)
+    pub network: String,
+    /// Schema version the base node's migrations wrote (metadata table), if present
+    pub migration_version: Option<u64>,
+}
+
+/// Body of `/api/v1/version`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct VersionInfo {
+    /// Crate version of the inspector
+    pub version: String,
+    /// Git commit of the inspector build
+    pub git_hash: Option<String>,
+    pub schema: SchemaProfile,
+}
+
+impl VersionInfo {
+    /// Version of this build serving a database on `network` with `migration_version`
+    pub fn new(network: String, migration_version: Option<u64>) -> Self {
+        Self {
+            version: VERSION.to_string(),
+            git_hash: GIT_HASH.map(str::to_string),
+            schema: SchemaProfile {
+                tari_version: TARI_VERSION.map(str::to_string),
+                tari_revision: TARI_REVISION.map(str::to_string),
+                network,
+                migration_version,
+            },
+        }
+    }
+}
+
+/// The parts of a GitHub release the update check uses
+#[derive(Debug, Clone, Deserialize)]
+pub struct Release {
+    pub tag_name: String,
+    pub html_url: String,
+}
+
+/// Ask GitHub for the latest inspector release
+pub async fn latest_release() -> Result<Release> {
+    let client = reqwest::Client::builder()
+        .timeout(UPDATE_CHECK_TIMEOUT)
+        // GitHub rejects API requests without a user agent
+        .user_agent(concat!("tari-lmdb-inspector/", env!("CARGO_PKG_VERSION")))
+        .build()?;
+    let release = client.get(RELEASES_URL)
+        .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    Ok(release)
+}
+
+/// Numeric `major.minor.patch` (missing parts 0) and whether there is a pre-release suffix
+fn parse_version(version: &str) -> Option<([u64; 3], bool)> {
+    let version = version.trim().trim_start_matches('v');
+    let (core, pre_release) = match version.split_once('-') {
+        Some((core, _)) => (core, true),
+        None => (version, false),
+    };
+    let mut parts = [0; 3];
+    for (i, part) in core.split('.').enumerate() {
+        *parts.get_mut(i)? = part.parse().ok()?;
+    }
+    Some((parts, pre_release))
+}
+
+/// Whether release tag `candidate` (e.g. `v0.4.0`) is newer than version `current`
+/// Tags that do not parse as a version are never newer
+pub fn is_newer(candidate: &str, current: &str) -> bool {
+    match (parse_version(candidate), parse_version(current)) {
+        (Some((candidate, candidate_pre)), Some((current, current_pre))) => {
+            candidate > current || (candidate == current && current_pre && !candidate_pre)
+        }
+        _ => false,
+    }
+}
+
+/// Check for a newer release in the background and print a note if there is one
+/// Failures (offline, rate limited) are only reported with --verbosity debug
+pub fn spawn_update_check() {
+    tokio::spawn(async {
+        match latest_release().await {
+            Ok(release) if is_newer(&release.tag_name, VERSION) => {
+                println!("🆕 Tari LMDB Inspector {} is available (running {}): {}", release.tag_name, VERSION, release.html_url);
+            }
+            Ok(_) => {
+                if verbosity::debug() {
+                    println!("✅ Tari LMDB Inspector {} is the latest release", VERSION);
+                }
+            }
+            Err(e) => {
+                if verbosity::debug() {
+                    println!("⚠️  Update check failed: {}", e);
+                }
+            }
+        }
+    });
+}
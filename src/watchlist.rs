@@ -0,0 +1,158 @@
+// File: src/watchlist.rs
+// Version: 1.0.0 - Commitment / kernel excess watchlist checked against new blocks
+// Tree: tari-lmdb-inspector/src/watchlist.rs
+//
+// Watched items come from a TOML file (`--watchlist`) and from `POST /api/v1/watchlist`. Web
+// mode checks every block above the last checked height when the database changes, then
+// pushes a `WatchHit` to WebSocket clients and the webhook. Items added over the API live in
+// memory only.
+//
+//   [[watch]]
+//   kind = "commitment"
+//   value = "0a1b..."
+//   label = "exchange deposit"
+
+use std::path::Path;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::lmdb_reader::BlockDetailSummary;
+
+/// Most blocks checked after one database change; older gaps are skipped
+pub const MAX_BLOCKS_PER_CHECK: u64 = 50;
+
+/// What a watched value is compared against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchKind {
+    /// Output commitment, matched against outputs and the inputs spending them
+    Commitment,
+    /// Kernel excess
+    Excess,
+}
+
+/// One watched commitment or excess
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct WatchItem {
+    pub kind: WatchKind,
+    /// 64 hex characters
+    pub value: String,
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+impl WatchItem {
+    /// Lowercase the value and check it is 32 bytes of hex
+    pub fn normalized(mut self) -> Option<Self> {
+        self.value = self.value.trim().trim_start_matches("0x").to_ascii_lowercase();
+        (self.value.len() == 64 && self.value.chars().all(|c| c.is_ascii_hexdigit())).then_some(self)
+    }
+}
+
+/// Where in a block a watched item appeared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WatchLocation {
+    Output,
+    Input,
+    Kernel,
+}
+
+/// A watched item found in a block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct WatchHit {
+    pub item: WatchItem,
+    pub location: WatchLocation,
+    pub height: u64,
+    pub block_hash: String,
+}
+
+/// TOML layout of the `--watchlist` file
+#[derive(Debug, Default, Deserialize)]
+struct WatchlistFile {
+    #[serde(default)]
+    watch: Vec<WatchItem>,
+}
+
+/// Watched items and the last height checked against them
+#[derive(Debug, Default)]
+pub struct Watchlist {
+    items: Vec<WatchItem>,
+    last_checked: Option<u64>,
+}
+
+impl Watchlist {
+    pub fn new(items: Vec<WatchItem>) -> Self {
+        let mut watchlist = Self::default();
+        for item in items {
+            watchlist.add(item);
+        }
+        watchlist
+    }
+
+    /// Read `[[watch]]` entries from a TOML file; invalid values are rejected
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read watchlist {}", path.display()))?;
+        let file: WatchlistFile = toml::from_str(&text)
+            .with_context(|| format!("Invalid watchlist {}", path.display()))?;
+        let mut items = Vec::new();
+        for item in file.watch {
+            let value = item.value.clone();
+            items.push(item.normalized().with_context(|| format!("Watchlist value {} is not 64 hex characters", value))?);
+        }
+        Ok(Self::new(items))
+    }
+
+    pub fn items(&self) -> &[WatchItem] {
+        &self.items
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Add an item; returns `false` if it is invalid or already watched
+    pub fn add(&mut self, item: WatchItem) -> bool {
+        match item.normalized() {
+            Some(item) if !self.items.iter().any(|i| i.kind == item.kind && i.value == item.value) => {
+                self.items.push(item);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Heights to check for a new `tip`: everything above the last checked height, at most
+    /// `MAX_BLOCKS_PER_CHECK`. The first call only records the tip.
+    pub fn pending_heights(&mut self, tip: u64) -> std::ops::RangeInclusive<u64> {
+        let start = match self.last_checked {
+            Some(last) => (last + 1).max(tip.saturating_sub(MAX_BLOCKS_PER_CHECK - 1)),
+            None => tip + 1,
+        };
+        self.last_checked = Some(tip.max(self.last_checked.unwrap_or(0)));
+        start..=tip
+    }
+
+    /// Watched items appearing in `block`
+    pub fn check_block(&self, block: &BlockDetailSummary) -> Vec<WatchHit> {
+        let transactions = &block.transactions;
+        let candidates = transactions.outputs.iter().map(|o| (WatchKind::Commitment, WatchLocation::Output, &o.commitment))
+            .chain(transactions.inputs.iter().map(|i| (WatchKind::Commitment, WatchLocation::Input, &i.commitment)))
+            .chain(transactions.kernels.iter().map(|k| (WatchKind::Excess, WatchLocation::Kernel, &k.excess)));
+
+        let mut hits = Vec::new();
+        for (kind, location, value) in candidates {
+            for item in self.items.iter().filter(|item| item.kind == kind && item.value == *value) {
+                hits.push(WatchHit {
+                    item: item.clone(),
+                    location,
+                    height: block.height,
+                    block_hash: block.hash.clone(),
+                });
+            }
+        }
+        hits
+    }
+}
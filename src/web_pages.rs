@@ -0,0 +1,116 @@
+// File: src/web_pages.rs
+// Version: 1.1.1 - Error pages use the status ReaderError maps to
+// Tree: tari-lmdb-inspector/src/web_pages.rs
+//
+// `/block/:height` and `/search?q=` are rendered from minijinja templates compiled into the
+// binary. They read through the same `DataProvider` as the JSON API, so they also work in
+// demo mode. Amounts go through the `amount` filter (`{{ kernel.fee | amount(denom) }}`) so pages
+// follow `--denom` like the CLI and TUI.
+
+use std::sync::OnceLock;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
+    routing::{get, Router},
+};
+use minijinja::value::ViaDeserialize;
+use minijinja::{context, Environment};
+use serde::Deserialize;
+
+use crate::denomination::Denomination;
+use crate::lmdb_reader::DataCompleteness;
+use crate::search::{SearchQuery, SearchResult};
+use crate::web_server::AppState;
+
+/// Query parameters for the search page
+#[derive(Deserialize)]
+struct SearchPageQuery {
+    #[serde(default)]
+    q: String,
+}
+
+/// Page routes; mounted with the other LMDB-reading routes
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/block/:height", get(block_page))
+        .route("/search", get(search_page))
+}
+
+/// Templates are parsed once on first use
+fn templates() -> &'static Environment<'static> {
+    static TEMPLATES: OnceLock<Environment<'static>> = OnceLock::new();
+    TEMPLATES.get_or_init(|| {
+        let mut env = Environment::new();
+        env.add_filter("amount", |micro_tari: u64, denom: ViaDeserialize<Denomination>| denom.amount(micro_tari));
+        env.add_template("layout.html", include_str!("templates/layout.html")).expect("layout template parses");
+        env.add_template("block.html", include_str!("templates/block.html")).expect("block template parses");
+        env.add_template("search.html", include_str!("templates/search.html")).expect("search template parses");
+        env
+    })
+}
+
+/// Render a template, mapping template errors to a plain 500
+fn render(status: StatusCode, name: &str, ctx: minijinja::Value) -> Response {
+    match templates().get_template(name).and_then(|template| template.render(ctx)) {
+        Ok(html) => (status, Html(html)).into_response(),
+        Err(e) => {
+            eprintln!("❌ Template {} failed: {}", name, e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// Search page showing only a message
+fn message_page(status: StatusCode, query: &str, message: String) -> Response {
+    render(status, "search.html", context! { query, message })
+}
+
+/// Full block detail page
+async fn block_page(Path(height): Path<u64>, State(state): State<AppState>) -> Response {
+    match state.provider.block(height).await {
+        Ok(block) => {
+            let timestamp = state.config.timezone.datetime(block.header.timestamp);
+            let completeness_note = match block.data_completeness {
+                DataCompleteness::Full => None,
+                ref completeness => Some(completeness.label()),
+            };
+            let (denom, unit) = (state.config.denom, state.config.denom.unit());
+            render(StatusCode::OK, "block.html", context! { block, timestamp, completeness_note, denom, unit })
+        }
+        Err(e) if e.is_not_found() => {
+            message_page(StatusCode::NOT_FOUND, &height.to_string(), format!("Block {} not found", height))
+        }
+        Err(e) if e.status() == StatusCode::INTERNAL_SERVER_ERROR => {
+            eprintln!("❌ Block page {} read error: {}", height, e);
+            message_page(StatusCode::INTERNAL_SERVER_ERROR, &height.to_string(), format!("Could not read block {}", height))
+        }
+        // Pruned (410), undecodable header (409) or busy (503): the reason is worth showing
+        Err(e) => message_page(e.status(), &height.to_string(), e.to_string()),
+    }
+}
+
+/// Search page; block matches redirect straight to the block page
+async fn search_page(Query(params): Query<SearchPageQuery>, State(state): State<AppState>) -> Response {
+    let query = params.q.trim();
+    if query.is_empty() {
+        return render(StatusCode::OK, "search.html", context! {});
+    }
+    let Some(parsed) = SearchQuery::parse(query) else {
+        return message_page(StatusCode::BAD_REQUEST, query, "Enter a height or a 64-character hex hash".to_string());
+    };
+
+    match state.provider.search(parsed).await {
+        Ok(SearchResult::Block { height, .. }) => Redirect::to(&format!("/block/{}", height)).into_response(),
+        Ok(SearchResult::NotFound) => message_page(StatusCode::NOT_FOUND, query, SearchResult::NotFound.describe(state.config.denom)),
+        Ok(result) => {
+            let description = result.describe(state.config.denom);
+            let (denom, unit) = (state.config.denom, state.config.denom.unit());
+            render(StatusCode::OK, "search.html", context! { query, result, description, denom, unit })
+        }
+        Err(e) => {
+            eprintln!("❌ Search page error: {}", e);
+            message_page(e.status(), query, format!("Search failed: {}", e))
+        }
+    }
+}
@@ -1,31 +1,345 @@
 // File: src/web_server.rs
-// Version: 2.22.0 - Added blockchain-wide hash searching
+// Version: 2.56.2 - Request spans log the URI with ?token= redacted
 
-use anyhow::Result;
+use anyhow::{Context, Result};
+use axum_server::tls_rustls::RustlsConfig;
 use axum::{
-    extract::{ws::WebSocket, ws::Message, WebSocketUpgrade, State, Query},
-    http::StatusCode,
-    response::{Html, IntoResponse},
-    routing::{get, Router},
+    extract::{ws::WebSocket, ws::Message, ConnectInfo, FromRequestParts, WebSocketUpgrade, State, Query},
+    http::{header, request::Parts, StatusCode},
+    middleware,
+    response::{Html, IntoResponse, Response},
+    routing::{get, post, Router},
     Json,
 };
 use futures::{sink::SinkExt, stream::StreamExt};
 use serde_json;
-use serde::{Deserialize};
-use std::{net::SocketAddr, sync::Arc, time::Duration};
-use tokio::sync::{RwLock, broadcast};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, OpenApi, ToSchema};
+use utoipa_swagger_ui::SwaggerUi;
+use std::{future::IntoFuture, net::SocketAddr, path::PathBuf, sync::Arc};
+use tokio::sync::{RwLock, Mutex, broadcast};
 use tower::ServiceBuilder;
 use tower_http::cors::CorsLayer;
-use notify::{Watcher, RecursiveMode, Event};
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
 
-use crate::data_models::{AppConfig, DashboardData, DatabaseStats, WebSocketMessage};
-use crate::lmdb_reader::{read_lmdb_headers_with_filter, read_block_with_transactions, search_block_by_hash, BlockFilter};
+use crate::data_models::{
+    AppConfig, BlockBatchMiss, BlockBatchRequest, BlockBatchResponse, BlockDetailResponse, BlockHeaderResponse,
+    BlockInfo, BlockRangeResponse, BlockTransactionsPage, BlockTransactionsResponse, DashboardData, DatabaseStats, EventsHistoryResponse, NetworkStats, RangeBlock, WebSocketMessage,
+};
+use crate::lmdb_reader::{BlockCounts, BlockFilter, BlockInclude, DataCompleteness, InputSummary, KernelKind, KernelSummary, OutputFeaturesLite, OutputKind, OutputSummary, Page, ReaderError, ScriptPattern, ScriptSummary, SpendStatus, TxComponent};
+use crate::lmdb_reader::r#async;
+use crate::lmdb_reader::metadata::{ChainMetadata, PruningInfo};
+use crate::search::{SearchQuery, SearchResult};
+use crate::web_pages;
+use crate::static_assets;
+use crate::data_provider::{self, DataProvider};
+use crate::chain_events::{self, ChainEvent, ChainEventKind};
+use crate::analytics::csv::{self as analytics_csv, CsvTable};
+use crate::analytics::fees::{self, BlockFeeStats, FeeAnalytics, FeeSummary};
+use crate::analytics::intervals::{self, IntervalBucket, IntervalHistogram};
+use crate::analytics::utxo_growth::{self, UtxoGrowth, UtxoSample};
+use crate::analytics::coinbase::{self, LockedCoinbase, LockedCoinbaseReport};
+use crate::analytics::burns::{self, BlockBurns, BurnAnalytics, BurnKernel, BurnedOutput};
+use crate::analytics::state_at::{self, ChainStateAt};
+use crate::analytics::pow_share::{self, PowShare, PowSharePoint};
+use crate::analytics::top_blocks::{self, AggregateIndex, BlockAggregate, TopBlocks, TopBy};
+use crate::analytics::tx_volume::{self, TxVolume, TxVolumeCache, TxVolumePoint};
+use crate::audit::timestamps::{self, AnomalyKind, TimestampAnomalies, TimestampAnomaly};
+use crate::audit::follow::{AuditStatus, SharedAuditStatus};
+use crate::notify::{Notifier, NotifyEvent};
+use crate::watchlist::{WatchHit, WatchItem, WatchKind, WatchLocation, Watchlist};
+use crate::block_cache::{self, BlockCache};
+use crate::cache::{self, CacheDir, HeightHashes};
+use crate::file_watcher::LmdbWatcher;
+use crate::utxo_bloom::{self, OutputExistence, UtxoBloom};
+use crate::rate_limit::{self, RateLimitConfig, RateLimiter};
+use crate::limits::{LimitExceeded, LimitKind};
+use crate::auth::{self, AuthToken};
+use crate::progress::{Progress, ProgressEvent};
+use crate::sync_status::SyncStatus;
+use crate::dashboard_engine::DashboardEngine;
+use tokio_util::sync::CancellationToken;
+use crate::ws_clients::{self, ClientRegistry};
+use crate::request_trace::{self, RequestMetrics};
+use crate::block_stream::BlockStream;
+use crate::verbosity;
+use crate::version::{SchemaProfile, VersionInfo};
+use crate::shutdown;
 
 /// Query parameters for range search
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
 struct RangeQuery {
     start: u64,
     end: u64,
+    /// Optional PoW algorithm filter (case-insensitive)
+    pow: Option<String>,
+    /// Optional minimum kernel count filter
+    min_kernels: Option<u64>,
+}
+
+/// Query parameters for chain event history
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct EventsQuery {
+    limit: Option<usize>,
+}
+
+/// Query parameters for the unified search
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct SearchParams {
+    /// Height, or 64 hex characters (block hash, output hash/commitment, kernel excess)
+    q: String,
+}
+
+/// Query parameters for block detail endpoints
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct BlockDetailQuery {
+    /// Only return kernels of this kind (plain, coinbase, burn)
+    kernels: Option<KernelKind>,
+    /// Comma-separated parts to return besides the header: transactions (default), counts
+    include: Option<String>,
+}
+
+impl BlockDetailQuery {
+    /// Parts named by `include`; `None` when it names an unknown part
+    fn include(&self) -> Option<BlockInclude> {
+        match &self.include {
+            Some(list) => BlockInclude::parse(list),
+            None => Some(BlockInclude::default()),
+        }
+    }
+
+    /// Trim a response to the requested parts and kernel kind
+    fn apply(&self, mut response: BlockDetailResponse) -> BlockDetailResponse {
+        let include = self.include().unwrap_or_default();
+        if !include.counts {
+            response.counts = None;
+        } else if response.counts.is_none() {
+            response.counts = response.transactions.as_ref().map(|transactions| BlockCounts {
+                inputs: transactions.inputs.len() as u64,
+                outputs: transactions.outputs.len() as u64,
+                kernels: transactions.kernels.len() as u64,
+            });
+        }
+        if !include.transactions {
+            response.transactions = None;
+        }
+        if let (Some(kind), Some(transactions)) = (self.kernels, response.transactions.as_mut()) {
+            transactions.kernels.retain(|kernel| kernel.kind == kind);
+        }
+        response
+    }
+}
+
+/// Query parameters for paged block transactions
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct PageQuery {
+    /// Rows to skip (default 0)
+    offset: Option<usize>,
+    /// Rows returned (default 50, max 500)
+    limit: Option<usize>,
+}
+
+/// Query parameter selecting the analytics response encoding
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct FormatQuery {
+    /// `csv` for a CSV download of the chart series (also `Accept: text/csv` or a `.csv` path)
+    format: Option<String>,
+}
+
+/// Encoding of an analytics response: CSV for a `.csv` path, `?format=csv` or `Accept: text/csv`,
+/// JSON otherwise
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResponseFormat {
+    Json,
+    Csv,
+}
+
+#[axum::async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ResponseFormat {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let csv_path = parts.uri.path().ends_with(".csv");
+        let csv_query = Query::<FormatQuery>::try_from_uri(&parts.uri)
+            .is_ok_and(|Query(query)| query.format.is_some_and(|format| format.eq_ignore_ascii_case("csv")));
+        let csv_accept = parts.headers.get(header::ACCEPT)
+            .and_then(|accept| accept.to_str().ok())
+            .is_some_and(|accept| accept.split(',').any(|media| media.trim().starts_with("text/csv")));
+        Ok(if csv_path || csv_query || csv_accept { Self::Csv } else { Self::Json })
+    }
+}
+
+impl ResponseFormat {
+    /// `value` as JSON, or its chart series as a `<name>.csv` download
+    fn respond<T: Serialize + CsvTable>(self, name: &str, value: T) -> Response {
+        match self {
+            Self::Json => Json(value).into_response(),
+            Self::Csv => (
+                [
+                    (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                    (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{}.csv\"", name)),
+                ],
+                analytics_csv::to_csv(&value),
+            ).into_response(),
+        }
+    }
+}
+
+/// Query parameters for analytics windows
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct WindowQuery {
+    window: Option<u64>,
+}
+
+/// Query parameters for the PoW share series
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct PowShareQuery {
+    /// Rolling window in blocks (default 720)
+    window: Option<u64>,
+    /// Heights charted, ending at the tip (default 2880)
+    span: Option<u64>,
+}
+
+/// Query parameters for the top blocks leaderboard
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct TopBlocksQuery {
+    /// Ranking: kernels (default), outputs or fees
+    by: Option<TopBy>,
+    /// Blocks returned (default 20, max 500)
+    limit: Option<usize>,
+}
+
+/// Query parameters for the transaction volume series
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct TxVolumeQuery {
+    /// First height (default 0)
+    from: Option<u64>,
+    /// Last height (default: highest cached)
+    to: Option<u64>,
+    /// Heights per point; widened to return at most 1000 points
+    bucket: Option<u64>,
+}
+
+/// Query parameters for the UTXO growth series
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct GrowthQuery {
+    samples: Option<u64>,
+    step: Option<u64>,
+}
+
+/// PEM certificate chain and private key for serving HTTPS/WSS directly
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+}
+
+/// Name of the node given with --database
+pub const PRIMARY_NODE: &str = "default";
+
+/// Another base node served by the same web instance (`web --node NAME=PATH`)
+#[derive(Debug, Clone)]
+pub struct NodeProfile {
+    pub name: String,
+    pub config: AppConfig,
+}
+
+impl NodeProfile {
+    /// Parse `NAME=PATH`. The node shares `base`'s settings but gets its own cache
+    /// subdirectory, and sends no webhooks or watchlist alerts (those cover --database only).
+    pub fn parse(spec: &str, base: &AppConfig) -> Result<Self> {
+        let (name, path) = spec.split_once('=')
+            .with_context(|| format!("Node '{}' must be NAME=PATH", spec))?;
+        let valid_name = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid_name || name == PRIMARY_NODE {
+            anyhow::bail!("Node name '{}' must be letters, digits, '-' or '_' and not '{}'", name, PRIMARY_NODE);
+        }
+        let database_path = PathBuf::from(path);
+        if !database_path.exists() {
+            anyhow::bail!("Database path for node '{}' does not exist: {:?}", name, database_path);
+        }
+        Ok(Self {
+            name: name.to_string(),
+            config: AppConfig {
+                database_path,
+                notify: None,
+                watchlist_path: None,
+                cache: CacheDir::new(base.cache.dir().join("nodes").join(name)),
+                ..base.clone()
+            },
+        })
+    }
+}
+
+/// A node selectable in the dashboard
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct NodeLink {
+    pub name: String,
+    /// Prefix of this node's REST API
+    pub api_base: String,
+    /// Path of this node's WebSocket
+    pub ws_path: String,
+}
+
+impl NodeLink {
+    fn primary() -> Self {
+        Self { name: PRIMARY_NODE.to_string(), api_base: "/api/v1".to_string(), ws_path: "/ws".to_string() }
+    }
+
+    fn named(name: &str) -> Self {
+        Self { name: name.to_string(), api_base: format!("/node/{}/api/v1", name), ws_path: format!("/node/{}/ws", name) }
+    }
+}
+
+/// Forwards long scan progress to every WebSocket client
+/// Concurrent scans share one stream; each event names its operation
+struct BroadcastProgress {
+    sender: broadcast::Sender<ProgressEvent>,
+    current: std::sync::Mutex<ProgressEvent>,
+}
+
+impl BroadcastProgress {
+    fn new(sender: broadcast::Sender<ProgressEvent>) -> Self {
+        Self { sender, current: std::sync::Mutex::new(ProgressEvent::default()) }
+    }
+
+    fn publish(&self, change: impl FnOnce(&mut ProgressEvent)) {
+        let mut current = self.current.lock().unwrap_or_else(|e| e.into_inner());
+        change(&mut current);
+        // No subscribers just means no dashboard is open
+        let _ = self.sender.send(current.clone());
+    }
+}
+
+impl Progress for BroadcastProgress {
+    fn begin(&self, operation: &str, total: Option<u64>) {
+        self.publish(|event| {
+            *event = ProgressEvent { operation: operation.to_string(), total, ..ProgressEvent::default() };
+        });
+    }
+
+    fn update(&self, done: u64) {
+        self.publish(|event| event.done = done);
+    }
+
+    fn finish(&self, message: &str) {
+        self.publish(|event| {
+            event.finished = true;
+            event.message = Some(message.to_string());
+        });
+    }
 }
 
 /// Shared application state
@@ -34,35 +348,156 @@ pub struct AppState {
     pub config: AppConfig,
     pub dashboard_data: Arc<RwLock<DashboardData>>,
     pub update_broadcaster: broadcast::Sender<DashboardData>,
+    pub progress_broadcaster: broadcast::Sender<ProgressEvent>,
+    pub notifier: Option<Arc<Mutex<Notifier>>>,
+    pub watchlist: Arc<Mutex<Watchlist>>,
+    pub watch_broadcaster: broadcast::Sender<WatchHit>,
+    /// Per-block aggregates behind the top blocks leaderboard
+    pub block_index: Arc<RwLock<AggregateIndex>>,
+    /// Per-height kernel counts, refreshed by the stats worker and persisted to a sidecar file
+    pub tx_volume: Arc<RwLock<TxVolumeCache>>,
+    /// Height → hash map from the cache directory, answering hash lookups without a scan
+    pub height_hashes: Arc<RwLock<HeightHashes>>,
+    /// Bloom filter over output hashes and commitments, answering negative lookups without a scan
+    pub utxo_bloom: Arc<RwLock<UtxoBloom>>,
+    pub block_cache: Arc<Mutex<BlockCache>>,
+    pub provider: Arc<dyn DataProvider>,
+    pub ws_clients: ClientRegistry,
+    /// Per-route request counts, latency and LMDB read time for /metrics
+    pub requests: RequestMetrics,
+    /// Builds dashboard snapshots (shared with TUI mode) and watches LMDB for changes
+    pub engine: Arc<DashboardEngine>,
+    /// Every node served by this instance
+    pub nodes: Arc<Vec<NodeLink>>,
+    /// Cancelled on SIGINT / SIGTERM; closes WebSockets and stops LMDB scans
+    pub shutdown: CancellationToken,
 }
 
+/// OpenAPI description of the v1 REST API, served at `/api/v1/openapi.json`
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Tari LMDB Inspector API", version = "1", description = "Read-only access to a Tari base node LMDB database. Unversioned `/api/*` paths are deprecated aliases of `/api/v1/*`."),
+    paths(
+        get_dashboard_data,
+        get_block_detail,
+        get_block_by_hash,
+        get_block_inputs,
+        get_block_outputs,
+        get_block_kernels,
+        get_blocks_range,
+        get_blocks_batch,
+        get_fee_analytics,
+        get_interval_analytics,
+        get_utxo_growth,
+        get_locked_coinbase,
+        get_burn_analytics,
+        get_timestamp_anomalies,
+        get_pow_share,
+        get_top_blocks,
+        get_nodes,
+        get_version,
+        get_tx_volume,
+        get_state_at_height,
+        get_chain_metadata,
+        get_search,
+        get_output_exists,
+        get_events_history,
+        get_watchlist,
+        add_watch_item,
+    ),
+    components(schemas(
+        DashboardData, DatabaseStats, BlockInfo, NetworkStats, DataCompleteness, SyncStatus,
+        BlockDetailResponse, BlockHeaderResponse, BlockTransactionsResponse, BlockTransactionsPage, TxComponent, BlockCounts, InputSummary, OutputSummary, OutputFeaturesLite, ScriptSummary, ScriptPattern, SpendStatus, OutputKind, KernelSummary, KernelKind,
+        BlockRangeResponse, RangeBlock, BlockBatchRequest, BlockBatchResponse, BlockBatchMiss, LimitExceeded, LimitKind,
+        EventsHistoryResponse, ChainEvent, ChainEventKind,
+        ChainMetadata, PruningInfo, SearchResult, OutputExistence,
+        FeeAnalytics, BlockFeeStats, FeeSummary, IntervalHistogram, IntervalBucket,
+        UtxoGrowth, UtxoSample, LockedCoinbaseReport, LockedCoinbase,
+        BurnAnalytics, BlockBurns, BurnedOutput, BurnKernel, TimestampAnomalies, TimestampAnomaly, AnomalyKind, ChainStateAt, PowShare, PowSharePoint,
+        TopBlocks, BlockAggregate, TopBy, TxVolume, TxVolumePoint, NodeLink, VersionInfo, SchemaProfile,
+        WatchItem, WatchKind, WatchHit, WatchLocation,
+    )),
+    tags(
+        (name = "blocks", description = "Block headers and transaction detail"),
+        (name = "analytics", description = "Aggregates over recent blocks"),
+        (name = "chain", description = "Dashboard, metadata, search and events"),
+    )
+)]
+pub struct ApiDoc;
+
 /// Run the web server with block height monitoring
 pub async fn run_web_mode(
     config: &AppConfig,
+    nodes: Vec<NodeProfile>,
     bind: &str,
     port: u16,
     enable_cors: bool,
+    rate_limit: RateLimitConfig,
+    auth: AuthToken,
+    tls: Option<TlsConfig>,
 ) -> Result<()> {
-    // Create broadcast channel for dashboard updates
-    let (update_tx, _update_rx) = broadcast::channel(100);
-    
-    let app_state = AppState {
-        config: config.clone(),
-        dashboard_data: Arc::new(RwLock::new(DashboardData::default())),
-        update_broadcaster: update_tx,
-    };
+    config.verbosity.apply();
+    let shutdown = shutdown::on_signal();
 
-    // Update data initially
-    update_dashboard_data(&app_state).await?;
+    // Every node can list the others for the dashboard's node selector
+    let links = Arc::new(
+        std::iter::once(NodeLink::primary())
+            .chain(nodes.iter().map(|node| NodeLink::named(&node.name)))
+            .collect::<Vec<_>>()
+    );
+    let app_state = start_node(config, links.clone(), shutdown.clone()).await?;
+    let mut node_states = Vec::new();
+    for node in &nodes {
+        println!("🖧  Node '{}': {}", node.name, node.config.database_path.display());
+        node_states.push((node.name.clone(), start_node(&node.config, links.clone(), shutdown.clone()).await?));
+    }
+
+    let limiter = RateLimiter::new(rate_limit.clone());
+    let api = api_routes(&limiter);
+    let concurrency_cap = middleware::from_fn_with_state(limiter.clone(), rate_limit::limit_concurrency);
 
     // Build our application with routes
     let mut app = Router::new()
         .route("/", get(dashboard_html))
-        .route("/api/dashboard", get(get_dashboard_data))
-        .route("/api/block/:height", get(get_block_detail))
-        .route("/api/block/hash/:hash", get(get_block_by_hash))
-        .route("/api/blocks/range", get(get_blocks_range))
         .route("/ws", get(websocket_handler))
+        .route("/metrics", get(get_metrics))
+        .nest("/api/v1", api.clone())
+        // Deprecated unversioned aliases, kept for existing consumers
+        .nest("/api", api)
+        .merge(web_pages::routes().route_layer(concurrency_cap))
+        .merge(SwaggerUi::new("/api/docs").url("/api/v1/openapi.json", ApiDoc::openapi()));
+
+    // Additional nodes get the same API and WebSocket under /node/<name>
+    for (name, state) in &node_states {
+        app = app.nest(&format!("/node/{}", name), Router::new()
+            .route("/ws", get(websocket_handler))
+            .nest("/api/v1", api_routes(&limiter))
+            .with_state(state.clone()));
+    }
+
+    let mut app = app
+        // Everything above may expose node data; static assets below stay public
+        .route_layer(middleware::from_fn_with_state(auth.clone(), auth::require_token))
+        .merge(static_assets::routes())
+        .layer(middleware::from_fn_with_state(limiter, rate_limit::limit_per_ip))
+        // Outermost first: assign the request ID, open its span, echo the ID, then time the request
+        .layer(
+            ServiceBuilder::new()
+                .layer(SetRequestIdLayer::new(request_trace::REQUEST_ID_HEADER, MakeRequestUuid))
+                .layer(TraceLayer::new_for_http()
+                    // warn level so slow-request warnings keep their request ID at the default filter
+                    .make_span_with(|request: &axum::extract::Request| {
+                        let request_id = request.headers().get(request_trace::REQUEST_ID_HEADER)
+                            .and_then(|id| id.to_str().ok())
+                            .unwrap_or("-");
+                        tracing::warn_span!("request", request_id = %request_id, method = %request.method(), uri = %request_trace::redacted_uri(request.uri()))
+                    })
+                    .on_request(())
+                    .on_response(())
+                    .on_failure(()))
+                .layer(PropagateRequestIdLayer::new(request_trace::REQUEST_ID_HEADER))
+                .layer(middleware::from_fn_with_state(app_state.requests.clone(), request_trace::record_request)),
+        )
         .with_state(app_state.clone());
 
     // Add CORS if enabled
@@ -78,270 +513,1056 @@ pub async fn run_web_mode(
     }
 
     let addr: SocketAddr = format!("{}:{}", bind, port).parse()?;
+    let (http_scheme, ws_scheme) = if tls.is_some() { ("https", "wss") } else { ("http", "ws") };
     
-    println!("🌐 Web dashboard available at: http://{}", addr);
-    println!("🔌 WebSocket endpoint: ws://{}/ws", addr);
-    println!("📊 API endpoints:");
-    println!("   GET /api/dashboard - Dashboard data");
-    println!("   GET /api/block/:height - Block details by height");
-    println!("   GET /api/block/hash/:hash - Block details by hash (entire blockchain)");
-    println!("   GET /api/blocks/range?start=X&end=Y - Block ranges (max 1000)");
-    println!("🔍 File system watcher: STARTING (monitoring LMDB changes)");
-    
-    // Start file system watcher (INSTEAD of polling)
-    let watch_state = app_state.clone();
-    tokio::spawn(async move {
-        start_lmdb_file_watcher(watch_state).await;
-    });
+    println!("🌐 Web dashboard available at: {}://{}", http_scheme, addr);
+    println!("🔌 WebSocket endpoint: {}://{}/ws", ws_scheme, addr);
+    if auth.is_enabled() {
+        println!("🔒 Token required: send 'Authorization: Bearer <token>' or open {}://{}/?token=<token>", http_scheme, addr);
+    }
+    if verbosity::normal() {
+        print_endpoints();
+    }
+    if rate_limit.requests_per_minute > 0 {
+        println!("🚦 Rate limit: {} requests/min per IP, {} concurrent LMDB requests", rate_limit.requests_per_minute, rate_limit.max_concurrent);
+    } else {
+        println!("🚦 Rate limit: disabled, {} concurrent LMDB requests", rate_limit.max_concurrent);
+    }
+
+    // Start the server using axum 0.7 API, or axum-server for rustls
+    match tls {
+        Some(tls) => {
+            let rustls = RustlsConfig::from_pem_file(&tls.cert_path, &tls.key_path).await
+                .with_context(|| format!("Failed to load TLS certificate {} / key {}", tls.cert_path.display(), tls.key_path.display()))?;
+            println!("🔐 TLS: serving HTTPS with {}", tls.cert_path.display());
+            let handle = axum_server::Handle::new();
+            let shutdown_handle = handle.clone();
+            let signalled = shutdown.clone();
+            tokio::spawn(async move {
+                signalled.cancelled().await;
+                println!("🛑 Shutting down, draining connections (up to {}s)...", DRAIN_TIMEOUT.as_secs());
+                shutdown_handle.graceful_shutdown(Some(DRAIN_TIMEOUT));
+            });
+            axum_server::bind_rustls(addr, rustls)
+                .handle(handle)
+                .serve(app.into_make_service_with_connect_info::<SocketAddr>())
+                .await?;
+        }
+        None => {
+            let listener = tokio::net::TcpListener::bind(&addr).await?;
+            let signalled = shutdown.clone();
+            let server = axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+                .with_graceful_shutdown(async move {
+                    signalled.cancelled().await;
+                    println!("🛑 Shutting down, draining connections (up to {}s)...", DRAIN_TIMEOUT.as_secs());
+                });
+            // axum waits for every open connection; stop waiting after the drain timeout
+            tokio::select! {
+                result = server.into_future() => result?,
+                _ = async { shutdown.cancelled().await; tokio::time::sleep(DRAIN_TIMEOUT).await } => {
+                    eprintln!("⚠️  Connections still open after {}s, stopping anyway", DRAIN_TIMEOUT.as_secs());
+                }
+            }
+        }
+    }
+
+    // Saved for a warm start next time
+    for state in std::iter::once(&app_state).chain(node_states.iter().map(|(_, state)| state)) {
+        flush_caches(state).await;
+    }
+    println!("👋 Web server stopped");
+
+    Ok(())
+}
+
+/// List the API endpoints at startup
+fn print_endpoints() {
+    println!("📊 API endpoints (v1; unversioned /api/* paths are deprecated aliases):");
+    println!("   GET /api/v1/dashboard - Dashboard data");
+    println!("   GET /api/v1/nodes - Nodes served by this instance (others under /node/<name>/api/v1 and /node/<name>/ws)");
+    println!("   GET /api/v1/version - Inspector version, git commit and schema profile (Tari revision, network, migration version)");
+    println!("   GET /block/:height, /search?q=... - Server-rendered explorer pages (no JavaScript)");
+    println!("   GET /static/* - Embedded dashboard assets");
+    println!("   GET /metrics - WebSocket client and per-route request metrics (Prometheus text format)");
+    println!("   GET /api/v1/openapi.json, /api/docs - OpenAPI spec and Swagger UI");
+    println!("   GET /api/v1/metadata - Chain tip, accumulated work and pruning info");
+    println!("   GET /api/v1/search?q=... - Height, block hash, output hash/commitment or kernel excess");
+    println!("   GET /api/v1/outputs/:hash/exists - Output hash/commitment existence (bloom filter misses answer instantly)");
+    println!("   GET /api/v1/block/:height - Block details by height");
+    println!("   GET /api/v1/block/hash/:hash - Block details by hash (block_hashes index, else full scan)");
+    println!("   GET /api/v1/blocks/range?start=X&end=Y - Block ranges (max --max-blocks, default 1000)");
+    println!("   POST /api/v1/blocks/batch {{\"heights\": [...]}} - Details for up to 100 blocks in one read");
+    println!("   GET /api/v1/events/history?limit=N - Chain event timeline (newest first)");
+    println!("   GET|POST /api/v1/watchlist - Watched commitments / kernel excesses (hits arrive as WatchHit WebSocket events)");
+    println!("   GET /api/v1/analytics/fees?window=N - Kernel fee percentiles (default 1000 blocks)");
+    println!("   GET /api/v1/analytics/intervals?window=N - Block interval histogram (default 1000 blocks)");
+    println!("   GET /api/v1/analytics/utxo-growth?samples=N|step=H - UTXO set size over time");
+    println!("   GET /api/v1/analytics/locked-coinbase?window=N - Immature coinbase outputs and locked emission");
+    println!("   GET /api/v1/analytics/top-blocks?by=kernels|outputs|fees&limit=N - Busiest blocks among the last {} indexed", top_blocks::DEFAULT_INDEX_WINDOW);
+    println!("   GET /api/v1/analytics/tx-volume?from=N&to=N&bucket=N - Kernels per block from the cached series");
+    println!("   GET /api/v1/analytics/pow-share?window=N&span=M - Rolling PoW algorithm share (default 720-block window)");
+    println!("   GET /api/v1/stats/at/:height - Kernel count, UTXO count and emission as of a height");
+    println!("   GET /api/v1/analytics/burns?window=N - Burn outputs, burn kernels and cumulative burned value (default 10000 blocks)");
+    println!("   GET /api/v1/analytics/timestamp-anomalies?window=N - Timestamps before the median of the previous 11 blocks or beyond the FTL");
+}
+
+/// Serve the `audit --follow` status at `/api/v1/audit/status` (and the `/api/audit/status` alias)
+/// until the process stops
+pub async fn serve_audit_status(bind: &str, port: u16, status: SharedAuditStatus) -> Result<()> {
+    let addr: SocketAddr = format!("{}:{}", bind, port).parse()?;
+    let app = Router::new()
+        .route("/api/v1/audit/status", get(get_audit_status))
+        .route("/api/audit/status", get(get_audit_status))
+        .with_state(status);
 
-    // Start the server using axum 0.7 API
     let listener = tokio::net::TcpListener::bind(&addr).await?;
+    println!("🧾 Audit status: http://{}/api/v1/audit/status", addr);
     axum::serve(listener, app).await?;
-
     Ok(())
 }
 
-/// File system watcher for LMDB changes (zero CPU when idle)
-async fn start_lmdb_file_watcher(state: AppState) {
-    let database_path = state.config.database_path.clone();
-    
-    println!("📁 Watching: {}", database_path.display());
-    println!("⚡ Zero-CPU monitoring - updates only when LMDB files change");
+/// Get the totals and recent findings of the audit loop
+async fn get_audit_status(State(status): State<SharedAuditStatus>) -> Json<AuditStatus> {
+    Json(status.read().await.clone())
+}
+
+/// Build one node's state, load its first data and start its file watcher
+async fn start_node(config: &AppConfig, nodes: Arc<Vec<NodeLink>>, shutdown: CancellationToken) -> Result<AppState> {
+    // Create broadcast channel for dashboard updates
+    let (update_tx, _update_rx) = broadcast::channel(100);
+    let (progress_tx, _progress_rx) = broadcast::channel(100);
+    let (watch_tx, _watch_rx) = broadcast::channel(100);
     
-    // Create channel for file system events
-    let (tx, mut rx) = tokio::sync::mpsc::channel(100);
+    // Optional webhook notifier
+    let notifier = match &config.notify {
+        Some(notify_config) => {
+            println!("🔔 Webhook notifications: {}", notify_config.webhook_url);
+            Some(Arc::new(Mutex::new(Notifier::new(notify_config.clone())?)))
+        }
+        None => None,
+    };
+
+    let watchlist = match &config.watchlist_path {
+        Some(path) => {
+            let watchlist = Watchlist::load(path)?;
+            println!("👀 Watchlist: {} items from {}", watchlist.items().len(), path.display());
+            watchlist
+        }
+        None => Watchlist::default(),
+    };
     
-    // Setup file system watcher
-    let watcher = notify::recommended_watcher(move |res: Result<Event, notify::Error>| {
-        match res {
-            Ok(event) => {
-                // Only care about modify events on .mdb files
-                if event.kind.is_modify() {
-                    let has_mdb_files = event.paths.iter().any(|p| {
-                        p.extension().map_or(false, |ext| ext == "mdb")
-                    });
-                    
-                    if has_mdb_files {
-                        if let Err(e) = tx.blocking_send(()) {
-                            eprintln!("Failed to send file change event: {}", e);
-                        }
-                    }
-                }
-            }
-            Err(e) => eprintln!("File watch error: {:?}", e),
+    // Restarts only read blocks added since the cached index was stored
+    let block_index = if config.demo {
+        AggregateIndex::new(top_blocks::DEFAULT_INDEX_WINDOW)
+    } else {
+        AggregateIndex::load_cached(&config.cache, &config.database_path, top_blocks::DEFAULT_INDEX_WINDOW)
+    };
+    println!("🗄️  Cache directory: {}", config.cache.dir().display());
+
+    // The previous run's dashboard is served (marked stale) while the first read runs
+    let warm_start = if config.demo { None } else { config.cache.load_dashboard().map(|data| data.localized(&config.timezone)) };
+
+    let provider = data_provider::from_config_with(config, Arc::new(BroadcastProgress::new(progress_tx.clone())), shutdown.clone());
+    let app_state = AppState {
+        config: config.clone(),
+        dashboard_data: Arc::new(RwLock::new(warm_start.clone().unwrap_or_default())),
+        update_broadcaster: update_tx,
+        progress_broadcaster: progress_tx.clone(),
+        notifier,
+        watchlist: Arc::new(Mutex::new(watchlist)),
+        watch_broadcaster: watch_tx,
+        block_index: Arc::new(RwLock::new(block_index)),
+        tx_volume: Arc::new(RwLock::new(TxVolumeCache::load(&config.cache.file(tx_volume::CACHE_FILE)))),
+        height_hashes: Arc::new(RwLock::new(HeightHashes::default())),
+        utxo_bloom: Arc::new(RwLock::new(UtxoBloom::default())),
+        block_cache: Arc::new(Mutex::new(BlockCache::new(block_cache::DEFAULT_CAPACITY))),
+        provider: provider.clone(),
+        ws_clients: ClientRegistry::new(),
+        requests: RequestMetrics::new(),
+        engine: Arc::new(DashboardEngine::new(config.clone(), provider.clone())),
+        nodes,
+        shutdown,
+    };
+
+    // Update data initially; with a warm start the server comes up first and clients get the
+    // fresh data over the WebSocket when the read finishes
+    match &warm_start {
+        Some(data) => {
+            println!("♨️  Warm start: serving saved dashboard (height {}) until the first read completes", data.network_stats.latest_block_height);
+            tokio::spawn(refresh_and_broadcast(app_state.clone()));
         }
-    });
+        None => {
+            update_dashboard_data(&app_state).await?;
+            notify_observers(&app_state).await;
+            check_watchlist(&app_state).await;
+            // The first index build reads every block in the window, so it runs in the background
+            tokio::spawn(refresh_block_index(app_state.clone()));
+        }
+    }
+
+    if config.demo {
+        println!("🎭 Demo mode: serving synthetic blocks, file watcher disabled");
+    } else {
+        println!("🔍 File system watcher: STARTING (monitoring LMDB changes)");
+        
+        // Start file system watcher (INSTEAD of polling)
+        let watch_state = app_state.clone();
+        tokio::spawn(async move {
+            start_lmdb_file_watcher(watch_state).await;
+        });
+
+        // A stalled node writes nothing, so the watcher alone would never notice it
+        tokio::spawn(watch_sync_status(app_state.clone()));
+    }
+
+    Ok(app_state)
+}
+
+/// The `/api/v1` routes of one node
+fn api_routes(limiter: &RateLimiter) -> Router<AppState> {
+    // Routes that read LMDB directly share a global concurrency cap
+    let concurrency_cap = middleware::from_fn_with_state(limiter.clone(), rate_limit::limit_concurrency);
+    let lmdb_api = Router::new()
+        .route("/block/:height", get(get_block_detail))
+        .route("/block/hash/:hash", get(get_block_by_hash))
+        .route("/block/:height/inputs", get(get_block_inputs))
+        .route("/block/:height/outputs", get(get_block_outputs))
+        .route("/block/:height/kernels", get(get_block_kernels))
+        .route("/blocks/range", get(get_blocks_range))
+        .route("/blocks/batch", post(get_blocks_batch))
+        .route("/analytics/fees", get(get_fee_analytics))
+        .route("/analytics/fees.csv", get(get_fee_analytics))
+        .route("/analytics/intervals", get(get_interval_analytics))
+        .route("/analytics/intervals.csv", get(get_interval_analytics))
+        .route("/analytics/utxo-growth", get(get_utxo_growth))
+        .route("/analytics/utxo-growth.csv", get(get_utxo_growth))
+        .route("/analytics/locked-coinbase", get(get_locked_coinbase))
+        .route("/analytics/locked-coinbase.csv", get(get_locked_coinbase))
+        .route("/analytics/burns", get(get_burn_analytics))
+        .route("/analytics/burns.csv", get(get_burn_analytics))
+        .route("/analytics/timestamp-anomalies", get(get_timestamp_anomalies))
+        .route("/analytics/timestamp-anomalies.csv", get(get_timestamp_anomalies))
+        .route("/analytics/pow-share", get(get_pow_share))
+        .route("/analytics/pow-share.csv", get(get_pow_share))
+        .route("/stats/at/:height", get(get_state_at_height))
+        .route("/metadata", get(get_chain_metadata))
+        .route("/search", get(get_search))
+        .route("/outputs/:hash/exists", get(get_output_exists))
+        .route_layer(concurrency_cap);
+
+    Router::new()
+        .route("/dashboard", get(get_dashboard_data))
+        .route("/events/history", get(get_events_history))
+        .route("/watchlist", get(get_watchlist).post(add_watch_item))
+        .route("/analytics/top-blocks", get(get_top_blocks))
+        .route("/analytics/top-blocks.csv", get(get_top_blocks))
+        .route("/analytics/tx-volume", get(get_tx_volume))
+        .route("/analytics/tx-volume.csv", get(get_tx_volume))
+        .route("/nodes", get(get_nodes))
+        .route("/version", get(get_version))
+        .merge(lmdb_api)
+}
+
+/// Time open connections get to finish after a shutdown signal
+const DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Write the dashboard and the derived indexes to the cache directory for the next start
+async fn flush_caches(state: &AppState) {
+    if state.config.demo {
+        return;
+    }
+    if let Err(e) = state.config.cache.store_dashboard(&*state.dashboard_data.read().await) {
+        eprintln!("⚠️  Could not save dashboard for warm start: {}", e);
+    }
+    if let Err(e) = state.block_index.read().await.store(&state.config.cache) {
+        eprintln!("⚠️  Could not store top blocks index: {}", e);
+    }
+    let volume = state.tx_volume.read().await;
+    if volume.covered_to().is_some() {
+        if let Err(e) = volume.save(&state.config.cache.file(tx_volume::CACHE_FILE)) {
+            eprintln!("⚠️  Could not save tx volume: {}", e);
+        }
+    }
+}
+
+/// File system watcher for LMDB changes (zero CPU when idle)
+async fn start_lmdb_file_watcher(state: AppState) {
+    println!("📁 Watching: {}", state.config.database_path.display());
     
-    match watcher {
-        Ok(mut watcher) => {
-            // Watch the LMDB directory
-            if let Err(e) = watcher.watch(&database_path, RecursiveMode::NonRecursive) {
-                eprintln!("❌ Failed to start file watcher: {}", e);
-                return;
+    match LmdbWatcher::from_config(&state.config) {
+        Ok(watcher) => {
+            if watcher.is_polling() {
+                println!("⏱️  Change detection: {}", watcher.describe());
+            } else {
+                println!("⚡ Zero-CPU monitoring - updates only when LMDB files change");
+                println!("✅ File system watcher: ACTIVE");
             }
+            let changes = state.engine.clone().changes_from(watcher);
             
-            println!("✅ File system watcher: ACTIVE");
-            
-            // Debouncing state
-            let mut debounce_handle: Option<tokio::task::JoinHandle<()>> = None;
-            
-            // Listen for file change events
-            while let Some(_) = rx.recv().await {
-                // Cancel any pending update
-                if let Some(handle) = debounce_handle.take() {
-                    handle.abort();
-                }
-                
-                // Schedule debounced update
-                let update_state = state.clone();
-                debounce_handle = Some(tokio::spawn(async move {
-                    // Wait for writes to complete
-                    tokio::time::sleep(Duration::from_millis(500)).await;
-                    
-                    println!("📊 LMDB modified - updating dashboard...");
-                    
-                    if let Err(e) = update_dashboard_data(&update_state).await {
-                        eprintln!("❌ Error updating dashboard: {}", e);
-                    } else {
-                        // Broadcast update to all WebSocket clients
-                        let data = update_state.dashboard_data.read().await;
-                        if let Err(e) = update_state.update_broadcaster.send(data.clone()) {
-                            eprintln!("Warning: Failed to broadcast update: {}", e);
-                        } else {
-                            println!("✅ Dashboard updated (triggered by file change)");
-                        }
+            // One debounced snapshot per LMDB change
+            let mut changes = std::pin::pin!(changes);
+            while let Some(update) = changes.next().await {
+                match update {
+                    Ok(data) => {
+                        apply_dashboard(&state, data).await;
+                        broadcast_and_observe(&state).await;
                     }
-                }));
+                    Err(e) => eprintln!("❌ Error updating dashboard: {}", e),
+                }
             }
-            
-            // Keep watcher alive
-            drop(watcher);
         }
         Err(e) => {
-            eprintln!("❌ Failed to create file watcher: {}", e);
+            eprintln!("❌ Failed to start file watcher: {}", e);
             eprintln!("💡 Falling back to manual refresh only");
         }
     }
 }
 
+/// Pass the latest blocks and sync status to the webhook notifier, if configured
+async fn notify_observers(state: &AppState) {
+    if let Some(notifier) = &state.notifier {
+        let data = state.dashboard_data.read().await;
+        let blocks = data.recent_blocks.clone();
+        let sync = (data.network_stats.latest_block_height, data.network_stats.sync_status);
+        drop(data);
+
+        let mut notifier = notifier.lock().await;
+        notifier.observe_and_send(&blocks).await;
+        if let Some(event) = notifier.observe_sync(sync.0, sync.1) {
+            notifier.send_all(&[event]).await;
+        }
+    }
+}
+
+/// Re-check the sync status every minute between LMDB updates and push any change
+async fn watch_sync_status(state: AppState) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let data = state.dashboard_data.read().await;
+        if data.stale {
+            continue;
+        }
+        let previous = data.network_stats.sync_status;
+        let tip = data.recent_blocks.first().cloned();
+        drop(data);
+
+        let status = state.engine.sync_status(tip.as_ref()).await;
+        if status == previous {
+            continue;
+        }
+        println!("⏳ Sync status: {}", status.label());
+        let mut data = state.dashboard_data.write().await;
+        data.network_stats.sync_status = status;
+        let _ = state.update_broadcaster.send(data.clone());
+        drop(data);
+        notify_observers(&state).await;
+    }
+}
+
+/// Read LMDB, push the new dashboard to WebSocket clients and run the per-update observers
+async fn refresh_and_broadcast(state: AppState) {
+    if let Err(e) = update_dashboard_data(&state).await {
+        eprintln!("❌ Error updating dashboard: {}", e);
+        return;
+    }
+    broadcast_and_observe(&state).await;
+}
+
+/// Push the current dashboard to WebSocket clients and run the per-update observers
+async fn broadcast_and_observe(state: &AppState) {
+    // Broadcast update to all WebSocket clients
+    let data = state.dashboard_data.read().await;
+    if let Err(e) = state.update_broadcaster.send(data.clone()) {
+        eprintln!("Warning: Failed to broadcast update: {}", e);
+    } else if verbosity::debug() {
+        println!("✅ Dashboard updated");
+    }
+    drop(data);
+
+    notify_observers(state).await;
+    check_watchlist(state).await;
+    tokio::spawn(refresh_block_index(state.clone()));
+}
+
+/// Bring the top blocks index up to the current tip
+async fn refresh_block_index(state: AppState) {
+    if state.config.demo {
+        return;
+    }
+    let tip = state.dashboard_data.read().await.network_stats.latest_block_height;
+    let (start, end) = state.block_index.read().await.pending(tip);
+    let path = state.config.database_path.clone();
+    match tokio::task::spawn_blocking(move || top_blocks::scan_aggregates(&path, start, end)).await {
+        Ok(Ok(rows)) => {
+            let mut index = state.block_index.write().await;
+            index.apply(tip, rows);
+            if let Err(e) = index.store(&state.config.cache) {
+                eprintln!("⚠️  Could not store top blocks index: {}", e);
+            }
+        },
+        Ok(Err(e)) => eprintln!("⚠️  Top blocks index refresh failed: {}", e),
+        Err(e) => eprintln!("⚠️  Top blocks index task failed: {}", e),
+    }
+}
+
+/// Check blocks above the last checked height against the watchlist, pushing hits to
+/// WebSocket clients and the webhook
+async fn check_watchlist(state: &AppState) {
+    let tip = state.dashboard_data.read().await.network_stats.latest_block_height;
+    let (heights, watching) = {
+        let mut watchlist = state.watchlist.lock().await;
+        (watchlist.pending_heights(tip), !watchlist.is_empty())
+    };
+    if !watching || heights.is_empty() {
+        return;
+    }
+
+    let mut hits = Vec::new();
+    for height in heights {
+        match state.provider.block(height).await {
+            Ok(block) => hits.extend(state.watchlist.lock().await.check_block(&block)),
+            Err(e) => eprintln!("⚠️  Watchlist check skipped block {}: {}", height, e),
+        }
+    }
+
+    for hit in &hits {
+        println!("👀 Watch hit: {:?} {} in block {}", hit.location, hit.item.value, hit.height);
+        // No subscribers just means no dashboards are open
+        let _ = state.watch_broadcaster.send(hit.clone());
+    }
+    if let (Some(notifier), false) = (&state.notifier, hits.is_empty()) {
+        let events: Vec<NotifyEvent> = hits.into_iter().map(|hit| NotifyEvent::WatchHit { hit }).collect();
+        notifier.lock().await.send_all(&events).await;
+    }
+}
+
 /// Serve the main dashboard HTML page
 async fn dashboard_html() -> Html<&'static str> {
     Html(include_str!("dashboard.html"))
 }
 
+/// List the nodes served by this instance via REST API
+#[utoipa::path(get, path = "/api/v1/nodes", tag = "chain",
+    responses((status = 200, description = "Node names with their API prefix and WebSocket path", body = [NodeLink])))]
+async fn get_nodes(State(state): State<AppState>) -> Json<Vec<NodeLink>> {
+    Json(state.nodes.as_ref().clone())
+}
+
+/// Report the inspector build and the schema it reads via REST API
+#[utoipa::path(get, path = "/api/v1/version", tag = "chain",
+    responses((status = 200, description = "Crate version, git commit and schema profile", body = VersionInfo)))]
+async fn get_version(State(state): State<AppState>) -> Json<VersionInfo> {
+    // An unreadable metadata table only leaves the migration version unknown
+    let migration_version = match state.provider.chain_metadata().await {
+        Ok(metadata) => metadata.and_then(|metadata| metadata.migration_version),
+        Err(_) => None,
+    };
+    Json(VersionInfo::new(state.config.network.to_string(), migration_version))
+}
+
 /// Get dashboard data via REST API
+#[utoipa::path(get, path = "/api/v1/dashboard", tag = "chain",
+    responses((status = 200, description = "Cached dashboard snapshot", body = DashboardData)))]
 async fn get_dashboard_data(State(state): State<AppState>) -> Json<DashboardData> {
     let data = state.dashboard_data.read().await;
     Json(data.clone())
 }
 
 /// Get block details by height via REST API
+#[utoipa::path(get, path = "/api/v1/block/{height}", tag = "blocks",
+    params(("height" = u64, Path, description = "Block height"), BlockDetailQuery),
+    responses(
+        (status = 200, description = "Header, inputs, outputs and kernels", body = BlockDetailResponse),
+        (status = 400, description = "Unknown part in include, or more transaction items than --max-tx-items", body = LimitExceeded),
+        (status = 404, description = "No header at this height"),
+        (status = 409, description = "The header does not decode with this build's Tari types"),
+        (status = 410, description = "Below the pruning horizon"),
+        (status = 503, description = "Read timed out or LMDB busy; retry after Retry-After seconds"),
+    ))]
 async fn get_block_detail(
     axum::extract::Path(height): axum::extract::Path<u64>,
+    Query(params): Query<BlockDetailQuery>,
     State(state): State<AppState>,
-) -> Result<Json<serde_json::Value>, StatusCode> {
-    match read_block_with_transactions(&state.config.database_path, height) {
-        Ok(block_detail) => {
-            let response = serde_json::json!({
-                "height": block_detail.height,
-                "hash": block_detail.hash,
-                "header": {
-                    "version": block_detail.header.version,
-                    "timestamp": block_detail.header.timestamp,
-                    "nonce": block_detail.header.nonce,
-                    "previous_hash": block_detail.header.previous_hash,
-                    "output_mr": block_detail.header.output_mr,
-                    "kernel_mr": block_detail.header.kernel_mr,
-                    "input_mr": block_detail.header.input_mr,
-                    "total_kernel_offset": block_detail.header.total_kernel_offset,
-                    "total_script_offset": block_detail.header.total_script_offset,
-                    "pow_data_hash": block_detail.header.pow_data_hash,
-                    "raw_header_length": block_detail.header.raw_header_length,
-                    "pow_algorithm": block_detail.header.pow_algorithm
-                },
-                "transactions": {
-                    "inputs": block_detail.transactions.inputs,
-                    "outputs": block_detail.transactions.outputs,
-                    "kernels": block_detail.transactions.kernels
-                }
-            });
-            Ok(Json(response))
+) -> Result<Json<BlockDetailResponse>, Response> {
+    let include = params.include().ok_or_else(|| StatusCode::BAD_REQUEST.into_response())?;
+
+    // Only blocks below the tip are confirmed and safe to serve from cache
+    let tip_height = state.dashboard_data.read().await.network_stats.latest_block_height;
+    let cacheable = height < tip_height;
+    
+    if cacheable {
+        // A header read gives the hash now at this height, so a reorged entry is never served
+        let current = state.provider.block_with(height, BlockInclude::HEADER).await.map_err(IntoResponse::into_response)?;
+        if let Some(cached) = state.block_cache.lock().await.get(height, &current.hash) {
+            return Ok(Json(params.apply((*cached).clone()).localized(&state.config.timezone)));
         }
-        Err(_) => Err(StatusCode::NOT_FOUND),
     }
+    
+    // Transactions are only decoded when asked for; counts come from prefix scans
+    let block_detail = state.provider.block_with(height, include).await.map_err(IntoResponse::into_response)?;
+    let response = BlockDetailResponse::from(block_detail);
+    state.config.limits.check_tx_items(transaction_items(&response)).map_err(IntoResponse::into_response)?;
+    if cacheable && include.transactions {
+        state.block_cache.lock().await.insert(height, response.hash.clone(), response.clone());
+    }
+    Ok(Json(params.apply(response).localized(&state.config.timezone)))
 }
 
-/// Get block details by hash via REST API (searches entire blockchain)
+/// Inputs, outputs and kernels decoded into a block response
+fn transaction_items(response: &BlockDetailResponse) -> u64 {
+    response.transactions.as_ref()
+        .map(|transactions| (transactions.inputs.len() + transactions.outputs.len() + transactions.kernels.len()) as u64)
+        .unwrap_or(0)
+}
+
+/// Get one page of a block's inputs via REST API
+#[utoipa::path(get, path = "/api/v1/block/{height}/inputs", tag = "blocks",
+    params(("height" = u64, Path, description = "Block height"), PageQuery),
+    responses(
+        (status = 200, description = "Inputs offset..offset+limit and the block's input count", body = BlockTransactionsPage),
+        (status = 404, description = "No header at this height"),
+        (status = 410, description = "Below the pruning horizon"),
+        (status = 503, description = "Read timed out or LMDB busy; retry after Retry-After seconds"),
+    ))]
+async fn get_block_inputs(
+    axum::extract::Path(height): axum::extract::Path<u64>,
+    Query(params): Query<PageQuery>,
+    State(state): State<AppState>,
+) -> Result<Json<BlockTransactionsPage>, ReaderError> {
+    block_transactions_page(&state, height, TxComponent::Inputs, params).await
+}
+
+/// Get one page of a block's outputs via REST API
+#[utoipa::path(get, path = "/api/v1/block/{height}/outputs", tag = "blocks",
+    params(("height" = u64, Path, description = "Block height"), PageQuery),
+    responses(
+        (status = 200, description = "Outputs offset..offset+limit and the block's output count", body = BlockTransactionsPage),
+        (status = 404, description = "No header at this height"),
+        (status = 410, description = "Below the pruning horizon"),
+        (status = 503, description = "Read timed out or LMDB busy; retry after Retry-After seconds"),
+    ))]
+async fn get_block_outputs(
+    axum::extract::Path(height): axum::extract::Path<u64>,
+    Query(params): Query<PageQuery>,
+    State(state): State<AppState>,
+) -> Result<Json<BlockTransactionsPage>, ReaderError> {
+    block_transactions_page(&state, height, TxComponent::Outputs, params).await
+}
+
+/// Get one page of a block's kernels (fees, lock heights, kinds) via REST API
+#[utoipa::path(get, path = "/api/v1/block/{height}/kernels", tag = "blocks",
+    params(("height" = u64, Path, description = "Block height"), PageQuery),
+    responses(
+        (status = 200, description = "Kernels offset..offset+limit and the block's kernel count", body = BlockTransactionsPage),
+        (status = 404, description = "No header at this height"),
+        (status = 410, description = "Below the pruning horizon"),
+        (status = 503, description = "Read timed out or LMDB busy; retry after Retry-After seconds"),
+    ))]
+async fn get_block_kernels(
+    axum::extract::Path(height): axum::extract::Path<u64>,
+    Query(params): Query<PageQuery>,
+    State(state): State<AppState>,
+) -> Result<Json<BlockTransactionsPage>, ReaderError> {
+    block_transactions_page(&state, height, TxComponent::Kernels, params).await
+}
+
+/// Shared body of the paged block transaction endpoints
+/// Only the requested table is read; the other two and the block assembly are skipped
+async fn block_transactions_page(
+    state: &AppState,
+    height: u64,
+    component: TxComponent,
+    params: PageQuery,
+) -> Result<Json<BlockTransactionsPage>, ReaderError> {
+    let page = Page::new(params.offset, params.limit);
+    Ok(Json(state.provider.transactions(height, component, page).await?.into()))
+}
+
+/// Get block details by hash via REST API (block_hashes index, else a full header scan)
+#[utoipa::path(get, path = "/api/v1/block/hash/{hash}", tag = "blocks",
+    params(("hash" = String, Path, description = "64 hex character block hash"), BlockDetailQuery),
+    responses(
+        (status = 200, description = "Header, inputs, outputs and kernels", body = BlockDetailResponse),
+        (status = 400, description = "Not a 64 hex character hash, an unknown part in include, or more transaction items than --max-tx-items", body = LimitExceeded),
+        (status = 404, description = "No block with this hash"),
+    ))]
 async fn get_block_by_hash(
     axum::extract::Path(hash): axum::extract::Path<String>,
+    Query(params): Query<BlockDetailQuery>,
     State(state): State<AppState>,
-) -> Result<Json<serde_json::Value>, StatusCode> {
+) -> Result<Json<BlockDetailResponse>, Response> {
     // Validate hash format (should be 64 hex characters)
     if hash.len() != 64 {
-        return Err(StatusCode::BAD_REQUEST);
+        return Err(StatusCode::BAD_REQUEST.into_response());
     }
     
     // Validate hex characters
     if !hash.chars().all(|c| c.is_ascii_hexdigit()) {
-        return Err(StatusCode::BAD_REQUEST);
+        return Err(StatusCode::BAD_REQUEST.into_response());
+    }
+    if params.include().is_none() {
+        return Err(StatusCode::BAD_REQUEST.into_response());
     }
     
-    println!("🔍 API request: searching entire blockchain for hash {}", &hash[0..20]);
+    if verbosity::debug() {
+        println!("🔍 API request: looking up block hash {}", &hash[0..20]);
+    }
+
+    // The cached height → hash map avoids a header scan on databases without a hash index
+    let cached_height = match hex::decode(&hash) {
+        Ok(bytes) => state.height_hashes.read().await.height_of(&bytes),
+        Err(_) => None,
+    };
+    if let Some(height) = cached_height {
+        if let Ok(block_detail) = state.provider.block(height).await {
+            if block_detail.hash.eq_ignore_ascii_case(&hash) {
+                if verbosity::debug() {
+                    println!("✅ Hash found in cached map: block {}", height);
+                }
+                let response = BlockDetailResponse::from(block_detail);
+                state.config.limits.check_tx_items(transaction_items(&response)).map_err(IntoResponse::into_response)?;
+                return Ok(Json(params.apply(response).localized(&state.config.timezone)));
+            }
+        }
+    }
     
-    match search_block_by_hash(&state.config.database_path, &hash) {
+    match state.provider.block_by_hash(&hash).await {
         Ok(Some(block_detail)) => {
-            println!("✅ Hash search successful: found block {}", block_detail.height);
-            let response = serde_json::json!({
-                "height": block_detail.height,
-                "hash": block_detail.hash,
-                "header": {
-                    "version": block_detail.header.version,
-                    "timestamp": block_detail.header.timestamp,
-                    "nonce": block_detail.header.nonce,
-                    "previous_hash": block_detail.header.previous_hash,
-                    "output_mr": block_detail.header.output_mr,
-                    "kernel_mr": block_detail.header.kernel_mr,
-                    "input_mr": block_detail.header.input_mr,
-                    "total_kernel_offset": block_detail.header.total_kernel_offset,
-                    "total_script_offset": block_detail.header.total_script_offset,
-                    "pow_data_hash": block_detail.header.pow_data_hash,
-                    "raw_header_length": block_detail.header.raw_header_length,
-                    "pow_algorithm": block_detail.header.pow_algorithm
-                },
-                "transactions": {
-                    "inputs": block_detail.transactions.inputs,
-                    "outputs": block_detail.transactions.outputs,
-                    "kernels": block_detail.transactions.kernels
-                }
-            });
-            Ok(Json(response))
+            if verbosity::debug() {
+                println!("✅ Hash search successful: found block {}", block_detail.height);
+            }
+            let response = BlockDetailResponse::from(block_detail);
+            state.config.limits.check_tx_items(transaction_items(&response)).map_err(IntoResponse::into_response)?;
+            Ok(Json(params.apply(response).localized(&state.config.timezone)))
         }
         Ok(None) => {
-            println!("❌ Hash search failed: block not found");
-            Err(StatusCode::NOT_FOUND)
+            if verbosity::debug() {
+                println!("❌ Hash search failed: block not found");
+            }
+            Err(StatusCode::NOT_FOUND.into_response())
         }
-        Err(e) => {
-            eprintln!("❌ Hash search error: {}", e);
-            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        Err(e) => Err(e.into_response()),
+    }
+}
+
+/// Most heights accepted by one batch request
+const MAX_BATCH_BLOCKS: usize = 100;
+
+/// Get details for up to 100 blocks in one request via REST API
+#[utoipa::path(post, path = "/api/v1/blocks/batch", tag = "blocks", request_body = BlockBatchRequest,
+    responses(
+        (status = 200, description = "Found blocks in request order, plus the heights that were not returned", body = BlockBatchResponse),
+        (status = 400, description = "No heights, more than 100 or --max-blocks, or more transaction items than --max-tx-items", body = LimitExceeded),
+    ))]
+async fn get_blocks_batch(
+    State(state): State<AppState>,
+    Json(request): Json<BlockBatchRequest>,
+) -> Result<Json<BlockBatchResponse>, Response> {
+    let mut seen = std::collections::HashSet::new();
+    let heights: Vec<u64> = request.heights.into_iter().filter(|h| seen.insert(*h)).collect();
+    if heights.is_empty() || heights.len() > MAX_BATCH_BLOCKS {
+        return Err(StatusCode::BAD_REQUEST.into_response());
+    }
+    state.config.limits.check_blocks(heights.len() as u64).map_err(IntoResponse::into_response)?;
+
+    match state.provider.blocks(heights.clone()).await {
+        Ok(results) => {
+            let mut response = BlockBatchResponse { blocks: Vec::new(), missing: Vec::new() };
+            let mut items = 0;
+            for (height, result) in heights.into_iter().zip(results) {
+                match result {
+                    Ok(block) => {
+                        let block = BlockDetailResponse::from(block);
+                        // Counted as blocks are converted, so an oversized batch stops growing early
+                        items += transaction_items(&block);
+                        state.config.limits.check_tx_items(items).map_err(IntoResponse::into_response)?;
+                        response.blocks.push(block.localized(&state.config.timezone));
+                    }
+                    Err(e) => {
+                        let reason = if e.is_not_found() { "not_found" } else if e.is_pruned() { "pruned" } else { "error" };
+                        response.missing.push(BlockBatchMiss { height, reason: reason.to_string(), message: e.to_string() });
+                    }
+                }
+            }
+            Ok(Json(response))
         }
+        Err(e) => Err(e.into_response()),
     }
 }
 
 /// Get blocks in a range via REST API
+#[utoipa::path(get, path = "/api/v1/blocks/range", tag = "blocks", params(RangeQuery),
+    responses(
+        (status = 200, description = "Headers in the inclusive range", body = BlockRangeResponse),
+        (status = 400, description = "start > end or more blocks than --max-blocks (default 1000)", body = LimitExceeded),
+    ))]
 async fn get_blocks_range(
     Query(params): Query<RangeQuery>,
     State(state): State<AppState>,
-) -> Result<Json<serde_json::Value>, StatusCode> {
+) -> Result<Json<BlockRangeResponse>, Response> {
     // Validate range
     if params.start > params.end {
-        return Err(StatusCode::BAD_REQUEST);
+        return Err(StatusCode::BAD_REQUEST.into_response());
     }
     
     // Limit range size to prevent huge queries
-    let range_size = params.end - params.start + 1;
-    if range_size > 1000 {
-        return Err(StatusCode::BAD_REQUEST);
-    }
+    state.config.limits.check_blocks((params.end - params.start).saturating_add(1)).map_err(IntoResponse::into_response)?;
+    
+    let filter = BlockFilter::Range(params.start, params.end).with_options(params.pow.clone(), params.min_kernels);
     
-    match read_lmdb_headers_with_filter(&state.config.database_path, "headers", BlockFilter::Range(params.start, params.end)) {
+    match state.provider.headers(filter).await {
         Ok(blocks) => {
-            let response = serde_json::json!({
-                "start": params.start,
-                "end": params.end,
-                "total_found": blocks.len(),
-                "blocks": blocks.iter().map(|block| {
-                    serde_json::json!({
-                        "height": block.height,
-                        "hash": block.hash,
-                        "timestamp": block.header.timestamp,
-                        "previous_hash": block.header.previous_hash,
-                        "output_mr": block.header.output_mr,
-                        "kernel_mr": block.header.kernel_mr,
-                        "input_mr": block.header.input_mr,
-                        "total_kernel_offset": block.header.total_kernel_offset,
-                        "total_script_offset": block.header.total_script_offset,
-                        "pow_data_hash": block.header.pow_data_hash,
-                        "raw_header_length": block.header.raw_header_length,
-                        "pow_algorithm": block.header.pow_algorithm
-                    })
-                }).collect::<Vec<_>>()
-            });
+            let response = BlockRangeResponse {
+                start: params.start,
+                end: params.end,
+                total_found: blocks.len(),
+                blocks: blocks.into_iter().map(|block| RangeBlock::from(block).localized(&state.config.timezone)).collect(),
+            };
             Ok(Json(response))
         }
-        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
+        Err(e) => Err(e.into_response()),
+    }
+}
+
+/// Get kernel fee analytics over the last N blocks via REST API
+#[utoipa::path(get, path = "/api/v1/analytics/fees", tag = "analytics", params(WindowQuery, FormatQuery),
+    responses((status = 200, description = "Kernel fee percentiles", body = FeeAnalytics)))]
+async fn get_fee_analytics(
+    Query(params): Query<WindowQuery>,
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> Result<Response, Response> {
+    let window = params.window.unwrap_or(fees::DEFAULT_WINDOW);
+    if window == 0 || window > fees::MAX_WINDOW {
+        return Err(StatusCode::BAD_REQUEST.into_response());
+    }
+    
+    let path = state.config.database_path.clone();
+    let analytics = r#async::run_blocking(state.config.read_timeout, "fee analytics", move || fees::fee_analytics(&path, window)).await.map_err(IntoResponse::into_response)?;
+    Ok(format.respond("fees", analytics))
+}
+
+/// Get the block interval histogram over the last N blocks via REST API
+#[utoipa::path(get, path = "/api/v1/analytics/intervals", tag = "analytics", params(WindowQuery, FormatQuery),
+    responses((status = 200, description = "Block interval histogram", body = IntervalHistogram)))]
+async fn get_interval_analytics(
+    Query(params): Query<WindowQuery>,
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> Result<Response, Response> {
+    let window = params.window.unwrap_or(intervals::DEFAULT_WINDOW);
+    if window == 0 || window > intervals::MAX_WINDOW {
+        return Err(StatusCode::BAD_REQUEST.into_response());
+    }
+    
+    let path = state.config.database_path.clone();
+    let histogram = r#async::run_blocking(state.config.read_timeout, "interval analytics", move || intervals::interval_histogram(&path, window)).await.map_err(IntoResponse::into_response)?;
+    Ok(format.respond("intervals", histogram))
+}
+
+/// Get the UTXO set growth series via REST API
+#[utoipa::path(get, path = "/api/v1/analytics/utxo-growth", tag = "analytics", params(GrowthQuery, FormatQuery),
+    responses((status = 200, description = "UTXO set size over time", body = UtxoGrowth)))]
+async fn get_utxo_growth(
+    Query(params): Query<GrowthQuery>,
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> Result<Response, Response> {
+    let samples = params.samples.unwrap_or(utxo_growth::DEFAULT_SAMPLES);
+    if samples > utxo_growth::MAX_SAMPLES {
+        return Err(StatusCode::BAD_REQUEST.into_response());
+    }
+    
+    let path = state.config.database_path.clone();
+    let step = params.step;
+    let growth = r#async::run_blocking(state.config.read_timeout, "utxo growth", move || utxo_growth::utxo_growth(&path, samples, step)).await.map_err(IntoResponse::into_response)?;
+    Ok(format.respond("utxo-growth", growth))
+}
+
+/// Get immature coinbase outputs and the total locked emission via REST API
+#[utoipa::path(get, path = "/api/v1/analytics/locked-coinbase", tag = "analytics", params(WindowQuery, FormatQuery),
+    responses((status = 200, description = "Immature coinbase outputs", body = LockedCoinbaseReport)))]
+async fn get_locked_coinbase(
+    Query(params): Query<WindowQuery>,
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> Result<Response, Response> {
+    let window = params.window.unwrap_or(coinbase::DEFAULT_WINDOW);
+    if window == 0 || window > coinbase::MAX_WINDOW {
+        return Err(StatusCode::BAD_REQUEST.into_response());
     }
+
+    let path = state.config.database_path.clone();
+    let report = r#async::run_blocking(state.config.read_timeout, "locked coinbase", move || coinbase::locked_coinbase(&path, window)).await.map_err(IntoResponse::into_response)?;
+    Ok(format.respond("locked-coinbase", report))
+}
+
+/// Get burn outputs, burn kernels and the cumulative burned value via REST API
+#[utoipa::path(get, path = "/api/v1/analytics/burns", tag = "analytics", params(WindowQuery, FormatQuery),
+    responses((status = 200, description = "Burns over the window", body = BurnAnalytics)))]
+async fn get_burn_analytics(
+    Query(params): Query<WindowQuery>,
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> Result<Response, Response> {
+    let window = params.window.unwrap_or(burns::DEFAULT_WINDOW);
+    if window == 0 || window > burns::MAX_WINDOW {
+        return Err(StatusCode::BAD_REQUEST.into_response());
+    }
+
+    let path = state.config.database_path.clone();
+    let report = r#async::run_blocking(state.config.read_timeout, "burn analytics", move || burns::burn_analytics(&path, window)).await.map_err(IntoResponse::into_response)?;
+    Ok(format.respond("burns", report))
+}
+
+/// Get blocks whose timestamps break the median-time-past or future time limit rule via REST API
+#[utoipa::path(get, path = "/api/v1/analytics/timestamp-anomalies", tag = "analytics", params(WindowQuery, FormatQuery),
+    responses((status = 200, description = "Timestamp anomalies over the window", body = TimestampAnomalies)))]
+async fn get_timestamp_anomalies(
+    Query(params): Query<WindowQuery>,
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> Result<Response, Response> {
+    let window = params.window.unwrap_or(timestamps::DEFAULT_WINDOW);
+    if window == 0 || window > timestamps::MAX_WINDOW {
+        return Err(StatusCode::BAD_REQUEST.into_response());
+    }
+
+    let path = state.config.database_path.clone();
+    let now = chrono::Utc::now().timestamp() as u64;
+    let report = r#async::run_blocking(state.config.read_timeout, "timestamp anomalies", move || timestamps::timestamp_anomalies(&path, window, now)).await.map_err(IntoResponse::into_response)?;
+    Ok(format.respond("timestamp-anomalies", report))
+}
+
+/// Get the busiest blocks from the background aggregate index via REST API
+#[utoipa::path(get, path = "/api/v1/analytics/top-blocks", tag = "analytics", params(TopBlocksQuery, FormatQuery),
+    responses(
+        (status = 200, description = "Highest blocks by the chosen measure", body = TopBlocks),
+        (status = 400, description = "Limit is 0 or above 500"),
+    ))]
+async fn get_top_blocks(
+    Query(params): Query<TopBlocksQuery>,
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> Result<Response, StatusCode> {
+    let limit = params.limit.unwrap_or(top_blocks::DEFAULT_LIMIT);
+    if limit == 0 || limit > top_blocks::MAX_LIMIT {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    Ok(format.respond("top-blocks", state.block_index.read().await.top(params.by.unwrap_or_default(), limit)))
+}
+
+/// Get kernels per block (or per bucket of blocks) from the cached series via REST API
+#[utoipa::path(get, path = "/api/v1/analytics/tx-volume", tag = "analytics", params(TxVolumeQuery, FormatQuery),
+    responses(
+        (status = 200, description = "Kernel counts per bucket of heights", body = TxVolume),
+        (status = 400, description = "from is above to, or bucket is 0"),
+    ))]
+async fn get_tx_volume(
+    Query(params): Query<TxVolumeQuery>,
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> Result<Response, StatusCode> {
+    if params.bucket == Some(0) || matches!((params.from, params.to), (Some(from), Some(to)) if from > to) {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+    Ok(format.respond("tx-volume", state.tx_volume.read().await.series(params.from, params.to, params.bucket)))
+}
+
+/// Get the rolling share of each PoW algorithm via REST API
+#[utoipa::path(get, path = "/api/v1/analytics/pow-share", tag = "analytics", params(PowShareQuery, FormatQuery),
+    responses((status = 200, description = "PoW algorithm shares per height", body = PowShare)))]
+async fn get_pow_share(
+    Query(params): Query<PowShareQuery>,
+    State(state): State<AppState>,
+    format: ResponseFormat,
+) -> Result<Response, Response> {
+    let window = params.window.unwrap_or(pow_share::DEFAULT_WINDOW);
+    let span = params.span.unwrap_or(pow_share::DEFAULT_SPAN);
+    if window == 0 || window > pow_share::MAX_WINDOW || span == 0 || span > pow_share::MAX_SPAN {
+        return Err(StatusCode::BAD_REQUEST.into_response());
+    }
+
+    let path = state.config.database_path.clone();
+    let share = r#async::run_blocking(state.config.read_timeout, "pow share", move || pow_share::pow_share(&path, window, span)).await.map_err(IntoResponse::into_response)?;
+    Ok(format.respond("pow-share", share))
+}
+
+/// Get the kernel count, UTXO count and emission as of a height via REST API
+#[utoipa::path(get, path = "/api/v1/stats/at/{height}", tag = "chain",
+    params(("height" = u64, Path, description = "Block height")),
+    responses(
+        (status = 200, description = "Chain state after the block at this height", body = ChainStateAt),
+        (status = 404, description = "No header at this height"),
+    ))]
+async fn get_state_at_height(
+    axum::extract::Path(height): axum::extract::Path<u64>,
+    State(state): State<AppState>,
+) -> Result<Json<ChainStateAt>, ReaderError> {
+    let path = state.config.database_path.clone();
+    let network = state.config.network;
+    let chain_state = r#async::run_blocking(state.config.read_timeout, "state at height", move || state_at::state_at_height(&path, height, network)).await?;
+    Ok(Json(chain_state))
+}
+
+/// Get the base node chain metadata via REST API
+#[utoipa::path(get, path = "/api/v1/metadata", tag = "chain",
+    responses(
+        (status = 200, description = "Chain tip and pruning info", body = ChainMetadata),
+        (status = 404, description = "No metadata table"),
+    ))]
+async fn get_chain_metadata(State(state): State<AppState>) -> Result<Json<ChainMetadata>, Response> {
+    match state.provider.chain_metadata().await {
+        Ok(Some(metadata)) => Ok(Json(metadata)),
+        Ok(None) => Err(StatusCode::NOT_FOUND.into_response()),
+        Err(e) => Err(e.into_response()),
+    }
+}
+
+/// Resolve a search box query via REST API
+#[utoipa::path(get, path = "/api/v1/search", tag = "chain", params(SearchParams),
+    responses(
+        (status = 200, description = "Typed search result", body = SearchResult),
+        (status = 400, description = "Not a height or 64 hex character hash"),
+    ))]
+async fn get_search(
+    Query(params): Query<SearchParams>,
+    State(state): State<AppState>,
+) -> Result<Json<SearchResult>, Response> {
+    let query = SearchQuery::parse(&params.q).ok_or_else(|| StatusCode::BAD_REQUEST.into_response())?;
+
+    match state.provider.search(query).await {
+        Ok(result) => Ok(Json(result)),
+        Err(e) if e.is_not_found() => Ok(Json(SearchResult::NotFound)),
+        Err(e) => Err(e.into_response()),
+    }
+}
+
+/// Check whether an output hash or commitment exists via REST API; a bloom filter miss answers
+/// without reading the database
+#[utoipa::path(get, path = "/api/v1/outputs/{hash}/exists", tag = "chain",
+    params(("hash" = String, Path, description = "Output hash or commitment (64 hex characters)")),
+    responses(
+        (status = 200, description = "Whether an output with this hash or commitment was mined", body = OutputExistence),
+        (status = 400, description = "Not 64 hex characters"),
+    ))]
+async fn get_output_exists(
+    axum::extract::Path(hash): axum::extract::Path<String>,
+    State(state): State<AppState>,
+) -> Result<Json<OutputExistence>, Response> {
+    let Some(SearchQuery::Hash(bytes)) = SearchQuery::parse(&hash) else {
+        return Err(StatusCode::BAD_REQUEST.into_response());
+    };
+
+    let tip = state.dashboard_data.read().await.network_stats.latest_block_height;
+    if state.utxo_bloom.read().await.definitely_absent(&bytes, tip) {
+        return Ok(Json(OutputExistence { exists: false, from_filter: true, height: None }));
+    }
+
+    match state.provider.search(SearchQuery::Hash(bytes)).await {
+        Ok(SearchResult::Output { height, .. }) => Ok(Json(OutputExistence { exists: true, from_filter: false, height: Some(height) })),
+        Ok(_) => Ok(Json(OutputExistence { exists: false, from_filter: false, height: None })),
+        Err(e) if e.is_not_found() => Ok(Json(OutputExistence { exists: false, from_filter: false, height: None })),
+        Err(e) => Err(e.into_response()),
+    }
+}
+
+/// Get the persisted chain event history via REST API
+#[utoipa::path(get, path = "/api/v1/events/history", tag = "chain", params(EventsQuery),
+    responses((status = 200, description = "Chain event timeline, newest first", body = EventsHistoryResponse)))]
+async fn get_events_history(
+    Query(params): Query<EventsQuery>,
+    State(state): State<AppState>,
+) -> Result<Json<EventsHistoryResponse>, StatusCode> {
+    let limit = params.limit.unwrap_or(100).min(1000);
+
+    match chain_events::EventStore::load(&state.config.cache.file(chain_events::STORE_FILE)) {
+        Ok(store) => {
+            Ok(Json(EventsHistoryResponse {
+                total_recorded: store.events.len(),
+                last_scanned_height: store.last_scanned_height,
+                events: store.recent(limit),
+            }))
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to load chain events: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+/// List watched commitments and kernel excesses via REST API
+#[utoipa::path(get, path = "/api/v1/watchlist", tag = "chain",
+    responses((status = 200, description = "Watched items", body = [WatchItem])))]
+async fn get_watchlist(State(state): State<AppState>) -> Json<Vec<WatchItem>> {
+    Json(state.watchlist.lock().await.items().to_vec())
+}
+
+/// Watch a commitment or kernel excess via REST API (kept in memory until restart)
+#[utoipa::path(post, path = "/api/v1/watchlist", tag = "chain", request_body = WatchItem,
+    responses(
+        (status = 201, description = "Added; the full watchlist is returned", body = [WatchItem]),
+        (status = 400, description = "Value is not 64 hex characters"),
+        (status = 409, description = "Already watched"),
+    ))]
+async fn add_watch_item(
+    State(state): State<AppState>,
+    Json(item): Json<WatchItem>,
+) -> Result<(StatusCode, Json<Vec<WatchItem>>), StatusCode> {
+    let Some(item) = item.normalized() else {
+        return Err(StatusCode::BAD_REQUEST);
+    };
+    // A commitment the bloom filter has never seen is not on chain yet
+    if item.kind == WatchKind::Commitment {
+        let tip = state.dashboard_data.read().await.network_stats.latest_block_height;
+        let bloom = state.utxo_bloom.read().await;
+        if hex::decode(&item.value).is_ok_and(|bytes| bloom.definitely_absent(&bytes, tip)) {
+            println!("👀 Watching commitment {} (not on chain yet)", item.value);
+        }
+    }
+    let mut watchlist = state.watchlist.lock().await;
+    if !watchlist.add(item) {
+        return Err(StatusCode::CONFLICT);
+    }
+    Ok((StatusCode::CREATED, Json(watchlist.items().to_vec())))
+}
+
+/// WebSocket client and per-route request metrics in the Prometheus text format
+async fn get_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        state.ws_clients.render_prometheus() + &state.requests.render_prometheus(),
+    )
 }
 
 /// WebSocket connection handler
 async fn websocket_handler(
     ws: WebSocketUpgrade,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     State(state): State<AppState>,
 ) -> impl IntoResponse {
-    ws.on_upgrade(move |socket| handle_websocket(socket, state))
+    ws.max_message_size(state.config.limits.max_ws_message_bytes)
+        .on_upgrade(move |socket| handle_websocket(socket, addr, state))
+}
+
+/// Serialize a message for the socket, replacing it with `LimitExceeded` when it is over the
+/// WebSocket message cap
+fn encode_ws_message(message: &WebSocketMessage, state: &AppState) -> Option<String> {
+    let json = serde_json::to_string(message).ok()?;
+    match state.config.limits.check_ws_message(json.len() as u64) {
+        Ok(()) => Some(json),
+        Err(error) => serde_json::to_string(&WebSocketMessage::LimitExceeded { error }).ok(),
+    }
 }
 
 /// Handle individual WebSocket connections
-async fn handle_websocket(socket: WebSocket, state: AppState) {
+async fn handle_websocket(socket: WebSocket, addr: SocketAddr, state: AppState) {
+    let client = state.ws_clients.connect(addr);
+    if verbosity::debug() {
+        println!("🔌 WebSocket client {} connected ({} total)", addr, state.ws_clients.connected());
+    }
     let (mut sender, mut receiver) = socket.split();
 
     // Send initial dashboard data
@@ -350,39 +1571,99 @@ async fn handle_websocket(socket: WebSocket, state: AppState) {
         data: dashboard_data.clone(),
     };
     
-    if let Ok(json) = serde_json::to_string(&message) {
+    if let Some(json) = encode_ws_message(&message, &state) {
         if sender.send(Message::Text(json)).await.is_err() {
             return;
         }
+        client.record_sent();
     }
     drop(dashboard_data);
 
     // Subscribe to updates and spawn a task to handle them
     let mut update_receiver = state.update_broadcaster.subscribe();
+    let mut progress_receiver = state.progress_broadcaster.subscribe();
+    let mut watch_receiver = state.watch_broadcaster.subscribe();
     let (update_tx, mut update_rx) = tokio::sync::mpsc::channel(100);
+    let response_tx = update_tx.clone();
     
-    // Spawn task to forward broadcasts to this channel
-    tokio::spawn(async move {
-        while let Ok(dashboard_data) = update_receiver.recv().await {
-            let message = WebSocketMessage::DashboardData { data: dashboard_data };
+    // Spawn task to forward dashboard updates, scan progress and watch hits to this channel
+    let forwarder = tokio::spawn(async move {
+        loop {
+            let message = tokio::select! {
+                update = update_receiver.recv() => match update {
+                    Ok(dashboard_data) => WebSocketMessage::DashboardData { data: dashboard_data },
+                    Err(_) => break,
+                },
+                event = progress_receiver.recv() => match event {
+                    Ok(progress) => WebSocketMessage::Progress { progress },
+                    // Later events supersede the ones a slow client missed
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(_) => break,
+                },
+                hit = watch_receiver.recv() => match hit {
+                    Ok(hit) => WebSocketMessage::WatchHit { hit },
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(_) => break,
+                },
+            };
             if update_tx.send(message).await.is_err() {
                 break;
             }
         }
     });
 
-    // Handle incoming messages and updates
+    // Server-initiated pings; the first tick fires immediately, so skip it
+    let mut heartbeat = tokio::time::interval(ws_clients::PING_INTERVAL);
+    heartbeat.tick().await;
+    let mut missed_pongs = 0;
+
+    // In-flight requests; aborting them on disconnect cancels their LMDB scans
+    let mut requests = tokio::task::JoinSet::new();
+
+    // The running block stream, if any; dropping it stops the stream
+    let mut stream: Option<BlockStream> = None;
+    let mut streams_started = 0;
+
+    // Handle incoming messages, updates and heartbeats
     loop {
         tokio::select! {
+            // Reap finished requests; their responses arrive through `update_rx`
+            Some(_) = requests.join_next(), if !requests.is_empty() => {}
+
             // Handle update messages
             update_msg = update_rx.recv() => {
                 if let Some(message) = update_msg {
-                    if let Ok(json) = serde_json::to_string(&message) {
+                    if let Some(json) = encode_ws_message(&message, &state) {
                         if sender.send(Message::Text(json)).await.is_err() {
                             break;
                         }
+                        client.record_sent();
+                    }
+                }
+            }
+
+            // Tell the client the server is going away, then close so the server can drain
+            _ = state.shutdown.cancelled() => {
+                if let Some(json) = encode_ws_message(&WebSocketMessage::Shutdown, &state) {
+                    let _ = sender.send(Message::Text(json)).await;
+                }
+                let _ = sender.send(Message::Close(None)).await;
+                break;
+            }
+
+            // Ping the client, dropping it once it stops answering
+            _ = heartbeat.tick() => {
+                if missed_pongs >= ws_clients::MAX_MISSED_PONGS {
+                    if verbosity::debug() {
+                        println!("💤 WebSocket client {} missed {} pongs, disconnecting", addr, missed_pongs);
                     }
+                    client.record_idle_disconnect();
+                    break;
                 }
+                if sender.send(Message::Ping(Vec::new())).await.is_err() {
+                    break;
+                }
+                missed_pongs += 1;
             }
             
             // Handle incoming messages from client
@@ -394,16 +1675,38 @@ async fn handle_websocket(socket: WebSocket, state: AppState) {
                         break;
                     };
 
+                    // Any traffic proves the client is alive
+                    missed_pongs = 0;
+
                     match msg {
                         Message::Text(text) => {
-                            if let Ok(request) = serde_json::from_str::<WebSocketMessage>(&text) {
-                                let response = handle_websocket_message(request, &state).await;
-                                
-                                if let Ok(json) = serde_json::to_string(&response) {
-                                    if sender.send(Message::Text(json)).await.is_err() {
-                                        break;
+                            client.record_received();
+                            match serde_json::from_str::<WebSocketMessage>(&text) {
+                                Ok(WebSocketMessage::StreamBlocks { from, to }) => {
+                                    streams_started += 1;
+                                    let tip = state.dashboard_data.read().await.network_stats.latest_block_height;
+                                    stream = Some(BlockStream::start(streams_started, from, to.min(tip), state.config.timezone, state.provider.clone(), response_tx.clone()));
+                                }
+                                Ok(WebSocketMessage::StreamAck { stream: id, seq }) => {
+                                    if let Some(running) = stream.as_ref().filter(|running| running.id() == id) {
+                                        running.ack(seq);
                                     }
                                 }
+                                Ok(WebSocketMessage::StopStream { stream: id }) => {
+                                    if stream.as_ref().is_some_and(|running| running.id() == id) {
+                                        stream = None;
+                                    }
+                                }
+                                Ok(request) => {
+                                    // Answered off the loop so heartbeats keep flowing during long reads
+                                    let state = state.clone();
+                                    let response_tx = response_tx.clone();
+                                    requests.spawn(async move {
+                                        let response = handle_websocket_message(request, &state).await;
+                                        let _ = response_tx.send(response).await;
+                                    });
+                                }
+                                Err(_) => {}
                             }
                         }
                         Message::Close(_) => break,
@@ -415,6 +1718,15 @@ async fn handle_websocket(socket: WebSocket, state: AppState) {
             }
         }
     }
+
+    // Stop relaying broadcasts to a socket that is gone and cancel its pending reads
+    forwarder.abort();
+    requests.abort_all();
+    drop(stream);
+    drop(client);
+    if verbosity::debug() {
+        println!("🔌 WebSocket client {} disconnected ({} remaining)", addr, state.ws_clients.connected());
+    }
 }
 
 /// Handle individual WebSocket messages
@@ -429,56 +1741,40 @@ async fn handle_websocket_message(
         }
         
         WebSocketMessage::GetBlockDetail { height } => {
-            match read_block_with_transactions(&state.config.database_path, height) {
-                Ok(block_detail) => {
-                    let block_info = crate::data_models::BlockInfo {
-                        height: block_detail.height,
-                        hash: block_detail.hash.clone(),
-                        timestamp: block_detail.header.timestamp,
-                        transaction_count: block_detail.transactions.inputs.len() + 
-                                         block_detail.transactions.outputs.len() + 
-                                         block_detail.transactions.kernels.len(),
-                        interval_seconds: None,
-                        pow_algorithm: Some(block_detail.header.pow_algorithm.clone()),
-                    };
-                    
-                    let transactions = crate::data_models::TransactionDetail {
-                        inputs: block_detail.transactions.inputs.into_iter().map(|i| {
-                            crate::data_models::InputInfo {
-                                commitment: i.commitment,
-                                input_type: i.input_type,
-                                amount: None,
-                            }
-                        }).collect(),
-                        outputs: block_detail.transactions.outputs.into_iter().map(|o| {
-                            crate::data_models::OutputInfo {
-                                commitment: o.commitment,
-                                features: o.features,
-                                amount: None,
-                                script_type: o.script_type,
-                            }
-                        }).collect(),
-                        kernels: block_detail.transactions.kernels.into_iter().map(|k| {
-                            crate::data_models::KernelInfo {
-                                excess: k.excess,
-                                fee: k.fee,
-                                lock_height: k.lock_height,
-                            }
-                        }).collect(),
-                    };
-                    
-                    WebSocketMessage::BlockDetail {
-                        height,
-                        block_info,
-                        transactions,
-                    }
-                }
+            match state.provider.block(height).await {
+                Ok(block_detail) => block_detail_message(block_detail.into(), state),
                 Err(e) => WebSocketMessage::Error {
                     message: format!("Failed to get block {}: {}", height, e),
                 },
             }
         }
+
+        WebSocketMessage::GetBlockByHash { hash } => {
+            if hash.len() != 64 || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                return WebSocketMessage::Error {
+                    message: "Block hash must be 64 hex characters".to_string(),
+                };
+            }
+            match state.provider.block_by_hash(&hash).await {
+                Ok(Some(block_detail)) => block_detail_message(block_detail.into(), state),
+                Ok(None) => WebSocketMessage::Error {
+                    message: format!("No block with hash {}", hash),
+                },
+                Err(e) => WebSocketMessage::Error {
+                    message: format!("Failed to get block {}: {}", hash, e),
+                },
+            }
+        }
         
+        WebSocketMessage::GetBlockTransactions { height, component, offset, limit } => {
+            match state.provider.transactions(height, component, Page::new(offset, limit)).await {
+                Ok(page) => WebSocketMessage::BlockTransactions { page: page.into() },
+                Err(e) => WebSocketMessage::Error {
+                    message: format!("Failed to get block {} transactions: {}", height, e),
+                },
+            }
+        }
+
         WebSocketMessage::Ping => WebSocketMessage::Pong,
         
         _ => WebSocketMessage::Error {
@@ -487,204 +1783,96 @@ async fn handle_websocket_message(
     }
 }
 
-/// Update dashboard data from LMDB (now only called when LMDB files change)
+/// `BlockDetail` for a block within the transaction item cap, else `LimitExceeded`
+fn block_detail_message(block: BlockDetailResponse, state: &AppState) -> WebSocketMessage {
+    match state.config.limits.check_tx_items(transaction_items(&block)) {
+        Ok(()) => WebSocketMessage::BlockDetail { block: block.localized(&state.config.timezone) },
+        Err(error) => WebSocketMessage::LimitExceeded { error },
+    }
+}
+
+/// Read a fresh dashboard snapshot and apply it
 async fn update_dashboard_data(state: &AppState) -> Result<()> {
-    println!("🔄 Reading LMDB data...");
-    
-    // Try to read real blocks and calculate real statistics
-    let (recent_blocks, database_stats) = match read_lmdb_headers_with_filter(&state.config.database_path, "headers", BlockFilter::LastN(1000)) {
-        Ok(blocks) => {
-            println!("📊 Loaded {} blocks to cache for network analysis", blocks.len());
-            
-            // Convert and sort blocks by height (newest first)
-            let mut recent_blocks: Vec<crate::data_models::BlockInfo> = blocks.into_iter().map(|block| {
-                crate::data_models::BlockInfo {
-                    height: block.height,
-                    hash: block.hash,
-                    timestamp: block.header.timestamp,
-                    transaction_count: 0,
-                    interval_seconds: None,
-                    pow_algorithm: Some(block.header.pow_algorithm),
-                }
-            }).collect();
-            
-            // Sort by height descending (newest first)
-            recent_blocks.sort_by(|a, b| b.height.cmp(&a.height));
-            
-            // Calculate intervals between consecutive blocks
-            for i in 0..recent_blocks.len().saturating_sub(1) {
-                let current = &recent_blocks[i];
-                let previous = &recent_blocks[i + 1];
-                
-                if current.timestamp > previous.timestamp {
-                    recent_blocks[i].interval_seconds = Some((current.timestamp - previous.timestamp) as i64);
-                }
-            }
-            
-            // Take top 200 for display (from 1000 available)
-            let display_count = recent_blocks.len().min(200);
-            recent_blocks.truncate(display_count);
-            println!("🖥️  Displaying {} most recent blocks in dashboard", display_count);
-            
-            // Calculate REAL database statistics by counting actual LMDB entries
-            let database_stats = calculate_real_database_stats(&state.config.database_path).await;
-            
-            (recent_blocks, database_stats)
-        },
-        Err(e) => {
-            println!("⚠️  Could not read from LMDB ({}), using mock data", e);
-            
-            // Generate mock blocks for demo (200 blocks)
-            let now = chrono::Utc::now().timestamp() as u64;
-            let mock_blocks = (0..200).map(|i| {
-                crate::data_models::BlockInfo {
-                    height: 100000 - i,
-                    hash: format!("0x{:064x}", 1000000 - i),
-                    timestamp: now - (i * 120), // 2 minute intervals
-                    transaction_count: 5 + (i % 3) as usize,
-                    interval_seconds: if i < 199 { Some(120) } else { None },
-                    pow_algorithm: Some("RandomXM".to_string()),
-                }
-            }).collect();
-            
-            let database_stats = DatabaseStats {
-                utxos_count: 1_234_567,
-                inputs_count: 987_654,
-                kernels_count: 543_210,
-                total_transactions: 543_210,
-                total_io_records: 2_222_221,
-            };
-            
-            (mock_blocks, database_stats)
-        }
-    };
-    
-    // Calculate network stats from the blocks
-    let latest_height = recent_blocks.first().map(|b| b.height).unwrap_or(0);
-    
-    // Calculate average block time from intervals
-    let valid_intervals: Vec<i64> = recent_blocks.iter()
-        .filter_map(|b| b.interval_seconds)
-        .filter(|&interval| interval > 0 && interval < 3600)
-        .collect();
-    
-    let average_block_time = if !valid_intervals.is_empty() {
-        valid_intervals.iter().sum::<i64>() / valid_intervals.len() as i64
-    } else {
-        120
-    };
-    
-    let tps = if average_block_time > 0 {
-        10.0 / average_block_time as f64 // Estimate 10 transactions per block
-    } else {
-        0.083 // ~1 transaction per 12 seconds
-    };
+    let data = state.engine.read().await?;
+    apply_dashboard(state, data).await;
+    Ok(())
+}
 
-    let network_stats = crate::data_models::NetworkStats {
-        latest_block_height: latest_height,
-        average_block_time,
-        transactions_per_second: tps.max(0.001), // Minimum TPS
-        utxo_set_size: database_stats.utxos_count,
-    };
+/// Store a snapshot from the engine and bring the block cache and derived indexes up to date
+async fn apply_dashboard(state: &AppState, fresh: DashboardData) {
+    let latest_height = fresh.network_stats.latest_block_height;
 
     // Update shared state
     let mut data = state.dashboard_data.write().await;
-    data.database_stats = database_stats;
-    data.recent_blocks = recent_blocks;
-    data.network_stats = network_stats;
-    data.last_updated = chrono::Utc::now().timestamp() as u64;
+    *data = fresh.localized(&state.config.timezone);
+
+    // Drop cached details for the old tip and for any block replaced by a reorg
+    let mut cache = state.block_cache.lock().await;
+    cache.reconcile(latest_height, &data.recent_blocks);
+    let (cached, hits, misses) = cache.stats();
+    println!("🗄️  Block cache: {} entries ({} hits, {} misses)", cached, hits, misses);
+    drop(cache);
     
-    println!("⚡ Full blockchain searchable via search/range/hash queries");
-    println!("✅ Dashboard ready - latest height: {}", latest_height);
+    drop(data);
 
-    Ok(())
-}
+    // Refresh the chain event timeline from the same LMDB change
+    if state.config.demo {
+        println!("✅ Dashboard ready (demo data) - latest height: {}", latest_height);
+        return;
+    }
+    let events_db_path = state.config.database_path.clone();
+    let events_store_path = state.config.cache.file(chain_events::STORE_FILE);
+    let events_result = r#async::run_blocking(state.config.read_timeout, "refresh chain events", move || {
+        chain_events::refresh_events(&events_db_path, &events_store_path, chain_events::DEFAULT_SCAN_WINDOW)
+    }).await;
+    match events_result {
+        Ok(store) => println!("🗓️  Chain events: {} recorded", store.events.len()),
+        Err(e) => eprintln!("⚠️  Could not refresh chain events: {}", e),
+    }
 
-/// Calculate real database statistics by scanning LMDB
-async fn calculate_real_database_stats(database_path: &std::path::Path) -> DatabaseStats {
-    println!("🔍 Scanning LMDB for real statistics...");
-    
-    // Try to get real counts (this is expensive, so we do it occasionally)
-    let (utxos_count, inputs_count, kernels_count) = tokio::task::spawn_blocking({
-        let path = database_path.to_path_buf();
-        move || {
-            let mut utxos = 0;
-            let mut inputs = 0; 
-            let mut kernels = 0;
-            
-            // Try to count actual database entries
-            if let Ok(mut builder) = lmdb_zero::EnvBuilder::new() {
-                if builder.set_maxdbs(40).is_ok() {
-                    if let Ok(env) = unsafe { builder.open(&path.to_string_lossy(), lmdb_zero::open::Flags::empty(), 0o600) } {
-                        
-                        // Count UTXOs
-                        if let Ok(utxos_db) = lmdb_zero::Database::open(&env, Some("utxos"), &lmdb_zero::DatabaseOptions::defaults()) {
-                            if let Ok(txn) = lmdb_zero::ReadTransaction::new(&env) {
-                                utxos = count_db_entries_fast(&txn, &utxos_db);
-                            }
-                        }
-                        
-                        // Count Inputs  
-                        if let Ok(inputs_db) = lmdb_zero::Database::open(&env, Some("inputs"), &lmdb_zero::DatabaseOptions::defaults()) {
-                            if let Ok(txn) = lmdb_zero::ReadTransaction::new(&env) {
-                                inputs = count_db_entries_fast(&txn, &inputs_db);
-                            }
-                        }
-                        
-                        // Count Kernels
-                        if let Ok(kernels_db) = lmdb_zero::Database::open(&env, Some("kernels"), &lmdb_zero::DatabaseOptions::defaults()) {
-                            if let Ok(txn) = lmdb_zero::ReadTransaction::new(&env) {
-                                kernels = count_db_entries_fast(&txn, &kernels_db);
-                            }
-                        }
-                    }
-                }
+    // Extend the cached kernel count series from the same change
+    let volume_db_path = state.config.database_path.clone();
+    let volume_cache_path = state.config.cache.file(tx_volume::CACHE_FILE);
+    let volume_result = r#async::run_blocking(state.config.read_timeout, "refresh tx volume", move || {
+        tx_volume::refresh_tx_volume(&volume_db_path, &volume_cache_path)
+    }).await;
+    match volume_result {
+        Ok(cache) => {
+            if let Some(covered_to) = cache.covered_to() {
+                println!("📈 Tx volume: cached to height {}", covered_to);
             }
-            
-            (utxos, inputs, kernels)
-        }
-    }).await.unwrap_or((0, 0, 0));
-    
-    println!("📊 Database stats: UTXOs: {}, Inputs: {}, Kernels: {}", 
-             utxos_count.to_string().as_str(), 
-             inputs_count.to_string().as_str(), 
-             kernels_count.to_string().as_str());
-    
-    DatabaseStats {
-        utxos_count,
-        inputs_count,
-        kernels_count,
-        total_transactions: kernels_count, // 1 kernel = 1 transaction
-        total_io_records: utxos_count + inputs_count,
-    }
-}
-
-/// Fast database entry counting without limits
-fn count_db_entries_fast(txn: &lmdb_zero::ReadTransaction, db: &lmdb_zero::Database) -> usize {
-    match txn.cursor(db) {
-        Ok(mut cursor) => {
-            let access = txn.access();
-            if cursor.first::<[u8], [u8]>(&access).is_ok() {
-                let mut count = 1;
-                
-                loop {
-                    if cursor.next::<[u8], [u8]>(&access).is_err() {
-                        break;
-                    }
-                    count += 1;
-                    
-                    // Show progress every 500k entries
-                    if count % 500_000 == 0 {
-                        print!("{}M.", count / 1_000_000);
-                    }
-                }
-                
-                println!(" {} total entries", count.to_string());
-                count
-            } else {
-                0
+            *state.tx_volume.write().await = cache;
+        },
+        Err(e) => eprintln!("⚠️  Could not refresh tx volume: {}", e),
+    }
+
+    // Extend the cached height → hash map the same way
+    let hashes_db_path = state.config.database_path.clone();
+    let hashes_cache = state.config.cache.clone();
+    let hashes_result = r#async::run_blocking(state.config.read_timeout, "refresh height hashes", move || {
+        cache::refresh_height_hashes(&hashes_db_path, &hashes_cache)
+    }).await;
+    match hashes_result {
+        Ok(hashes) => *state.height_hashes.write().await = hashes,
+        Err(e) => eprintln!("⚠️  Could not refresh height → hash map: {}", e),
+    }
+
+    // And the output bloom filter
+    let bloom_db_path = state.config.database_path.clone();
+    let bloom_cache = state.config.cache.clone();
+    let bloom_result = r#async::run_blocking(state.config.read_timeout, "refresh output bloom filter", move || {
+        utxo_bloom::refresh_utxo_bloom(&bloom_db_path, &bloom_cache)
+    }).await;
+    match bloom_result {
+        Ok(bloom) => {
+            if let Some(covered_to) = bloom.covered_to() {
+                println!("🌸 Output bloom filter: {} keys to height {}", bloom.keys(), covered_to);
             }
+            *state.utxo_bloom.write().await = bloom;
         },
-        Err(_) => 0,
+        Err(e) => eprintln!("⚠️  Could not refresh output bloom filter: {}", e),
     }
-}
\ No newline at end of file
+
+    println!("⚡ Full blockchain searchable via search/range/hash queries");
+    println!("✅ Dashboard ready - latest height: {}", latest_height);
+}
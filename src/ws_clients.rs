@@ -0,0 +1,142 @@
+// File: src/ws_clients.rs
+// Version: 1.0.0 - WebSocket client registry, heartbeat settings and /metrics output
+// Tree: tari-lmdb-inspector/src/ws_clients.rs
+//
+// Every `/ws` connection registers here for its lifetime. The server pings each client every
+// `PING_INTERVAL` and drops it after `MAX_MISSED_PONGS` unanswered pings, so dead sockets no
+// longer linger. Connection counts and per-client message rates are rendered in the Prometheus
+// text format on `/metrics`.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Interval between server-initiated pings
+pub const PING_INTERVAL: Duration = Duration::from_secs(20);
+
+/// Unanswered pings before a client is considered dead
+pub const MAX_MISSED_PONGS: u32 = 3;
+
+/// Counters for one connected client
+struct ClientStats {
+    addr: SocketAddr,
+    connected_at: Instant,
+    messages_sent: u64,
+    messages_received: u64,
+}
+
+#[derive(Default)]
+struct Registry {
+    next_id: u64,
+    clients: HashMap<u64, ClientStats>,
+    total_connections: u64,
+    idle_disconnects: u64,
+}
+
+/// Connected WebSocket clients, shared through `AppState`
+#[derive(Clone, Default)]
+pub struct ClientRegistry {
+    inner: Arc<Mutex<Registry>>,
+}
+
+/// Registration for one connection; unregisters on drop
+pub struct ClientHandle {
+    id: u64,
+    registry: ClientRegistry,
+}
+
+impl ClientRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Registry> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Register a new connection from `addr`
+    pub fn connect(&self, addr: SocketAddr) -> ClientHandle {
+        let mut registry = self.lock();
+        registry.next_id += 1;
+        registry.total_connections += 1;
+        let id = registry.next_id;
+        registry.clients.insert(id, ClientStats {
+            addr,
+            connected_at: Instant::now(),
+            messages_sent: 0,
+            messages_received: 0,
+        });
+        ClientHandle { id, registry: self.clone() }
+    }
+
+    /// Number of currently connected clients
+    pub fn connected(&self) -> usize {
+        self.lock().clients.len()
+    }
+
+    /// Prometheus text exposition of the client metrics
+    pub fn render_prometheus(&self) -> String {
+        let registry = self.lock();
+        let mut out = String::new();
+
+        let _ = writeln!(out, "# HELP inspector_ws_connected_clients WebSocket clients currently connected");
+        let _ = writeln!(out, "# TYPE inspector_ws_connected_clients gauge");
+        let _ = writeln!(out, "inspector_ws_connected_clients {}", registry.clients.len());
+        let _ = writeln!(out, "# HELP inspector_ws_connections_total WebSocket connections accepted since start");
+        let _ = writeln!(out, "# TYPE inspector_ws_connections_total counter");
+        let _ = writeln!(out, "inspector_ws_connections_total {}", registry.total_connections);
+        let _ = writeln!(out, "# HELP inspector_ws_idle_disconnects_total Clients dropped after {} missed pongs", MAX_MISSED_PONGS);
+        let _ = writeln!(out, "# TYPE inspector_ws_idle_disconnects_total counter");
+        let _ = writeln!(out, "inspector_ws_idle_disconnects_total {}", registry.idle_disconnects);
+
+        let mut clients: Vec<_> = registry.clients.iter().collect();
+        clients.sort_by_key(|(id, _)| **id);
+
+        let _ = writeln!(out, "# HELP inspector_ws_client_messages_sent_total Messages sent to a client");
+        let _ = writeln!(out, "# TYPE inspector_ws_client_messages_sent_total counter");
+        for (id, client) in &clients {
+            let _ = writeln!(out, "inspector_ws_client_messages_sent_total{{client=\"{}\",addr=\"{}\"}} {}", id, client.addr, client.messages_sent);
+        }
+        let _ = writeln!(out, "# HELP inspector_ws_client_messages_received_total Messages received from a client");
+        let _ = writeln!(out, "# TYPE inspector_ws_client_messages_received_total counter");
+        for (id, client) in &clients {
+            let _ = writeln!(out, "inspector_ws_client_messages_received_total{{client=\"{}\",addr=\"{}\"}} {}", id, client.addr, client.messages_received);
+        }
+        let _ = writeln!(out, "# HELP inspector_ws_client_messages_per_minute Average messages per minute (sent + received) since connecting");
+        let _ = writeln!(out, "# TYPE inspector_ws_client_messages_per_minute gauge");
+        for (id, client) in &clients {
+            let minutes = (client.connected_at.elapsed().as_secs_f64() / 60.0).max(1.0 / 60.0);
+            let rate = (client.messages_sent + client.messages_received) as f64 / minutes;
+            let _ = writeln!(out, "inspector_ws_client_messages_per_minute{{client=\"{}\",addr=\"{}\"}} {:.2}", id, client.addr, rate);
+        }
+
+        out
+    }
+}
+
+impl ClientHandle {
+    pub fn record_sent(&self) {
+        if let Some(client) = self.registry.lock().clients.get_mut(&self.id) {
+            client.messages_sent += 1;
+        }
+    }
+
+    pub fn record_received(&self) {
+        if let Some(client) = self.registry.lock().clients.get_mut(&self.id) {
+            client.messages_received += 1;
+        }
+    }
+
+    /// Count this connection as dropped for missing pongs
+    pub fn record_idle_disconnect(&self) {
+        self.registry.lock().idle_disconnects += 1;
+    }
+}
+
+impl Drop for ClientHandle {
+    fn drop(&mut self) {
+        self.registry.lock().clients.remove(&self.id);
+    }
+}
@@ -0,0 +1,1726 @@
+// File: tests/reader.rs
+// Integration tests for the LMDB reader against synthetic fixtures
+
+use tari_lmdb_inspector::lmdb_reader::{
+    count_block_kernels_fast, count_transaction_tables, iter_headers_desc, read_block, read_block_with_transactions, read_blocks_with_transactions,
+    read_block_transactions, read_lmdb_headers_with_filter, search_block_by_hash, BlockCounts, BlockFilter, BlockInclude, KernelKind, OutputKind, Page,
+    ScriptPattern, ScriptSummary, SpendStatus, TxComponent, MAX_PAGE_LIMIT,
+};
+use tari_lmdb_inspector::analytics::burns::burn_analytics;
+use tari_lmdb_inspector::audit::roots::verify_roots;
+use tari_lmdb_inspector::audit::double_spends::find_double_spends;
+use tari_lmdb_inspector::audit::follow::{audit_blocks, AuditRule, AuditStatus};
+use tari_lmdb_inspector::audit::timestamps::{anomalies_from_timestamps, median_timestamp, timestamp_anomalies, AnomalyKind, FUTURE_TIME_LIMIT};
+use tari_lmdb_inspector::analytics::csv::to_csv;
+use tari_lmdb_inspector::analytics::intervals::{IntervalBucket, IntervalHistogram};
+use tari_lmdb_inspector::analytics::state_at::state_at_height;
+use tari_lmdb_inspector::analytics::pow_share::{pow_share, share_from_algorithms};
+use tari_lmdb_inspector::analytics::top_blocks::{AggregateIndex, TopBy};
+use tari_lmdb_inspector::analytics::tx_volume::{refresh_tx_volume, TxVolumeCache};
+use tari_lmdb_inspector::auth::parse_token;
+use tari_lmdb_inspector::block_cache::BlockCache;
+use tari_lmdb_inspector::cache::{refresh_height_hashes, CacheDir, HeightHashes, TipKey, HEIGHT_HASHES_FILE};
+use tari_lmdb_inspector::data_models::{AppConfig, BlockDetailResponse, BlockTransactionsPage, DashboardData, WebSocketMessage};
+use tari_lmdb_inspector::dashboard_engine::DashboardEngine;
+use tari_lmdb_inspector::data_provider::{self, MockProvider};
+use tari_lmdb_inspector::block_stream::{chunk_count, BlockStream, STREAM_CHUNK, STREAM_WINDOW};
+use tari_lmdb_inspector::file_watcher::{is_database_write, min_interval, network_filesystem_in, LmdbWatcher, TipCoalescer, TipMark, WatchMode, DEFAULT_POLL_INTERVAL};
+use tari_lmdb_inspector::key_inspector::{guess_value_types, pattern_heights, prefix_histogram, raw_header, table_stats, tip_height, RowType};
+use tari_lmdb_inspector::preflight::preflight;
+use tari_lmdb_inspector::genesis::{genesis_hash, genesis_report, network_of, KNOWN_NETWORKS};
+use tari_common::configuration::Network;
+use tari_lmdb_inspector::lmdb_reader::metadata::read_chain_metadata;
+use tari_lmdb_inspector::lmdb_reader::paths::{default_database_path, env_path};
+use tari_lmdb_inspector::lmdb_reader::{annotate_header, open_backend, with_backend, BackendKind, BlockSummary, HeaderView, ReaderError, StreamReader, TransactionKernelRowData, TransactionOutputRowData};
+use tari_lmdb_inspector::mmr::{resolve_position, MmrKind, MmrLookup};
+use tari_lmdb_inspector::lmdb_reader::parallel::{par_find_chunks, par_map_chunks, PARALLEL_THRESHOLD};
+use tari_lmdb_inspector::output_scanner::{parse_public_key, pays_to, scan_outputs};
+use tari_lmdb_inspector::progress::{NoProgress, SharedProgress};
+use tari_lmdb_inspector::notify::{Notifier, NotifyConfig, NotifyEvent};
+use tari_lmdb_inspector::sync_status::{SyncStatus, SyncTracker};
+use tari_lmdb_inspector::search::{search, SearchQuery, SearchResult};
+use tari_lmdb_inspector::theme::{Theme, ThemeName};
+use tari_lmdb_inspector::timezone::DisplayZone;
+use tari_lmdb_inspector::denomination::{tari, Denomination};
+use tari_lmdb_inspector::verbosity::{self, Verbosity};
+use tari_lmdb_inspector::lmdb_reader::r#async::{measure_reads, AsyncReader};
+use tari_lmdb_inspector::request_trace::{redacted_uri, RequestMetrics, SLOW_REQUEST};
+use tari_lmdb_inspector::limits::{LimitKind, Limits};
+use tari_lmdb_inspector::version::{is_newer, VersionInfo};
+use tari_lmdb_inspector::utxo_bloom::{refresh_utxo_bloom, UtxoBloom, UTXO_BLOOM_FILE};
+use tari_lmdb_inspector::tui_dashboard::{restore_terminal, TuiApp, TuiMessage, TuiTab, ERROR_LOG_FILE};
+use tari_lmdb_inspector::shutdown::Signal;
+use tari_lmdb_inspector::daemon::{self, running_pid};
+use tari_lmdb_inspector::web_server::NodeProfile;
+use tari_lmdb_inspector::watchlist::{WatchItem, WatchKind, WatchLocation, Watchlist};
+use tari_lmdb_inspector::testutil::{add_duplicate_spend, build_chain, drop_table, ChainSpec, FixtureBlock, FIXTURE_ACCUMULATED_WORK, FIXTURE_BURN_VALUE};
+use tokio_util::sync::CancellationToken;
+use crossterm::event::KeyCode;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn fixture(spec: ChainSpec) -> (tempfile::TempDir, Vec<FixtureBlock>) {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let blocks = build_chain(dir.path(), &spec).expect("fixture builds");
+    (dir, blocks)
+}
+
+fn app_config(database_path: PathBuf) -> AppConfig {
+    AppConfig {
+        database_path,
+        notify: None,
+        read_timeout: Duration::from_secs(5),
+        demo: false,
+        watchlist_path: None,
+        network: Network::MainNet,
+        cache: CacheDir::new(PathBuf::from("cache")),
+        theme: Theme::default(),
+        max_update_rate: None,
+        watch_mode: WatchMode::default(),
+        poll_interval: DEFAULT_POLL_INTERVAL,
+        timezone: DisplayZone::default(),
+        denom: Denomination::default(),
+        verbosity: Verbosity::default(),
+        limits: Limits::default(),
+    }
+}
+
+#[test]
+fn block_detail_includes_kernels_and_outputs() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let expected = &blocks[5];
+
+    let detail = read_block_with_transactions(dir.path(), 5).unwrap();
+
+    assert_eq!(detail.height, 5);
+    assert_eq!(detail.hash, expected.hash);
+    assert_eq!(detail.header.timestamp, expected.timestamp);
+    assert_eq!(detail.transactions.outputs.len() as u64, expected.output_count);
+    // Rows are keyed by row hash, so compare fees regardless of order
+    let mut fees: Vec<u64> = detail.transactions.kernels.iter().map(|k| k.fee).collect();
+    fees.sort_unstable();
+    assert_eq!(fees, expected.kernel_fees);
+}
+
+#[test]
+fn block_reads_load_only_the_included_parts() {
+    let (dir, blocks) = fixture(ChainSpec { spends_per_block: 1, ..ChainSpec::default() });
+    let expected = &blocks[5];
+
+    let counted = read_block(dir.path(), 5, BlockInclude::parse("counts").unwrap()).unwrap();
+    assert_eq!(counted.hash, expected.hash);
+    assert!(counted.transactions.kernels.is_empty() && counted.transactions.outputs.is_empty());
+    let counts = counted.counts.expect("counts requested");
+    assert_eq!((counts.kernels, counts.outputs, counts.inputs), (expected.kernel_fees.len() as u64, expected.output_count, 1));
+
+    // Prefix counts agree with the decoded rows
+    let both = read_block(dir.path(), 5, BlockInclude::parse("transactions, counts").unwrap()).unwrap();
+    assert_eq!(both.counts, Some(BlockCounts::of(&both.transactions)));
+    assert_eq!(read_block_with_transactions(dir.path(), 5).unwrap().counts, None);
+
+    assert_eq!(BlockInclude::parse(""), Some(BlockInclude::HEADER));
+    assert_eq!(BlockInclude::parse("transactions,receipts"), None);
+}
+
+#[test]
+fn transaction_pages_split_a_block_in_key_order() {
+    let (dir, blocks) = fixture(ChainSpec { outputs_per_block: 7, ..ChainSpec::default() });
+    let full = read_block_with_transactions(dir.path(), 4).unwrap();
+    let commitments: Vec<_> = full.transactions.outputs.iter().map(|o| o.commitment.clone()).collect();
+
+    let mut paged = Vec::new();
+    for offset in (0..10).step_by(3) {
+        let page = read_block_transactions(dir.path(), 4, TxComponent::Outputs, Page::new(Some(offset), Some(3))).unwrap();
+        assert_eq!(page.hash, full.hash);
+        assert_eq!(page.total, blocks[4].output_count);
+        assert!(page.transactions.inputs.is_empty() && page.transactions.kernels.is_empty());
+        paged.extend(page.transactions.outputs.into_iter().map(|o| o.commitment));
+    }
+    // Pages past the end are empty, so the pages together are the block's outputs
+    assert_eq!(paged, commitments);
+
+    let kernels = read_block_transactions(dir.path(), 4, TxComponent::Kernels, Page::default()).unwrap();
+    assert_eq!(kernels.transactions.kernels.len() as u64, kernels.total);
+    assert_eq!(Page::new(None, Some(100_000)).limit, MAX_PAGE_LIMIT);
+    assert!(matches!(
+        read_block_transactions(dir.path(), 99, TxComponent::Outputs, Page::default()),
+        Err(ReaderError::BlockNotFound(99))
+    ));
+}
+
+#[test]
+fn component_reads_return_only_that_component() {
+    let (dir, blocks) = fixture(ChainSpec { spends_per_block: 2, ..ChainSpec::default() });
+    let full = read_block_with_transactions(dir.path(), 6).unwrap();
+
+    let inputs = read_block_transactions(dir.path(), 6, TxComponent::Inputs, Page::default()).unwrap();
+    assert_eq!(inputs.total, 2);
+    assert!(inputs.transactions.outputs.is_empty() && inputs.transactions.kernels.is_empty());
+    let spent: Vec<_> = inputs.transactions.inputs.iter().map(|i| &i.output_hash).collect();
+    assert_eq!(spent, full.transactions.inputs.iter().map(|i| &i.output_hash).collect::<Vec<_>>());
+
+    // Fees come straight from the kernels table
+    let kernels = read_block_transactions(dir.path(), 6, TxComponent::Kernels, Page::default()).unwrap();
+    assert!(kernels.transactions.inputs.is_empty() && kernels.transactions.outputs.is_empty());
+    let mut fees: Vec<_> = kernels.transactions.kernels.iter().map(|k| k.fee).collect();
+    fees.sort();
+    assert_eq!(fees, blocks[6].kernel_fees);
+}
+
+/// Serialize, parse back and serialize again; both encodings must agree
+fn round_trip<T: serde::Serialize + serde::de::DeserializeOwned>(value: &T) -> serde_json::Value {
+    let json = serde_json::to_value(value).unwrap();
+    let parsed: T = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+    json
+}
+
+#[test]
+fn response_models_round_trip_and_match_across_transports() {
+    let (dir, _) = fixture(ChainSpec { spends_per_block: 1, burns_per_block: 1, ..ChainSpec::default() });
+    let block = read_block(dir.path(), 3, BlockInclude::parse("transactions,counts").unwrap()).unwrap();
+
+    let rest = round_trip(&BlockDetailResponse::from(block.clone()));
+    for part in ["inputs", "outputs", "kernels"] {
+        assert!(!rest["transactions"][part].as_array().unwrap().is_empty(), "{} decoded", part);
+    }
+
+    // The WebSocket message wraps the REST body unchanged
+    let message = round_trip(&WebSocketMessage::BlockDetail { block: block.into() });
+    assert_eq!(message["type"], "BlockDetail");
+    assert_eq!(message["block"], rest);
+
+    let page = read_block_transactions(dir.path(), 3, TxComponent::Outputs, Page::default()).unwrap();
+    let page = round_trip(&BlockTransactionsPage::from(page));
+    assert_eq!(page["component"], "outputs");
+    assert_eq!(page["transactions"]["outputs"], rest["transactions"]["outputs"]);
+    round_trip(&WebSocketMessage::GetBlockTransactions { height: 3, component: TxComponent::Kernels, offset: None, limit: Some(10) });
+}
+
+/// Next queued message, or `None` if nothing arrives within 200ms
+async fn next_message(rx: &mut tokio::sync::mpsc::Receiver<WebSocketMessage>) -> Option<WebSocketMessage> {
+    tokio::time::timeout(std::time::Duration::from_millis(200), rx.recv()).await.ok().flatten()
+}
+
+#[tokio::test]
+async fn block_streams_wait_for_acks() {
+    let provider = std::sync::Arc::new(MockProvider::new(10_000, 1_700_000_000));
+    let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+    let (from, to) = (9_500, 9_999);
+    let stream = BlockStream::start(7, from, to, DisplayZone::Utc, provider, tx);
+    assert_eq!(chunk_count(from, to), 5);
+
+    assert!(matches!(next_message(&mut rx).await, Some(WebSocketMessage::StreamStarted { stream: 7, chunks: 5, .. })));
+
+    // Only the window is sent before the first ack
+    let mut heights = Vec::new();
+    for expected in 0..STREAM_WINDOW {
+        match next_message(&mut rx).await {
+            Some(WebSocketMessage::BlockChunk { seq, blocks, last: false, .. }) => {
+                assert_eq!(seq, expected);
+                assert_eq!(blocks.len() as u64, STREAM_CHUNK);
+                heights.extend(blocks.iter().map(|b| b.height));
+            }
+            other => panic!("expected chunk {}, got {:?}", expected, other),
+        }
+    }
+    assert!(next_message(&mut rx).await.is_none());
+
+    // Acking the newest chunk releases the rest of the window
+    stream.ack(STREAM_WINDOW - 1);
+    loop {
+        match next_message(&mut rx).await {
+            Some(WebSocketMessage::BlockChunk { seq, blocks, last, .. }) => {
+                heights.extend(blocks.iter().map(|b| b.height));
+                stream.ack(seq);
+                if last {
+                    break;
+                }
+            }
+            other => panic!("expected a chunk, got {:?}", other),
+        }
+    }
+    assert_eq!(heights, (from..=to).collect::<Vec<_>>());
+    assert!(next_message(&mut rx).await.is_none());
+}
+
+#[test]
+fn table_counting_reports_progress() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let progress = SharedProgress::new();
+
+    let counts = count_transaction_tables(dir.path(), &progress, &CancellationToken::new()).unwrap();
+    assert_eq!(counts.utxos, Some(blocks.iter().map(|b| b.output_count as usize).sum()));
+
+    // Kernels are counted last
+    let event = progress.current().expect("progress reported");
+    assert_eq!(event.operation, "Counting Kernels entries");
+    assert!(event.finished);
+    assert!(!progress.is_active());
+    assert_eq!(event.done as usize, blocks.iter().map(|b| b.kernel_fees.len()).sum::<usize>());
+}
+
+#[test]
+fn cancelled_read_stops_with_cancelled_error() {
+    let (dir, _) = fixture(ChainSpec::default());
+    let cancel = CancellationToken::new();
+    cancel.cancel();
+
+    let err = count_transaction_tables(dir.path(), &NoProgress, &cancel).unwrap_err();
+    assert!(err.is_cancelled());
+}
+
+#[test]
+fn inputs_link_to_the_block_that_mined_the_spent_output() {
+    let (dir, _) = fixture(ChainSpec { spends_per_block: 2, ..ChainSpec::default() });
+
+    let detail = read_block_with_transactions(dir.path(), 7).unwrap();
+
+    assert_eq!(detail.transactions.inputs.len(), 2);
+    for input in &detail.transactions.inputs {
+        assert_eq!(input.spent_output_mined_height, Some(6));
+        assert_eq!(input.output_hash.len(), 64);
+    }
+}
+
+#[test]
+fn outputs_report_the_block_that_spent_them() {
+    let (dir, _) = fixture(ChainSpec { spends_per_block: 2, ..ChainSpec::default() });
+
+    let detail = read_block_with_transactions(dir.path(), 6).unwrap();
+
+    let spent = detail.transactions.outputs.iter().filter(|o| o.spend_status == SpendStatus::Spent { height: 7 }).count();
+    let unspent = detail.transactions.outputs.iter().filter(|o| o.spend_status == SpendStatus::Unspent).count();
+    assert_eq!((spent, unspent), (2, 1));
+}
+
+#[test]
+fn burn_outputs_are_decoded_and_totalled() {
+    let (dir, blocks) = fixture(ChainSpec { burns_per_block: 1, ..ChainSpec::default() });
+
+    let detail = read_block_with_transactions(dir.path(), 3).unwrap();
+    let burns = detail.transactions.outputs.iter().filter(|o| o.kind == OutputKind::Burn).count();
+    assert_eq!(burns, 1);
+
+    let report = burn_analytics(dir.path(), 10).unwrap();
+    assert_eq!(report.start_height, blocks.len() as u64 - 10);
+    assert_eq!(report.outputs.len(), 10);
+    assert_eq!(report.kernels.len(), 10);
+    assert!(report.kernels.iter().all(|k| k.burn_commitment.is_some()));
+    assert_eq!(report.total_burned, 10 * FIXTURE_BURN_VALUE);
+    assert_eq!(report.blocks.last().unwrap().cumulative_burned, report.total_burned);
+}
+
+#[test]
+fn output_features_serialize_as_structured_json() {
+    let (dir, _) = fixture(ChainSpec { burns_per_block: 1, ..ChainSpec::default() });
+
+    let detail = read_block_with_transactions(dir.path(), 2).unwrap();
+    let burn = detail.transactions.outputs.iter().find(|o| o.kind == OutputKind::Burn).expect("burn output");
+
+    assert_eq!(burn.features.kind, OutputKind::Burn);
+    assert_eq!(burn.features.output_type, "Burn");
+    assert!(!burn.features.sidechain_data);
+    let json = serde_json::to_value(&burn.features).unwrap();
+    assert_eq!(json["output_type"], "Burn");
+    assert_eq!(json["maturity"], 0);
+    assert!(burn.features.to_string().starts_with("Burn"));
+}
+
+#[test]
+fn scripts_are_classified_by_pattern() {
+    let key = "ab".repeat(32);
+
+    let nop = ScriptSummary::from_opcodes(vec!["Nop".to_string()]);
+    let one_sided = ScriptSummary::from_opcodes(vec![format!("PushPubKey({})", key)]);
+    let custom = ScriptSummary::from_opcodes(vec!["CheckHeightVerify(100)".to_string(), format!("PushPubKey({})", key)]);
+
+    assert_eq!(nop.pattern, ScriptPattern::Nop);
+    assert_eq!(one_sided.pattern, ScriptPattern::OneSided { public_key: key.clone() });
+    assert_eq!(custom.pattern, ScriptPattern::Custom);
+    assert_eq!(custom.text(), format!("CheckHeightVerify(100) PushPubKey({})", key));
+    assert_eq!(ScriptSummary::from_opcodes(Vec::new()).pattern, ScriptPattern::Empty);
+
+    let (dir, _) = fixture(ChainSpec::default());
+    let detail = read_block_with_transactions(dir.path(), 1).unwrap();
+    assert!(detail.transactions.outputs.iter().all(|o| o.script_type == o.script.pattern.label()));
+}
+
+#[test]
+fn kernels_are_classified_from_feature_bits() {
+    let (dir, _) = fixture(ChainSpec { kernels_per_block: 2, burns_per_block: 1, ..ChainSpec::default() });
+
+    let detail = read_block_with_transactions(dir.path(), 4).unwrap();
+    let kernels = &detail.transactions.kernels;
+
+    assert_eq!(kernels.iter().filter(|k| k.kind == KernelKind::Plain).count(), 2);
+    let burn = kernels.iter().find(|k| k.kind == KernelKind::Burn).expect("burn kernel");
+    assert!(burn.burn_commitment.is_some());
+    assert!(kernels.iter().all(|k| !k.is_coinbase));
+}
+
+#[test]
+fn scanner_matches_one_sided_scripts_for_the_key() {
+    let key = parse_public_key(&format!("0x{}", "AB".repeat(32))).expect("valid key");
+    assert_eq!(key, "ab".repeat(32));
+    assert!(parse_public_key("abcd").is_none());
+
+    assert!(pays_to(&ScriptSummary::from_opcodes(vec![format!("PushPubKey({})", key)]), &key));
+    assert!(!pays_to(&ScriptSummary::from_opcodes(vec![format!("PushPubKey({})", "cd".repeat(32))]), &key));
+    assert!(!pays_to(&ScriptSummary::from_opcodes(vec!["Nop".to_string()]), &key));
+
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let progress = SharedProgress::new();
+    let report = scan_outputs(dir.path(), &key, None, &progress, &CancellationToken::new()).unwrap();
+    assert!(report.matches.is_empty());
+    assert_eq!(report.end_height, blocks.last().unwrap().height);
+    assert_eq!(progress.current().unwrap().done, blocks.len() as u64);
+}
+
+#[test]
+fn watchlist_finds_watched_commitments_and_excesses() {
+    let (dir, _) = fixture(ChainSpec::default());
+    let block = read_block_with_transactions(dir.path(), 3).unwrap();
+    let commitment = block.transactions.outputs[0].commitment.to_uppercase();
+    let excess = block.transactions.kernels[0].excess.clone();
+
+    let file = dir.path().join("watch.toml");
+    std::fs::write(&file, format!("[[watch]]\nkind = \"commitment\"\nvalue = \"{}\"\nlabel = \"test\"\n", commitment)).unwrap();
+    let mut watchlist = Watchlist::load(&file).unwrap();
+    assert!(watchlist.add(WatchItem { kind: WatchKind::Excess, value: excess, label: None }));
+    assert!(!watchlist.add(WatchItem { kind: WatchKind::Commitment, value: commitment.clone(), label: None }));
+    assert!(!watchlist.add(WatchItem { kind: WatchKind::Excess, value: "xyz".to_string(), label: None }));
+
+    let hits = watchlist.check_block(&block);
+    assert!(hits.iter().any(|h| h.location == WatchLocation::Output && h.item.label.as_deref() == Some("test")));
+    assert!(hits.iter().any(|h| h.location == WatchLocation::Kernel));
+    assert!(hits.iter().all(|h| h.height == 3 && h.block_hash == block.hash));
+
+    assert!(watchlist.pending_heights(10).is_empty());
+    assert_eq!(watchlist.pending_heights(12), 11..=12);
+    assert!(watchlist.pending_heights(12).is_empty());
+}
+
+#[test]
+fn state_at_height_comes_from_header_sizes() {
+    let spec = ChainSpec { kernels_per_block: 2, outputs_per_block: 3, ..ChainSpec::default() };
+    let (dir, blocks) = fixture(spec);
+
+    let early = state_at_height(dir.path(), 4, Network::MainNet).unwrap();
+    let late = state_at_height(dir.path(), 12, Network::MainNet).unwrap();
+
+    assert_eq!(early.hash, blocks[4].hash);
+    assert_eq!(early.kernel_count, 5 * 2);
+    assert_eq!(early.utxo_count, 5 * 3);
+    assert_eq!(late.kernel_count, 13 * 2);
+    if let (Some(early_emission), Some(late_emission)) = (early.emission, late.emission) {
+        assert!(late_emission > early_emission);
+    }
+    assert!(state_at_height(dir.path(), 500, Network::MainNet).unwrap_err().is_not_found());
+}
+
+#[test]
+fn pow_share_rolls_over_the_window() {
+    // Heights 0..10 alternate in runs: 0-4 RandomX, 5-9 Sha3x
+    let algos: Vec<(u64, String)> = (0..10)
+        .map(|h| (h, if h < 5 { "RandomXM" } else { "Sha3x" }.to_string()))
+        .collect();
+
+    let share = share_from_algorithms(&algos, 4, 3, 9);
+
+    assert_eq!(share.algorithms, vec!["RandomXM".to_string(), "Sha3x".to_string()]);
+    assert_eq!(share.points.first().unwrap().height, 3);
+    assert_eq!(share.points.first().unwrap().shares["RandomXM"], 100.0);
+    let at_six = share.points.iter().find(|p| p.height == 6).unwrap();
+    assert_eq!(at_six.shares["RandomXM"], 50.0);
+    assert_eq!(at_six.shares["Sha3x"], 50.0);
+    assert!(!share.points.last().unwrap().shares.contains_key("RandomXM"));
+    assert_eq!(share.totals.values().sum::<u64>(), 7);
+
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let live = pow_share(dir.path(), 5, 10).unwrap();
+    assert_eq!(live.end_height, blocks.last().unwrap().height);
+    assert_eq!(live.points.last().unwrap().shares.values().sum::<f64>(), 100.0);
+}
+
+#[test]
+fn analytics_series_render_as_csv() {
+    let (dir, _) = fixture(ChainSpec { burns_per_block: 1, ..ChainSpec::default() });
+    let share = pow_share(dir.path(), 5, 10).unwrap();
+    let csv = to_csv(&share);
+    let lines: Vec<_> = csv.split_terminator("\r\n").collect();
+    assert_eq!(lines[0], std::iter::once("height".to_string()).chain(share.algorithms.iter().cloned()).collect::<Vec<_>>().join(","));
+    assert_eq!(lines.len(), share.points.len() + 1);
+    assert!(lines[1..].iter().all(|line| line.split(',').count() == share.algorithms.len() + 1));
+
+    let burns = burn_analytics(dir.path(), 100).unwrap();
+    let csv = to_csv(&burns);
+    assert!(csv.starts_with("height,timestamp,burn_outputs,burned,cumulative_burned\r\n"));
+    assert!(!burns.blocks.is_empty());
+    assert_eq!(csv.lines().count(), burns.blocks.len() + 1);
+
+    // Fields with separators are quoted
+    let histogram = IntervalHistogram {
+        window: 1, start_height: 0, end_height: 0, sample_count: 0, average_seconds: 0.0, median_seconds: 0, non_positive_intervals: 0,
+        buckets: vec![IntervalBucket { label: "1,5\"m".to_string(), min_seconds: 60, max_seconds: None, count: 2 }],
+    };
+    assert_eq!(to_csv(&histogram), "label,min_seconds,max_seconds,count\r\n\"1,5\"\"m\",60,,2\r\n");
+}
+
+#[test]
+fn top_blocks_index_ranks_and_follows_the_tip() {
+    let spec = ChainSpec { kernels_per_block: 2, outputs_per_block: 3, ..ChainSpec::default() };
+    let (dir, blocks) = fixture(spec);
+    let tip = blocks.last().unwrap().height;
+
+    let mut index = AggregateIndex::new(8);
+    assert_eq!(index.pending(tip), (tip - 7, tip));
+    index.refresh(dir.path()).unwrap();
+
+    let top = index.top(TopBy::Outputs, 3);
+    assert_eq!((top.indexed_from, top.indexed_to), (Some(tip - 7), Some(tip)));
+    assert_eq!(top.blocks.len(), 3);
+    // Equal counts rank the newer block first
+    assert_eq!(top.blocks[0].height, tip);
+    assert!(top.blocks.iter().all(|b| b.kernels == 2 && b.outputs == 3));
+    let fees: u64 = blocks[tip as usize].kernel_fees.iter().sum();
+    assert_eq!(index.top(TopBy::Fees, 1).blocks[0].total_fees, fees);
+
+    // The window slides with the tip, and a shorter chain drops heights above the new tip
+    assert_eq!(index.pending(tip + 2), (tip - 5, tip + 2));
+    index.apply(tip - 1, Vec::new());
+    assert_eq!(index.top(TopBy::Kernels, 20).indexed_to, Some(tip - 1));
+}
+
+#[test]
+fn tx_volume_series_is_cached_in_the_sidecar() {
+    let spec = ChainSpec { kernels_per_block: 2, burns_per_block: 1, outputs_per_block: 2, ..ChainSpec::default() };
+    let (dir, blocks) = fixture(spec);
+    let tip = blocks.last().unwrap().height;
+    let cache_path = dir.path().join("tx_volume.bin");
+
+    let cache = refresh_tx_volume(dir.path(), &cache_path).unwrap();
+    assert_eq!(cache.covered_to(), Some(tip));
+    assert!((0..=tip).all(|h| cache.count(h) == Some(3)));
+
+    // Reloading keeps the series and an up-to-date refresh reads nothing
+    let mut reloaded = TxVolumeCache::load(&cache_path);
+    assert_eq!(reloaded.covered_to(), Some(tip));
+    assert_eq!(reloaded.refresh(dir.path()).unwrap(), 0);
+
+    let series = reloaded.series(Some(4), Some(11), Some(4));
+    assert_eq!(series.points.len(), 2);
+    assert_eq!(series.points[1].height, 8);
+    assert_eq!(series.points[1].kernels, 12);
+    assert_eq!(series.total_kernels, 24);
+    assert_eq!(reloaded.series(None, Some(tip + 100), None).end_height, tip);
+}
+
+#[test]
+fn utxo_bloom_rules_out_unknown_outputs() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let tip = blocks.last().unwrap().height;
+    let cache = CacheDir::new(dir.path().join("cache"));
+
+    let bloom = refresh_utxo_bloom(dir.path(), &cache).unwrap();
+    assert_eq!(bloom.covered_to(), Some(tip));
+    assert_eq!(bloom.keys(), 2 * 3 * blocks.len() as u64);
+
+    let reader = StreamReader::open(dir.path()).unwrap();
+    let snapshot = reader.snapshot().unwrap();
+    for row in snapshot.outputs::<TransactionOutputRowData>(&[]).unwrap() {
+        let row = row.unwrap();
+        assert!(bloom.might_contain(row.hash.as_slice()));
+        assert!(!bloom.definitely_absent(row.output.commitment.as_bytes(), tip));
+    }
+
+    // No false negatives, and at ~1% false positives nearly all random keys are ruled out
+    let absent = (0u64..1000)
+        .filter(|i| bloom.definitely_absent(blake3::hash(&i.to_le_bytes()).as_bytes(), tip))
+        .count();
+    assert!(absent > 950, "only {} of 1000 unknown keys ruled out", absent);
+
+    // A filter behind the tip never answers negatively
+    assert!(!bloom.definitely_absent(&[7; 32], tip + 1));
+    assert!(UtxoBloom::default().might_contain(&[7; 32]));
+
+    let (_, reloaded) = cache.load::<UtxoBloom>(UTXO_BLOOM_FILE, dir.path()).unwrap();
+    assert_eq!(reloaded.covered_to(), Some(tip));
+}
+
+#[test]
+fn cache_dir_discards_artifacts_replaced_by_a_reorg() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let cache = CacheDir::new(dir.path().join("cache"));
+
+    let hashes = refresh_height_hashes(dir.path(), &cache).unwrap();
+    assert_eq!(hashes.covered_to(), Some(blocks.last().unwrap().height));
+    assert_eq!(hashes.height_of(&hex::decode(&blocks[7].hash).unwrap()), Some(7));
+    assert!(cache.load::<HeightHashes>(HEIGHT_HASHES_FILE, dir.path()).is_some());
+
+    let current = TipKey { height: 5, hash: blocks[5].hash.clone() };
+    cache.store("series.bin", &current, &vec![1u64, 2, 3]).unwrap();
+    assert_eq!(cache.load::<Vec<u64>>("series.bin", dir.path()), Some((current, vec![1, 2, 3])));
+
+    let replaced = TipKey { height: 5, hash: blocks[6].hash.clone() };
+    cache.store("series.bin", &replaced, &vec![1u64]).unwrap();
+    assert!(cache.load::<Vec<u64>>("series.bin", dir.path()).is_none());
+    assert!(!cache.file("series.bin").exists());
+}
+
+#[test]
+fn saved_dashboard_loads_as_stale() {
+    let dir = tempfile::tempdir().expect("temp dir");
+    let cache = CacheDir::new(dir.path().to_path_buf());
+    assert!(cache.load_dashboard().is_none());
+
+    // Nothing worth saving before the first read
+    cache.store_dashboard(&DashboardData::default()).unwrap();
+    assert!(cache.load_dashboard().is_none());
+
+    let dashboard = DashboardData { last_updated: 1_700_000_000, ..DashboardData::default() };
+    cache.store_dashboard(&dashboard).unwrap();
+    let loaded = cache.load_dashboard().expect("saved dashboard");
+    assert!(loaded.stale);
+    assert_eq!(loaded.last_updated, 1_700_000_000);
+
+    // A stale dashboard is never written back over the saved one
+    cache.store_dashboard(&DashboardData { last_updated: 1, ..loaded }).unwrap();
+    assert_eq!(cache.load_dashboard().unwrap().last_updated, 1_700_000_000);
+}
+
+#[test]
+fn preflight_reads_the_tip_and_rejects_non_tari_directories() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let report = preflight(dir.path(), Network::MainNet).unwrap();
+    assert_eq!(report.tip_height, Some(blocks.last().unwrap().height));
+    assert_eq!(report.decoded_height, report.tip_height);
+    assert_eq!(report.genesis_hash.as_deref(), Some(blocks[0].hash.as_str()));
+    // The fixture's genesis is synthetic, so no network is recognised
+    assert!(report.network.is_none());
+    assert!(!report.warnings.is_empty());
+
+    let empty = tempfile::tempdir().expect("temp dir");
+    let error = preflight(empty.path(), Network::MainNet).unwrap_err().to_string();
+    assert!(error.contains("not a Tari base node database"), "{}", error);
+}
+
+#[test]
+fn genesis_report_decodes_block_zero_and_checks_known_networks() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let report = genesis_report(dir.path(), Network::MainNet).unwrap();
+    assert_eq!(report.block.height, 0);
+    assert_eq!(report.block.hash, blocks[0].hash);
+    assert!(report.block.transactions.is_some());
+
+    // Every known network is listed; the synthetic genesis matches none of them
+    assert_eq!(report.known.len(), KNOWN_NETWORKS.len());
+    assert!(report.known.iter().all(|known| !known.matches && known.hash.len() == 64));
+    assert!(report.network.is_none());
+    assert_eq!(report.warnings.len(), 1);
+
+    let esmeralda = genesis_hash(Network::Esmeralda);
+    assert_eq!(network_of(&esmeralda), Some(Network::Esmeralda));
+    assert_eq!(network_of(&esmeralda.to_uppercase()), Some(Network::Esmeralda));
+    assert_eq!(network_of(&blocks[0].hash), None);
+}
+
+#[test]
+fn batch_read_returns_each_height_in_order() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let missing = blocks.last().unwrap().height + 5;
+
+    let results = read_blocks_with_transactions(dir.path(), &[blocks[6].height, missing, blocks[2].height]).unwrap();
+
+    assert_eq!(results.len(), 3);
+    let first = results[0].as_ref().expect("block 6");
+    assert_eq!(first.hash, blocks[6].hash);
+    assert_eq!(first.transactions.kernels.len(), blocks[6].kernel_fees.len());
+    assert!(results[1].as_ref().is_err_and(|e| e.is_not_found()));
+    assert_eq!(results[2].as_ref().map(|b| b.height).ok(), Some(blocks[2].height));
+}
+
+#[test]
+fn tip_block_hash_comes_from_metadata() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let tip = blocks.last().unwrap();
+
+    let detail = read_block_with_transactions(dir.path(), tip.height).unwrap();
+
+    assert_eq!(detail.hash, tip.hash);
+}
+
+#[test]
+fn missing_block_is_not_found() {
+    let (dir, _) = fixture(ChainSpec::default());
+
+    let err = read_block_with_transactions(dir.path(), 10_000).unwrap_err();
+
+    assert!(err.is_not_found());
+}
+
+#[test]
+fn range_filter_returns_inclusive_heights() {
+    let (dir, _) = fixture(ChainSpec::default());
+
+    let blocks = read_lmdb_headers_with_filter(dir.path(), "headers", BlockFilter::Range(3, 7)).unwrap();
+
+    let heights: Vec<u64> = blocks.iter().map(|b| b.height).collect();
+    assert_eq!(heights, vec![3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn last_n_returns_tip_blocks_ascending() {
+    let (dir, _) = fixture(ChainSpec { blocks: 12, ..ChainSpec::default() });
+
+    let blocks = read_lmdb_headers_with_filter(dir.path(), "headers", BlockFilter::LastN(3)).unwrap();
+
+    let heights: Vec<u64> = blocks.iter().map(|b| b.height).collect();
+    assert_eq!(heights, vec![9, 10, 11]);
+}
+
+#[test]
+fn heights_past_256_keep_numeric_order() {
+    // LE height keys only sort correctly with INTEGERKEY once the low byte wraps
+    let (dir, blocks) = fixture(ChainSpec { blocks: 300, kernels_per_block: 1, outputs_per_block: 1, ..ChainSpec::default() });
+    let tip = blocks.last().unwrap().height;
+    assert_eq!(tip, 299);
+
+    let last: Vec<u64> = read_lmdb_headers_with_filter(dir.path(), "headers", BlockFilter::LastN(5)).unwrap().iter().map(|b| b.height).collect();
+    assert_eq!(last, vec![295, 296, 297, 298, 299]);
+
+    let desc: Vec<u64> = iter_headers_desc(dir.path(), None, 3).unwrap().iter().map(|b| b.height).collect();
+    assert_eq!(desc, vec![299, 298, 297]);
+
+    let across: Vec<u64> = iter_headers_desc(dir.path(), Some(257), 3).unwrap().iter().map(|b| b.height).collect();
+    assert_eq!(across, vec![257, 256, 255]);
+
+    let reader = StreamReader::open(dir.path()).unwrap();
+    let snapshot = reader.snapshot().unwrap();
+    assert_eq!(snapshot.tip_height().unwrap(), Some(tip));
+    let heights: Vec<u64> = snapshot.headers(254, 258).unwrap().map(|r| r.unwrap().0).collect();
+    assert_eq!(heights, vec![254, 255, 256, 257, 258]);
+}
+
+#[test]
+fn descending_iteration_starts_at_requested_height() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let tip = blocks.last().unwrap().height;
+
+    let from_tip: Vec<u64> = iter_headers_desc(dir.path(), None, 3).unwrap().iter().map(|b| b.height).collect();
+    let from_five: Vec<u64> = iter_headers_desc(dir.path(), Some(5), 10).unwrap().iter().map(|b| b.height).collect();
+
+    assert_eq!(from_tip, vec![tip, tip - 1, tip - 2]);
+    assert_eq!(from_five, vec![5, 4, 3, 2, 1, 0]);
+}
+
+#[test]
+fn streaming_iterators_walk_headers_and_block_rows() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let reader = StreamReader::open(dir.path()).unwrap();
+    let snapshot = reader.snapshot().unwrap();
+
+    let heights: Vec<u64> = snapshot.headers(2, 5).unwrap().map(|r| r.unwrap().0).collect();
+    assert_eq!(heights, vec![2, 3, 4, 5]);
+
+    let target = &blocks[3];
+    let block_hash = hex::decode(&target.hash).unwrap();
+    let outputs = snapshot.outputs::<TransactionOutputRowData>(&block_hash).unwrap().count() as u64;
+    assert_eq!(outputs, target.output_count);
+
+    let all_kernels = snapshot.kernels::<TransactionKernelRowData>(&[]).unwrap().count();
+    assert_eq!(all_kernels, blocks.iter().map(|b| b.kernel_fees.len()).sum::<usize>());
+}
+
+#[test]
+fn parallel_chunks_merge_in_height_order() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let reader = StreamReader::open(dir.path()).unwrap();
+
+    // Wide enough to take the rayon path; chunks past the tip simply come back empty
+    let end = PARALLEL_THRESHOLD * 2;
+    let spans = par_map_chunks(&reader, 0, end, 1_000, |_, start, end| Ok((start..=end).collect())).unwrap();
+    assert_eq!(spans, (0..=end).collect::<Vec<u64>>());
+
+    let heights = par_map_chunks(&reader, 0, end, 3, |snapshot, start, end| {
+        snapshot.headers(start, end)?.map(|r| r.map(|(height, _)| height)).collect()
+    }).unwrap();
+    assert_eq!(heights, blocks.iter().map(|b| b.height).collect::<Vec<u64>>());
+}
+
+#[test]
+fn mmr_positions_resolve_to_blocks_or_report_the_tip() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let tip = blocks.last().unwrap().height;
+
+    let MmrLookup::Found(kernel) = resolve_position(dir.path(), MmrKind::Kernel, 5).unwrap() else { panic!("kernel position found") };
+    assert_eq!((kernel.height, kernel.block_start, kernel.block_end, kernel.index_in_block), (2, 4, 6, 1));
+
+    let MmrLookup::Found(output) = resolve_position(dir.path(), MmrKind::Output, 7).unwrap() else { panic!("output index found") };
+    assert_eq!((output.height, output.block_start, output.block_end, output.index_in_block), (2, 6, 9, 1));
+
+    let kernel_size = (tip + 1) * 2;
+    assert!(matches!(
+        resolve_position(dir.path(), MmrKind::Kernel, kernel_size).unwrap(),
+        MmrLookup::BeyondTip { tip_height, tip_size } if tip_height == tip && tip_size == kernel_size
+    ));
+    assert!(matches!(resolve_position(dir.path(), MmrKind::Output, u64::MAX).unwrap(), MmrLookup::BeyondTip { tip_height, .. } if tip_height == tip));
+}
+
+#[test]
+fn kernel_counts_come_from_mmr_deltas() {
+    let spec = ChainSpec { kernels_per_block: 4, ..ChainSpec::default() };
+    let (dir, _) = fixture(spec);
+
+    let blocks = read_lmdb_headers_with_filter(dir.path(), "headers", BlockFilter::Range(1, 4)).unwrap();
+
+    assert!(blocks.iter().all(|b| b.kernel_count == Some(4)));
+    let filtered = read_lmdb_headers_with_filter(
+        dir.path(),
+        "headers",
+        BlockFilter::Range(1, 4).with_options(None, Some(5)),
+    ).unwrap();
+    assert!(filtered.is_empty());
+}
+
+#[test]
+fn output_and_input_counts_come_from_smt_deltas() {
+    let spec = ChainSpec { outputs_per_block: 5, ..ChainSpec::default() };
+    let (dir, _) = fixture(spec);
+
+    let blocks = read_lmdb_headers_with_filter(dir.path(), "headers", BlockFilter::Range(2, 6)).unwrap();
+
+    assert!(blocks.iter().all(|b| b.output_count == Some(5) && b.input_count == Some(0)));
+}
+
+#[test]
+fn fast_kernel_count_matches_fixture() {
+    let spec = ChainSpec { kernels_per_block: 3, ..ChainSpec::default() };
+    let (dir, blocks) = fixture(spec);
+
+    for block in [&blocks[0], &blocks[7]] {
+        let count = count_block_kernels_fast(dir.path(), block.height).unwrap();
+        assert_eq!(count, Some(block.kernel_fees.len() as u64));
+    }
+}
+
+#[test]
+fn hash_search_finds_block() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let target = &blocks[8];
+
+    let found = search_block_by_hash(dir.path(), &target.hash.to_uppercase()).unwrap();
+
+    assert_eq!(found.map(|b| b.height), Some(target.height));
+}
+
+#[test]
+fn hash_lookup_returns_same_detail_as_height() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let target = &blocks[4];
+
+    let by_hash = search_block_by_hash(dir.path(), &target.hash).unwrap().expect("indexed block");
+    let by_height = read_block_with_transactions(dir.path(), target.height).unwrap();
+
+    assert_eq!(by_hash.hash, by_height.hash);
+    assert_eq!(by_hash.transactions.kernels.len(), by_height.transactions.kernels.len());
+    assert_eq!(by_hash.transactions.outputs.len(), by_height.transactions.outputs.len());
+}
+
+#[test]
+fn hash_search_scans_headers_without_the_index() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    drop_table(dir.path(), "block_hashes").unwrap();
+    let target = &blocks[13];
+
+    let found = search_block_by_hash(dir.path(), &target.hash).unwrap();
+    assert_eq!(found.map(|b| b.height), Some(target.height));
+    assert!(search_block_by_hash(dir.path(), &"ab".repeat(32)).unwrap().is_none());
+
+    // Past the parallel threshold the range is split across workers and the first match wins
+    let reader = StreamReader::open(dir.path()).unwrap();
+    let found = par_find_chunks(&reader, 0, PARALLEL_THRESHOLD * 4, 100, |snapshot, start, end, _stop| {
+        Ok(snapshot.headers(start, end)?.filter_map(Result::ok).map(|(height, _)| height).find(|&height| height == target.height))
+    }).unwrap();
+    assert_eq!(found, Some(target.height));
+}
+
+#[test]
+fn hash_search_misses_unknown_hash() {
+    let (dir, _) = fixture(ChainSpec::default());
+
+    let found = search_block_by_hash(dir.path(), &"ab".repeat(32)).unwrap();
+
+    assert!(found.is_none());
+}
+
+#[test]
+fn chain_metadata_reports_tip() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let tip = blocks.last().unwrap();
+
+    let metadata = read_chain_metadata(dir.path()).unwrap().expect("metadata table present");
+
+    assert_eq!(metadata.best_block_height, tip.height);
+    assert_eq!(metadata.best_block_hash.as_deref(), Some(tip.hash.as_str()));
+    assert_eq!(metadata.best_block_timestamp, Some(tip.timestamp));
+    assert_eq!(metadata.accumulated_work.as_deref(), Some(FIXTURE_ACCUMULATED_WORK));
+    assert!(!metadata.pruning.is_pruned());
+}
+
+#[test]
+fn search_dispatches_heights_and_block_hashes() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let target = &blocks[4];
+
+    let by_height = search(dir.path(), &SearchQuery::parse("4").unwrap()).unwrap();
+    let by_hash = search(dir.path(), &SearchQuery::parse(&target.hash).unwrap()).unwrap();
+
+    let expected = SearchResult::Block { height: 4, hash: target.hash.clone() };
+    assert_eq!(by_height, expected);
+    assert_eq!(by_hash, expected);
+    assert!(SearchQuery::parse("not-a-hash").is_none());
+}
+
+#[test]
+fn search_misses_unknown_hash() {
+    let (dir, _) = fixture(ChainSpec::default());
+
+    let result = search(dir.path(), &SearchQuery::parse(&"cd".repeat(32)).unwrap()).unwrap();
+
+    assert_eq!(result, SearchResult::NotFound);
+}
+
+#[test]
+fn node_profiles_get_their_own_database_and_cache() {
+    let (dir, _) = fixture(ChainSpec::default());
+    let base = AppConfig { watchlist_path: Some(PathBuf::from("watch.toml")), ..app_config(PathBuf::from("primary")) };
+
+    let node = NodeProfile::parse(&format!("backup={}", dir.path().display()), &base).unwrap();
+    assert_eq!(node.name, "backup");
+    assert_eq!(node.config.database_path, dir.path());
+    assert_eq!(node.config.cache.dir(), Path::new("cache/nodes/backup"));
+    assert!(node.config.watchlist_path.is_none());
+
+    assert!(NodeProfile::parse("backup", &base).is_err());
+    assert!(NodeProfile::parse(&format!("default={}", dir.path().display()), &base).is_err());
+    assert!(NodeProfile::parse(&format!("../up={}", dir.path().display()), &base).is_err());
+    assert!(NodeProfile::parse("backup=/no/such/db", &base).is_err());
+}
+
+#[test]
+fn sync_status_tells_lagging_from_stalled() {
+    let now = 1_700_000_000;
+    let mut tracker = SyncTracker::default();
+    assert_eq!(tracker.observe(100, now - 60, now), SyncStatus::Synced);
+
+    // Catching up: old tips, but the height keeps moving
+    let mut syncing = SyncTracker::default();
+    assert_eq!(syncing.observe(50, now - 7_200, now), SyncStatus::Stalled { minutes: 120 });
+    assert_eq!(syncing.observe(60, now - 6_000, now + 30), SyncStatus::Lagging { minutes: 100 });
+    assert_eq!(syncing.observe(60, now - 6_000, now + 1_830), SyncStatus::Stalled { minutes: 130 });
+
+    let mut notifier = Notifier::new(NotifyConfig { webhook_url: "http://localhost".to_string(), interval_threshold_secs: 600 }).unwrap();
+    assert!(notifier.observe_sync(60, SyncStatus::Lagging { minutes: 100 }).is_none());
+    assert!(matches!(notifier.observe_sync(60, SyncStatus::Stalled { minutes: 130 }), Some(NotifyEvent::Stalled { height: 60, minutes_behind: 130 })));
+    // One alert per stall
+    assert!(notifier.observe_sync(60, SyncStatus::Stalled { minutes: 131 }).is_none());
+}
+
+#[test]
+fn tui_keys_become_messages() {
+    let config = AppConfig { demo: true, ..app_config(PathBuf::from("unused")) };
+    let mut app = TuiApp::new(config, 5, None, None);
+
+    assert_eq!(app.message_for_key(KeyCode::Char('3')), Some(TuiMessage::SwitchTab(TuiTab::Transactions)));
+    assert_eq!(app.message_for_key(KeyCode::Char('9')), None);
+    app.handle_input(KeyCode::Char('5'));
+    assert_eq!(app.active_tab, TuiTab::Logs);
+    app.handle_input(KeyCode::Tab);
+    assert_eq!(app.active_tab, TuiTab::Overview);
+
+    // Digits typed into the search box stay there
+    app.handle_input(KeyCode::Char('/'));
+    app.handle_input(KeyCode::Char('2'));
+    assert_eq!(app.active_tab, TuiTab::Overview);
+    assert_eq!(app.search_input.as_deref(), Some("2"));
+
+    app.handle_input(KeyCode::Esc);
+    app.handle_input(KeyCode::Char('q'));
+    assert!(app.should_quit);
+}
+
+#[tokio::test]
+async fn tui_shows_real_table_counts_from_the_engine() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let config = AppConfig { cache: CacheDir::new(dir.path().join("cache")), ..app_config(dir.path().to_path_buf()) };
+    let mut app = TuiApp::new(config, 5, None, None);
+
+    app.update_data().await.unwrap();
+
+    let outputs: u64 = blocks.iter().map(|b| b.output_count).sum();
+    let tip = blocks.last().unwrap();
+    assert_eq!(app.dashboard_data.database_stats.utxos_count as u64, outputs);
+    assert!(app.dashboard_data.database_stats.kernels_count > 0);
+    assert_eq!(app.dashboard_data.network_stats.latest_block_height, tip.height);
+    assert_eq!(app.dashboard_data.recent_blocks[0].hash, tip.hash);
+}
+
+#[tokio::test]
+async fn dashboard_engine_extends_the_tip_window_and_rebuilds_on_reorg() {
+    let (dir, _) = fixture(ChainSpec::default());
+    let config = app_config(dir.path().to_path_buf());
+    let engine = DashboardEngine::new(config.clone(), data_provider::from_config(&config)).quiet();
+    engine.read().await.unwrap();
+    assert_eq!(engine.window_rebuilds().await, 1);
+
+    // The same chain five blocks longer only reads the new blocks
+    let grown = build_chain(dir.path(), &ChainSpec { blocks: 25, ..ChainSpec::default() }).unwrap();
+    let data = engine.read().await.unwrap();
+    assert_eq!(engine.window_rebuilds().await, 1);
+    assert_eq!(data.recent_blocks.len(), grown.len());
+    assert_eq!(data.recent_blocks[0].hash, grown.last().unwrap().hash);
+    assert_eq!(data.recent_blocks[0].interval_seconds, Some(120));
+
+    // A different chain at the same heights replaces the cached tip
+    let forked = build_chain(dir.path(), &ChainSpec { blocks: 25, start_timestamp: 1_800_000_000, ..ChainSpec::default() }).unwrap();
+    let data = engine.read().await.unwrap();
+    assert_eq!(engine.window_rebuilds().await, 2);
+    assert_eq!(data.recent_blocks[0].hash, forked.last().unwrap().hash);
+    assert_eq!(data.recent_blocks.last().map(|b| b.hash.as_str()), Some(forked[0].hash.as_str()));
+}
+
+#[test]
+fn tip_coalescer_skips_bursts_that_leave_the_tip() {
+    let mark = |height, hash: &str| TipMark { height, hash: Some(hash.to_string()) };
+    let mut tips = TipCoalescer::default();
+
+    let first = tips.observe(mark(10, "a")).unwrap();
+    assert_eq!(first.new_heights(), None);
+    assert!(tips.observe(mark(10, "a")).is_none());
+    assert!(!tips.is_new(&mark(10, "a")));
+
+    let grown = tips.observe(mark(14, "b")).unwrap();
+    assert!(!grown.is_rewind());
+    assert_eq!(grown.new_heights(), Some(11..=14));
+
+    let replaced = tips.observe(mark(14, "c")).unwrap();
+    assert!(replaced.is_rewind());
+    assert_eq!(replaced.new_heights(), Some(14..=14));
+    assert_eq!(tips.observe(mark(12, "d")).unwrap().new_heights(), Some(12..=12));
+
+    assert_eq!(min_interval(Some(4.0)), Duration::from_millis(250));
+    assert_eq!(min_interval(Some(0.0)), Duration::ZERO);
+    assert_eq!(min_interval(None), Duration::ZERO);
+}
+
+#[tokio::test]
+async fn polling_watcher_reports_a_new_tip() {
+    let (dir, _) = fixture(ChainSpec::default());
+    let mut watcher = LmdbWatcher::poll(dir.path(), Duration::from_millis(20), "test");
+    assert!(watcher.is_polling());
+    assert!(watcher.describe().starts_with("polling the tip"));
+
+    build_chain(dir.path(), &ChainSpec { blocks: 21, ..ChainSpec::default() }).unwrap();
+    let change = tokio::time::timeout(Duration::from_secs(5), watcher.next_change()).await;
+    assert_eq!(change, Ok(Some(())));
+}
+
+#[test]
+fn network_mounts_are_detected_from_the_mount_table() {
+    let mounts = "/dev/sda1 / ext4 rw 0 0\n\
+        server:/export /mnt/tari nfs4 rw,relatime 0 0\n\
+        /dev/sdb1 /mnt/tari/local\\040disk xfs rw 0 0\n";
+    assert_eq!(network_filesystem_in(mounts, Path::new("/mnt/tari/mainnet/db")).as_deref(), Some("nfs4"));
+    assert_eq!(network_filesystem_in(mounts, Path::new("/mnt/tari/local disk/db")), None);
+    assert_eq!(network_filesystem_in(mounts, Path::new("/home/me/.tari/db")), None);
+}
+
+#[test]
+fn database_writes_are_recognised_across_platform_event_kinds() {
+    use notify::event::{AccessKind, CreateKind, DataChange, MetadataKind, ModifyKind};
+    use notify::{Event, EventKind};
+
+    let event = |kind, file: &str| Event::new(kind).add_path(PathBuf::from("/db").join(file));
+    // inotify / ReadDirectoryChangesW
+    assert!(is_database_write(&event(EventKind::Modify(ModifyKind::Data(DataChange::Content)), "data.mdb")));
+    assert!(is_database_write(&event(EventKind::Modify(ModifyKind::Any), "lock.mdb")));
+    // FSEvents reports accumulated flags, so writes can arrive as creates or metadata changes
+    assert!(is_database_write(&event(EventKind::Create(CreateKind::File), "data.mdb")));
+    assert!(is_database_write(&event(EventKind::Modify(ModifyKind::Metadata(MetadataKind::Any)), "data.mdb")));
+    assert!(!is_database_write(&event(EventKind::Access(AccessKind::Any), "data.mdb")));
+    assert!(!is_database_write(&event(EventKind::Modify(ModifyKind::Any), "data.mdb-journal")));
+}
+
+#[tokio::test]
+async fn file_events_report_writes_to_the_environment() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("data.mdb"), b"").unwrap();
+    // Sandboxes without inotify (or FSEvents) are what polling is for
+    let Ok(mut watcher) = LmdbWatcher::new(dir.path()) else { return };
+    assert!(!watcher.is_polling());
+
+    // FSEvents delivers with latency, so keep writing until the change arrives
+    let path = dir.path().join("data.mdb");
+    let writer = tokio::spawn(async move {
+        for i in 0u8..50 {
+            std::fs::write(&path, [i; 16]).unwrap();
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+    });
+    let change = tokio::time::timeout(Duration::from_secs(10), watcher.next_change()).await;
+    writer.abort();
+    assert_eq!(change, Ok(Some(())));
+}
+
+#[test]
+fn default_database_path_follows_the_network() {
+    let path = default_database_path("Esmeralda");
+    assert!(path.ends_with(Path::new(".tari").join("esmeralda").join("data").join("base_node").join("db")));
+}
+
+// APFS and NTFS reject names that are not valid Unicode, so this only runs on Linux
+#[cfg(target_os = "linux")]
+#[test]
+fn non_utf8_database_paths_open_through_an_alias() {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+
+    let root = tempfile::tempdir().unwrap();
+    let db = root.path().join(OsStr::from_bytes(b"base_node-\xff-db"));
+    assert!(db.to_str().is_none());
+    let blocks = build_chain(&db, &ChainSpec::default()).unwrap();
+
+    let alias = env_path(&db).unwrap();
+    let alias_path = std::path::PathBuf::from(&*alias);
+    assert_eq!(std::fs::canonicalize(&alias_path).unwrap(), std::fs::canonicalize(&db).unwrap());
+    drop(alias);
+    assert!(std::fs::symlink_metadata(&alias_path).is_err());
+    let headers = read_lmdb_headers_with_filter(&db, "headers", BlockFilter::LastN(5)).unwrap();
+    assert_eq!(headers.last().map(|b| b.hash.as_str()), Some(blocks.last().unwrap().hash.as_str()));
+    assert_eq!(read_chain_metadata(&db).unwrap().map(|m| m.best_block_height), Some(blocks.last().unwrap().height));
+}
+
+#[test]
+fn block_cache_misses_when_the_hash_at_a_height_changes() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let response = BlockDetailResponse::from(read_block_with_transactions(dir.path(), 3).unwrap());
+    let mut cache = BlockCache::new(8);
+    cache.insert(3, blocks[3].hash.clone(), response);
+
+    assert!(cache.get(3, &blocks[3].hash).is_some());
+    // A reorg put another block at height 3: miss, and the old entry is gone
+    assert!(cache.get(3, &blocks[4].hash).is_none());
+    assert!(cache.get(3, &blocks[3].hash).is_none());
+    assert_eq!(cache.stats(), (0, 1, 2));
+}
+
+#[test]
+fn request_spans_redact_the_auth_token() {
+    let uri = "/api/v1/block/5?include=counts&token=s3cret&limit=2".parse().unwrap();
+    assert_eq!(redacted_uri(&uri), "/api/v1/block/5?include=counts&token=REDACTED&limit=2");
+    let plain = "/ws".parse().unwrap();
+    assert_eq!(redacted_uri(&plain), "/ws");
+}
+
+#[test]
+fn auth_tokens_must_be_cookie_safe() {
+    assert_eq!(parse_token("s3cret-Token_+/=").unwrap(), "s3cret-Token_+/=");
+    for bad in ["two words", "semi;colon", "com,ma", "quo\"te", "back\\slash", "caf\u{e9}"] {
+        assert!(parse_token(bad).is_err(), "{:?} accepted", bad);
+    }
+}
+
+#[tokio::test]
+async fn tui_block_list_pages_in_older_blocks() {
+    let (dir, blocks) = fixture(ChainSpec { blocks: 150, ..ChainSpec::default() });
+    let config = AppConfig { cache: CacheDir::new(dir.path().join("cache")), ..app_config(dir.path().to_path_buf()) };
+    let mut app = TuiApp::new(config, 5, None, None);
+    app.viewport = ratatui::layout::Rect::new(0, 0, 120, 40);
+    app.update_data().await.unwrap();
+    app.update(TuiMessage::SwitchTab(TuiTab::Blocks));
+
+    let live = app.dashboard_data.recent_blocks.len();
+    app.update(TuiMessage::MoveSelection(live as i64));
+    app.load_older_blocks().await;
+    assert_eq!(app.selected_block, live - 1);
+    assert!(!app.older_blocks.is_empty());
+    assert_eq!(app.older_blocks.first().map(|b| b.height), Some(blocks.last().unwrap().height - live as u64));
+
+    // Scrolling keeps the selection on screen
+    app.update(TuiMessage::MoveSelection(50));
+    assert!(app.block_scroll > 0 && app.block_scroll <= app.selected_block);
+}
+
+#[test]
+fn no_color_and_dumb_terminals_get_the_monochrome_theme() {
+    assert_eq!(ThemeName::HighContrast.resolve(false, None, Some("xterm-256color")), ThemeName::HighContrast);
+    assert_eq!(ThemeName::HighContrast.resolve(true, None, None), ThemeName::Monochrome);
+    assert_eq!(ThemeName::Default.resolve(false, Some("1"), None), ThemeName::Monochrome);
+    assert_eq!(ThemeName::Default.resolve(false, Some(""), None), ThemeName::Default);
+    assert_eq!(ThemeName::Default.resolve(false, None, Some("dumb")), ThemeName::Monochrome);
+
+    let mono = Theme::named(ThemeName::Monochrome);
+    for style in [mono.title, mono.heading, mono.warn, mono.error, mono.selected, mono.bar_value] {
+        assert!(style.fg.is_none() && style.bg.is_none());
+    }
+    assert_eq!(mono.paint(mono.text, "tip"), "tip");
+    assert_eq!(mono.paint(mono.warn, "tip"), "\x1b[1mtip\x1b[0m");
+
+    let default = Theme::default();
+    assert_eq!(default.paint(default.error, "reorg"), "\x1b[31mreorg\x1b[0m");
+    assert_eq!(Theme { ansi: false, ..default }.paint(default.error, "reorg"), "reorg");
+}
+
+#[test]
+fn timestamps_render_in_the_chosen_zone() {
+    let summer = 1717250591; // 2024-06-01 14:03:11 UTC
+    let winter = 1733061791; // 2024-12-01 14:03:11 UTC
+
+    let utc = DisplayZone::parse("UTC").unwrap();
+    assert_eq!(utc, DisplayZone::Utc);
+    assert_eq!(utc.datetime(summer), "2024-06-01 14:03:11 UTC");
+    assert_eq!(utc.local_time(summer), None);
+
+    let berlin = DisplayZone::parse("europe/berlin").unwrap();
+    assert_eq!(berlin.datetime(summer), "2024-06-01 16:03:11 CEST");
+    assert_eq!(berlin.datetime(winter), "2024-12-01 15:03:11 CET");
+    assert_eq!(berlin.format(summer, "%H:%M:%S").as_deref(), Some("16:03:11"));
+    assert_eq!(berlin.local_time(summer).as_deref(), Some("2024-06-01T16:03:11+02:00"));
+
+    assert_eq!(DisplayZone::parse("local").unwrap(), DisplayZone::Local);
+    assert!(DisplayZone::parse("Mars/Olympus_Mons").is_err());
+
+    let (dir, _) = fixture(ChainSpec::default());
+    let block = BlockDetailResponse::from(read_block_with_transactions(dir.path(), 1).unwrap()).localized(&berlin);
+    assert_eq!(block.header.timestamp_local, berlin.local_time(block.header.timestamp));
+}
+
+#[test]
+fn amounts_render_in_microtari_or_tari() {
+    assert_eq!(tari(0), "0.000000");
+    assert_eq!(tari(2_500), "0.002500");
+    assert_eq!(tari(12_345_678), "12.345678");
+    assert_eq!(Denomination::Microtari.format(2_500), "2500 µT");
+    assert_eq!(Denomination::Tari.format(2_500), "0.002500 T");
+    assert_eq!(Denomination::Tari.unit(), "T");
+
+    // The API carries both units whatever --denom is
+    let (dir, _) = fixture(ChainSpec::default());
+    let block = read_block_with_transactions(dir.path(), 3).unwrap();
+    assert!(!block.transactions.kernels.is_empty());
+    for kernel in &block.transactions.kernels {
+        assert_eq!(kernel.fee_ut, kernel.fee);
+        assert_eq!(kernel.fee_t, tari(kernel.fee));
+    }
+    let json = serde_json::to_value(&block.transactions.kernels[0]).unwrap();
+    assert!(json.get("fee_ut").is_some() && json.get("fee_t").is_some());
+
+    let hit = SearchResult::Kernel {
+        height: Some(3),
+        block_hash: String::new(),
+        excess: String::new(),
+        fee: 2_500,
+        fee_ut: 2_500,
+        fee_t: tari(2_500),
+        features: String::new(),
+    };
+    assert_eq!(hit.describe(Denomination::Tari), "Kernel in block 3 (fee 0.002500 T)");
+}
+
+#[tokio::test]
+async fn tui_block_detail_exports_the_selected_block() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let config = AppConfig { cache: CacheDir::new(dir.path().join("cache")), ..app_config(dir.path().to_path_buf()) };
+    let mut app = TuiApp::new(config, 5, None, None);
+    app.viewport = ratatui::layout::Rect::new(0, 0, 120, 40);
+    app.export_dir = dir.path().to_path_buf();
+    app.update_data().await.unwrap();
+    app.update(TuiMessage::SwitchTab(TuiTab::Blocks));
+    app.update(TuiMessage::MoveSelection(1));
+
+    app.handle_input(KeyCode::Enter);
+    assert!(app.detail_open());
+    app.load_block_detail().await;
+    let expected = &blocks[blocks.len() - 2];
+    assert_eq!(app.block_detail.as_ref().map(|b| b.hash.as_str()), Some(expected.hash.as_str()));
+
+    app.handle_input(KeyCode::Char('e'));
+    let exported = std::fs::read(dir.path().join(format!("block-{}.json", expected.height))).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&exported).unwrap();
+    assert_eq!(json["height"], expected.height);
+    assert!(json["transactions"]["kernels"].is_array() && json["header"]["pow_algorithm"].is_string());
+    assert!(app.detail_status.as_deref().is_some_and(|s| s.starts_with("Exported")));
+
+    app.handle_input(KeyCode::Esc);
+    assert!(!app.detail_open() && !app.should_quit);
+}
+
+#[tokio::test]
+async fn tui_read_errors_open_a_retry_popup_and_the_error_log() {
+    let dir = tempfile::tempdir().unwrap();
+    let config = AppConfig { cache: CacheDir::new(dir.path().join("cache")), ..app_config(dir.path().join("missing")) };
+    let mut app = TuiApp::new(config, 5, None, None);
+
+    assert!(app.update_data().await.is_err());
+    let error = app.error.clone().expect("error popup open");
+    assert!(error.message.starts_with("Reading"));
+    let log_file = error.log_file.expect("error logged");
+    assert_eq!(log_file, dir.path().join("cache").join(ERROR_LOG_FILE));
+    assert!(std::fs::read_to_string(&log_file).unwrap().contains(&error.message));
+
+    // Only retry and quit reach the app while the popup is open
+    assert_eq!(app.message_for_key(KeyCode::Char('3')), None);
+    assert_eq!(app.message_for_key(KeyCode::Char('/')), None);
+    app.handle_input(KeyCode::Char('r'));
+    assert!(app.error.is_none() && !app.should_quit);
+
+    app.report_error("Panic: test".to_string(), String::new());
+    app.handle_input(KeyCode::Char('q'));
+    assert!(app.should_quit);
+}
+
+#[test]
+fn version_info_and_release_comparison() {
+    let info = VersionInfo::new("mainnet".to_string(), Some(3));
+    assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    assert_eq!((info.schema.network.as_str(), info.schema.migration_version), ("mainnet", Some(3)));
+    let json = serde_json::to_value(&info).unwrap();
+    assert!(json.get("git_hash").is_some() && json["schema"].get("tari_revision").is_some());
+
+    assert!(is_newer("v0.4.0", "0.3.0"));
+    assert!(is_newer("0.3.1", "0.3.0"));
+    assert!(is_newer("v0.3.0", "0.3.0-rc.1"));
+    assert!(!is_newer("v0.3.0", "0.3.0"));
+    assert!(!is_newer("v0.4.0-rc.1", "0.4.0"));
+    assert!(!is_newer("v0.2.9", "0.3.0"));
+    assert!(!is_newer("nightly", "0.3.0"));
+}
+
+#[cfg(unix)]
+#[test]
+fn pid_files_name_only_running_processes() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join(daemon::DEFAULT_PID_FILE);
+    assert_eq!(running_pid(&path), None);
+
+    std::fs::write(&path, format!("{}\n", std::process::id())).unwrap();
+    assert_eq!(running_pid(&path), Some(std::process::id() as i32));
+
+    // Stale or garbled PID files do not block a new daemon
+    std::fs::write(&path, format!("{}\n", i32::MAX)).unwrap();
+    assert_eq!(running_pid(&path), None);
+    std::fs::write(&path, "not a pid").unwrap();
+    assert_eq!(running_pid(&path), None);
+}
+
+#[test]
+fn table_stats_report_key_and_value_sizes() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let report = table_stats(dir.path()).unwrap();
+
+    let headers = report.tables.iter().find(|t| t.name == "headers").unwrap();
+    assert_eq!(headers.entries, blocks.len() as u64);
+    assert_eq!((headers.key_size.min, headers.key_size.max), (8, 8));
+    assert_eq!(headers.key_lengths.get(&8), Some(&(blocks.len() as u64)));
+    assert!(headers.value_size.min > 0 && headers.value_size.avg >= headers.value_size.min as f64);
+
+    let hashes = report.tables.iter().find(|t| t.name == "block_hashes").unwrap();
+    assert_eq!(hashes.key_lengths.get(&32), Some(&(blocks.len() as u64)));
+    assert_eq!(hashes.value_size.total, 8 * blocks.len() as u64);
+}
+
+#[test]
+fn prefix_histogram_groups_kernels_by_block_hash() {
+    let spec = ChainSpec::default();
+    let (dir, blocks) = fixture(spec.clone());
+
+    let by_block = prefix_histogram(dir.path(), "kernels", 32).unwrap();
+    assert_eq!(by_block.entries, blocks.len() as u64 * spec.kernels_per_block);
+    assert_eq!(by_block.buckets.len(), blocks.len());
+    assert!(by_block.buckets.iter().all(|b| b.count == spec.kernels_per_block));
+    assert!(by_block.buckets.iter().any(|b| b.prefix == blocks[3].hash));
+
+    let whole_keys = prefix_histogram(dir.path(), "headers", 64).unwrap();
+    assert_eq!(whole_keys.bucket_sizes(), (1, 1.0, 1));
+    assert!(prefix_histogram(dir.path(), "kernels", 0).is_err());
+}
+
+#[test]
+fn value_guesser_recognises_row_types() {
+    let (dir, _) = fixture(ChainSpec { spends_per_block: 1, ..ChainSpec::default() });
+    let guesses = guess_value_types(dir.path(), &[], 5).unwrap();
+    let best = |table: &str| guesses.iter().find(|g| g.table == table).and_then(|g| g.best);
+
+    assert_eq!(best("headers"), Some(RowType::BlockHeader));
+    assert_eq!(best("kernels"), Some(RowType::KernelRow));
+    assert_eq!(best("utxos"), Some(RowType::OutputRow));
+    assert_eq!(best("inputs"), Some(RowType::InputRow));
+    assert_eq!(best("block_hashes"), None);
+
+    assert!(guess_value_types(dir.path(), &["no_such_table".to_string()], 5).is_err());
+}
+
+#[test]
+fn kv_backend_reads_tables_like_the_fixture() {
+    let spec = ChainSpec::default();
+    let (dir, blocks) = fixture(spec.clone());
+    let backend = open_backend(dir.path()).unwrap();
+    assert!(["lmdb-zero", "heed"].contains(&backend.name()));
+    let read = backend.read().unwrap();
+
+    let tables = read.tables().unwrap();
+    assert!(["headers", "kernels", "block_hashes"].iter().all(|t| tables.iter().any(|name| name == t)));
+    assert_eq!(read.entries("headers").unwrap(), blocks.len() as u64);
+
+    let tip = blocks.last().unwrap();
+    let (key, _) = read.last("headers").unwrap().unwrap();
+    assert_eq!(key, tip.height.to_le_bytes());
+    let height = read.get("block_hashes", &hex::decode(&tip.hash).unwrap()).unwrap().unwrap();
+    assert_eq!(height, tip.height.to_le_bytes());
+    assert_eq!(read.get("block_hashes", &[0; 32]).unwrap(), None);
+
+    // A scan from a block hash stops at the end of its kernels
+    let block = &blocks[3];
+    let prefix = hex::decode(&block.hash).unwrap();
+    let mut kernels = 0;
+    read.scan("kernels", &prefix, &mut |key, _| {
+        if !key.starts_with(&prefix) {
+            return false;
+        }
+        kernels += 1;
+        true
+    }).unwrap();
+    assert_eq!(kernels, spec.kernels_per_block);
+
+    assert!(matches!(read.entries("no_such_table"), Err(ReaderError::TableNotFound(_))));
+}
+
+#[test]
+fn lookups_and_cursors_interleave_on_one_read() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    for kind in BackendKind::available() {
+        let backend = kind.open(dir.path()).unwrap();
+        let read = backend.read().unwrap();
+        let mut headers = read.cursor("headers").unwrap();
+        let mut kernels = read.cursor("kernels").unwrap();
+
+        let mut entry = headers.first().unwrap();
+        let mut walked = 0;
+        while let Some((key, _)) = entry {
+            let hash = hex::decode(&blocks[walked].hash).unwrap();
+            assert_eq!(read.get("block_hashes", &hash).unwrap().as_deref(), Some(key), "{}", kind.name());
+            assert!(kernels.seek(&hash).unwrap().is_some_and(|(k, _)| k.starts_with(&hash)), "{}", kind.name());
+            walked += 1;
+            entry = headers.next().unwrap();
+        }
+        assert_eq!(walked, blocks.len(), "{}", kind.name());
+    }
+}
+
+#[test]
+fn reader_results_match_on_every_backend() {
+    let spec = ChainSpec { spends_per_block: 1, ..ChainSpec::default() };
+    let (dir, blocks) = fixture(spec.clone());
+    let tip = blocks.last().unwrap();
+
+    let results: Vec<String> = BackendKind::available().into_iter().map(|kind| with_backend(kind, || {
+        assert_eq!(open_backend(dir.path()).unwrap().name(), kind.name());
+
+        let block = read_block(dir.path(), 4, BlockInclude { transactions: true, counts: true }).unwrap();
+        assert_eq!(block.hash, blocks[4].hash);
+        assert_eq!(block.counts.unwrap().kernels, spec.kernels_per_block);
+        let range = read_lmdb_headers_with_filter(dir.path(), "headers", BlockFilter::Range(3, 7)).unwrap();
+        assert_eq!(range.iter().map(|b| b.height).collect::<Vec<_>>(), vec![3, 4, 5, 6, 7]);
+        let last = read_lmdb_headers_with_filter(dir.path(), "headers", BlockFilter::LastN(3)).unwrap();
+        assert_eq!(last.iter().map(|b| b.height).collect::<Vec<_>>(), vec![tip.height - 2, tip.height - 1, tip.height]);
+        let desc = iter_headers_desc(dir.path(), Some(5), 10).unwrap();
+        assert_eq!(desc.iter().map(|b| b.height).collect::<Vec<_>>(), vec![5, 4, 3, 2, 1, 0]);
+        let page = read_block_transactions(dir.path(), 4, TxComponent::Outputs, Page::new(Some(1), Some(2))).unwrap();
+
+        let reader = StreamReader::open(dir.path()).unwrap();
+        let snapshot = reader.snapshot().unwrap();
+        assert_eq!(snapshot.tip_height().unwrap(), Some(tip.height));
+        assert_eq!(snapshot.headers(0, tip.height).unwrap().count(), blocks.len());
+        let kernels: Vec<TransactionKernelRowData> = snapshot.kernels(&hex::decode(&blocks[2].hash).unwrap()).unwrap()
+            .collect::<Result<_, _>>().unwrap();
+        assert_eq!(kernels.len() as u64, spec.kernels_per_block);
+
+        let found = search(dir.path(), &SearchQuery::parse(&tip.hash).unwrap()).unwrap();
+        assert_eq!(found, SearchResult::Block { height: tip.height, hash: tip.hash.clone() });
+        let metadata = read_chain_metadata(dir.path()).unwrap().unwrap();
+
+        serde_json::to_string(&(block, range, last, desc, page.transactions, found, metadata)).unwrap()
+    })).collect();
+
+    assert!(results.windows(2).all(|pair| pair[0] == pair[1]), "backends disagree");
+}
+
+#[test]
+fn header_view_matches_the_full_header_decode() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let backend = open_backend(dir.path()).unwrap();
+    let read = backend.read().unwrap();
+
+    let block = &blocks[4];
+    let data = read.get("headers", &block.height.to_le_bytes()).unwrap().unwrap();
+    let view = HeaderView::parse(&data).expect("fixture headers have the linked layout");
+    assert_eq!((view.height, view.timestamp, view.raw_length), (block.height, block.timestamp, data.len()));
+
+    // The list view's summary is the detail view's header
+    let listed = BlockSummary::from((block.height, block.hash.clone(), view));
+    let detail = read_block_with_transactions(dir.path(), block.height).unwrap();
+    assert_eq!(serde_json::to_value(&listed.header).unwrap(), serde_json::to_value(&detail.header).unwrap());
+
+    // Rows that do not end where the header does are left to the full decode
+    assert_eq!(HeaderView::parse(&data[..data.len() - 1]), None);
+    assert_eq!(HeaderView::parse(&[data.as_slice(), &[0]].concat()), None);
+
+    let listed = read_lmdb_headers_with_filter(dir.path(), "headers", BlockFilter::Range(0, 9)).unwrap();
+    assert_eq!(listed[4].hash, block.hash);
+    assert_eq!(listed[4].kernel_count, detail.header.kernel_mmr_size.checked_sub(listed[3].header.kernel_mmr_size));
+}
+
+#[test]
+fn root_verification_rebuilds_the_kernel_mmr_and_flags_bad_rows() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let cancel = CancellationToken::new();
+    let report = verify_roots(dir.path(), 5, true, &NoProgress, &cancel).unwrap();
+    assert_eq!(report.hash, blocks[5].hash);
+
+    // Every kernel up to the block is a leaf; the fixture's headers carry zero roots
+    let kernels = &report.checks[0];
+    assert_eq!(kernels.root, "kernel_mr");
+    assert_eq!(kernels.leaves, 6 * 2);
+    assert!(kernels.computed.is_some());
+    assert!(!kernels.matches);
+    let outputs = &report.checks[1];
+    assert_eq!((outputs.root.as_str(), outputs.leaves), ("block_output_mr", 3));
+
+    // Fixture row hashes are not content hashes, so each row is reported as damaged
+    assert!(report.problems.iter().any(|p| p.starts_with("Stored hash does not match the content of kernels")));
+    assert!(!report.is_ok());
+
+    // The MMR grows with the chain
+    let later = verify_roots(dir.path(), 6, false, &NoProgress, &cancel).unwrap();
+    assert_eq!(later.checks.len(), 1);
+    assert_ne!(later.checks[0].computed, kernels.computed);
+
+    cancel.cancel();
+    assert!(matches!(verify_roots(dir.path(), 5, false, &NoProgress, &cancel), Err(ReaderError::Cancelled)));
+}
+
+#[test]
+fn timestamp_anomalies_follow_the_median_and_future_rules() {
+    assert_eq!(median_timestamp(&[]), None);
+    assert_eq!(median_timestamp(&[30, 10, 20]), Some(20));
+    assert_eq!(median_timestamp(&[10, 20, 30, 40]), Some(25));
+
+    // Block 13 is older than its parent but not than the median; block 14 is older than both
+    let mut timestamps: Vec<(u64, u64)> = (0..16).map(|h| (h, 1_000 + h * 120)).collect();
+    timestamps[13].1 = timestamps[12].1 - 60;
+    timestamps[14].1 = 1_000;
+    let report = anomalies_from_timestamps(&timestamps, 5, 10_000);
+    assert_eq!((report.window, report.start_height, report.end_height), (11, 5, 15));
+    assert_eq!(report.non_positive_intervals, 2);
+    assert_eq!(report.anomalies.len(), 1);
+    let anomaly = &report.anomalies[0];
+    assert_eq!((anomaly.height, anomaly.kind), (14, AnomalyKind::BeforeMedian));
+    assert_eq!(anomaly.reference, 1_000 + 8 * 120);
+    assert_eq!(anomaly.seconds, 8 * 120);
+
+    // Against the fixture: a clock before the chain puts the later blocks in the future
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let tip = blocks.last().unwrap();
+    let report = timestamp_anomalies(dir.path(), 10, tip.timestamp).unwrap();
+    assert_eq!((report.window, report.start_height, report.end_height), (10, 10, 19));
+    assert!(report.anomalies.is_empty());
+    let early = tip.timestamp - FUTURE_TIME_LIMIT - 4 * 120 - 1;
+    let report = timestamp_anomalies(dir.path(), 10, early).unwrap();
+    assert!(report.anomalies.iter().all(|a| a.kind == AnomalyKind::FutureDrift && a.reference == early));
+    assert_eq!(report.anomalies.iter().map(|a| a.height).collect::<Vec<_>>(), vec![15, 16, 17, 18, 19]);
+}
+
+#[test]
+fn double_spend_pass_reports_outputs_spent_twice() {
+    let (dir, blocks) = fixture(ChainSpec { spends_per_block: 1, ..ChainSpec::default() });
+    let cancel = CancellationToken::new();
+    let report = find_double_spends(dir.path(), &NoProgress, &cancel).unwrap();
+    assert_eq!((report.inputs, report.spent_outputs, report.undecodable), (19, 19, 0));
+    assert!(report.is_ok());
+
+    // Output 0 of block 4 is already spent at height 5
+    add_duplicate_spend(dir.path(), 12, &blocks[12].hash, 4, 0).unwrap();
+    let report = find_double_spends(dir.path(), &NoProgress, &cancel).unwrap();
+    assert_eq!((report.inputs, report.spent_outputs), (20, 19));
+    assert_eq!(report.collisions.len(), 1);
+    assert_eq!(report.collisions[0].heights, vec![5, 12]);
+    assert!(!report.is_ok());
+
+    cancel.cancel();
+    assert!(matches!(find_double_spends(dir.path(), &NoProgress, &cancel), Err(ReaderError::Cancelled)));
+}
+
+#[test]
+fn follow_audit_checks_links_mmr_sizes_and_timestamps() {
+    let (dir, blocks) = fixture(ChainSpec { spends_per_block: 1, burns_per_block: 1, ..ChainSpec::default() });
+    let now = blocks.last().unwrap().timestamp;
+    let audits = audit_blocks(dir.path(), 0, 19, now).unwrap();
+    assert_eq!(audits.len(), 20);
+    assert_eq!(audits[7].hash, blocks[7].hash);
+    assert!(audits.iter().all(|audit| audit.is_ok()), "{:?}", audits.iter().find(|audit| !audit.is_ok()));
+
+    let mut status = AuditStatus::new(now);
+    status.record(&audits[18..], now);
+    assert_eq!((status.blocks_checked, status.blocks_failed, status.last_height), (2, 0, Some(19)));
+    assert!(status.last_block_ok);
+
+    // A second spend of an output makes block 12's inputs disagree with its output_smt_size
+    add_duplicate_spend(dir.path(), 12, &blocks[12].hash, 4, 0).unwrap();
+    let audits = audit_blocks(dir.path(), 12, 12, now).unwrap();
+    assert_eq!(audits.len(), 1);
+    assert_eq!(audits[0].findings.iter().map(|f| f.rule).collect::<Vec<_>>(), vec![AuditRule::OutputSmtSize]);
+    status.record(&audits, now);
+    assert_eq!((status.blocks_checked, status.blocks_failed, status.findings.len()), (3, 1, 1));
+    assert!(!status.last_block_ok);
+}
+
+#[test]
+fn verbosity_levels_gate_logs_in_order() {
+    assert_eq!(Verbosity::default(), Verbosity::Normal);
+    assert!(Verbosity::Quiet < Verbosity::Normal && Verbosity::Normal < Verbosity::Debug);
+
+    Verbosity::Debug.apply();
+    assert_eq!(Verbosity::current(), Verbosity::Debug);
+    assert!(verbosity::normal() && verbosity::debug());
+    Verbosity::Quiet.apply();
+    assert!(!verbosity::normal() && !verbosity::debug());
+    Verbosity::Normal.apply();
+    assert!(verbosity::normal() && !verbosity::debug());
+}
+
+#[tokio::test]
+async fn request_metrics_sum_latency_and_lmdb_read_time_per_route() {
+    let (dir, _) = fixture(ChainSpec::default());
+    let reader = AsyncReader::new(dir.path(), Duration::from_secs(5));
+
+    // Only reads awaited inside the scope are counted
+    let (block, lmdb) = measure_reads(reader.block(5, BlockInclude::default())).await;
+    assert_eq!(block.unwrap().height, 5);
+    assert!(lmdb > Duration::ZERO);
+    let (_, idle) = measure_reads(async {}).await;
+    assert_eq!(idle, Duration::ZERO);
+
+    let metrics = RequestMetrics::new();
+    metrics.record("/api/v1/block/:height", 200, Duration::from_millis(40), lmdb);
+    metrics.record("/api/v1/block/:height", 404, Duration::from_millis(10), Duration::ZERO);
+    metrics.record("/api/v1/block/:height", 503, SLOW_REQUEST, Duration::from_millis(900));
+    let stats = metrics.route("/api/v1/block/:height").unwrap();
+    assert_eq!((stats.requests, stats.client_errors, stats.server_errors, stats.slow), (3, 1, 1, 1));
+    assert_eq!(stats.latency_max, SLOW_REQUEST);
+    assert_eq!(stats.lmdb_total, lmdb + Duration::from_millis(900));
+    assert!(metrics.route("/metrics").is_none());
+
+    let text = metrics.render_prometheus();
+    assert!(text.contains("inspector_http_requests_total{route=\"/api/v1/block/:height\"} 3"));
+    assert!(text.contains("inspector_http_server_errors_total{route=\"/api/v1/block/:height\"} 1"));
+    assert!(text.contains("inspector_http_request_seconds_max{route=\"/api/v1/block/:height\"} 1.000000"));
+}
+
+#[test]
+fn response_limits_name_the_cap_that_was_exceeded() {
+    let limits = Limits { max_blocks: 10, max_tx_items: 100, max_ws_message_bytes: 1024 };
+    assert!(limits.check_blocks(10).is_ok() && limits.check_tx_items(100).is_ok() && limits.check_ws_message(1024).is_ok());
+
+    let error = limits.check_blocks(11).unwrap_err();
+    assert_eq!((error.limit, error.max, error.requested), (LimitKind::Blocks, 10, 11));
+    let error = limits.check_tx_items(250).unwrap_err();
+    assert_eq!((error.limit, error.max, error.requested), (LimitKind::TransactionItems, 100, 250));
+    assert!(error.message.contains("include=counts"));
+
+    // The WebSocket carries the same body as the REST error
+    let message = WebSocketMessage::LimitExceeded { error: limits.check_ws_message(4096).unwrap_err() };
+    let json: serde_json::Value = serde_json::to_value(&message).unwrap();
+    assert_eq!(json["type"], "LimitExceeded");
+    assert_eq!(json["error"]["limit"], "ws_message_bytes");
+    assert_eq!(json["error"]["requested"], 4096);
+}
+
+#[test]
+fn reader_errors_map_to_one_http_status_each() {
+    use axum::http::{header, StatusCode};
+    use axum::response::IntoResponse;
+
+    assert_eq!(ReaderError::BlockNotFound(7).status(), StatusCode::NOT_FOUND);
+    assert_eq!(ReaderError::BelowPruningHorizon { height: 1, horizon: 5 }.status(), StatusCode::GONE);
+    assert_eq!(ReaderError::SchemaMismatch { table: "headers", message: "4-byte key".to_string() }.status(), StatusCode::CONFLICT);
+    assert_eq!(ReaderError::Task { operation: "block".to_string(), message: "panicked".to_string() }.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    // Reads that may succeed later tell the client when to retry
+    for error in [ReaderError::Timeout { operation: "block".to_string(), seconds: 5 }, ReaderError::LmdbBusy("readers full".to_string())] {
+        let response = error.into_response();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(response.headers().contains_key(header::RETRY_AFTER));
+    }
+
+    // key_inspector reports typed errors too
+    let (dir, _) = fixture(ChainSpec::default());
+    assert!(matches!(raw_header(dir.path(), 10_000), Err(ReaderError::BlockNotFound(10_000))));
+    assert!(matches!(prefix_histogram(dir.path(), "kernels", 0), Err(ReaderError::InvalidArgument(_))));
+}
+
+#[test]
+fn shutdown_notice_is_a_plain_websocket_message() {
+    let json = serde_json::to_value(&WebSocketMessage::Shutdown).unwrap();
+    assert_eq!(json, serde_json::json!({ "type": "Shutdown" }));
+    assert!(matches!(serde_json::from_value(json).unwrap(), WebSocketMessage::Shutdown));
+    assert_eq!((Signal::Interrupt.name(), Signal::Terminate.name()), ("SIGINT", "SIGTERM"));
+
+    // Restoring a terminal the TUI never took over does nothing
+    restore_terminal();
+}
+
+#[test]
+fn annotated_header_layout_covers_every_byte() {
+    let (dir, blocks) = fixture(ChainSpec::default());
+    let block = &blocks[3];
+    let data = raw_header(dir.path(), block.height).unwrap();
+    let layout = annotate_header(&data);
+    assert!(layout.matches);
+    assert!(layout.unparsed.is_empty());
+
+    // Fields are contiguous and end where the row does
+    let mut offset = 0;
+    for field in &layout.fields {
+        assert_eq!(field.offset, offset, "{}", field.name);
+        assert_eq!(field.raw.len(), field.length * 2);
+        offset += field.length;
+    }
+    assert_eq!(offset, data.len());
+
+    let value = |name: &str| layout.fields.iter().find(|f| f.name == name).map(|f| f.value.clone()).unwrap();
+    assert_eq!(value("height"), block.height.to_string());
+    assert_eq!(value("timestamp"), block.timestamp.to_string());
+    assert_eq!(value("total_kernel_offset (length)"), "32");
+
+    // A row cut short keeps the fields that fit and reports the rest as unparsed
+    let cut = annotate_header(&data[..60]);
+    assert!(!cut.matches);
+    assert_eq!(cut.fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(), ["version", "height", "prev_hash", "timestamp"]);
+    assert_eq!(cut.unparsed.len(), 10 * 2);
+    assert!(raw_header(dir.path(), 10_000).is_err());
+}
+
+#[test]
+fn pattern_heights_follow_the_tip() {
+    let (dir, blocks) = fixture(ChainSpec { blocks: 100, ..ChainSpec::default() });
+    let tip = tip_height(dir.path()).unwrap();
+    assert_eq!(tip, blocks.last().unwrap().height);
+
+    let heights = pattern_heights(tip, 7);
+    assert_eq!(&heights[..3], &[99, 98, 89]);
+    assert!(heights[3] >= tip / 4 && heights[3] <= tip * 3 / 4);
+
+    // Short chains do not repeat heights
+    assert_eq!(pattern_heights(0, 12345), vec![0]);
+}